@@ -20,7 +20,7 @@ Usage:
     >>> user_logger.info("User action")
 
     >>> # Custom levels
-    >>> logger.level("NOTICE", no=25, color="cyan")
+    >>> logger.level("NOTICE", no=22, color="cyan")
     >>> logger.log("NOTICE", "Custom level message")
 
     >>> # Color markup
@@ -52,16 +52,26 @@ Integrations (logust.contrib):
 
 from __future__ import annotations
 
+import atexit
 from typing import TYPE_CHECKING, Any
 
-from ._logger import CallerInfo, CollectOptions, Logger, ProcessInfo, ThreadInfo
-from ._logust import LogLevel, PyLogger, Rotation
+from ._logger import (
+    CallerInfo,
+    CollectOptions,
+    ConfigSnapshot,
+    Logger,
+    ProcessInfo,
+    ThreadInfo,
+)
+from ._logust import HandlerHandle, LogLevel, PyLogger, Rotation, _shutdown
 from ._logust import logger as _rust_logger
 from ._opt import OptLogger
 from ._parse import parse, parse_json
 from ._types import (
     FilterCallback,
+    FormatValidation,
     HandlerConfig,
+    HandlerStats,
     LevelConfig,
     LogCallback,
     LogRecord,
@@ -86,6 +96,9 @@ __version__ = "0.4.1"
 
 logger = Logger(_rust_logger)
 
+# Flush every logger (and join enqueue=True writers) even if complete() is never called
+atexit.register(_shutdown)
+
 
 def __getattr__(name: str) -> Any:
     if hasattr(logger, name):
@@ -96,8 +109,12 @@ def __getattr__(name: str) -> Any:
 __all__ = [
     "CallerInfo",
     "CollectOptions",
+    "ConfigSnapshot",
     "FilterCallback",
+    "FormatValidation",
     "HandlerConfig",
+    "HandlerHandle",
+    "HandlerStats",
     "LevelConfig",
     "LogCallback",
     "LogLevel",
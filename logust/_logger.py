@@ -7,15 +7,28 @@ import os
 import re
 import string
 import sys
-import threading
-import traceback
-from collections.abc import Callable, Generator, Mapping
+from collections.abc import Callable, Generator, Iterable, Mapping
 from contextlib import contextmanager
 from dataclasses import dataclass
+from datetime import datetime
 from typing import TYPE_CHECKING, Any, TextIO, cast
 
-from ._logust import LogLevel, PyLogger
-from ._template import CALLER_TOKENS, KNOWN_TOKENS, ParsedCallableTemplate
+from ._logust import ConfigSnapshot as _RustConfigSnapshot
+from ._logust import (
+    HandlerHandle,
+    LogLevel,
+    PyLogger,
+    _format_exception,
+    _validate_format,
+)
+from ._template import (
+    CALLER_TOKENS,
+    JSON_RECORD_KEYS,
+    KNOWN_TOKENS,
+    TRACE_CONTEXT_KEYS,
+    ParsedCallableTemplate,
+    to_utc_timestamp,
+)
 
 
 @dataclass(frozen=True, slots=True)
@@ -75,7 +88,7 @@ class CollectOptions:
 # Token pattern for format analysis (matches known tokens only)
 # Built from KNOWN_TOKENS to ensure consistency with ParsedCallableTemplate
 _FORMAT_TOKEN_PATTERN = re.compile(
-    r"\{(" + "|".join(re.escape(t) for t in KNOWN_TOKENS) + r"|extra\[[^\]]+\])(?::[^}]+)?\}"
+    r"\{(" + "|".join(re.escape(t) for t in KNOWN_TOKENS) + r"|extra(?:\[[^\]]+\])+)(?::[^}]+)?\}"
 )
 _FORMATTER = string.Formatter()
 
@@ -123,6 +136,65 @@ def _split_kwargs_for_format(message: Any, kwargs: dict[str, Any]) -> tuple[str,
     return formatted_message, extra_kwargs
 
 
+def _timestamp_arg(value: int | str | datetime) -> int | str:
+    """Record ``timestamp`` in the form the Rust side parses (datetimes as ISO 8601)."""
+    return value.isoformat() if isinstance(value, datetime) else value
+
+
+def _context_filter_predicate(
+    spec: str | Mapping[str, str],
+) -> Callable[[dict[str, Any]], bool]:
+    """Python version of a native ``filter`` (module name or extra patterns), for callable
+    sinks. Matches like ``ContextFilter`` in src/filter.rs."""
+    if isinstance(spec, str):
+        prefix = spec
+
+        def name_matches(record: dict[str, Any]) -> bool:
+            name = record.get("name") or ""
+            return not prefix or name == prefix or name.startswith(prefix + ".")
+
+        return name_matches
+    if not isinstance(spec, Mapping):
+        raise TypeError("filter must be a callable, a module name or a dict of extra patterns")
+    patterns = {
+        key: re.compile(
+            "".join(
+                ".*" if c == "*" else "." if c == "?" else re.escape(c) for c in str(pattern)
+            ),
+            re.DOTALL,
+        )
+        for key, pattern in spec.items()
+    }
+
+    def extra_matches(record: dict[str, Any]) -> bool:
+        extra = record.get("extra") or {}
+        return all(
+            key in extra and pattern.fullmatch(str(extra[key])) is not None
+            for key, pattern in patterns.items()
+        )
+
+    return extra_matches
+
+
+_DEFAULT_FORMAT = "{time} | {level:<8} | {name}:{function}:{line} - {message}"
+
+
+def _default_format() -> str:
+    """Template for handlers added without one: ``LOGUST_FORMAT`` or the built-in default."""
+    return os.environ.get("LOGUST_FORMAT") or _DEFAULT_FORMAT
+
+
+@dataclass(frozen=True, slots=True)
+class ConfigSnapshot:
+    """Opaque token returned by ``Logger.save_config()``; pass it to ``restore_config()``."""
+
+    _inner: _RustConfigSnapshot
+    _collect_options: dict[int, CollectOptions]
+    _callback_ids: frozenset[int]
+    _filter_ids: frozenset[int]
+    _raw_callback_ids: frozenset[int]
+
+
 def _collect_options_from_format(format_str: str) -> CollectOptions:
     """Compute CollectOptions from a format string.
 
@@ -156,11 +228,11 @@ def _collect_options_from_format(format_str: str) -> CollectOptions:
 
 
 if TYPE_CHECKING:
+    from pathlib import Path
+
     from ._opt import OptLogger
+    from ._types import FormatValidation, HandlerStats
 
-# Cached process info (invalidated on fork by checking PID)
-_CACHED_PROCESS_INFO: tuple[str, int] | None = None
-_CACHED_PROCESS_PID: int | None = None
 
 
 def _get_caller_info(depth: int = 1) -> tuple[str, str, int, str]:
@@ -184,40 +256,28 @@ def _get_caller_info(depth: int = 1) -> tuple[str, str, int, str]:
         return ("", "", 0, "")
 
 
-def _get_thread_info() -> tuple[str, int]:
-    """Get current thread name and ID.
-
-    Returns:
-        Tuple of (thread_name, thread_id)
-    """
-    thread = threading.current_thread()
-    return (thread.name, thread.ident or 0)
+_PACKAGE_DIR = os.path.dirname(os.path.abspath(__file__)) + os.sep
 
 
-def _get_process_info() -> tuple[str, int]:
-    """Get current process name and ID.
+def _get_stacklevel(depth: int) -> int:
+    """Get the stack level of the frame ``depth`` levels above the caller of this function.
 
-    Caches the result, but invalidates cache after fork (detected by PID change).
+    Frames inside logust are not counted, so the code that called the logger is
+    level 1 and each frame skipped with ``opt(depth=...)`` adds one.
 
-    Returns:
-        Tuple of (process_name, process_id)
+    Args:
+        depth: Number of frames to go back from the caller of this function
+               (the same value passed to _get_caller_info)
     """
-    global _CACHED_PROCESS_INFO, _CACHED_PROCESS_PID
-    current_pid = os.getpid()
-
-    # Invalidate cache if PID changed (fork occurred)
-    if _CACHED_PROCESS_INFO is not None and _CACHED_PROCESS_PID == current_pid:
-        return _CACHED_PROCESS_INFO
-
-    try:
-        import multiprocessing
-
-        name = multiprocessing.current_process().name
-    except Exception:
-        name = "MainProcess"
-    _CACHED_PROCESS_INFO = (name, current_pid)
-    _CACHED_PROCESS_PID = current_pid
-    return _CACHED_PROCESS_INFO
+    frame = sys._getframe(1)
+    internal = 0
+    while internal < depth:
+        outer = frame.f_back
+        if outer is None or not outer.f_code.co_filename.startswith(_PACKAGE_DIR):
+            break
+        frame = outer
+        internal += 1
+    return max(depth - internal, 1)
 
 
 def _to_log_level(level: LogLevel | str) -> LogLevel:
@@ -369,6 +429,10 @@ class Logger:
             | None
         ] = aggregated_options_box if aggregated_options_box is not None else [None]
 
+    def __repr__(self) -> str:
+        """Summarize handlers, e.g. ``<Logger handlers=[Console(stderr, level=DEBUG)]>``."""
+        return repr(self._inner)
+
     def _invalidate_requirements_cache(self) -> None:
         """Invalidate all caches (call when handlers change)."""
         self._requirements_cache_box[0] = None
@@ -583,7 +647,8 @@ class Logger:
         elif thread_fixed is not None:
             needs_thread = thread_fixed
         elif thread_false:
-            needs_thread = False
+            # Pass explicit empty values so Rust does not fill thread info natively.
+            needs_thread = ThreadInfo() if merged_thread else False
         else:
             needs_thread = False
 
@@ -595,7 +660,7 @@ class Logger:
         elif process_fixed is not None:
             needs_process = process_fixed
         elif process_false:
-            needs_process = False
+            needs_process = ProcessInfo() if merged_proc else False
         else:
             needs_process = False
 
@@ -622,7 +687,10 @@ class Logger:
         if not self._patchers:
             return message_str, exception, extra
 
-        base_extra = dict(self._context)
+        # Lazily bound values are computed here so patchers see (and may replace) the result
+        base_extra = {
+            key: value() if callable(value) else value for key, value in self._context.items()
+        }
         if extra:
             base_extra.update(extra)
         original_extra_keys = {str(key) for key in base_extra}
@@ -661,6 +729,66 @@ class Logger:
             extra_out,
         )
 
+    def _resolve_record_info(
+        self,
+        needs_caller: bool | CallerInfo,
+        needs_thread: bool | ThreadInfo,
+        needs_process: bool | ProcessInfo,
+        depth: int,
+    ) -> tuple[
+        str | None,
+        str | None,
+        int | None,
+        str | None,
+        int | None,
+        str | None,
+        int | None,
+        str | None,
+        int | None,
+    ]:
+        """Collect caller/thread/process fields per the effective requirements.
+
+        Args:
+            depth: Number of frames to go back from the caller of this method.
+        """
+        # Handle caller info
+        c_name: str | None
+        c_function: str | None
+        c_line: int | None
+        c_file: str | None
+        stacklevel: int | None = None
+        if needs_caller is True:
+            c_name, c_function, c_line, c_file = _get_caller_info(depth + 1)
+            stacklevel = _get_stacklevel(depth + 1)
+        elif needs_caller is not False:
+            c_name, c_function, c_line, c_file = (
+                needs_caller.name,
+                needs_caller.function,
+                needs_caller.line,
+                needs_caller.file,
+            )
+        else:
+            c_name, c_function, c_line, c_file = None, None, None, None
+
+        # Thread/process info: when needed, leave both fields None so Rust resolves them
+        t_name: str | None
+        t_id: int | None
+        if isinstance(needs_thread, ThreadInfo):
+            t_name = needs_thread.name
+            t_id = needs_thread.id
+        else:
+            t_name, t_id = None, None
+
+        p_name: str | None
+        p_id: int | None
+        if isinstance(needs_process, ProcessInfo):
+            p_name = needs_process.name
+            p_id = needs_process.id
+        else:
+            p_name, p_id = None, None
+
+        return c_name, c_function, c_line, c_file, stacklevel, t_name, t_id, p_name, p_id
+
     def _log_with_level(
         self,
         level_value: int,
@@ -669,15 +797,22 @@ class Logger:
         exception: str | None,
         depth: int,
         kwargs: dict[str, Any] | None = None,
+        extra: dict[str, Any] | None = None,
+        timestamp: int | str | datetime | None = None,
+        end: str | None = None,
     ) -> None:
         if level_value < self._inner.min_level:
             return
+        if timestamp is not None:
+            timestamp = _timestamp_arg(timestamp)
 
         extra_kwargs: dict[str, Any] | None = None
         if kwargs:
             message, extra_kwargs = _split_kwargs_for_format(message, kwargs)
             if not extra_kwargs:
                 extra_kwargs = None
+        if extra:
+            extra_kwargs = {**extra, **extra_kwargs} if extra_kwargs else dict(extra)
 
         message, exception, extra_kwargs = self._apply_patchers(
             level_name=level_name,
@@ -687,7 +822,8 @@ class Logger:
             extra=extra_kwargs,
         )
 
-        inner = self._inner if extra_kwargs is None else self._inner.bind(extra_kwargs)
+        # Per-call extra is merged into this record only (no bound logger is created)
+        inner = self._inner
 
         # Compute effective requirements considering CollectOptions
         needs_caller, needs_thread, needs_process = self._compute_effective_requirements(
@@ -696,14 +832,25 @@ class Logger:
 
         if needs_caller is False and needs_thread is False and needs_process is False:
             if exception is None:
-                getattr(inner, level_name)(str(message))
+                getattr(inner, level_name)(
+                    str(message), extra=extra_kwargs, timestamp=timestamp, end=end
+                )
             else:
-                getattr(inner, level_name)(str(message), exception=exception)
+                getattr(inner, level_name)(
+                    str(message),
+                    exception=exception,
+                    extra=extra_kwargs,
+                    timestamp=timestamp,
+                    end=end,
+                )
             return
 
         if needs_thread is False and needs_process is False:
+            stacklevel: int | None
             if needs_caller is True:
                 name, function, line, file = _get_caller_info(depth + 1)
+                # depth == 1 is a direct call from trace()/info()/...; skip the frame walk
+                stacklevel = 1 if depth == 1 else _get_stacklevel(depth + 1)
             else:
                 # needs_caller is CallerInfo (False case already returned above)
                 assert isinstance(needs_caller, CallerInfo)
@@ -713,9 +860,18 @@ class Logger:
                     needs_caller.line,
                     needs_caller.file,
                 )
+                stacklevel = None
             if exception is None:
                 getattr(inner, level_name)(
-                    str(message), name=name, function=function, line=line, file=file
+                    str(message),
+                    name=name,
+                    function=function,
+                    line=line,
+                    file=file,
+                    extra=extra_kwargs,
+                    stacklevel=stacklevel,
+                    timestamp=timestamp,
+                    end=end,
                 )
             else:
                 getattr(inner, level_name)(
@@ -725,47 +881,16 @@ class Logger:
                     function=function,
                     line=line,
                     file=file,
+                    extra=extra_kwargs,
+                    stacklevel=stacklevel,
+                    timestamp=timestamp,
+                    end=end,
                 )
             return
 
-        # Handle caller info
-        c_name: str | None
-        c_function: str | None
-        c_line: int | None
-        c_file: str | None
-        if needs_caller is True:
-            c_name, c_function, c_line, c_file = _get_caller_info(depth + 1)
-        elif needs_caller is not False:
-            c_name, c_function, c_line, c_file = (
-                needs_caller.name,
-                needs_caller.function,
-                needs_caller.line,
-                needs_caller.file,
-            )
-        else:
-            c_name, c_function, c_line, c_file = None, None, None, None
-
-        # Handle thread info
-        t_name: str | None
-        t_id: int | None
-        if needs_thread is True:
-            t_name, t_id = _get_thread_info()
-        elif needs_thread is not False:
-            t_name = needs_thread.name
-            t_id = needs_thread.id
-        else:
-            t_name, t_id = None, None
-
-        # Handle process info
-        p_name: str | None
-        p_id: int | None
-        if needs_process is True:
-            p_name, p_id = _get_process_info()
-        elif needs_process is not False:
-            p_name = needs_process.name
-            p_id = needs_process.id
-        else:
-            p_name, p_id = None, None
+        c_name, c_function, c_line, c_file, c_stacklevel, t_name, t_id, p_name, p_id = (
+            self._resolve_record_info(needs_caller, needs_thread, needs_process, depth + 1)
+        )
 
         if exception is None:
             getattr(inner, level_name)(
@@ -778,6 +903,10 @@ class Logger:
                 thread_id=t_id,
                 process_name=p_name,
                 process_id=p_id,
+                extra=extra_kwargs,
+                stacklevel=c_stacklevel,
+                timestamp=timestamp,
+                end=end,
             )
         else:
             getattr(inner, level_name)(
@@ -791,120 +920,385 @@ class Logger:
                 thread_id=t_id,
                 process_name=p_name,
                 process_id=p_id,
+                extra=extra_kwargs,
+                stacklevel=c_stacklevel,
+                timestamp=timestamp,
+                end=end,
             )
 
     def trace(
-        self, message: str, *, exception: str | None = None, _depth: int = 0, **kwargs: Any
+        self,
+        message: str,
+        *,
+        exception: str | None = None,
+        extra: dict[str, Any] | None = None,
+        timestamp: int | str | datetime | None = None,
+        end: str | None = None,
+        _depth: int = 0,
+        **kwargs: Any,
     ) -> None:
         """Output TRACE level log message."""
-        self._log_with_level(5, "trace", message, exception, _depth + 1, kwargs)
+        self._log_with_level(
+            5, "trace", message, exception, _depth + 1, kwargs, extra, timestamp, end
+        )
 
     def debug(
-        self, message: str, *, exception: str | None = None, _depth: int = 0, **kwargs: Any
+        self,
+        message: str,
+        *,
+        exception: str | None = None,
+        extra: dict[str, Any] | None = None,
+        timestamp: int | str | datetime | None = None,
+        end: str | None = None,
+        _depth: int = 0,
+        **kwargs: Any,
     ) -> None:
         """Output DEBUG level log message."""
-        self._log_with_level(10, "debug", message, exception, _depth + 1, kwargs)
+        self._log_with_level(
+            10, "debug", message, exception, _depth + 1, kwargs, extra, timestamp, end
+        )
 
     def info(
-        self, message: str, *, exception: str | None = None, _depth: int = 0, **kwargs: Any
+        self,
+        message: str,
+        *,
+        exception: str | None = None,
+        extra: dict[str, Any] | None = None,
+        timestamp: int | str | datetime | None = None,
+        end: str | None = None,
+        _depth: int = 0,
+        **kwargs: Any,
     ) -> None:
         """Output INFO level log message."""
-        self._log_with_level(20, "info", message, exception, _depth + 1, kwargs)
+        self._log_with_level(
+            20, "info", message, exception, _depth + 1, kwargs, extra, timestamp, end
+        )
 
     def success(
-        self, message: str, *, exception: str | None = None, _depth: int = 0, **kwargs: Any
+        self,
+        message: str,
+        *,
+        exception: str | None = None,
+        extra: dict[str, Any] | None = None,
+        timestamp: int | str | datetime | None = None,
+        end: str | None = None,
+        _depth: int = 0,
+        **kwargs: Any,
     ) -> None:
         """Output SUCCESS level log message."""
-        self._log_with_level(25, "success", message, exception, _depth + 1, kwargs)
+        self._log_with_level(
+            25, "success", message, exception, _depth + 1, kwargs, extra, timestamp, end
+        )
 
     def warning(
-        self, message: str, *, exception: str | None = None, _depth: int = 0, **kwargs: Any
+        self,
+        message: str,
+        *,
+        exception: str | None = None,
+        extra: dict[str, Any] | None = None,
+        timestamp: int | str | datetime | None = None,
+        end: str | None = None,
+        _depth: int = 0,
+        **kwargs: Any,
     ) -> None:
         """Output WARNING level log message."""
-        self._log_with_level(30, "warning", message, exception, _depth + 1, kwargs)
+        self._log_with_level(
+            30, "warning", message, exception, _depth + 1, kwargs, extra, timestamp, end
+        )
 
     def error(
-        self, message: str, *, exception: str | None = None, _depth: int = 0, **kwargs: Any
+        self,
+        message: str,
+        *,
+        exception: str | None = None,
+        extra: dict[str, Any] | None = None,
+        timestamp: int | str | datetime | None = None,
+        end: str | None = None,
+        _depth: int = 0,
+        **kwargs: Any,
     ) -> None:
         """Output ERROR level log message."""
-        self._log_with_level(40, "error", message, exception, _depth + 1, kwargs)
+        self._log_with_level(
+            40, "error", message, exception, _depth + 1, kwargs, extra, timestamp, end
+        )
 
     def fail(
-        self, message: str, *, exception: str | None = None, _depth: int = 0, **kwargs: Any
+        self,
+        message: str,
+        *,
+        exception: str | None = None,
+        extra: dict[str, Any] | None = None,
+        timestamp: int | str | datetime | None = None,
+        end: str | None = None,
+        _depth: int = 0,
+        **kwargs: Any,
     ) -> None:
         """Output FAIL level log message."""
-        self._log_with_level(45, "fail", message, exception, _depth + 1, kwargs)
+        self._log_with_level(
+            45, "fail", message, exception, _depth + 1, kwargs, extra, timestamp, end
+        )
 
     def critical(
-        self, message: str, *, exception: str | None = None, _depth: int = 0, **kwargs: Any
+        self,
+        message: str,
+        *,
+        exception: str | None = None,
+        extra: dict[str, Any] | None = None,
+        timestamp: int | str | datetime | None = None,
+        end: str | None = None,
+        _depth: int = 0,
+        **kwargs: Any,
     ) -> None:
         """Output CRITICAL level log message."""
-        self._log_with_level(50, "critical", message, exception, _depth + 1, kwargs)
-
-    def exception(self, message: str, *, _depth: int = 0, **kwargs: Any) -> None:
-        """Log ERROR with current exception traceback.
-
-        Must be called from within an except block to capture the exception.
-        If called outside an except block, logs a plain ERROR message.
+        self._log_with_level(
+            50, "critical", message, exception, _depth + 1, kwargs, extra, timestamp, end
+        )
 
-        Args:
-            message: The error message.
-            _depth: Internal depth adjustment for wrapper methods.
-            **kwargs: Additional arguments passed to error().
+    def _log_exc(
+        self,
+        level_value: int,
+        level_name: str,
+        message: str,
+        exc: BaseException,
+        depth: int,
+        kwargs: dict[str, Any],
+        extra: dict[str, Any] | None,
+    ) -> None:
+        # Skip formatting the traceback when nothing would be logged
+        if level_value < self._inner.min_level:
+            return
+        exception = _format_exception(exc)
+        self._log_with_level(level_value, level_name, message, exception, depth + 1, kwargs, extra)
 
-        Examples:
-            >>> try:
-            ...     risky_operation()
-            ... except:
-            ...     logger.exception("Operation failed")
-            # Output: ERROR with full traceback
-        """
-        exc_info = sys.exc_info()
-        if exc_info[0] is not None:
-            tb = traceback.format_exc()
-            self.error(message, exception=tb, _depth=_depth + 1, **kwargs)
-        else:
-            self.error(message, _depth=_depth + 1, **kwargs)
+    def trace_exc(
+        self,
+        message: str,
+        exc: BaseException,
+        *,
+        extra: dict[str, Any] | None = None,
+        _depth: int = 0,
+        **kwargs: Any,
+    ) -> None:
+        """Output TRACE level log message with the traceback of ``exc``."""
+        self._log_exc(5, "trace", message, exc, _depth + 1, kwargs, extra)
 
-    def level(
+    def debug_exc(
         self,
-        name: str,
+        message: str,
+        exc: BaseException,
         *,
-        no: int,
-        color: str | None = None,
-        icon: str | None = None,
+        extra: dict[str, Any] | None = None,
+        _depth: int = 0,
+        **kwargs: Any,
     ) -> None:
-        """Register a custom log level.
+        """Output DEBUG level log message with the traceback of ``exc``."""
+        self._log_exc(10, "debug", message, exc, _depth + 1, kwargs, extra)
 
-        Args:
-            name: Level name (e.g., "NOTICE"). Case-insensitive.
-            no: Numeric severity (higher = more severe).
-                Built-in levels: TRACE=5, DEBUG=10, INFO=20, SUCCESS=25,
-                WARNING=30, ERROR=40, FAIL=45, CRITICAL=50
-            color: Color name (e.g., "cyan", "bright_blue", "red").
-            icon: Optional icon symbol for display.
+    def info_exc(
+        self,
+        message: str,
+        exc: BaseException,
+        *,
+        extra: dict[str, Any] | None = None,
+        _depth: int = 0,
+        **kwargs: Any,
+    ) -> None:
+        """Output INFO level log message with the traceback of ``exc``."""
+        self._log_exc(20, "info", message, exc, _depth + 1, kwargs, extra)
 
-        Examples:
-            >>> logger.level("NOTICE", no=25, color="cyan", icon="...")
-            >>> logger.log("NOTICE", "Custom level message")
-        """
-        self._inner.level(name, no, color, icon)
+    def success_exc(
+        self,
+        message: str,
+        exc: BaseException,
+        *,
+        extra: dict[str, Any] | None = None,
+        _depth: int = 0,
+        **kwargs: Any,
+    ) -> None:
+        """Output SUCCESS level log message with the traceback of ``exc``."""
+        self._log_exc(25, "success", message, exc, _depth + 1, kwargs, extra)
 
-    def log(
+    def warning_exc(
         self,
-        level: str | int,
         message: str,
+        exc: BaseException,
         *,
-        exception: str | None = None,
+        extra: dict[str, Any] | None = None,
         _depth: int = 0,
         **kwargs: Any,
     ) -> None:
-        """Log at any level (built-in or custom).
+        """Output WARNING level log message with the traceback of ``exc``."""
+        self._log_exc(30, "warning", message, exc, _depth + 1, kwargs, extra)
 
-        Args:
-            level: Level name (str) or numeric value (int).
-            message: Log message.
+    def error_exc(
+        self,
+        message: str,
+        exc: BaseException,
+        *,
+        extra: dict[str, Any] | None = None,
+        _depth: int = 0,
+        **kwargs: Any,
+    ) -> None:
+        """Output ERROR level log message with the traceback of ``exc``.
+
+        The traceback is formatted natively (as ``traceback.format_exception`` would),
+        so an exception object can be logged without formatting it at the call site.
+
+        Args:
+            message: The error message.
+            exc: The exception to log; its ``__traceback__`` and chained causes are included.
+            extra: Structured fields added to this record only.
+            _depth: Internal depth adjustment for wrapper methods.
+            **kwargs: Additional arguments, as for error().
+
+        Examples:
+            >>> try:
+            ...     risky_operation()
+            ... except ValueError as e:
+            ...     logger.error_exc("Operation failed", e)
+        """
+        self._log_exc(40, "error", message, exc, _depth + 1, kwargs, extra)
+
+    def fail_exc(
+        self,
+        message: str,
+        exc: BaseException,
+        *,
+        extra: dict[str, Any] | None = None,
+        _depth: int = 0,
+        **kwargs: Any,
+    ) -> None:
+        """Output FAIL level log message with the traceback of ``exc``."""
+        self._log_exc(45, "fail", message, exc, _depth + 1, kwargs, extra)
+
+    def critical_exc(
+        self,
+        message: str,
+        exc: BaseException,
+        *,
+        extra: dict[str, Any] | None = None,
+        _depth: int = 0,
+        **kwargs: Any,
+    ) -> None:
+        """Output CRITICAL level log message with the traceback of ``exc``."""
+        self._log_exc(50, "critical", message, exc, _depth + 1, kwargs, extra)
+
+    def exception(self, message: str, *, _depth: int = 0, **kwargs: Any) -> None:
+        """Log ERROR with current exception traceback.
+
+        Must be called from within an except block to capture the exception.
+        If called outside an except block, logs a plain ERROR message.
+
+        Args:
+            message: The error message.
+            _depth: Internal depth adjustment for wrapper methods.
+            **kwargs: Additional arguments passed to error().
+
+        Examples:
+            >>> try:
+            ...     risky_operation()
+            ... except:
+            ...     logger.exception("Operation failed")
+            # Output: ERROR with full traceback
+        """
+        exc = sys.exc_info()[1]
+        if exc is not None:
+            self.error(message, exception=_format_exception(exc), _depth=_depth + 1, **kwargs)
+        else:
+            self.error(message, _depth=_depth + 1, **kwargs)
+
+    def level(
+        self,
+        name: str,
+        *,
+        no: int,
+        color: str | None = None,
+        icon: str | None = None,
+        overwrite: bool = False,
+    ) -> None:
+        """Register a custom log level.
+
+        Args:
+            name: Level name (e.g., "NOTICE"). Case-insensitive.
+            no: Numeric severity (higher = more severe).
+                Built-in levels: TRACE=5, DEBUG=10, INFO=20, SUCCESS=25,
+                WARNING=30, ERROR=40, FAIL=45, CRITICAL=50
+            color: Color name (e.g., "cyan", "bright_blue", "red").
+            icon: Optional icon symbol for display.
+            overwrite: Register even if another custom level already uses
+                ``no``; lookups then find this level.
+
+        Raises:
+            ValueError: If ``name`` or ``no`` belongs to a built-in level, or
+                another custom level already uses ``no`` and ``overwrite`` is False.
+
+        Examples:
+            >>> logger.level("NOTICE", no=22, color="cyan", icon="...")
+            >>> logger.log("NOTICE", "Custom level message")
+        """
+        self._inner.level(name, no, color, icon, overwrite)
+
+    def set_level_color(self, name: str, color: str) -> None:
+        """Change the console color of a level.
+
+        Works for built-in levels (e.g. a more readable DEBUG on light terminal
+        themes) and custom ones. The change applies to every logger.
+
+        Args:
+            name: Level name (e.g., "DEBUG"). Case-insensitive.
+            color: Color name, as accepted by ``level()``.
+
+        Raises:
+            ValueError: If the level or the color is unknown.
+
+        Examples:
+            >>> logger.set_level_color("DEBUG", "bright_black")
+        """
+        self._inner.set_level_color(name, color)
+
+    def set_level_icon(self, name: str, icon: str) -> None:
+        """Change the icon a level renders for the ``{icon}`` format token.
+
+        Built-in levels have default icons (e.g. ``✓`` for SUCCESS, ``✗`` for
+        ERROR); custom levels use the one given to ``level()``. The change
+        applies to every logger.
+
+        Args:
+            name: Level name (e.g., "ERROR"). Case-insensitive.
+            icon: Icon text; an empty string hides it.
+
+        Raises:
+            ValueError: If the level is unknown.
+
+        Examples:
+            >>> logger.set_level_icon("ERROR", "🔥")
+            >>> logger.add(sys.stderr, format="{icon} {level} | {message}")
+        """
+        self._inner.set_level_icon(name, icon)
+
+    def log(
+        self,
+        level: str | int,
+        message: str,
+        *,
+        exception: str | None = None,
+        extra: dict[str, Any] | None = None,
+        timestamp: int | str | datetime | None = None,
+        end: str | None = None,
+        _depth: int = 0,
+        **kwargs: Any,
+    ) -> None:
+        """Log at any level (built-in or custom).
+
+        Args:
+            level: Level name (str) or numeric value (int).
+            message: Log message.
             exception: Optional exception traceback.
+            extra: Structured fields added to this record only.
+            timestamp: Record time instead of now, for replaying past events: epoch
+                milliseconds, an ISO 8601 string or a datetime (naive means local time).
+            end: Written after the record instead of the console or file line
+                terminator; ``""`` leaves the line open for the next record.
             _depth: Internal depth adjustment for wrapper methods.
 
         Examples:
@@ -921,17 +1315,30 @@ class Logger:
                     exception,
                     _depth + 1,
                     kwargs,
+                    extra,
+                    timestamp,
+                    end,
                 )
                 return
         elif isinstance(level, int) and level in _LEVEL_VALUE_MAP:
             self._log_with_level(
-                level, _LEVEL_VALUE_MAP[level], message, exception, _depth + 1, kwargs
+                level,
+                _LEVEL_VALUE_MAP[level],
+                message,
+                exception,
+                _depth + 1,
+                kwargs,
+                extra,
+                timestamp,
+                end,
             )
             return
 
+        if timestamp is not None:
+            timestamp = _timestamp_arg(timestamp)
         resolved_emit = self._inner.try_resolve_emit_level_no(level)
         if resolved_emit is None:
-            extra_kw: dict[str, Any] | None = None
+            extra_kw: dict[str, Any] | None = dict(extra) if extra else None
             message, exception, extra_kw = self._apply_patchers(
                 level_name=str(level),
                 level_no=0,
@@ -939,11 +1346,19 @@ class Logger:
                 exception=exception,
                 extra=extra_kw,
             )
-            inner = self._inner if extra_kw is None else self._inner.bind(extra_kw)
             if exception is None:
-                inner.log(level, str(message))
+                self._inner.log(
+                    level, str(message), extra=extra_kw, timestamp=timestamp, end=end
+                )
             else:
-                inner.log(level, str(message), exception=exception)
+                self._inner.log(
+                    level,
+                    str(message),
+                    exception=exception,
+                    extra=extra_kw,
+                    timestamp=timestamp,
+                    end=end,
+                )
             return
         if resolved_emit < self._inner.min_level:
             return
@@ -953,6 +1368,8 @@ class Logger:
             message, extra_kw = _split_kwargs_for_format(message, kwargs)
             if not extra_kw:
                 extra_kw = None
+        if extra:
+            extra_kw = {**extra, **extra_kw} if extra_kw else dict(extra)
 
         message, exception, extra_kw = self._apply_patchers(
             level_name=str(level),
@@ -965,19 +1382,28 @@ class Logger:
         needs_caller, needs_thread, needs_process = self._compute_effective_requirements(
             resolved_emit
         )
-        inner = self._inner if extra_kw is None else self._inner.bind(extra_kw)
+        inner = self._inner
 
         if needs_caller is False and needs_thread is False and needs_process is False:
             if exception is None:
-                inner.log(level, str(message))
+                inner.log(level, str(message), extra=extra_kw, timestamp=timestamp, end=end)
             else:
-                inner.log(level, str(message), exception=exception)
+                inner.log(
+                    level,
+                    str(message),
+                    exception=exception,
+                    extra=extra_kw,
+                    timestamp=timestamp,
+                    end=end,
+                )
             return
 
+        stacklevel: int | None = None
         if needs_thread is False and needs_process is False:
             if needs_caller is True:
                 # Custom ``log()`` has no ``info()`` → ``_log_with_level`` wrapper frame.
                 name, function, line, file = _get_caller_info(_depth + 1)
+                stacklevel = 1 if _depth == 0 else _get_stacklevel(_depth + 1)
             else:
                 # needs_caller is CallerInfo (False case already returned above)
                 assert isinstance(needs_caller, CallerInfo)
@@ -988,7 +1414,18 @@ class Logger:
                     needs_caller.file,
                 )
             if exception is None:
-                inner.log(level, str(message), name=name, function=function, line=line, file=file)
+                inner.log(
+                    level,
+                    str(message),
+                    name=name,
+                    function=function,
+                    line=line,
+                    file=file,
+                    extra=extra_kw,
+                    stacklevel=stacklevel,
+                    timestamp=timestamp,
+                    end=end,
+                )
             else:
                 inner.log(
                     level,
@@ -998,6 +1435,10 @@ class Logger:
                     function=function,
                     line=line,
                     file=file,
+                    extra=extra_kw,
+                    stacklevel=stacklevel,
+                    timestamp=timestamp,
+                    end=end,
                 )
             return
 
@@ -1007,6 +1448,7 @@ class Logger:
         file_: str | None
         if needs_caller is True:
             name_, function_, line_, file_ = _get_caller_info(_depth + 1)
+            stacklevel = 1 if _depth == 0 else _get_stacklevel(_depth + 1)
         elif needs_caller is not False:
             name_, function_, line_, file_ = (
                 needs_caller.name,
@@ -1019,9 +1461,7 @@ class Logger:
 
         thread_name: str | None
         thread_id: int | None
-        if needs_thread is True:
-            thread_name, thread_id = _get_thread_info()
-        elif needs_thread is not False:
+        if isinstance(needs_thread, ThreadInfo):
             thread_name = needs_thread.name
             thread_id = needs_thread.id
         else:
@@ -1029,9 +1469,7 @@ class Logger:
 
         process_name: str | None
         process_id: int | None
-        if needs_process is True:
-            process_name, process_id = _get_process_info()
-        elif needs_process is not False:
+        if isinstance(needs_process, ProcessInfo):
             process_name = needs_process.name
             process_id = needs_process.id
         else:
@@ -1049,6 +1487,10 @@ class Logger:
                 thread_id=thread_id,
                 process_name=process_name,
                 process_id=process_id,
+                extra=extra_kw,
+                stacklevel=stacklevel,
+                timestamp=timestamp,
+                end=end,
             )
         else:
             inner.log(
@@ -1063,8 +1505,136 @@ class Logger:
                 thread_id=thread_id,
                 process_name=process_name,
                 process_id=process_id,
+                extra=extra_kw,
+                stacklevel=stacklevel,
+                timestamp=timestamp,
+                end=end,
             )
 
+    def log_batch(self, level: str | int, messages: Iterable[Any], *, _depth: int = 0) -> None:
+        """Log many messages at one level in a single call.
+
+        Caller/thread/process info and bound context (including lazily bound
+        values) are collected once and shared by every record, and the Rust
+        side takes the handler lock (and the GIL for callbacks/filters) once
+        for the whole batch.
+
+        Args:
+            level: Level name (str) or numeric value (int).
+            messages: Messages to log, in order.
+            _depth: Internal depth adjustment for wrapper methods.
+
+        Examples:
+            >>> logger.log_batch("INFO", (f"replayed {e.id}" for e in events))
+        """
+        if isinstance(level, str) and level.lower() in _LEVEL_VALUES:
+            level_value = _LEVEL_VALUES[level.lower()]
+        elif isinstance(level, int) and level in _LEVEL_VALUE_MAP:
+            level_value = level
+        else:
+            resolved = self._inner.try_resolve_emit_level_no(level)
+            if resolved is None:
+                raise ValueError(f"Invalid log level: {level}")
+            level_value = resolved
+        if level_value < self._inner.min_level:
+            return
+
+        if self._patchers:
+            # Patchers can rewrite each record, so there is nothing to share.
+            for message in messages:
+                self.log(level, message, _depth=_depth + 1)
+            return
+
+        needs_caller, needs_thread, needs_process = self._compute_effective_requirements(
+            level_value
+        )
+        c_name, c_function, c_line, c_file, c_stacklevel, t_name, t_id, p_name, p_id = (
+            self._resolve_record_info(needs_caller, needs_thread, needs_process, _depth + 1)
+        )
+        self._inner.log_batch(
+            level,
+            [m if isinstance(m, str) else str(m) for m in messages],
+            name=c_name,
+            function=c_function,
+            line=c_line,
+            file=c_file,
+            thread_name=t_name,
+            thread_id=t_id,
+            process_name=p_name,
+            process_id=p_id,
+            stacklevel=c_stacklevel,
+        )
+
+    def log_fmt(
+        self,
+        level: str | int,
+        template: str,
+        *args: Any,
+        exception: str | None = None,
+        extra: dict[str, Any] | None = None,
+        _depth: int = 0,
+    ) -> None:
+        """Log ``template.format(*args)``, rendering only if the level is enabled.
+
+        Unlike ``logger.info(f"...")``, no string is built for disabled levels:
+        the template and arguments are handed to the Rust side, which
+        substitutes ``{}`` / ``{0}`` fields only when a handler or callback
+        accepts the level. Other fields (``{:.2f}``, ``{name}``) fall back to
+        ``str.format``.
+
+        Args:
+            level: Level name (str) or numeric value (int), built-in or custom.
+            template: Message template with positional fields.
+            *args: Values for the fields.
+            exception: Optional exception traceback.
+            extra: Structured fields added to this record only.
+            _depth: Internal depth adjustment for wrapper methods.
+
+        Examples:
+            >>> logger.log_fmt("INFO", "user {} logged in from {}", user_id, ip)
+        """
+        if isinstance(level, str) and level.lower() in _LEVEL_VALUES:
+            level_value = _LEVEL_VALUES[level.lower()]
+        elif isinstance(level, int) and level in _LEVEL_VALUE_MAP:
+            level_value = level
+        else:
+            resolved = self._inner.try_resolve_emit_level_no(level)
+            if resolved is None:
+                raise ValueError(f"Invalid log level: {level}")
+            level_value = resolved
+        if level_value < self._inner.min_level:
+            return
+
+        if self._patchers:
+            # Patchers see the rendered message, so render it here.
+            self.log(
+                level, template.format(*args), exception=exception, extra=extra, _depth=_depth + 1
+            )
+            return
+
+        needs_caller, needs_thread, needs_process = self._compute_effective_requirements(
+            level_value
+        )
+        c_name, c_function, c_line, c_file, c_stacklevel, t_name, t_id, p_name, p_id = (
+            self._resolve_record_info(needs_caller, needs_thread, needs_process, _depth + 1)
+        )
+        self._inner.log_fmt(
+            level,
+            template,
+            args,
+            exception=exception,
+            name=c_name,
+            function=c_function,
+            line=c_line,
+            file=c_file,
+            thread_name=t_name,
+            thread_id=t_id,
+            process_name=p_name,
+            process_id=p_id,
+            extra=extra,
+            stacklevel=c_stacklevel,
+        )
+
     def set_level(self, level: LogLevel | str) -> None:
         """Set minimum log level for console output."""
         self._inner.set_level(_to_log_level(level))
@@ -1085,13 +1655,36 @@ class Logger:
         """
         return self._inner.is_level_enabled(_to_log_level(level))
 
+    def would_log(self, level: LogLevel | str | int) -> bool:
+        """Cheap guard for expensive message construction.
+
+        Compares against the cached minimum level without touching handler
+        locks. Custom levels can be passed by name or numeric ``no``.
+
+        Args:
+            level: Built-in level, level name, or numeric level number.
+
+        Returns:
+            True if a record at this level would be emitted (False while paused).
+
+        Examples:
+            >>> if logger.would_log("DEBUG"):
+            ...     logger.debug(f"state={expensive_dump()}")
+        """
+        if isinstance(level, str):
+            resolved = self._inner.try_resolve_emit_level_no(level)
+            if resolved is None:
+                return False
+            return self._inner.would_log(resolved)
+        return self._inner.would_log(level)
+
     def enable(self, level: LogLevel | str | None = None) -> None:
-        """Enable console logging."""
+        """Enable console logging (restores both streams after split_console())."""
         self._inner.enable(_to_log_level(level) if level is not None else None)
         self._invalidate_requirements_cache()
 
     def disable(self) -> None:
-        """Disable console logging."""
+        """Disable console logging (all console handlers, stdout and stderr)."""
         self._inner.disable()
         self._invalidate_requirements_cache()
 
@@ -1099,71 +1692,296 @@ class Logger:
         """Check if console logging is enabled."""
         return self._inner.is_enabled()
 
-    def complete(self) -> None:
+    def pause(self) -> None:
+        """Temporarily drop all log records without removing handlers.
+
+        The pause is shared with every logger derived via bind()/patch().
+
+        Examples:
+            >>> logger.pause()
+            >>> run_noisy_migration()  # nothing is logged
+            >>> logger.resume()
+        """
+        self._inner.pause()
+
+    def resume(self) -> None:
+        """Resume logging after pause()."""
+        self._inner.resume()
+
+    def set_global_level(self, level: LogLevel | str | None) -> None:
+        """Drop records below ``level`` whatever the handler and callback levels.
+
+        A master verbosity dial: handlers keep their own levels, and records
+        below the floor are dropped before any of them is consulted. Shared with
+        every logger derived via bind()/patch().
+
+        Args:
+            level: Minimum level to emit, or None to remove the floor.
+
+        Examples:
+            >>> logger.add("debug.log", level="DEBUG")
+            >>> logger.set_global_level("INFO")  # debug.log now gets INFO and above
+            >>> logger.set_global_level(None)
+        """
+        self._inner.set_global_level(None if level is None else _to_log_level(level))
+
+    def get_global_level(self) -> LogLevel | None:
+        """Get the level set with set_global_level(), or None when there is none."""
+        return self._inner.get_global_level()
+
+    def is_paused(self) -> bool:
+        """Check if logging is currently paused."""
+        return self._inner.is_paused
+
+    def complete(self, handler_id: int | None = None) -> bool:
         """Flush all file handlers to ensure pending logs are written.
 
-        Call this before program exit to ensure all logs are persisted.
+        Blocks until async (``enqueue=True``) writers have written every message
+        logged before the call. Call this before program exit to ensure all logs
+        are persisted.
+
+        Args:
+            handler_id: Flush only this handler (ID or handle) instead of all.
+
+        Returns:
+            False if ``handler_id`` is not a handler of this logger, else True.
 
         Examples:
             >>> logger.info("Final message")
             >>> logger.complete()  # Ensure message is written to files
+            >>> handle = logger.add("app.log")
+            >>> logger.complete(handle)  # Flush only app.log
         """
-        self._inner.complete()
+        return self._inner.complete(handler_id)
 
     def add(
         self,
         sink: str | os.PathLike[str] | TextIO | Callable[[str], Any],
         *,
         level: LogLevel | str | None = None,
+        max_level: LogLevel | str | None = None,
         format: str | None = None,
+        strict_format: bool = False,
         rotation: str | None = None,
         retention: str | int | None = None,
         compression: bool = False,
         serialize: bool = False,
-        filter: Callable[[dict[str, Any]], bool] | None = None,
+        serialize_wrapped: bool = False,
+        logfmt: bool = False,
+        csv: bool = False,
+        csv_header: bool = False,
+        if_exists: str | None = None,
+        mode: str = "a",
+        on_error: str = "raise",
+        on_rotation: Callable[[Path], Any] | None = None,
+        compress_on_close: bool = False,
+        filter: Callable[[dict[str, Any]], bool] | str | Mapping[str, str] | None = None,
+        filter_fields: Iterable[str] | None = None,
         enqueue: bool = False,
+        shared_writer: bool = False,
+        max_queue_bytes: int | str | None = None,
+        write_retries: int = 0,
+        write_retry_backoff: float = 0.01,
+        watch: bool = False,
+        line_terminator: str = "\n",
+        autoflush: bool = False,
+        fsync: bool = False,
+        max_open_files: int | None = None,
+        rate_limit: str | None = None,
+        dedupe: bool = False,
         colorize: bool | None = None,
+        colorize_full: bool = False,
+        colorize_exception: bool = False,
+        utc: bool = False,
+        time_format: str | None = None,
+        time_precision: str | None = None,
+        prefix: str | None = None,
+        suffix: str | None = None,
+        level_map: Mapping[str, LogLevel | str] | None = None,
+        static_fields: Mapping[str, Any] | None = None,
+        message_colors: Mapping[str, str] | None = None,
         collect: CollectOptions | None = None,
-    ) -> int:
+    ) -> HandlerHandle:
         """Add a handler (file, console, or callable sink).
 
         Args:
             sink: Path to the log file (str or Path object), sys.stdout/sys.stderr,
                   or a callable that receives formatted log messages.
-            level: Minimum log level for this handler.
+                  A path containing {extra[key]} placeholders routes each record
+                  to the file named by its extra fields (opened on first use).
+            level: Minimum log level for this handler (LOGUST_LEVEL or DEBUG if omitted).
+            max_level: Highest log level written by this handler (inclusive).
+                       Only valid for file and console sinks.
             format: Custom format string (e.g., "{time} | {level} | {message}").
+                    Falls back to LOGUST_FORMAT, then the built-in default.
+            strict_format: Raise ValueError if the format has an unrecognized
+                           placeholder such as {levl}, instead of writing it as
+                           literal text. Only valid for file and console sinks.
             rotation: Rotation strategy ("daily", "hourly", "500 MB", etc.)
                       Only valid for file sinks.
-            retention: Retention policy ("10 days" or count as int)
+            retention: Retention policy ("10 days", "2 weeks", "3 months" with a
+                       month as 30 days, or count as int)
                        Only valid for file sinks.
             compression: Enable gzip compression for rotated files.
                          Only valid for file sinks.
             serialize: Output as JSON instead of text format.
+            serialize_wrapped: Output loguru-style JSON, with the formatted
+                               text under "text" and structured fields under
+                               "record". Implies serialize.
+                               Only valid for file and console sinks.
+            logfmt: Output logfmt (ts=... level=info msg="..." plus caller and
+                    extra fields as key=value pairs) instead of text format.
+                    Ignored with serialize. Only valid for file and console sinks.
+            csv: Output one RFC 4180 CSV row per record with the columns time,
+                 level, name, line, message and extra (a JSON object).
+                 Ignored with serialize or logfmt. Only valid for file sinks.
+            csv_header: Write the "time,level,name,line,message,extra" header
+                        row at the start of every new or empty file (including
+                        each rotated one). Requires csv. Only valid for file sinks.
+            if_exists: What to do when a file handler for the same path (compared
+                       after resolving it) already exists: "error" raises ValueError,
+                       "replace" removes the existing handler first, "ignore" keeps it
+                       and returns its handle. None (default) adds another handler.
+                       Only valid for file sinks.
+            mode: "a" (default) appends to an existing file, "w" empties it first,
+                  as with open(). Files started by rotation are new either way.
+                  Only valid for file sinks.
+            on_error: "raise" (default) raises OSError if the file or its
+                      directory can't be created; "skip" prints a warning to
+                      stderr and adds a handler that discards records instead,
+                      so startup survives e.g. a volume that isn't mounted yet.
+                      Only valid for file sinks.
+            on_rotation: Called with the Path of each rotated file once it has
+                         been renamed (and compressed, with compression: the
+                         .gz path), e.g. to upload it. Runs on a background
+                         thread, never on the logging call that rotated.
+                         Only valid for file sinks.
+            compress_on_close: With compression, also gzip the active file to
+                               <name>.gz when the handler is removed or the
+                               interpreter exits, for files that never rotate.
+                               Empty files are left alone. Only valid for file sinks.
             filter: Optional callable that receives a record dict and returns
-                    True if the record should be logged, False to skip.
+                    True if the record should be logged, False to skip. A module
+                    name (``"app.db"``, also matching submodules) or a dict of
+                    extra patterns (``{"component": "db*"}``, ``*``/``?``
+                    wildcards) is checked natively, without calling into Python.
+            filter_fields: Record keys a callable filter reads (e.g. ``["message"]``).
+                           The filter then receives only those keys and no longer
+                           forces full record collection. Only valid for file and
+                           console sinks.
             enqueue: If True, writes are queued and processed asynchronously
                      in a background thread (thread-safe).
                      If False (default), writes are synchronous (reliable).
-                     Only valid for file sinks.
+                     Valid for file and console sinks; console sinks share
+                     one writer thread, so a slow terminal doesn't block.
+            shared_writer: With enqueue, write through one background thread
+                           shared by all such file sinks instead of a thread
+                           per sink. Only valid for file sinks.
+            max_queue_bytes: With enqueue, bytes of records (int or e.g.
+                             "64 MB") the queue may hold; logging calls wait
+                             for the writer thread beyond that. Only valid
+                             for file sinks.
+            write_retries: Retries after a write fails with a transient error
+                           (interrupted, would block, timed out), e.g. on a
+                           network mount. Other errors are not retried.
+                           Without enqueue the retries block the logging call.
+                           Only valid for file sinks.
+            write_retry_backoff: Seconds to wait before the first retry,
+                                 doubled for each further one.
+                                 Only valid for file sinks.
+            watch: Reopen the file if it is deleted or replaced by an external
+                   tool such as logrotate, checked before writes.
+                   Only valid for file sinks.
+            line_terminator: String written after each record ("\n" by
+                             default; e.g. "\r\n" or "\0").
+                             Only valid for file sinks.
+            autoflush: Flush after every record so a crash loses nothing
+                       already logged, at the cost of one write per record.
+                       Only valid for file sinks.
+            fsync: Also fsync the file so records reach stable storage: after
+                   every record with autoflush, otherwise on complete(),
+                   before rotation and when the handler is removed.
+                   Much slower than autoflush. Only valid for file sinks.
+            max_open_files: For {extra[key]} paths, how many files stay open at
+                            once (default 64); the least recently used one is
+                            closed beyond that. Only valid for file sinks.
+            rate_limit: Maximum records written per period ("100/s", "10/min",
+                        "500/hour"). Bursts up to the count are allowed; records
+                        over the limit are dropped and counted (see suppressed()).
+                        Only valid for file and console sinks.
+            dedupe: Collapse consecutive records with the same level and
+                    message into one "Last message repeated N times" record,
+                    written (with this handler's format) when a different
+                    message arrives, on complete(), or when the handler is removed.
+                    Only valid for file and console sinks.
             colorize: Enable ANSI color codes (for console sinks).
-                      If None, auto-detect based on whether sink is a TTY.
+                      If None, NO_COLOR disables and FORCE_COLOR enables colors;
+                      otherwise auto-detect based on whether sink is a TTY.
                       Only valid for console sinks.
+            colorize_full: Tint the whole line in the level color instead of
+                           only the level token (when colors are enabled).
+                           Only valid for console sinks.
+            colorize_exception: Color tracebacks, with file paths in cyan and the
+                                exception line in red (when colors are enabled).
+                                Only valid for console sinks.
+            utc: Render {time} and JSON timestamps in UTC instead of local time
+                 ({time:utc} converts a single token). Display only: rotation
+                 and retention keep using local time.
+            time_format: chrono strftime format of {time} and JSON timestamps,
+                         e.g. "%Y-%m-%dT%H:%M:%S%.3f". Raises ValueError for an
+                         unknown specifier. Only valid for file and console sinks.
+            time_precision: Fractional seconds of {time} and JSON timestamps:
+                            "seconds", "millis" (default), "micros" or "nanos".
+                            Only valid for file and console sinks.
+            prefix: Text written before every formatted line, after template
+                    rendering. Not applied to JSON output.
+            suffix: Text written after every formatted line, before the line
+                    terminator. Not applied to JSON output.
+            level_map: Remap record levels for this handler only, e.g.
+                       {"DEBUG": "TRACE"}. The mapped level is used for the
+                       level check and shown in the output.
+                       Only valid for file and console sinks.
+            static_fields: Fields written at the top level of every JSON record
+                           from this handler, e.g. {"_v": 1}. Values keep their
+                           JSON type. Not applied to text output.
+            message_colors: Color the parts of the message matching a regex,
+                            e.g. {r"^\[OK\]": "green"} (values are markup tags).
+                            Only valid for console sinks, when colors are enabled.
             collect: Options for controlling information collection.
                      Can override auto-detection from format string.
 
         Returns:
-            Handler ID for later removal.
+            A HandlerHandle. It behaves like the integer handler ID (remove(),
+            equality, hashing) and also offers remove(), set_level() and stats().
 
         Examples:
             >>> logger.add("app.log")
             >>> logger.add(Path("debug.log"), level="DEBUG")
             >>> logger.add("app.log", rotation="500 MB", retention="10 days")
             >>> logger.add("app.json", serialize=True)
+            >>> logger.add("app.json", serialize_wrapped=True)  # {"text", "record"}
+            >>> logger.add("app.logfmt", logfmt=True)  # ts=... level=info msg=...
+            >>> logger.add("ops.csv", csv=True, csv_header=True)  # Spreadsheet-friendly rows
+            >>> logger.add("app.log", if_exists="ignore")  # No-op if app.log is handled
+            >>> logger.add("dev.log", mode="w")  # Fresh file on every run
             >>> logger.add("async.log", enqueue=True)  # Async writes
+            >>> logger.add("audit.log", autoflush=True)  # Flushed after every record
+            >>> logger.add("audit.log", autoflush=True, fsync=True)  # On stable storage
+            >>> logger.add("logs/{extra[tenant]}/app.log")  # One file per tenant
+            >>> logger.add("noisy.log", rate_limit="100/s")  # Drop records over the limit
+            >>> logger.add("retry.log", dedupe=True)  # "Last message repeated N times"
             >>> logger.add(sys.stdout, colorize=True)  # Colored console output
+            >>> logger.add(sys.stderr, colorize_exception=True)  # Colored tracebacks
+            >>> logger.add("central.log", utc=True)  # Timestamps in UTC
+            >>> logger.add("trace.log", time_precision="micros")  # Microsecond timestamps
+            >>> logger.add(sys.stderr, prefix="[web-1] ")  # Tag every line
+            >>> logger.add("app.log", level_map={"DEBUG": "TRACE"})  # Demote DEBUG
+            >>> logger.add("app.json", serialize=True, static_fields={"_v": 1})  # Schema version
             >>> logger.add(sys.stderr, serialize=True)  # JSON to stderr
+            >>> logger.add("info.log", level="INFO", max_level="INFO")  # INFO records only
             >>> logger.add(lambda msg: print(msg))  # Callable sink
             >>> logger.add("app.log", collect=CollectOptions(caller=False))
+            >>> logger.add("app.log").set_level("WARNING")
 
         Note:
             Callable sinks can be removed with remove() or remove_callback().
@@ -1172,20 +1990,24 @@ class Logger:
 
         # Check for callable sink first (before checking stdout/stderr)
         if callable(sink) and sink not in (sys.stdout, sys.stderr):
-            handler_id = self._add_callable_sink(
+            handle = self._add_callable_sink(
                 sink,
                 level=level,
                 format=format,
                 serialize=serialize,
                 filter=filter,
+                utc=utc,
+                prefix=prefix,
+                suffix=suffix,
+                static_fields=static_fields,
             )
+            handler_id = handle.id
             # For callable sinks, compute CollectOptions from format if not specified
             # This avoids relying on Rust's needs_* which is polluted by callback registration
             if collect is not None:
                 resolved_collect = collect
             else:
-                default_format = "{time} | {level:<8} | {name}:{function}:{line} - {message}"
-                resolved_collect = _collect_options_from_format(format or default_format)
+                resolved_collect = _collect_options_from_format(format or _default_format())
             self._collect_options[handler_id] = resolved_collect
             # Track as callback for proper removal via remove()
             self._callback_ids.add(handler_id)
@@ -1193,41 +2015,64 @@ class Logger:
             if filter is not None:
                 self._filter_ids.add(handler_id)
             self._invalidate_requirements_cache()
-            return handler_id
+            return self._track(handle)
 
         if sink is sys.stdout or sink is sys.stderr:
             stream_name = "stdout" if sink is sys.stdout else "stderr"
             resolved_level = _to_log_level(level) if level is not None else None
-            resolved_colorize = colorize
-            if resolved_colorize is None:
-                stream = cast("TextIO", sink)
-                resolved_colorize = stream.isatty() if hasattr(stream, "isatty") else False
-
-            handler_id = self._inner.add_console(
+            resolved_max_level = _to_log_level(max_level) if max_level is not None else None
+            # colorize=None lets the Rust side apply NO_COLOR/FORCE_COLOR and TTY detection
+            handle = self._inner.add_console(
                 stream=stream_name,
                 level=resolved_level,
                 format=format,
                 serialize=serialize,
                 filter=filter,
-                colorize=resolved_colorize,
+                filter_fields=list(filter_fields) if filter_fields is not None else None,
+                colorize=colorize,
+                colorize_full=colorize_full,
+                serialize_wrapped=serialize_wrapped,
+                rate_limit=rate_limit,
+                dedupe=dedupe,
+                max_level=resolved_max_level,
+                colorize_exception=colorize_exception,
+                utc=utc,
+                time_format=time_format,
+                time_precision=time_precision,
+                prefix=prefix,
+                suffix=suffix,
+                level_map=dict(level_map) if level_map is not None else None,
+                static_fields=dict(static_fields) if static_fields is not None else None,
+                message_colors=dict(message_colors) if message_colors is not None else None,
+                logfmt=logfmt,
+                strict_format=strict_format,
+                enqueue=enqueue,
             )
             # Always track handler with CollectOptions (default to auto-detect if not specified)
-            self._collect_options[handler_id] = collect if collect is not None else CollectOptions()
-            if filter is not None:
-                self._filter_ids.add(handler_id)
+            self._collect_options[handle.id] = collect if collect is not None else CollectOptions()
+            # Native filters and ones declaring filter_fields don't need full records
+            if callable(filter) and filter_fields is None:
+                self._filter_ids.add(handle.id)
             self._invalidate_requirements_cache()
-            return handler_id
+            return self._track(handle)
 
         # At this point sink must be a path (str or PathLike), not TextIO
         sink_str = os.fspath(cast("str | os.PathLike[str]", sink))
 
         resolved_level = _to_log_level(level) if level is not None else None
+        resolved_max_level = _to_log_level(max_level) if max_level is not None else None
 
         retention_str = None
         if retention is not None:
             retention_str = str(retention) if isinstance(retention, int) else retention
 
-        handler_id = self._inner.add(
+        existing = self._inner.file_handler_id(sink_str) if if_exists is not None else None
+        if if_exists == "replace" and existing is not None:
+            # Removed here rather than in Rust so the bookkeeping below stays in sync
+            self.remove(existing)
+            existing = None
+
+        handle = self._inner.add(
             sink_str,
             level=resolved_level,
             format=format,
@@ -1236,14 +2081,148 @@ class Logger:
             compression=compression,
             serialize=serialize,
             filter=filter,
+            filter_fields=list(filter_fields) if filter_fields is not None else None,
             enqueue=enqueue,
+            shared_writer=shared_writer,
+            max_queue_bytes=str(max_queue_bytes) if max_queue_bytes is not None else None,
+            write_retries=write_retries,
+            write_retry_backoff=write_retry_backoff,
+            on_error=on_error,
+            on_rotation=on_rotation,
+            compress_on_close=compress_on_close,
+            serialize_wrapped=serialize_wrapped,
+            watch=watch,
+            line_terminator=line_terminator,
+            autoflush=autoflush,
+            fsync=fsync,
+            max_open_files=max_open_files,
+            rate_limit=rate_limit,
+            dedupe=dedupe,
+            max_level=resolved_max_level,
+            utc=utc,
+            time_format=time_format,
+            time_precision=time_precision,
+            prefix=prefix,
+            suffix=suffix,
+            level_map=dict(level_map) if level_map is not None else None,
+            static_fields=dict(static_fields) if static_fields is not None else None,
+            logfmt=logfmt,
+            csv=csv,
+            csv_header=csv_header,
+            if_exists=if_exists,
+            mode=mode,
+            strict_format=strict_format,
         )
+        if handle.id == existing:
+            # if_exists="ignore": the existing handler keeps its options
+            return self._track(handle)
         # Always track handler with CollectOptions (default to auto-detect if not specified)
-        self._collect_options[handler_id] = collect if collect is not None else CollectOptions()
-        if filter is not None:
-            self._filter_ids.add(handler_id)
+        self._collect_options[handle.id] = collect if collect is not None else CollectOptions()
+        if callable(filter) and filter_fields is None:
+            self._filter_ids.add(handle.id)
+        self._invalidate_requirements_cache()
+        return self._track(handle)
+
+    def split_console(
+        self,
+        level: LogLevel | str | None = None,
+        *,
+        format: str | None = None,
+        serialize: bool = False,
+        colorize: bool | None = None,
+        colorize_full: bool = False,
+        colorize_exception: bool = False,
+        utc: bool = False,
+    ) -> tuple[HandlerHandle, HandlerHandle]:
+        """Replace console output with a stdout/stderr pair split at WARNING.
+
+        Records below WARNING are written to stdout, WARNING and above to stderr.
+        Afterwards disable()/enable() remove and restore both handlers, and
+        set_level() never lowers the stderr handler below WARNING.
+
+        Args:
+            level: Minimum log level for the console (default DEBUG).
+            format: Custom format string for both streams.
+            serialize: Output as JSON instead of text format.
+            colorize: Enable ANSI color codes. If None, detected per stream.
+            colorize_full: Tint the whole line in the level color.
+            colorize_exception: Color tracebacks (paths in cyan, exception line in red).
+            utc: Render {time} and JSON timestamps in UTC.
+
+        Returns:
+            The (stdout, stderr) handler handles.
+
+        Examples:
+            >>> logger.split_console()
+            >>> logger.info("to stdout")
+            >>> logger.warning("to stderr")
+        """
+        stdout_handle, stderr_handle = self._inner.split_console(
+            level=_to_log_level(level) if level is not None else None,
+            format=format,
+            serialize=serialize,
+            colorize=colorize,
+            colorize_full=colorize_full,
+            colorize_exception=colorize_exception,
+            utc=utc,
+        )
+        for handle in (stdout_handle, stderr_handle):
+            self._collect_options[handle.id] = CollectOptions()
+        self._invalidate_requirements_cache()
+        return self._track(stdout_handle), self._track(stderr_handle)
+
+    def add_journald(
+        self,
+        identifier: str,
+        level: LogLevel | str | None = None,
+        *,
+        socket_path: str | Path | None = None,
+    ) -> HandlerHandle:
+        """Send records to the systemd journal as native entries.
+
+        Each record becomes one journal entry with ``MESSAGE`` (followed by the
+        traceback, if any), ``PRIORITY`` mapped from the level (CRITICAL is 2,
+        ERROR and FAIL 3, WARNING 4, SUCCESS 5, INFO 6, lower levels 7),
+        ``SYSLOG_IDENTIFIER``, the caller's ``CODE_FILE``/``CODE_LINE``/
+        ``CODE_FUNC``, and one field per ``extra`` key (uppercased, e.g.
+        ``request_id`` becomes ``REQUEST_ID``).
+
+        Args:
+            identifier: SYSLOG_IDENTIFIER of every entry (``journalctl -t``).
+            level: Minimum log level (default DEBUG).
+            socket_path: Journal socket (default /run/systemd/journal/socket).
+
+        Returns:
+            The handler handle.
+
+        Raises:
+            OSError: If the journal socket can't be reached.
+
+        Examples:
+            >>> logger.add_journald("myapp", level="INFO")
+            >>> logger.bind(request_id="abc").info("Served")  # journalctl REQUEST_ID=abc
+        """
+        handle = self._inner.add_journald(
+            identifier,
+            level=_to_log_level(level) if level is not None else None,
+            socket_path=socket_path,
+        )
+        self._collect_options[handle.id] = CollectOptions()
+        self._invalidate_requirements_cache()
+        return self._track(handle)
+
+    def _track(self, handle: HandlerHandle) -> HandlerHandle:
+        """Keep this logger's bookkeeping in sync with changes made through a handle."""
+        handle._set_on_change(self._on_handle_change)
+        return handle
+
+    def _on_handle_change(self, handler_id: int, removed: bool) -> None:
+        if removed:
+            self._collect_options.pop(handler_id, None)
+            self._callback_ids.discard(handler_id)
+            self._filter_ids.discard(handler_id)
+            self._raw_callback_ids.discard(handler_id)
         self._invalidate_requirements_cache()
-        return handler_id
 
     def _add_callable_sink(
         self,
@@ -1252,8 +2231,12 @@ class Logger:
         level: LogLevel | str | None = None,
         format: str | None = None,
         serialize: bool = False,
-        filter: Callable[[dict[str, Any]], bool] | None = None,
-    ) -> int:
+        filter: Callable[[dict[str, Any]], bool] | str | Mapping[str, str] | None = None,
+        utc: bool = False,
+        prefix: str | None = None,
+        suffix: str | None = None,
+        static_fields: Mapping[str, Any] | None = None,
+    ) -> HandlerHandle:
         """Add a callable as a sink (internal method).
 
         The callable will receive formatted log messages as strings.
@@ -1265,55 +2248,73 @@ class Logger:
             serialize: Output as JSON instead of text format.
             filter: Optional callable that receives a record dict and returns
                     True if the record should be logged, False to skip.
+            utc: Render timestamps in UTC instead of local time.
+            prefix: Text prepended to every formatted (non-JSON) message.
+            suffix: Text appended to every formatted (non-JSON) message.
+            static_fields: Fields written at the top level of every JSON message.
 
         Returns:
-            Handler ID for later removal.
+            Handle of the underlying callback.
         """
         import json
 
+        fields = dict(static_fields) if static_fields else {}
+        for key in fields:
+            if key in JSON_RECORD_KEYS:
+                raise ValueError(f"static_fields key {key!r} is already a JSON record field")
+
         resolved_level = _to_log_level(level) if level is not None else None
-        default_format = "{time} | {level:<8} | {name}:{function}:{line} - {message}"
-        template_str = format or default_format
+        template_str = format or _default_format()
+        if filter is not None and not callable(filter):
+            filter = _context_filter_predicate(filter)
 
         # Pre-parse template for efficient single-pass formatting
-        parsed_template = ParsedCallableTemplate(template_str)
+        parsed_template = ParsedCallableTemplate(template_str, utc=utc)
 
         def callback_wrapper(record: dict[str, Any]) -> None:
             # Apply filter if provided
             if filter is not None and not filter(record):
                 return
 
-            try:
-                if serialize:
-                    # Output as JSON matching Rust's format_record_json
-                    json_record: dict[str, Any] = {
-                        "time": record.get("timestamp", ""),
-                        "level": record.get("level", ""),
-                        "message": record.get("message", ""),
-                    }
-                    # Only include non-empty caller info
-                    if record.get("name"):
-                        json_record["name"] = record["name"]
-                    if record.get("function"):
-                        json_record["function"] = record["function"]
-                    if record.get("line"):
-                        json_record["line"] = record["line"]
-                    # Include extra if non-empty
-                    extra = record.get("extra", {})
-                    if extra:
-                        json_record["extra"] = extra
-                    # Include exception if present
-                    if record.get("exception"):
-                        json_record["exception"] = record["exception"]
-                    formatted = json.dumps(json_record)
-                else:
-                    # Format using pre-parsed template (single-pass, ~1-2us faster)
-                    formatted = parsed_template.format(record)
-
-                sink(formatted)
-            except Exception:
-                # Silently ignore sink errors (like loguru behavior)
-                pass
+            # Sink errors propagate to Rust, which counts and reports them
+            # (see on_callback_error) without affecting the logging call.
+            if serialize:
+                # Output as JSON matching Rust's format_record_json
+                timestamp = record.get("timestamp", "")
+                json_record: dict[str, Any] = {
+                    **fields,
+                    "time": to_utc_timestamp(timestamp) if utc and timestamp else timestamp,
+                    "level": record.get("level", ""),
+                    "message": record.get("message", ""),
+                }
+                # Only include non-empty caller info
+                if record.get("name"):
+                    json_record["name"] = record["name"]
+                if record.get("function"):
+                    json_record["function"] = record["function"]
+                if record.get("line"):
+                    json_record["line"] = record["line"]
+                if record.get("seq"):
+                    json_record["seq"] = record["seq"]
+                # Trace context is promoted to top-level fields, the rest stays in extra
+                extra = record.get("extra", {})
+                for key in TRACE_CONTEXT_KEYS:
+                    if key in extra:
+                        json_record[key] = extra[key]
+                extra = {k: v for k, v in extra.items() if k not in TRACE_CONTEXT_KEYS}
+                if extra:
+                    json_record["extra"] = extra
+                # Include exception if present
+                if record.get("exception"):
+                    json_record["exception"] = record["exception"]
+                formatted = json.dumps(json_record)
+            else:
+                # Format using pre-parsed template (single-pass, ~1-2us faster)
+                formatted = parsed_template.format(record)
+                if prefix or suffix:
+                    formatted = f"{prefix or ''}{formatted}{suffix or ''}"
+
+            sink(formatted)
 
         # Lightweight path: Rust builds a minimal dict; filter/JSON need full dict.
         if filter is None and not serialize:
@@ -1368,10 +2369,87 @@ class Logger:
             return result or callbacks_removed > 0
         return result
 
-    def bind(self, **kwargs: Any) -> Logger:
+    def reset(self) -> None:
+        """Return the logger to its initial state.
+
+        Removes every handler, callback and callable sink, reinstalls the default
+        console handler at DEBUG, and clears bound context, patchers, pause state
+        and the callback error handler. Handlers are shared with bound loggers,
+        which therefore lose their handlers too. Custom levels stay registered.
+
+        Useful for isolating tests that share the global ``logger``.
+
+        Examples:
+            >>> logger.add("test.log")
+            >>> logger.reset()  # Back to a single console handler
+        """
+        self._inner.reset()
+        self._patchers = []
+        self._context = {}
+        self._collect_options.clear()
+        self._callback_ids.clear()
+        self._filter_ids.clear()
+        self._raw_callback_ids.clear()
+        self._invalidate_requirements_cache()
+
+    def save_config(self) -> ConfigSnapshot:
+        """Capture the current handlers, callbacks and custom levels.
+
+        Pass the result to ``restore_config()`` to bring them back, e.g. after a
+        test or a temporary reconfiguration. Saved file handlers keep their files
+        open (and their rotation state) until the snapshot is dropped, so
+        restoring a ``mode="w"`` handler does not truncate the file again.
+        Bound context, patchers and the pause state are not part of the snapshot.
+
+        Returns:
+            An opaque snapshot; it can be restored any number of times.
+
+        Examples:
+            >>> saved = logger.save_config()
+            >>> logger.remove()
+            >>> logger.add("debug.log", level="TRACE")
+            >>> logger.restore_config(saved)  # Original handlers are back
+        """
+        return ConfigSnapshot(
+            self._inner.save_config(),
+            dict(self._collect_options),
+            frozenset(self._callback_ids),
+            frozenset(self._filter_ids),
+            frozenset(self._raw_callback_ids),
+        )
+
+    def restore_config(self, snapshot: ConfigSnapshot) -> None:
+        """Replace the handlers, callbacks and custom levels with a saved snapshot.
+
+        Handlers keep the ids (and handles) they had when saved. Handlers added
+        since are removed and their pending repeat summaries written. Like
+        ``remove()``, this affects bound loggers, which share the handlers.
+
+        Args:
+            snapshot: Value returned by ``save_config()``.
+        """
+        self._inner.restore_config(snapshot._inner)
+        self._collect_options.clear()
+        self._collect_options.update(snapshot._collect_options)
+        self._callback_ids.clear()
+        self._callback_ids.update(snapshot._callback_ids)
+        self._filter_ids.clear()
+        self._filter_ids.update(snapshot._filter_ids)
+        self._raw_callback_ids.clear()
+        self._raw_callback_ids.update(snapshot._raw_callback_ids)
+        self._invalidate_requirements_cache()
+
+    def bind(self, *, override: bool = True, **kwargs: Any) -> Logger:
         """Create a new logger with bound context values.
 
+        Callable values are called with no arguments each time a record is
+        emitted, and their result is used as the value. They are skipped when
+        the level is filtered out or no handler or callback accepts the record.
+
         Args:
+            override: Whether new values replace keys that are already bound.
+                With False, existing keys keep their value and only new keys
+                are added.
             **kwargs: Key-value pairs to bind to log records.
 
         Returns:
@@ -1381,9 +2459,14 @@ class Logger:
             >>> user_logger = logger.bind(user_id="123", session="abc")
             >>> user_logger.info("User action")
             # Output includes extra context in JSON mode
+            >>> host_logger = logger.bind(host=socket.gethostname)  # Computed at log time
+            >>> user_logger.bind(user_id="default", override=False)  # user_id stays "123"
         """
-        new_inner = self._inner.bind(kwargs)
-        new_context = {**self._context, **kwargs}
+        new_inner = self._inner.bind(kwargs, not override)
+        if override:
+            new_context = {**self._context, **kwargs}
+        else:
+            new_context = {**kwargs, **self._context}
         return Logger(
             new_inner,
             patchers=self._patchers.copy(),
@@ -1422,6 +2505,28 @@ class Logger:
             self._inner = original
             self._context = original_context
 
+    def set_context_provider(self, provider: Callable[[], dict[str, Any] | None] | None) -> None:
+        """Add the extra of the current context to every record.
+
+        ``provider`` is called with no arguments once per record that some
+        handler or callback accepts, in the thread or task that logs, so it can
+        read ``contextvars`` that asyncio copies into each task. The dict it
+        returns is merged into the record's extra; bound and per-call values
+        win over its keys. The provider is shared by every logger derived from
+        this one.
+
+        Args:
+            provider: Callable returning a dict (or None for no extra), or None
+                to remove the provider.
+
+        Examples:
+            >>> request_ctx: ContextVar[dict[str, Any]] = ContextVar("request_ctx", default={})
+            >>> logger.set_context_provider(request_ctx.get)
+            >>> request_ctx.set({"request_id": "abc"})
+            >>> logger.info("Handled")  # includes request_id
+        """
+        self._inner.set_context_provider(provider)
+
     def catch(
         self,
         exception: type[BaseException] | tuple[type[BaseException], ...] = Exception,
@@ -1459,10 +2564,9 @@ class Logger:
                 try:
                     return func(*args, **func_kwargs)
                 except exception as e:
-                    tb = traceback.format_exc()
                     log_method = getattr(self, level.lower())
                     # _depth=1 to skip this wrapper and show caller of decorated function
-                    log_method(f"{message}: {e}", exception=tb, _depth=1)
+                    log_method(f"{message}: {e}", exception=_format_exception(e), _depth=1)
                     if reraise:
                         raise
 
@@ -1471,16 +2575,29 @@ class Logger:
         return decorator
 
     def add_callback(
-        self, callback: Callable[[dict[str, Any]], None], level: LogLevel | str | None = None
-    ) -> int:
+        self,
+        callback: Callable[[dict[str, Any]], None],
+        level: LogLevel | str | None = None,
+        *,
+        fields: Iterable[str] | None = None,
+    ) -> HandlerHandle:
         """Add a callback to receive log records.
 
         Args:
             callback: Function to call with log record dict.
             level: Minimum log level for callback invocation.
+            fields: Only put these record keys in the dict (e.g. ``["level",
+                    "message"]``). Cheaper than the full record, and caller,
+                    thread and process info are only collected when requested.
+                    Valid keys: level, level_no, message, timestamp, name,
+                    function, line, file, thread_name, thread_id, process_name,
+                    process_id, elapsed, extra, exception.
 
         Returns:
-            Callback ID for later removal.
+            Handle for the callback (usable wherever a callback ID is expected).
+
+        Raises:
+            ValueError: If ``fields`` contains an unknown key.
 
         Examples:
             >>> def my_callback(record):
@@ -1488,15 +2605,24 @@ class Logger:
             >>> callback_id = logger.add_callback(my_callback)
             >>> logger.info("Hello")  # Triggers callback
             >>> logger.remove_callback(callback_id)
+            >>> logger.add_callback(count_levels, fields=["level"])
         """
         resolved_level = _to_log_level(level) if level is not None else None
-        callback_id = self._inner.add_callback(callback, resolved_level)
+        if fields is not None:
+            handle = self._inner.add_callback(callback, resolved_level, list(fields))
+            # Requirements follow the requested fields (auto-detect from Rust)
+            self._collect_options[handle.id] = CollectOptions()
+            self._callback_ids.add(handle.id)
+            self._invalidate_requirements_cache()
+            return self._track(handle)
+
+        handle = self._inner.add_callback(callback, resolved_level)
         # Track with default CollectOptions (auto-detect) so callbacks get full records
-        self._collect_options[callback_id] = CollectOptions()
+        self._collect_options[handle.id] = CollectOptions()
         # Track as raw callback (receives raw records, needs full records)
-        self._raw_callback_ids.add(callback_id)
+        self._raw_callback_ids.add(handle.id)
         self._invalidate_requirements_cache()
-        return callback_id
+        return self._track(handle)
 
     def remove_callback(self, callback_id: int) -> bool:
         """Remove a callback by ID.
@@ -1516,6 +2642,121 @@ class Logger:
         self._invalidate_requirements_cache()
         return result
 
+    def on_callback_error(self, handler: Callable[[int, BaseException], Any] | None) -> None:
+        """Route exceptions raised by callbacks and callable sinks to a handler.
+
+        Exceptions never propagate to the logging call. Without a handler, the
+        first exception of each callback is reported on stderr (through
+        ``sys.unraisablehook``) and later ones are only counted.
+
+        Args:
+            handler: Called as ``handler(callback_id, exc)`` for every error,
+                     or None to restore the default reporting.
+
+        Examples:
+            >>> logger.on_callback_error(lambda cid, exc: print(cid, exc))
+        """
+        self._inner.set_callback_error_handler(handler)
+
+    def callback_errors(self, callback_id: int | None = None) -> int:
+        """Count exceptions raised by callbacks and callable sinks.
+
+        Args:
+            callback_id: Count only this callback, or all registered ones if None.
+
+        Returns:
+            Number of exceptions raised so far.
+        """
+        return self._inner.callback_error_count(callback_id)
+
+    def callback_calls(self, callback_id: int | None = None) -> int:
+        """Count records passed to callbacks and callable sinks.
+
+        Args:
+            callback_id: Count only this callback, or all registered ones if None.
+
+        Returns:
+            Number of calls so far, including calls that raised.
+        """
+        return self._inner.callback_call_count(callback_id)
+
+    def suppressed(self, handler_id: int | None = None) -> int:
+        """Count records dropped by handler rate limits.
+
+        Args:
+            handler_id: Count only this handler, or all handlers if None.
+
+        Returns:
+            Number of records dropped so far (0 for handlers without rate_limit).
+
+        Examples:
+            >>> handler_id = logger.add("noisy.log", rate_limit="100/s")
+            >>> logger.suppressed(handler_id)
+            0
+        """
+        return self._inner.suppressed_count(handler_id)
+
+    def has_file_handler(self, path: str | os.PathLike[str]) -> bool:
+        """Check whether a file handler already writes to a path.
+
+        Paths are compared after resolving symlinks and relative parts, so
+        ``"logs/app.log"`` and its absolute form match. A path with
+        {extra[key]} placeholders matches the template it was added with.
+
+        Args:
+            path: File path to look for.
+
+        Returns:
+            True if some file handler writes to the path.
+
+        Examples:
+            >>> if not logger.has_file_handler("app.log"):
+            ...     logger.add("app.log")
+        """
+        return self._inner.has_file_handler(os.fspath(path))
+
+    def file_handler_id(self, path: str | os.PathLike[str]) -> int | None:
+        """Get the ID of the file handler writing to a path.
+
+        Paths are compared like in has_file_handler().
+
+        Args:
+            path: File path to look for.
+
+        Returns:
+            The handler ID, or None if no file handler writes to the path.
+
+        Examples:
+            >>> handler_id = logger.file_handler_id("app.log")
+            >>> if handler_id is not None:
+            ...     logger.remove(handler_id)
+        """
+        return self._inner.file_handler_id(os.fspath(path))
+
+    def handler_stats(self, handler_id: int) -> HandlerStats | None:
+        """Get the record count, and for files the size and rotation state, of a handler.
+
+        Values come from counters the handler already keeps, so the file is not stat-ed.
+        ``records`` counts records that passed the level, filter and rate_limit checks.
+        For paths with {extra[key]} placeholders, sizes are summed over the files
+        currently open.
+
+        Args:
+            handler_id: ID returned by add().
+
+        Returns:
+            Dict with records, path, size, file_time, next_rotation,
+            write_failures, rotations and queued_bytes (the file fields are None
+            for console handlers), or None for unknown IDs.
+
+        Examples:
+            >>> handler_id = logger.add("app.log", rotation="daily")
+            >>> stats = logger.handler_stats(handler_id)
+            >>> stats["size"], stats["next_rotation"]
+            (0, '2025-01-02T00:00:00+00:00')
+        """
+        return cast("HandlerStats | None", self._inner.handler_stats(handler_id))
+
     def patch(self, patcher: Callable[[dict[str, Any]], None]) -> Logger:
         """Create a new logger with a patcher function.
 
@@ -1554,6 +2795,26 @@ class Logger:
             aggregated_options_box=self._aggregated_options_box,
         )
 
+    @staticmethod
+    def validate_format(template: str) -> FormatValidation:
+        """Check a format template without adding a handler.
+
+        Unknown placeholders such as a typo'd ``{levl}`` are written as literal text,
+        so check templates from configuration up front to fail fast.
+
+        Args:
+            template: Format template, as passed to add(format=...).
+
+        Returns:
+            Dict with the recognized and unrecognized placeholders (without braces).
+
+        Examples:
+            >>> logger.validate_format("{tiem} | {level} | {message}")
+            {'recognized': ['level', 'message'], 'unrecognized': ['tiem']}
+        """
+        recognized, unrecognized = _validate_format(template)
+        return {"recognized": recognized, "unrecognized": unrecognized}
+
     def configure(
         self,
         *,
@@ -1561,31 +2822,66 @@ class Logger:
         levels: list[dict[str, Any]] | None = None,
         extra: dict[str, Any] | None = None,
         patcher: Callable[[dict[str, Any]], None] | None = None,
-    ) -> list[int]:
+    ) -> list[HandlerHandle]:
         """Configure the logger from dictionaries.
 
         Args:
-            handlers: List of handler configurations. Each dict can have:
+            handlers: List of handler configurations, replacing all existing
+                handlers and callbacks (as ``remove()`` does). Each dict can have:
                 - sink (required): File path or sys.stdout/sys.stderr
                 - level: Minimum log level
+                - max_level: Highest log level written (file/console sinks)
                 - format: Format string
+                - strict_format: Reject unknown placeholders (file/console sinks)
                 - rotation: Rotation strategy (file sinks only)
                 - retention: Retention policy (file sinks only)
                 - compression: Enable compression (file sinks only)
                 - serialize: Output as JSON
-                - filter: Filter function
-                - enqueue: Async writes (file sinks only, default False)
+                - serialize_wrapped: Output {"text", "record"} JSON (file/console sinks)
+                - logfmt: Output key=value pairs (file/console sinks)
+                - csv: Output CSV rows (file sinks only)
+                - csv_header: Header row on new files, with csv (file sinks only)
+                - if_exists: "error", "replace" or "ignore" (file sinks only)
+                - mode: "a" to append (default) or "w" to truncate (file sinks only)
+                - on_error: "skip" to warn instead of raising if the file can't be
+                  opened (file sinks only)
+                - on_rotation: Called with each rotated file's path (file sinks only)
+                - compress_on_close: Gzip the active file on removal/exit (with compression)
+                - filter: Filter function, module name or extra patterns
+                - filter_fields: Record keys a callable filter reads (file/console sinks)
+                - enqueue: Async writes (file and console sinks, default False)
+                - shared_writer: One writer thread for all enqueued file sinks
+                - max_queue_bytes: Bytes an enqueued file sink may queue (file sinks only)
+                - write_retries: Retries after a transient write error (file sinks only)
+                - write_retry_backoff: Seconds before the first retry (file sinks only)
+                - watch: Reopen externally deleted/rotated files (file sinks only)
+                - line_terminator: Record terminator, default "\n" (file sinks only)
+                - autoflush: Flush after every record (file sinks only)
+                - fsync: Sync the file to stable storage (file sinks only)
+                - max_open_files: Open file cap for {extra[key]} paths (file sinks only)
+                - rate_limit: Maximum records per period, e.g. "100/s" (file/console sinks)
+                - dedupe: Collapse consecutive repeated messages (file/console sinks)
                 - colorize: Enable ANSI colors (console sinks only)
+                - colorize_full: Tint the whole line by level (console sinks only)
+                - colorize_exception: Color tracebacks (console sinks only)
+                - utc: Timestamps in UTC
+                - time_format: strftime format of timestamps (file/console sinks)
+                - time_precision: "seconds", "millis", "micros" or "nanos" (file/console sinks)
+                - prefix / suffix: Text around every formatted line (not JSON)
+                - level_map: Per-handler level remapping (file/console sinks)
+                - static_fields: Top-level fields added to every JSON record
+                - message_colors: Regex to color tag rules for the message (console sinks only)
             levels: List of custom level configurations. Each dict must have:
                 - name (required): Level name
                 - no (required): Numeric value
                 - color: Color name
                 - icon: Icon symbol
+                - overwrite: Take ``no`` from another custom level using it
             extra: Default extra fields to bind
             patcher: Default patcher function
 
         Returns:
-            List of handler IDs that were created.
+            Handles of the handlers that were created.
 
         Examples:
             >>> logger.configure(
@@ -1595,41 +2891,85 @@ class Logger:
             ...         {"sink": sys.stdout, "colorize": True},
             ...         {"sink": sys.stderr, "serialize": True},
             ...     ],
-            ...     levels=[{"name": "NOTICE", "no": 25, "color": "cyan"}],
+            ...     levels=[{"name": "NOTICE", "no": 22, "color": "cyan"}],
             ...     extra={"app": "myapp"},
             ... )
         """
-        handler_ids: list[int] = []
-
-        if levels:
-            for level_config in levels:
-                name = level_config.get("name")
-                no = level_config.get("no")
-                if name and no is not None:
-                    self.level(
-                        name,
-                        no=no,
-                        color=level_config.get("color"),
-                        icon=level_config.get("icon"),
-                    )
-
-        if handlers:
-            for handler_config in handlers:
-                sink = handler_config.get("sink")
-                if sink:
-                    handler_id = self.add(
-                        sink,
-                        level=handler_config.get("level"),
-                        format=handler_config.get("format"),
-                        rotation=handler_config.get("rotation"),
-                        retention=handler_config.get("retention"),
-                        compression=handler_config.get("compression", False),
-                        serialize=handler_config.get("serialize", False),
-                        filter=handler_config.get("filter"),
-                        enqueue=handler_config.get("enqueue", False),
-                        colorize=handler_config.get("colorize"),
-                    )
-                    handler_ids.append(handler_id)
+        handler_ids: list[HandlerHandle] = []
+
+        # Levels and handlers are applied one by one: undo them all if one is invalid
+        saved = self.save_config() if levels or handlers is not None else None
+        try:
+            if handlers is not None:
+                self.remove()
+
+            if levels:
+                for level_config in levels:
+                    name = level_config.get("name")
+                    no = level_config.get("no")
+                    if name and no is not None:
+                        self.level(
+                            name,
+                            no=no,
+                            color=level_config.get("color"),
+                            icon=level_config.get("icon"),
+                            overwrite=level_config.get("overwrite", False),
+                        )
+
+            if handlers:
+                for handler_config in handlers:
+                    sink = handler_config.get("sink")
+                    if sink:
+                        handler_id = self.add(
+                            sink,
+                            level=handler_config.get("level"),
+                            max_level=handler_config.get("max_level"),
+                            format=handler_config.get("format"),
+                            strict_format=handler_config.get("strict_format", False),
+                            rotation=handler_config.get("rotation"),
+                            retention=handler_config.get("retention"),
+                            compression=handler_config.get("compression", False),
+                            serialize=handler_config.get("serialize", False),
+                            serialize_wrapped=handler_config.get("serialize_wrapped", False),
+                            logfmt=handler_config.get("logfmt", False),
+                            csv=handler_config.get("csv", False),
+                            csv_header=handler_config.get("csv_header", False),
+                            if_exists=handler_config.get("if_exists"),
+                            mode=handler_config.get("mode", "a"),
+                            on_error=handler_config.get("on_error", "raise"),
+                            on_rotation=handler_config.get("on_rotation"),
+                            compress_on_close=handler_config.get("compress_on_close", False),
+                            filter=handler_config.get("filter"),
+                            filter_fields=handler_config.get("filter_fields"),
+                            enqueue=handler_config.get("enqueue", False),
+                            shared_writer=handler_config.get("shared_writer", False),
+                            max_queue_bytes=handler_config.get("max_queue_bytes"),
+                            write_retries=handler_config.get("write_retries", 0),
+                            write_retry_backoff=handler_config.get("write_retry_backoff", 0.01),
+                            watch=handler_config.get("watch", False),
+                            line_terminator=handler_config.get("line_terminator", "\n"),
+                            autoflush=handler_config.get("autoflush", False),
+                            fsync=handler_config.get("fsync", False),
+                            max_open_files=handler_config.get("max_open_files"),
+                            rate_limit=handler_config.get("rate_limit"),
+                            dedupe=handler_config.get("dedupe", False),
+                            colorize=handler_config.get("colorize"),
+                            colorize_full=handler_config.get("colorize_full", False),
+                            colorize_exception=handler_config.get("colorize_exception", False),
+                            utc=handler_config.get("utc", False),
+                            time_format=handler_config.get("time_format"),
+                            time_precision=handler_config.get("time_precision"),
+                            prefix=handler_config.get("prefix"),
+                            suffix=handler_config.get("suffix"),
+                            level_map=handler_config.get("level_map"),
+                            static_fields=handler_config.get("static_fields"),
+                            message_colors=handler_config.get("message_colors"),
+                        )
+                        handler_ids.append(handler_id)
+        except Exception:
+            if saved is not None:
+                self.restore_config(saved)
+            raise
 
         if extra:
             new_inner = self._inner.bind(extra)
@@ -1657,7 +2997,9 @@ class Logger:
                   Useful for expensive computations that should only run if
                   the log level is enabled.
             exception: Auto-capture current exception traceback.
-            depth: Stack frame adjustment (reserved for future use).
+            depth: Report the caller this many frames further up the stack, for
+                   logging helpers that should show their caller's location. The
+                   record's ``stacklevel`` is ``depth + 1``.
             backtrace: Extend trace beyond catch point to show full call stack.
             diagnose: Show variable values at each stack frame.
 
@@ -1,7 +1,9 @@
 """Type stubs for logust._logust Rust extension module."""
 
+import os
 from collections.abc import Callable
-from typing import Any
+from pathlib import Path
+from typing import Any, TextIO
 
 class LogLevel:
     """Log level enum with numeric ordering.
@@ -47,16 +49,70 @@ class Rotation:
     def __eq__(self, other: object) -> bool: ...
     def __hash__(self) -> int: ...
 
+class HandlerHandle:
+    """Returned by the ``add*`` methods.
+
+    Compares, hashes and converts (``int()``, ``__index__``) like the integer
+    handler ID, so it can be passed anywhere an ID is accepted.
+    """
+
+    @property
+    def id(self) -> int:
+        """Handler or callback ID."""
+        ...
+
+    def remove(self) -> bool:
+        """Remove the handler. Returns False if it was already removed."""
+        ...
+
+    def set_level(self, level: LogLevel | str) -> HandlerHandle:
+        """Change the minimum level and return the handle.
+
+        Raises ValueError if the handler has been removed or the level is above
+        its ``max_level``.
+        """
+        ...
+
+    def stats(self) -> dict[str, Any] | None:
+        """Same dict as ``PyLogger.handler_stats()`` (``records`` counts calls for callbacks)."""
+        ...
+
+    def _set_on_change(self, hook: Callable[[int, bool], Any]) -> None: ...
+    def __int__(self) -> int: ...
+    def __index__(self) -> int: ...
+    def __hash__(self) -> int: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __lt__(self, other: object) -> bool: ...
+
+class ConfigSnapshot:
+    """Handlers, callbacks and custom levels saved by ``PyLogger.save_config()``."""
+
+    def __repr__(self) -> str: ...
+
 class PyLogger:
     """Rust-implemented logger core.
 
     This class is the underlying Rust implementation wrapped by the
     Python Logger class. It handles all log record processing,
     handler management, and output formatting.
+
+    Caller info is resolved by the caller: ``name``/``function``/``line``/``file``
+    passed to the log methods must describe the frame ``stacklevel`` levels above
+    the logging call (1 = the direct caller). ``function`` and ``line`` must be
+    given together, and ``stacklevel`` (>= 1) requires them; otherwise the log
+    methods raise ValueError.
     """
 
-    def __init__(self, level: LogLevel | None = None) -> None:
-        """Create a new logger with optional default console level."""
+    def __init__(self, level: LogLevel | None = None, stream: str | TextIO | None = None) -> None:
+        """Create a new logger with optional default console level and stream.
+
+        ``stream`` (``"stdout"``/``"stderr"`` or ``sys.stdout``/``sys.stderr``) picks where
+        the default console writes; without it ``LOGUST_STREAM=stderr`` selects stderr.
+        """
+        ...
+
+    def __repr__(self) -> str:
+        """Summarize handlers, e.g. ``<Logger handlers=[Console(stderr, level=DEBUG)]>``."""
         ...
 
     def add(
@@ -68,10 +124,87 @@ class PyLogger:
         retention: str | None = None,
         compression: bool | None = None,
         serialize: bool | None = None,
-        filter: Callable[[dict[str, Any]], bool] | None = None,
+        filter: Callable[[dict[str, Any]], bool] | str | dict[str, str] | None = None,
         enqueue: bool | None = None,
-    ) -> int:
-        """Add a file handler and return its ID."""
+        serialize_wrapped: bool | None = None,
+        watch: bool | None = None,
+        line_terminator: str | None = None,
+        rate_limit: str | None = None,
+        dedupe: bool | None = None,
+        max_level: LogLevel | None = None,
+        autoflush: bool | None = None,
+        fsync: bool | None = None,
+        max_open_files: int | None = None,
+        utc: bool | None = None,
+        time_format: str | None = None,
+        time_precision: str | None = None,
+        prefix: str | None = None,
+        suffix: str | None = None,
+        level_map: dict[str, LogLevel | str] | None = None,
+        static_fields: dict[str, Any] | None = None,
+        logfmt: bool | None = None,
+        csv: bool | None = None,
+        csv_header: bool | None = None,
+        if_exists: str | None = None,
+        mode: str | None = None,
+        strict_format: bool | None = None,
+        filter_fields: list[str] | None = None,
+        shared_writer: bool | None = None,
+        on_error: str | None = None,
+        on_rotation: Callable[[Path], Any] | None = None,
+        compress_on_close: bool | None = None,
+        max_queue_bytes: str | None = None,
+        write_retries: int | None = None,
+        write_retry_backoff: float | None = None,
+    ) -> HandlerHandle:
+        """Add a file handler and return its handle.
+
+        ``{extra[key]}`` placeholders in ``path`` route each record to its own file,
+        keeping at most ``max_open_files`` (default 64) open.
+
+        ``serialize_wrapped`` emits ``{"text": ..., "record": {...}}`` JSON (implies ``serialize``).
+        ``watch`` reopens the file when it is deleted or replaced externally.
+        ``line_terminator`` is written after each record (default ``"\n"``).
+        ``autoflush`` flushes after every record instead of buffering.
+        ``fsync`` syncs the file to stable storage (per record with ``autoflush``,
+        otherwise on ``complete()``, rotation and removal).
+        ``shared_writer`` (with ``enqueue``) writes through one thread shared by such sinks.
+        ``max_queue_bytes`` (with ``enqueue``, e.g. ``"64 MB"``) caps the bytes queued.
+        ``write_retries`` retries transient write errors, waiting ``write_retry_backoff``
+        seconds (default 0.01) and doubling the wait each time.
+        ``rate_limit`` (e.g. ``"100/s"``) drops and counts records over the limit.
+        ``dedupe`` collapses consecutive repeats into "Last message repeated N times".
+        ``max_level`` caps the levels written (inclusive).
+        ``utc`` renders ``{time}`` and JSON timestamps in UTC.
+        ``time_format`` (chrono strftime, e.g. ``"%H:%M:%S"``) replaces their default format;
+        an unknown specifier raises ValueError.
+        ``time_precision`` sets their fractional seconds: ``"seconds"``, ``"millis"``,
+        ``"micros"`` or ``"nanos"``.
+        ``prefix`` / ``suffix`` wrap every formatted line (JSON output is left as is).
+        ``level_map`` remaps record levels for this handler only (``{"DEBUG": "TRACE"}``).
+        ``static_fields`` are written at the top level of every JSON record (``{"_v": 1}``).
+        ``logfmt`` writes ``ts=... level=info msg=...`` pairs instead of the template.
+        ``csv`` writes ``time,level,name,line,message,extra`` rows; ``csv_header`` adds that
+        header at the start of every new or empty file.
+        ``if_exists`` (``"error"``, ``"replace"`` or ``"ignore"``) applies when a file handler
+        for the same resolved path already exists.
+        ``mode`` is ``"a"`` (append, default) or ``"w"`` (empty the file when it is opened).
+        ``on_error="skip"`` warns on stderr and adds a handler that drops records when the
+        file can't be opened, instead of raising OSError.
+        ``on_rotation`` is called on a background thread with each rotated file's path.
+        ``compress_on_close`` (with ``compression``) gzips the active file on removal and exit.
+        ``strict_format`` raises ValueError for unrecognized placeholders in ``format``.
+        ``filter_fields`` names the record keys a callable ``filter`` reads; it then receives
+        a dict of just those keys.
+        """
+        ...
+
+    def file_handler_id(self, path: str | os.PathLike[str]) -> int | None:
+        """Id of the file handler writing to ``path`` (compared canonicalized), if any."""
+        ...
+
+    def has_file_handler(self, path: str | os.PathLike[str]) -> bool:
+        """Whether a file handler writes to ``path``."""
         ...
 
     def add_console(
@@ -80,18 +213,115 @@ class PyLogger:
         level: LogLevel | None = None,
         format: str | None = None,
         serialize: bool | None = None,
-        filter: Callable[[dict[str, Any]], bool] | None = None,
+        filter: Callable[[dict[str, Any]], bool] | str | dict[str, str] | None = None,
+        colorize: bool | None = None,
+        colorize_full: bool | None = None,
+        serialize_wrapped: bool | None = None,
+        rate_limit: str | None = None,
+        dedupe: bool | None = None,
+        max_level: LogLevel | None = None,
+        colorize_exception: bool | None = None,
+        utc: bool | None = None,
+        time_format: str | None = None,
+        time_precision: str | None = None,
+        prefix: str | None = None,
+        suffix: str | None = None,
+        level_map: dict[str, LogLevel | str] | None = None,
+        static_fields: dict[str, Any] | None = None,
+        message_colors: dict[str, str] | None = None,
+        logfmt: bool | None = None,
+        strict_format: bool | None = None,
+        filter_fields: list[str] | None = None,
+        enqueue: bool | None = None,
+    ) -> HandlerHandle:
+        """Add a console handler (stdout or stderr).
+
+        When ``colorize`` is None, ``NO_COLOR`` disables and ``FORCE_COLOR`` enables
+        colors; otherwise they are enabled only if the stream is a terminal.
+        ``colorize_full`` tints the whole line in the level color.
+        ``serialize_wrapped`` emits ``{"text": ..., "record": {...}}`` JSON (implies ``serialize``).
+        ``rate_limit`` (e.g. ``"100/s"``) drops and counts records over the limit.
+        ``dedupe`` collapses consecutive repeats into "Last message repeated N times".
+        ``max_level`` caps the levels written (inclusive).
+        ``colorize_exception`` colors tracebacks (paths in cyan, the exception line in red).
+        ``utc`` renders ``{time}`` and JSON timestamps in UTC.
+        ``time_format`` and ``time_precision`` set their format, as for ``add()``.
+        ``prefix`` / ``suffix`` wrap every formatted line (JSON output is left as is).
+        ``level_map`` remaps record levels for this handler only, as for ``add()``.
+        ``static_fields`` are written at the top level of every JSON record, as for ``add()``.
+        ``message_colors`` maps regexes to color tags for matching parts of the message.
+        ``logfmt`` writes key=value pairs instead of the template, as for ``add()``.
+        ``strict_format`` rejects unrecognized placeholders, as for ``add()``.
+        ``filter_fields`` limits the dict passed to ``filter``, as for ``add()``.
+        ``enqueue`` writes lines from a background thread shared by all such handlers.
+        """
+        ...
+
+    def split_console(
+        self,
+        level: LogLevel | None = None,
+        format: str | None = None,
+        serialize: bool | None = None,
         colorize: bool | None = None,
-    ) -> int:
-        """Add a console handler (stdout or stderr)."""
+        colorize_full: bool | None = None,
+        colorize_exception: bool | None = None,
+        utc: bool | None = None,
+    ) -> tuple[HandlerHandle, HandlerHandle]:
+        """Replace the console handlers with a stdout/stderr pair split at WARNING.
+
+        Records below WARNING go to stdout, the rest to stderr. ``enable()`` restores
+        the pair after ``disable()``. Returns the ``(stdout, stderr)`` handles.
+        ``utc`` renders ``{time}`` and JSON timestamps in UTC.
+        """
+        ...
+
+    def add_journald(
+        self,
+        identifier: str,
+        level: LogLevel | None = None,
+        socket_path: str | Path | None = None,
+    ) -> HandlerHandle:
+        """Add a handler sending native journal entries to journald.
+
+        Raises OSError when the journal socket can't be reached.
+        """
+        ...
+
+    def configure(
+        self,
+        handlers: list[dict[str, Any]] | None = None,
+        levels: list[dict[str, Any]] | None = None,
+        extra: dict[str, Any] | None = None,
+    ) -> list[HandlerHandle]:
+        """Replace all handlers from spec dicts, register levels, and merge extra.
+
+        A handler spec whose ``sink`` is ``"stdout"``/``"stderr"`` (or
+        ``sys.stdout``/``sys.stderr``) becomes a console handler; any other
+        sink is treated as a file path. Returns handles for the new handlers.
+        """
         ...
 
     def remove(self, handler_id: int | None = None) -> bool:
         """Remove a handler by ID, or all handlers if None."""
         ...
 
-    def bind(self, kwargs: dict[str, Any] | None = None) -> PyLogger:
-        """Create a new logger with bound context values."""
+    def bind(
+        self, kwargs: dict[str, Any] | None = None, keep_existing: bool = False
+    ) -> PyLogger:
+        """Create a new logger with bound context values.
+
+        Callable values are called at log time, only for records some handler or callback accepts.
+        With ``keep_existing``, keys that are already bound keep their value.
+        """
+        ...
+
+    def patch(self, patcher: Callable[[dict[str, Any]], Any]) -> PyLogger:
+        """Create a new logger that runs ``patcher(record)`` before dispatch.
+
+        ``record`` has ``level``, ``level_no``, ``message``, ``timestamp``, ``exception``
+        and ``extra``; changes to ``message``, ``exception`` and ``extra`` are applied.
+        Patchers are kept by ``bind()`` and run only for records some handler accepts.
+        """
         ...
 
     def set_level(self, level: LogLevel) -> None:
@@ -102,6 +332,10 @@ class PyLogger:
         """Get current minimum log level."""
         ...
 
+    def would_log(self, level: LogLevel | int) -> bool:
+        """Lock-free check against the cached minimum level (False while paused)."""
+        ...
+
     def is_level_enabled(self, level: LogLevel) -> bool:
         """Check if any handler would accept messages at the given level."""
         ...
@@ -111,6 +345,11 @@ class PyLogger:
         """Get the cached minimum log level across all handlers and callbacks."""
         ...
 
+    @property
+    def min_level_name(self) -> str | None:
+        """Level name of ``min_level`` (nearest built-in above it if unmapped; None if unset)."""
+        ...
+
     def enable(self, level: LogLevel | None = None) -> None:
         """Enable console output with given level."""
         ...
@@ -123,23 +362,48 @@ class PyLogger:
         """Check if console output is enabled."""
         ...
 
-    def complete(self) -> None:
-        """Flush all file handlers to ensure pending logs are written."""
+    def pause(self) -> None:
+        """Drop all records until resume() is called (handlers stay registered)."""
+        ...
+
+    def resume(self) -> None:
+        """Resume logging after pause()."""
+        ...
+
+    def set_global_level(self, level: LogLevel | None = None) -> None:
+        """Drop records below `level` whatever the handler levels (None removes the floor)."""
+        ...
+
+    def get_global_level(self) -> LogLevel | None:
+        """Level set with `set_global_level()`, None when there is no floor."""
+        ...
+
+    @property
+    def is_paused(self) -> bool:
+        """Check if logging is currently paused."""
+        ...
+
+    def complete(self, handler_id: int | None = None) -> bool:
+        """Flush all file handlers (or only ``handler_id``) to ensure pending logs are written.
+
+        Returns False if ``handler_id`` is not a handler or callback of this logger.
+        """
         ...
 
     def add_callback(
         self,
         callback: Callable[[dict[str, Any]], None],
         level: LogLevel | None = None,
-    ) -> int:
-        """Add a callback to receive log records."""
+        fields: list[str] | None = None,
+    ) -> HandlerHandle:
+        """Add a callback to receive log records (only the ``fields`` keys when given)."""
         ...
 
     def add_serialized_callback(
         self,
         callback: Callable[[dict[str, Any]], None],
         level: LogLevel | None = None,
-    ) -> int:
+    ) -> HandlerHandle:
         """Add a serialized callable sink with typed JSON extras."""
         ...
 
@@ -149,7 +413,7 @@ class PyLogger:
         requirements: tuple[bool, ...],
         extra_keys: tuple[str, ...],
         level: LogLevel | None = None,
-    ) -> int:
+    ) -> HandlerHandle:
         """Add a formatted callable sink (minimal record dict for templates)."""
         ...
 
@@ -157,6 +421,46 @@ class PyLogger:
         """Remove a callback by ID."""
         ...
 
+    def set_context_provider(
+        self, provider: Callable[[], dict[str, Any] | None] | None = None
+    ) -> None:
+        """Set the callable returning extra for the current context (None removes it)."""
+        ...
+
+    def set_callback_error_handler(
+        self, handler: Callable[[int, BaseException], Any] | None = None
+    ) -> None:
+        """Set the ``handler(callback_id, exc)`` for callback exceptions (None restores stderr reporting)."""
+        ...
+
+    def callback_error_count(self, callback_id: int | None = None) -> int:
+        """Count exceptions raised by one callback, or by all registered callbacks."""
+        ...
+
+    def callback_call_count(self, callback_id: int | None = None) -> int:
+        """Count records passed to one callback, or to all registered callbacks."""
+        ...
+
+    def suppressed_count(self, handler_id: int | None = None) -> int:
+        """Count records dropped by the rate limit of one handler, or of all handlers."""
+        ...
+
+    def reset(self) -> None:
+        """Return to a single DEBUG console handler with no callbacks, context or patchers."""
+        ...
+
+    def save_config(self) -> ConfigSnapshot:
+        """Capture the handlers, callbacks and custom levels (file sinks stay open)."""
+        ...
+
+    def restore_config(self, snapshot: ConfigSnapshot) -> None:
+        """Replace the handlers, callbacks and custom levels with a saved snapshot."""
+        ...
+
+    def handler_stats(self, handler_id: int) -> dict[str, Any] | None:
+        """Records written by a handler, plus size and rotation state for file handlers."""
+        ...
+
     def remove_callbacks(self, callback_ids: list[int]) -> int:
         """Remove multiple callbacks by IDs (batch operation).
 
@@ -227,8 +531,21 @@ class PyLogger:
         no: int,
         color: str | None = None,
         icon: str | None = None,
+        overwrite: bool = False,
     ) -> None:
-        """Register a custom log level."""
+        """Register a custom log level.
+
+        ValueError if `name` or `no` is a built-in level's, or `no` is taken by another custom
+        level unless `overwrite`.
+        """
+        ...
+
+    def set_level_color(self, name: str, color: str) -> None:
+        """Change the console color of a built-in or custom level (ValueError if unknown)."""
+        ...
+
+    def set_level_icon(self, name: str, icon: str) -> None:
+        """Change the ``{icon}`` of a built-in or custom level (ValueError if unknown)."""
         ...
 
     def log(
@@ -244,8 +561,54 @@ class PyLogger:
         thread_id: int | None = None,
         process_name: str | None = None,
         process_id: int | None = None,
+        extra: dict[str, Any] | None = None,
+        stacklevel: int | None = None,
+        timestamp: int | str | None = None,
+        end: str | None = None,
     ) -> None:
-        """Log at any level (built-in or custom)."""
+        """Log at any level (built-in or custom); ``extra`` is merged into this record only.
+
+        ``timestamp`` (epoch milliseconds or an ISO 8601 string) replaces the current time in
+        the record; file rotation still follows the wall clock. ``end`` is written after the
+        record instead of the console/file line terminator (``""`` leaves the line open).
+        """
+        ...
+
+    def log_fmt(
+        self,
+        level_arg: LogLevel | str | int,
+        template: str,
+        args: tuple[Any, ...],
+        exception: str | None = None,
+        name: str | None = None,
+        function: str | None = None,
+        line: int | None = None,
+        file: str | None = None,
+        thread_name: str | None = None,
+        thread_id: int | None = None,
+        process_name: str | None = None,
+        process_id: int | None = None,
+        extra: dict[str, Any] | None = None,
+        stacklevel: int | None = None,
+    ) -> None:
+        """Log ``template.format(*args)``, rendering only if some handler accepts the level."""
+        ...
+
+    def log_batch(
+        self,
+        level_arg: LogLevel | str | int,
+        messages: list[str],
+        name: str | None = None,
+        function: str | None = None,
+        line: int | None = None,
+        file: str | None = None,
+        thread_name: str | None = None,
+        thread_id: int | None = None,
+        process_name: str | None = None,
+        process_id: int | None = None,
+        stacklevel: int | None = None,
+    ) -> None:
+        """Log many messages at one level under a single handler-lock acquisition."""
         ...
 
     def trace(
@@ -260,6 +623,10 @@ class PyLogger:
         thread_id: int | None = None,
         process_name: str | None = None,
         process_id: int | None = None,
+        extra: dict[str, Any] | None = None,
+        stacklevel: int | None = None,
+        timestamp: int | str | None = None,
+        end: str | None = None,
     ) -> None:
         """Output TRACE level log message."""
         ...
@@ -276,6 +643,10 @@ class PyLogger:
         thread_id: int | None = None,
         process_name: str | None = None,
         process_id: int | None = None,
+        extra: dict[str, Any] | None = None,
+        stacklevel: int | None = None,
+        timestamp: int | str | None = None,
+        end: str | None = None,
     ) -> None:
         """Output DEBUG level log message."""
         ...
@@ -292,6 +663,10 @@ class PyLogger:
         thread_id: int | None = None,
         process_name: str | None = None,
         process_id: int | None = None,
+        extra: dict[str, Any] | None = None,
+        stacklevel: int | None = None,
+        timestamp: int | str | None = None,
+        end: str | None = None,
     ) -> None:
         """Output INFO level log message."""
         ...
@@ -308,6 +683,10 @@ class PyLogger:
         thread_id: int | None = None,
         process_name: str | None = None,
         process_id: int | None = None,
+        extra: dict[str, Any] | None = None,
+        stacklevel: int | None = None,
+        timestamp: int | str | None = None,
+        end: str | None = None,
     ) -> None:
         """Output SUCCESS level log message."""
         ...
@@ -324,6 +703,10 @@ class PyLogger:
         thread_id: int | None = None,
         process_name: str | None = None,
         process_id: int | None = None,
+        extra: dict[str, Any] | None = None,
+        stacklevel: int | None = None,
+        timestamp: int | str | None = None,
+        end: str | None = None,
     ) -> None:
         """Output WARNING level log message."""
         ...
@@ -340,6 +723,10 @@ class PyLogger:
         thread_id: int | None = None,
         process_name: str | None = None,
         process_id: int | None = None,
+        extra: dict[str, Any] | None = None,
+        stacklevel: int | None = None,
+        timestamp: int | str | None = None,
+        end: str | None = None,
     ) -> None:
         """Output ERROR level log message."""
         ...
@@ -356,6 +743,10 @@ class PyLogger:
         thread_id: int | None = None,
         process_name: str | None = None,
         process_id: int | None = None,
+        extra: dict[str, Any] | None = None,
+        stacklevel: int | None = None,
+        timestamp: int | str | None = None,
+        end: str | None = None,
     ) -> None:
         """Output FAIL level log message."""
         ...
@@ -372,8 +763,32 @@ class PyLogger:
         thread_id: int | None = None,
         process_name: str | None = None,
         process_id: int | None = None,
+        extra: dict[str, Any] | None = None,
+        stacklevel: int | None = None,
+        timestamp: int | str | None = None,
+        end: str | None = None,
     ) -> None:
         """Output CRITICAL level log message."""
         ...
 
 logger: PyLogger
+
+def _shutdown() -> None:
+    """Flush every live logger and join its async writers (registered with ``atexit``)."""
+    ...
+
+def _format_exception(exc: BaseException) -> str:
+    """Format ``exc`` with its traceback, as ``traceback.format_exception`` would."""
+    ...
+
+def _validate_format(template: str) -> tuple[list[str], list[str]]:
+    """Placeholders of ``template`` as ``(recognized, unrecognized)`` lists."""
+    ...
+
+def _level_icon(name: str) -> str:
+    """Icon of a level for ``{icon}`` in callable sink templates (empty if it has none)."""
+    ...
+
+def _hostname() -> str:
+    """Host name for ``{hostname}`` in callable sink templates, resolved once."""
+    ...
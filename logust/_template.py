@@ -6,10 +6,14 @@ at sink creation time.
 
 from __future__ import annotations
 
+import os
 import re
 from dataclasses import dataclass
+from datetime import datetime, timezone
 from typing import TYPE_CHECKING, Any
 
+from ._logust import _hostname, _level_icon
+
 if TYPE_CHECKING:
     pass
 
@@ -27,11 +31,42 @@ KNOWN_TOKENS: tuple[str, ...] = (
     "thread",
     "process",
     "message",
+    "icon",
+    "seq",
+    "hostname",
+    "pid",
 )
 
 # Tokens that require caller info collection
 CALLER_TOKENS: frozenset[str] = frozenset({"name", "module", "function", "line", "file"})
 
+# OpenTelemetry correlation keys: {trace_id} / {span_id} read these extra fields, and
+# serialized output writes them as top-level fields (must match TRACE_CONTEXT_KEYS in
+# src/format.rs)
+TRACE_CONTEXT_KEYS: tuple[str, ...] = ("trace_id", "span_id")
+
+# Top-level keys of serialized records, which static_fields can't reuse (must match
+# JSON_RECORD_KEYS in src/format.rs)
+JSON_RECORD_KEYS: frozenset[str] = frozenset(
+    {
+        "time",
+        "level",
+        "message",
+        "name",
+        "function",
+        "line",
+        "seq",
+        "trace_id",
+        "span_id",
+        "extra",
+        "exception",
+        "exception_type",
+        "exception_message",
+        "text",
+        "record",
+    }
+)
+
 
 @dataclass(frozen=True, slots=True)
 class LiteralSegment:
@@ -54,6 +89,39 @@ class TokenSegment:
 Segment = LiteralSegment | TokenSegment
 
 
+def truncate_message(message: str, max_chars: int) -> str:
+    """Cut a message to at most ``max_chars`` characters, the last an ellipsis marking the cut.
+
+    Below two characters there is no room for text and an ellipsis, so the cut has none.
+    """
+    if len(message) <= max_chars:
+        return message
+    if max_chars < 2:
+        return message[:max_chars]
+    return message[: max_chars - 1] + "\u2026"
+
+
+def format_logfmt(extra: dict[str, Any], separator: str = " ") -> str:
+    """Render extra fields as sorted ``key=value`` pairs (the ``{extra}`` token).
+
+    Values that are empty or contain whitespace, ``=`` or ``"`` are quoted,
+    matching ``write_logfmt`` in src/format.rs.
+    """
+    pairs: list[str] = []
+    for key in sorted(extra):
+        value = str(extra[key])
+        if not value or any(c.isspace() or c in '="' for c in value):
+            escaped = value.replace("\\", "\\\\").replace('"', '\\"').replace("\n", "\\n")
+            value = f'"{escaped}"'
+        pairs.append(f"{key}={value}")
+    return separator.join(pairs)
+
+
+def to_utc_timestamp(timestamp: str) -> str:
+    """Convert a record's RFC 3339 timestamp (local offset) to UTC."""
+    return datetime.fromisoformat(timestamp).astimezone(timezone.utc).isoformat()
+
+
 class ParsedCallableTemplate:
     """Pre-parsed format template for callable sinks.
 
@@ -64,22 +132,36 @@ class ParsedCallableTemplate:
     Performance improvement: ~1-2us/log for callable sinks.
     """
 
-    __slots__ = ("_needed_tokens", "_needs_extra", "_needs_process", "_needs_thread", "_segments")
+    __slots__ = (
+        "_needed_tokens",
+        "_needs_extra",
+        "_needs_process",
+        "_needs_thread",
+        "_segments",
+        "_utc",
+    )
 
-    # Token pattern: {token} or {token:spec} or {extra[key]} or {extra[key]:spec}
+    # Token pattern: {token} or {token:spec} or {extra[key]} or {extra[key]:spec},
+    # or a bare {extra} / {extra:separator} for all fields
     # Only matches known tokens to preserve unknown patterns as literals
-    # extra[...] allows any characters except ] (supports hyphens, dots, unicode, etc.)
+    # extra[...] allows any characters except ] (supports hyphens, dots, unicode, etc.),
+    # and {extra[user][id]} reads a value nested in a dict field
     # Built from KNOWN_TOKENS to ensure consistency with auto-detect
+    # {{ and }} are matched first so they become literal braces
     _TOKEN_PATTERN = re.compile(
-        r"\{(" + "|".join(re.escape(t) for t in KNOWN_TOKENS) + r"|extra\[[^\]]+\])(?::([^}]+))?\}"
+        r"\{\{|\}\}|\{("
+        + "|".join(re.escape(t) for t in KNOWN_TOKENS + TRACE_CONTEXT_KEYS)
+        + r"|extra(?:\[[^\]]+\])+|extra)(?::([^}]+))?\}"
     )
 
-    def __init__(self, template: str) -> None:
+    def __init__(self, template: str, *, utc: bool = False) -> None:
         """Parse the template into segments.
 
         Args:
             template: Format template string.
+            utc: Render every {time} token in UTC (not just {time:utc}).
         """
+        self._utc = utc
         self._segments: tuple[Segment, ...] = self._parse(template)
         # Pre-compute which tokens are needed for lazy evaluation
         self._needed_tokens: frozenset[str] = frozenset(
@@ -109,9 +191,17 @@ class ParsedCallableTemplate:
             key = match.group(1)
             spec = match.group(2)
 
-            if key.startswith("extra["):
-                extra_key = key[6:-1]  # Extract key from extra[key]
+            if key is None:
+                # Escaped brace: "{{" or "}}"
+                segments.append(LiteralSegment(match.group(0)[0]))
+            elif key.startswith("extra["):
+                # Extract key from extra[key]; "user][id" for extra[user][id], which the
+                # Rust side resolves when building the record
+                extra_key = key[6:-1]
                 segments.append(TokenSegment("extra", spec, True, extra_key))
+            elif key in TRACE_CONTEXT_KEYS:
+                # {trace_id} is shorthand for {extra[trace_id]}
+                segments.append(TokenSegment("extra", spec, True, key))
             else:
                 segments.append(TokenSegment(key, spec, False, None))
 
@@ -127,12 +217,12 @@ class ParsedCallableTemplate:
         """Booleans for Rust `FormattedSinkRequirements` / `build_mini_record_dict`.
 
         Order: timestamp, level, name, function, line, file, elapsed, thread, process,
-        message, nested extra. Must match ``src/lib.rs`` ``FormattedSinkRequirements``.
+        message, seq, nested extra. Must match ``src/lib.rs`` ``FormattedSinkRequirements``.
         """
         nt = self._needed_tokens
         return (
             "time" in nt,
-            "level" in nt,
+            "level" in nt or "icon" in nt,
             ("name" in nt) or ("module" in nt),
             "function" in nt,
             "line" in nt,
@@ -141,14 +231,20 @@ class ParsedCallableTemplate:
             self._needs_thread,
             self._needs_process,
             "message" in nt,
+            "seq" in nt,
             self._needs_extra,
         )
 
     def lightweight_extra_keys_for_rust(self) -> tuple[str, ...]:
         """``extra[key]`` names for Rust ``FormattedSinkRequirements.extra_keys``.
 
-        Order preserved, unique.
+        Order preserved, unique. Empty (all fields) when the template has a bare ``{extra}``.
         """
+        if any(
+            isinstance(seg, TokenSegment) and seg.key == "extra" and not seg.is_extra
+            for seg in self._segments
+        ):
+            return ()
         keys: list[str] = []
         seen: set[str] = set()
         for seg in self._segments:
@@ -204,8 +300,19 @@ class ParsedCallableTemplate:
                     key = seg.key
                     if key == "time":
                         value = record.get("timestamp", "")
+                        if value and (self._utc or seg.spec == "utc"):
+                            parts.append(to_utc_timestamp(value))
+                            continue
                     elif key == "level":
                         value = record.get("level", "")
+                    elif key == "icon":
+                        value = _level_icon(record.get("level", ""))
+                    elif key == "seq":
+                        value = record.get("seq", 0)
+                    elif key == "hostname":
+                        value = _hostname()
+                    elif key == "pid":
+                        value = os.getpid()
                     elif key == "name" or key == "module":
                         value = record.get("name", "")
                     elif key == "function":
@@ -220,8 +327,15 @@ class ParsedCallableTemplate:
                         value = thread_str
                     elif key == "process":
                         value = process_str
+                    elif key == "extra":
+                        parts.append(format_logfmt(extra, seg.spec or " "))
+                        continue
                     elif key == "message":
                         value = record.get("message", "")
+                        if seg.spec and seg.spec[0] == "." and seg.spec[1:].isdigit():
+                            # {message:.N}: cut to N characters with an ellipsis
+                            parts.append(truncate_message(value, int(seg.spec[1:])))
+                            continue
                     else:
                         value = ""
 
@@ -6,6 +6,8 @@ log records, callbacks, and configuration dictionaries.
 
 from __future__ import annotations
 
+from collections.abc import Callable
+from pathlib import Path
 from typing import Any, NamedTuple, Protocol, TextIO, TypedDict
 
 
@@ -49,6 +51,10 @@ class LogRecord(TypedDict, total=False):
         timestamp: ISO 8601 formatted timestamp.
         exception: Exception traceback if present.
         extra: Additional context from bind().
+        seq: Position among the records emitted by the logger (from 1).
+        stacklevel: Frames above the logging call that name/function/line
+            come from (1 = the direct caller, ``depth + 1`` with ``opt(depth=...)``).
+            None when caller info was not collected from the stack.
     """
 
     level: str
@@ -57,6 +63,8 @@ class LogRecord(TypedDict, total=False):
     timestamp: str
     exception: str | None
     extra: dict[str, Any]
+    seq: int
+    stacklevel: int | None
 
 
 class FilterCallback(Protocol):
@@ -111,7 +119,11 @@ class HandlerConfig(TypedDict, total=False):
     Attributes:
         sink: File path or sys.stdout/sys.stderr for output (required).
         level: Minimum log level (name or numeric value).
+        max_level: Highest log level written (inclusive).
+                   Only valid for file and console sinks.
         format: Custom format string.
+        strict_format: Raise ValueError for unrecognized placeholders in format.
+                       Only valid for file and console sinks.
         rotation: Rotation strategy ("daily", "hourly", "500 MB").
                   Only valid for file sinks.
         retention: Retention policy ("10 days" or count as int).
@@ -119,23 +131,149 @@ class HandlerConfig(TypedDict, total=False):
         compression: Enable gzip compression for rotated files.
                      Only valid for file sinks.
         serialize: Output as JSON instead of text format.
-        filter: Filter callback function.
+        serialize_wrapped: Output loguru-style {"text", "record"} JSON.
+                           Only valid for file and console sinks.
+        logfmt: Output logfmt key=value pairs instead of text format.
+                Only valid for file and console sinks.
+        csv: Output CSV rows (time, level, name, line, message, extra).
+             Only valid for file sinks.
+        csv_header: Write a header row at the start of every new file.
+                    Only valid for file sinks, with csv.
+        if_exists: "error", "replace" or "ignore" when a file handler for the
+                   same path already exists. Only valid for file sinks.
+        mode: "a" to append to an existing file (default) or "w" to empty it first.
+              Only valid for file sinks.
+        on_error: "skip" to warn and discard records when the file can't be opened
+                  instead of raising (default "raise"). Only valid for file sinks.
+        on_rotation: Called (on a background thread) with each rotated file's path.
+                     Only valid for file sinks.
+        compress_on_close: With compression, gzip the active file when the handler is
+                           removed or at exit. Only valid for file sinks.
+        filter: Filter callback function, module name or dict of extra patterns.
+        filter_fields: Record keys a callable filter reads; it then gets only those.
         enqueue: Enable async writes (default True).
-                 Only valid for file sinks.
+                 Valid for file and console sinks.
+        shared_writer: With enqueue, use one writer thread for all such sinks.
+                       Only valid for file sinks.
+        max_queue_bytes: With enqueue, bytes the queue may hold (int or "64 MB").
+                         Only valid for file sinks.
+        watch: Reopen the file if it is deleted or replaced externally.
+               Only valid for file sinks.
+        line_terminator: String written after each record (default "\n").
+                         Only valid for file sinks.
+        autoflush: Flush after every record (durable, slower).
+                   Only valid for file sinks.
+        fsync: Sync the file to stable storage (per record with autoflush).
+               Only valid for file sinks.
+        max_open_files: Files kept open for {extra[key]} paths (default 64).
+                        Only valid for file sinks.
+        rate_limit: Maximum records written per period ("100/s", "10/min").
+                    Only valid for file and console sinks.
+        dedupe: Collapse consecutive repeated messages into a summary record.
+                Only valid for file and console sinks.
         colorize: Enable ANSI color codes for console sinks.
                   If not specified, auto-detect based on TTY.
+        colorize_full: Tint the whole line in the level color.
+                       Only valid for console sinks.
+        colorize_exception: Color tracebacks (paths in cyan, exception line in red).
+                            Only valid for console sinks.
+        utc: Render timestamps in UTC instead of local time (display only).
+        time_format: chrono strftime format of timestamps, e.g. "%H:%M:%S".
+                     Only valid for file and console sinks.
+        time_precision: Fractional seconds of timestamps: "seconds", "millis",
+                        "micros" or "nanos". Only valid for file and console sinks.
+        prefix: Text written before every formatted line (not JSON).
+        suffix: Text written after every formatted line (not JSON).
+        level_map: Remap record levels for this handler only, e.g. {"DEBUG": "TRACE"}.
+                   Only valid for file and console sinks.
+        static_fields: Fields added at the top level of every JSON record, e.g. {"_v": 1}.
+        message_colors: Color message parts matching a regex, e.g. {r"^\[OK\]": "green"}.
+                        Only valid for console sinks.
     """
 
     sink: str | TextIO
     level: str | int
+    max_level: str | int
     format: str
     rotation: str
     retention: str | int
     compression: bool
     serialize: bool
-    filter: FilterCallback
+    serialize_wrapped: bool
+    logfmt: bool
+    csv: bool
+    csv_header: bool
+    strict_format: bool
+    if_exists: str
+    mode: str
+    on_error: str
+    on_rotation: Callable[[Path], Any]
+    compress_on_close: bool
+    filter: FilterCallback | str | dict[str, str]
+    filter_fields: list[str]
     enqueue: bool
+    shared_writer: bool
+    max_queue_bytes: int | str
+    watch: bool
+    line_terminator: str
+    autoflush: bool
+    fsync: bool
+    max_open_files: int
+    rate_limit: str
+    dedupe: bool
     colorize: bool
+    colorize_full: bool
+    colorize_exception: bool
+    utc: bool
+    time_format: str
+    time_precision: str
+    prefix: str
+    suffix: str
+    level_map: dict[str, str]
+    static_fields: dict[str, Any]
+    message_colors: dict[str, str]
+
+
+class FormatValidation(TypedDict):
+    """Result of Logger.validate_format().
+
+    Placeholders are listed without braces, in template order.
+
+    Attributes:
+        recognized: Placeholders that are replaced, e.g. "level:<8" or "extra[user]".
+        unrecognized: Placeholders written as literal text, e.g. a typo'd "levl".
+    """
+
+    recognized: list[str]
+    unrecognized: list[str]
+
+
+class HandlerStats(TypedDict):
+    """Handler counters returned by logger.handler_stats().
+
+    File fields are None for console handlers.
+
+    Attributes:
+        records: Records written since the handler was added (after level, filter
+                 and rate_limit).
+        path: Active file path (the template for {extra[key]} paths).
+        size: Bytes in the active file, including records not yet written by enqueue.
+        file_time: When the active file was started or last rotated (ISO 8601).
+        next_rotation: Next time-based rotation (ISO 8601), or None.
+        write_failures: Records dropped because writing them failed (after the
+                        write_retries, for transient errors).
+        rotations: Files rotated away since the handler was added.
+        queued_bytes: Bytes of records waiting for the enqueue writer thread.
+    """
+
+    records: int
+    path: str | None
+    size: int | None
+    file_time: str | None
+    next_rotation: str | None
+    write_failures: int | None
+    rotations: int | None
+    queued_bytes: int | None
 
 
 class LevelConfig(TypedDict, total=False):
@@ -146,9 +284,11 @@ class LevelConfig(TypedDict, total=False):
         no: Numeric severity value. Required.
         color: Color name for terminal output.
         icon: Icon symbol for display.
+        overwrite: Take the number even if another custom level uses it.
     """
 
     name: str
     no: int
     color: str
     icon: str
+    overwrite: bool
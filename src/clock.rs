@@ -0,0 +1,51 @@
+use chrono::{DateTime, Local};
+
+/// Current time for record timestamps, elapsed time and file rotation.
+/// Always the wall clock in builds; unit tests can pin it per thread via `test_clock`.
+#[inline]
+pub fn now() -> DateTime<Local> {
+    #[cfg(test)]
+    if let Some(pinned) = test_clock::current() {
+        return pinned;
+    }
+    Local::now()
+}
+
+/// Deterministic clock for unit tests (thread-local, so parallel tests don't interfere).
+/// Only code running on the test's own thread sees the pinned time (e.g. sync file sinks).
+#[cfg(test)]
+pub mod test_clock {
+    use std::cell::Cell;
+
+    use chrono::{DateTime, Duration, Local};
+
+    thread_local! {
+        static PINNED: Cell<Option<DateTime<Local>>> = const { Cell::new(None) };
+    }
+
+    /// Restores the wall clock for this thread when dropped
+    pub struct ClockGuard {
+        _private: (),
+    }
+
+    impl Drop for ClockGuard {
+        fn drop(&mut self) {
+            PINNED.with(|p| p.set(None));
+        }
+    }
+
+    /// Pin the clock for the current thread to `at`
+    pub fn set(at: DateTime<Local>) -> ClockGuard {
+        PINNED.with(|p| p.set(Some(at)));
+        ClockGuard { _private: () }
+    }
+
+    /// Move the pinned clock forward (no-op when the clock is not pinned)
+    pub fn advance(by: Duration) {
+        PINNED.with(|p| p.set(p.get().map(|t| t + by)));
+    }
+
+    pub(crate) fn current() -> Option<DateTime<Local>> {
+        PINNED.with(|p| p.get())
+    }
+}
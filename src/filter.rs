@@ -0,0 +1,118 @@
+use regex::Regex;
+
+use crate::format::TokenRequirements;
+use crate::handler::LogRecord;
+
+/// Filter given as data instead of a Python callable, checked without the GIL.
+/// A string keeps records logged from that module or its submodules; a dict keeps records
+/// whose `extra` fields match every `key: pattern` pair (`*` and `?` wildcards).
+#[derive(Clone, Debug)]
+pub enum ContextFilter {
+    /// Module name prefix, matched on dotted boundaries (`"app.db"` keeps `app.db.pool`)
+    Name(String),
+    /// `extra` key and compiled glob; a record missing the key is dropped
+    Extra(Vec<(String, Regex)>),
+}
+
+impl ContextFilter {
+    /// Filter on extra fields from `(key, glob pattern)` pairs
+    pub fn extra(patterns: Vec<(String, String)>) -> Self {
+        ContextFilter::Extra(
+            patterns
+                .into_iter()
+                .map(|(key, pattern)| (key, glob_to_regex(&pattern)))
+                .collect(),
+        )
+    }
+
+    pub fn matches(&self, record: &LogRecord) -> bool {
+        match self {
+            ContextFilter::Name(prefix) => {
+                let name = record.caller.name.as_str();
+                prefix.is_empty()
+                    || name
+                        .strip_prefix(prefix.as_str())
+                        .is_some_and(|rest| rest.is_empty() || rest.starts_with('.'))
+            }
+            ContextFilter::Extra(patterns) => patterns.iter().all(|(key, glob)| {
+                record
+                    .extra
+                    .get(key)
+                    .is_some_and(|value| glob.is_match(value.as_str()))
+            }),
+        }
+    }
+
+    /// Record data the filter reads (a name filter needs caller info collected)
+    pub fn requirements(&self) -> TokenRequirements {
+        TokenRequirements {
+            needs_caller: matches!(self, ContextFilter::Name(_)),
+            ..TokenRequirements::default()
+        }
+    }
+}
+
+/// Anchored regex for a glob where `*` matches any text and `?` one character
+fn glob_to_regex(pattern: &str) -> Regex {
+    let mut source = String::from("(?s)^");
+    for c in pattern.chars() {
+        match c {
+            '*' => source.push_str(".*"),
+            '?' => source.push('.'),
+            _ => source.push_str(&regex::escape(c.encode_utf8(&mut [0; 4]))),
+        }
+    }
+    source.push('$');
+    // Only wildcards are unescaped, so the pattern always compiles
+    Regex::new(&source).expect("escaped glob is a valid regex")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handler::{CallerInfo, ExtraValue};
+    use crate::level::LogLevel;
+    use std::sync::Arc;
+
+    fn record(name: &str, extra: &[(&str, &str)]) -> LogRecord {
+        let extra = extra
+            .iter()
+            .map(|&(key, value)| (key.to_string(), ExtraValue::from(value)))
+            .collect();
+        let mut record = LogRecord::with_extra(LogLevel::Info, "msg".into(), Arc::new(extra));
+        record.caller = CallerInfo {
+            name: name.to_string(),
+            ..CallerInfo::default()
+        };
+        record
+    }
+
+    #[test]
+    fn test_name_filter_matches_module_and_submodules() {
+        let filter = ContextFilter::Name("app.db".into());
+        assert!(filter.matches(&record("app.db", &[])));
+        assert!(filter.matches(&record("app.db.pool", &[])));
+        assert!(!filter.matches(&record("app.dbx", &[])));
+        assert!(!filter.matches(&record("app", &[])));
+        assert!(ContextFilter::Name(String::new()).matches(&record("any", &[])));
+    }
+
+    #[test]
+    fn test_extra_filter_globs() {
+        let filter = ContextFilter::extra(vec![
+            ("component".into(), "db*".into()),
+            ("region".into(), "eu-?".into()),
+        ]);
+        assert!(filter.matches(&record(
+            "app",
+            &[("component", "db.pool"), ("region", "eu-1")]
+        )));
+        assert!(!filter.matches(&record("app", &[("component", "web"), ("region", "eu-1")])));
+        assert!(!filter.matches(&record("app", &[("component", "db"), ("region", "eu-12")])));
+        assert!(!filter.matches(&record("app", &[("component", "db")])));
+
+        let literal = ContextFilter::extra(vec![("path".into(), "a.b+(c)".into())]);
+        assert!(literal.matches(&record("app", &[("path", "a.b+(c)")])));
+        assert!(!literal.matches(&record("app", &[("path", "aXb+(c)")])));
+    }
+}
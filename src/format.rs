@@ -1,16 +1,30 @@
-use std::collections::HashMap;
+use std::borrow::Cow;
+use std::collections::BTreeMap;
 use std::fmt::Write as _;
-use std::sync::LazyLock;
+use std::path::Path;
+use std::sync::{Arc, LazyLock};
 
-use chrono::{DateTime, Local};
+use chrono::format::{Item, StrftimeItems};
+use chrono::{DateTime, Local, Utc};
 use colored::Color;
+use regex::Regex;
 use serde::Serialize;
 
-use crate::handler::{ExtraMap, LogRecord};
+use crate::clock;
+use crate::handler::{ExtraMap, ExtraValue, HOSTNAME, LogRecord, cached_pid, split_extra_path};
 use crate::level::LogLevel;
 
 /// Logger initialization time for elapsed calculation
-pub static LOGGER_START_TIME: LazyLock<DateTime<Local>> = LazyLock::new(Local::now);
+pub static LOGGER_START_TIME: LazyLock<DateTime<Local>> = LazyLock::new(clock::now);
+
+/// Check a chrono strftime format (e.g. `"%Y-%m-%d %H:%M"`) for unknown specifiers
+pub fn validate_time_format(format: &str) -> Result<(), String> {
+    if StrftimeItems::new(format).any(|item| item == Item::Error) {
+        Err(format!("Invalid time format: {format:?}"))
+    } else {
+        Ok(())
+    }
+}
 
 /// Write elapsed time as HH:MM:SS.mmm into `out`.
 /// Handles negative durations (e.g., clock adjustment) by clamping to 0.
@@ -37,10 +51,10 @@ pub fn format_elapsed(start: &DateTime<Local>, now: &DateTime<Local>) -> String
     s
 }
 
-/// Apply ANSI color code to text (thread-safe, no global state)
+/// Map a color to its ANSI SGR foreground code
 #[inline]
-fn colorize_text(text: &str, color: Color, bold: bool) -> String {
-    let color_code = match color {
+fn ansi_color_code(color: Color) -> &'static str {
+    match color {
         Color::Black => "30",
         Color::Red => "31",
         Color::Green => "32",
@@ -58,7 +72,13 @@ fn colorize_text(text: &str, color: Color, bold: bool) -> String {
         Color::BrightCyan => "96",
         Color::BrightWhite => "97",
         _ => "0", // Default/reset
-    };
+    }
+}
+
+/// Apply ANSI color code to text (thread-safe, no global state)
+#[inline]
+fn colorize_text(text: &str, color: Color, bold: bool) -> String {
+    let color_code = ansi_color_code(color);
 
     if bold {
         format!("\x1b[1;{}m{}\x1b[0m", color_code, text)
@@ -67,6 +87,27 @@ fn colorize_text(text: &str, color: Color, bold: bool) -> String {
     }
 }
 
+const ANSI_RESET: &str = "\x1b[0m";
+
+/// Tint a whole (already colorized) line with `color`.
+/// The tint is re-applied after every reset, so colored tokens and `<tag>` markup keep
+/// their own styling and the text around them stays in the level color.
+fn tint_line(line: &str, color: Color) -> String {
+    let tint = format!("\x1b[{}m", ansi_color_code(color));
+    let mut result = String::with_capacity(line.len() + tint.len() * 4 + 4);
+    result.push_str(&tint);
+    let mut segments = line.split(ANSI_RESET).peekable();
+    while let Some(segment) = segments.next() {
+        result.push_str(segment);
+        if segments.peek().is_some() {
+            result.push_str(ANSI_RESET);
+            result.push_str(&tint);
+        }
+    }
+    result.push_str(ANSI_RESET);
+    result
+}
+
 /// Apply dim style to text (thread-safe)
 #[inline]
 fn dim_text(text: &str) -> String {
@@ -79,15 +120,172 @@ fn cyan_text(text: &str) -> String {
     format!("\x1b[36m{}\x1b[0m", text)
 }
 
+/// Lines of a Python traceback that introduce a (chained) exception rather than name one
+const TRACEBACK_HEADERS: [&str; 3] = [
+    "Traceback (most recent call last)",
+    "During handling of the above exception",
+    "The above exception was the direct cause",
+];
+
+/// Color a Python traceback: file paths in cyan, exception type and message in red.
+/// Source lines and headers are left as they are.
+fn colorize_traceback(exc: &str) -> String {
+    let mut result = String::with_capacity(exc.len() + 64);
+    for (i, line) in exc.split('\n').enumerate() {
+        if i > 0 {
+            result.push('\n');
+        }
+        let trimmed = line.trim_start();
+        if let Some(rest) = trimmed.strip_prefix("File \"")
+            && let Some(end) = rest.find('"')
+            && trimmed.len() < line.len()
+        {
+            result.push_str(&line[..line.len() - trimmed.len()]);
+            result.push_str("File \"");
+            result.push_str(&cyan_text(&rest[..end]));
+            result.push_str(&rest[end..]);
+        } else if !line.is_empty()
+            && trimmed.len() == line.len()
+            && !TRACEBACK_HEADERS.iter().any(|h| line.starts_with(h))
+        {
+            result.push_str(&colorize_text(line, Color::Red, true));
+        } else {
+            result.push_str(line);
+        }
+    }
+    result
+}
+
+/// Type and message of the exception a Python traceback ends with, e.g.
+/// `("ValueError", "bad input")` for `...\nValueError: bad input`. For chained exceptions
+/// this is the last one raised. The message is empty for an exception raised without one;
+/// None when the text does not end like a traceback.
+pub fn exception_parts(exc: &str) -> Option<(&str, &str)> {
+    // The exception line is the first unindented line after the frames of the last header
+    // (or the first line, for an exception that was never raised)
+    let mut start = None;
+    let mut seeking = true;
+    let mut offset = 0;
+    for line in exc.split_inclusive('\n') {
+        if TRACEBACK_HEADERS.iter().any(|h| line.starts_with(h)) {
+            start = None;
+            seeking = true;
+        } else if seeking && !line.trim_end().is_empty() && !line.starts_with([' ', '\t']) {
+            start = Some(offset);
+            seeking = false;
+        }
+        offset += line.len();
+    }
+    let rest = &exc[start?..];
+    let (type_name, message) = match rest.split_once(": ") {
+        Some((type_name, message)) if !type_name.contains('\n') => (type_name, message),
+        _ => (rest.lines().next()?, ""),
+    };
+    let is_identifier = |part: &str| {
+        part.chars()
+            .next()
+            .is_some_and(|c| c.is_alphabetic() || c == '_')
+            && part.chars().all(|c| c.is_alphanumeric() || c == '_')
+    };
+    type_name
+        .split('.')
+        .all(is_identifier)
+        .then(|| (type_name, message.trim_end()))
+}
+
 /// Default log format template (loguru-compatible with caller info)
 const DEFAULT_FORMAT_TEMPLATE: &str = "{time} | {level:<8} | {name}:{function}:{line} - {message}";
 
+/// Template for handlers added without a format: `LOGUST_FORMAT` (when non-empty),
+/// otherwise the built-in default
+fn default_template() -> String {
+    std::env::var("LOGUST_FORMAT")
+        .ok()
+        .filter(|template| !template.is_empty())
+        .unwrap_or_else(|| DEFAULT_FORMAT_TEMPLATE.to_string())
+}
+
 /// Default time format with milliseconds
 const DEFAULT_TIME_FORMAT: &str = "%Y-%m-%d %H:%M:%S%.3f";
 
+/// Fractional-seconds specifier of the default time format
+const DEFAULT_TIME_FRACTION: &str = "%.3f";
+
+/// Fractional-seconds precision of rendered timestamps
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TimePrecision {
+    Seconds,
+    Millis,
+    Micros,
+    Nanos,
+}
+
+impl TimePrecision {
+    /// Parse "seconds", "millis", "micros" or "nanos"
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "seconds" | "s" => Some(Self::Seconds),
+            "millis" | "ms" => Some(Self::Millis),
+            "micros" | "us" => Some(Self::Micros),
+            "nanos" | "ns" => Some(Self::Nanos),
+            _ => None,
+        }
+    }
+
+    /// chrono specifier for the fractional part (empty for whole seconds)
+    fn fraction(self) -> &'static str {
+        match self {
+            Self::Seconds => "",
+            Self::Millis => "%.3f",
+            Self::Micros => "%.6f",
+            Self::Nanos => "%.9f",
+        }
+    }
+}
+
 /// Initial capacity hint for formatted result strings
 const FORMAT_RESULT_CAPACITY: usize = 64;
 
+/// OpenTelemetry correlation keys: read from extra by the `{trace_id}` / `{span_id}` tokens
+/// and written as top-level fields (instead of under `extra`) in JSON output
+const TRACE_CONTEXT_KEYS: [&str; 2] = ["trace_id", "span_id"];
+
+/// Header row for CSV output, matching the columns of each row
+pub const CSV_HEADER: &str = "time,level,name,line,message,extra";
+
+/// Top-level keys written by the JSON formats (plain and wrapped); static fields can't reuse them
+pub const JSON_RECORD_KEYS: [&str; 15] = [
+    "time",
+    "level",
+    "message",
+    "name",
+    "function",
+    "line",
+    "seq",
+    "trace_id",
+    "span_id",
+    "extra",
+    "exception",
+    "exception_type",
+    "exception_message",
+    "text",
+    "record",
+];
+
+/// Per-handler fields written as top-level keys of every JSON record, ahead of the
+/// record's own keys (e.g. a schema version `"_v": 1`)
+#[derive(Clone, Copy)]
+struct StaticFields<'a>(&'a [(String, ExtraValue)]);
+
+impl Serialize for StaticFields<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_map(self.0.iter().map(|(key, value)| (key, value)))
+    }
+}
+
 /// Flags indicating which runtime information is needed for formatting
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub struct TokenRequirements {
@@ -135,6 +333,51 @@ impl TokenRequirements {
     }
 }
 
+/// Padding alignment for width-formatted tokens
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Align {
+    Left,
+    Center,
+    Right,
+}
+
+impl Align {
+    /// Parse a format-spec alignment character (`<`, `^`, `>`)
+    fn from_char(c: char) -> Option<Align> {
+        match c {
+            '<' => Some(Align::Left),
+            '^' => Some(Align::Center),
+            '>' => Some(Align::Right),
+            _ => None,
+        }
+    }
+}
+
+/// Write `text` padded to `width` with the given alignment
+#[inline]
+fn write_aligned(out: &mut String, text: &str, width: usize, align: Align) {
+    let _ = match align {
+        Align::Left => write!(out, "{:<width$}", text, width = width),
+        Align::Center => write!(out, "{:^width$}", text, width = width),
+        Align::Right => write!(out, "{:>width$}", text, width = width),
+    };
+}
+
+/// Cut `text` to at most `max` characters (not bytes), the last one an ellipsis marking
+/// the cut. A `max` with no room for text before the ellipsis cuts without one.
+fn truncate_chars(text: &str, max: usize) -> Cow<'_, str> {
+    let Some((cut, _)) = text.char_indices().nth(max) else {
+        return Cow::Borrowed(text);
+    };
+    let kept = &text[..cut];
+    if max < 2 {
+        return Cow::Borrowed(kept);
+    }
+    // Give the last kept character's place to the ellipsis
+    let end = kept.char_indices().next_back().map_or(0, |(i, _)| i);
+    Cow::Owned(format!("{}\u{2026}", &text[..end]))
+}
+
 /// Pre-parsed format token for efficient template rendering
 #[derive(Clone, Debug)]
 pub enum FormatToken {
@@ -142,14 +385,23 @@ pub enum FormatToken {
     Static(String),
     /// {time} placeholder
     Time,
+    /// {time:utc} placeholder - timestamp converted to UTC
+    TimeUtc,
     /// {level} placeholder (no width)
     Level,
-    /// {level:<N} placeholder with width
-    LevelWidth(usize),
+    /// {level:<N} / {level:^N} / {level:>N} placeholder with width and alignment
+    LevelWidth(usize, Align),
     /// {message} placeholder
     Message,
+    /// {message:.N} placeholder - message cut to N characters with an ellipsis
+    MessageTruncated(usize),
     /// {extra[key]} placeholder
     Extra(String),
+    /// {extra[key][sub]} placeholder - a value nested in a dict-valued extra field
+    ExtraPath(String, Vec<String>),
+    /// {extra} / {extra:SEP} placeholder - all extra fields as sorted `key=value` pairs
+    /// joined by SEP (default a space)
+    ExtraAll(String),
     /// {name} placeholder - module/logger name
     Name,
     /// {function} placeholder - function name
@@ -166,6 +418,20 @@ pub enum FormatToken {
     File,
     /// {module} placeholder - module name (alias for Name)
     Module,
+    /// {exception} placeholder - traceback text, empty when the record has none
+    Exception,
+    /// {exception.type} placeholder - the exception's type name (see [`exception_parts`])
+    ExceptionType,
+    /// {exception.message} placeholder - the exception's message
+    ExceptionMessage,
+    /// {icon} placeholder - level icon
+    Icon,
+    /// {seq} placeholder - the record's sequence number within its logger
+    Seq,
+    /// {hostname} placeholder - this host's name, resolved once
+    Hostname,
+    /// {pid} placeholder - the current process id (not the passed-in process info)
+    Pid,
 }
 
 /// Compute token requirements from parsed tokens
@@ -186,13 +452,13 @@ fn compute_requirements(tokens: &[FormatToken]) -> TokenRequirements {
             FormatToken::Process => {
                 reqs.needs_process = true;
             }
-            FormatToken::Time => {
+            FormatToken::Time | FormatToken::TimeUtc => {
                 reqs.needs_time = true;
             }
-            FormatToken::Level | FormatToken::LevelWidth(_) => {
+            FormatToken::Level | FormatToken::LevelWidth(..) => {
                 reqs.needs_level = true;
             }
-            FormatToken::Message => {
+            FormatToken::Message | FormatToken::MessageTruncated(_) => {
                 reqs.needs_message = true;
             }
             FormatToken::Elapsed => {
@@ -204,14 +470,182 @@ fn compute_requirements(tokens: &[FormatToken]) -> TokenRequirements {
     reqs
 }
 
+/// Write extra fields logfmt-style: `key=value` pairs in key order, joined by `separator`.
+/// Values that are empty or contain whitespace, `=` or `"` are quoted.
+fn write_logfmt(extra: &ExtraMap, separator: &str, out: &mut String) {
+    let mut fields: Vec<(&String, &ExtraValue)> = extra.iter().collect();
+    fields.sort_unstable_by_key(|&(key, _)| key);
+    for (i, (key, value)) in fields.into_iter().enumerate() {
+        if i > 0 {
+            out.push_str(separator);
+        }
+        write_logfmt_pair(key, value.as_str(), out);
+    }
+}
+
+/// Write one logfmt `key=value` pair, quoting the value if needed
+fn write_logfmt_pair(key: &str, value: &str, out: &mut String) {
+    out.push_str(key);
+    out.push('=');
+    if value.is_empty()
+        || value
+            .chars()
+            .any(|c| c.is_whitespace() || c == '=' || c == '"')
+    {
+        out.push('"');
+        for c in value.chars() {
+            match c {
+                '"' => out.push_str("\\\""),
+                '\\' => out.push_str("\\\\"),
+                '\n' => out.push_str("\\n"),
+                _ => out.push(c),
+            }
+        }
+        out.push('"');
+    } else {
+        out.push_str(value);
+    }
+}
+
+/// Append `field` to a CSV line, quoted (RFC 4180) if it contains `,`, `"` or a line break
+fn write_csv_field(field: &str, out: &mut String) {
+    if field.contains([',', '"', '\r', '\n']) {
+        out.push('"');
+        out.push_str(&field.replace('"', "\"\""));
+        out.push('"');
+    } else {
+        out.push_str(field);
+    }
+}
+
+/// Check whether a template places the exception with `{exception}`
+fn has_exception_token(tokens: &[FormatToken]) -> bool {
+    tokens.iter().any(|t| matches!(t, FormatToken::Exception))
+}
+
+fn is_caller_token(token: &FormatToken) -> bool {
+    matches!(
+        token,
+        FormatToken::Name
+            | FormatToken::Module
+            | FormatToken::Function
+            | FormatToken::Line
+            | FormatToken::File
+    )
+}
+
+/// Static text that can join caller placeholders within one group, like `:` in
+/// `{name}:{function}:{line}`
+fn is_caller_separator(token: &FormatToken) -> bool {
+    matches!(token, FormatToken::Static(s) if s.chars().all(|c| matches!(c, ':' | '.' | '@' | '#' | '/')))
+}
+
+/// Tokens for records without caller info: every `{name}:{function}:{line}`-style group is
+/// dropped along with the separator before it (or after it, at the start of the template),
+/// and brackets around a group go too, so `a | {name}:{line} - b` renders `a - b` instead of
+/// `a | :0 - b`. None when the template has no caller placeholders.
+fn tokens_without_caller(tokens: &[FormatToken]) -> Option<Vec<FormatToken>> {
+    if !tokens.iter().any(is_caller_token) {
+        return None;
+    }
+    let mut result: Vec<FormatToken> = Vec::with_capacity(tokens.len());
+    let mut i = 0;
+    while i < tokens.len() {
+        if !is_caller_token(&tokens[i]) {
+            result.push(tokens[i].clone());
+            i += 1;
+            continue;
+        }
+        // The group runs to the last caller placeholder joined by separators
+        let mut end = i + 1;
+        while end + 1 < tokens.len()
+            && is_caller_separator(&tokens[end])
+            && is_caller_token(&tokens[end + 1])
+        {
+            end += 2;
+        }
+        i = end;
+
+        let mut next = match tokens.get(end) {
+            Some(FormatToken::Static(s)) => {
+                i += 1;
+                Some(s.clone())
+            }
+            _ => None,
+        };
+        let mut prev = match result.last() {
+            Some(FormatToken::Static(s)) => Some(s.clone()),
+            _ => None,
+        };
+        if let (Some(before), Some(after)) = (&mut prev, &mut next)
+            && let Some(open) = before.chars().last()
+            && let Some(close) = matching_bracket(open)
+            && after.starts_with(close)
+        {
+            before.pop();
+            after.remove(0);
+        }
+        if prev.is_some() {
+            result.pop();
+        } else if result.is_empty() {
+            next = None;
+        }
+        if let Some(after) = next {
+            // `[{name}] {message}` at the start leaves no separator to drop before the group
+            let after = if result.is_empty() {
+                after.trim_start().to_string()
+            } else {
+                after
+            };
+            if !after.is_empty() {
+                result.push(FormatToken::Static(after));
+            }
+        }
+    }
+    Some(result)
+}
+
+fn matching_bracket(open: char) -> Option<char> {
+    match open {
+        '(' => Some(')'),
+        '[' => Some(']'),
+        '<' => Some('>'),
+        '{' => Some('}'),
+        _ => None,
+    }
+}
+
+/// Placeholders found in a template (without braces, in order), split by whether they
+/// are recognized. Unrecognized ones are written as literal text.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TemplatePlaceholders {
+    pub recognized: Vec<String>,
+    pub unrecognized: Vec<String>,
+}
+
+/// Check a template without building a handler, e.g. to catch a typo'd `{levl}`
+pub fn validate_template(template: &str) -> TemplatePlaceholders {
+    scan_template(template).1
+}
+
 /// Parse a template string into tokens
 fn parse_template(template: &str) -> Vec<FormatToken> {
+    scan_template(template).0
+}
+
+/// Parse a template into tokens, also reporting which placeholders were recognized.
+/// `{{` and `}}` produce literal braces.
+fn scan_template(template: &str) -> (Vec<FormatToken>, TemplatePlaceholders) {
     let mut tokens = Vec::new();
+    let mut placeholders = TemplatePlaceholders::default();
     let mut chars = template.chars().peekable();
     let mut static_buf = String::new();
 
     while let Some(c) = chars.next() {
-        if c == '{' {
+        // `{{` and `}}` are literal braces
+        if (c == '{' || c == '}') && chars.next_if_eq(&c).is_some() {
+            static_buf.push(c);
+        } else if c == '{' {
             let mut placeholder = String::new();
             while let Some(&ch) = chars.peek() {
                 if ch == '}' {
@@ -221,47 +655,20 @@ fn parse_template(template: &str) -> Vec<FormatToken> {
                 placeholder.push(chars.next().unwrap());
             }
 
-            if !static_buf.is_empty() {
-                tokens.push(FormatToken::Static(std::mem::take(&mut static_buf)));
-            }
-
-            if placeholder == "time" {
-                tokens.push(FormatToken::Time);
-            } else if placeholder == "message" {
-                tokens.push(FormatToken::Message);
-            } else if placeholder == "level" {
-                tokens.push(FormatToken::Level);
-            } else if placeholder == "name" {
-                tokens.push(FormatToken::Name);
-            } else if placeholder == "function" {
-                tokens.push(FormatToken::Function);
-            } else if placeholder == "line" {
-                tokens.push(FormatToken::Line);
-            } else if placeholder == "elapsed" {
-                tokens.push(FormatToken::Elapsed);
-            } else if placeholder == "thread" {
-                tokens.push(FormatToken::Thread);
-            } else if placeholder == "process" {
-                tokens.push(FormatToken::Process);
-            } else if placeholder == "file" {
-                tokens.push(FormatToken::File);
-            } else if placeholder == "module" {
-                tokens.push(FormatToken::Module);
-            } else if let Some(width_str) = placeholder.strip_prefix("level:<") {
-                if let Ok(width) = width_str.parse::<usize>() {
-                    tokens.push(FormatToken::LevelWidth(width));
-                } else {
+            match parse_placeholder(&placeholder) {
+                Some(token) => {
+                    if !static_buf.is_empty() {
+                        tokens.push(FormatToken::Static(std::mem::take(&mut static_buf)));
+                    }
+                    tokens.push(token);
+                    placeholders.recognized.push(placeholder);
+                }
+                None => {
                     static_buf.push('{');
                     static_buf.push_str(&placeholder);
                     static_buf.push('}');
+                    placeholders.unrecognized.push(placeholder);
                 }
-            } else if placeholder.starts_with("extra[") && placeholder.ends_with(']') {
-                let key = &placeholder[6..placeholder.len() - 1];
-                tokens.push(FormatToken::Extra(key.to_string()));
-            } else {
-                static_buf.push('{');
-                static_buf.push_str(&placeholder);
-                static_buf.push('}');
             }
         } else {
             static_buf.push(c);
@@ -272,41 +679,212 @@ fn parse_template(template: &str) -> Vec<FormatToken> {
         tokens.push(FormatToken::Static(static_buf));
     }
 
-    tokens
+    (tokens, placeholders)
+}
+
+/// Token for the text between `{` and `}`, None if it is not a known placeholder
+fn parse_placeholder(placeholder: &str) -> Option<FormatToken> {
+    let token = match placeholder {
+        "time" => FormatToken::Time,
+        "time:utc" => FormatToken::TimeUtc,
+        "message" => FormatToken::Message,
+        "level" => FormatToken::Level,
+        "name" => FormatToken::Name,
+        "function" => FormatToken::Function,
+        "line" => FormatToken::Line,
+        "elapsed" => FormatToken::Elapsed,
+        "thread" => FormatToken::Thread,
+        "process" => FormatToken::Process,
+        "file" => FormatToken::File,
+        "module" => FormatToken::Module,
+        "exception" => FormatToken::Exception,
+        "exception.type" => FormatToken::ExceptionType,
+        "exception.message" => FormatToken::ExceptionMessage,
+        "icon" => FormatToken::Icon,
+        "seq" => FormatToken::Seq,
+        "hostname" => FormatToken::Hostname,
+        "pid" => FormatToken::Pid,
+        "extra" => FormatToken::ExtraAll(" ".to_string()),
+        _ if TRACE_CONTEXT_KEYS.contains(&placeholder) => {
+            FormatToken::Extra(placeholder.to_string())
+        }
+        _ => {
+            if let Some(max) = placeholder.strip_prefix("message:.") {
+                FormatToken::MessageTruncated(max.parse::<usize>().ok()?)
+            } else if let Some(spec) = placeholder.strip_prefix("level:") {
+                let mut spec_chars = spec.chars();
+                let align = spec_chars.next().and_then(Align::from_char)?;
+                FormatToken::LevelWidth(spec_chars.as_str().parse::<usize>().ok()?, align)
+            } else if let Some(separator) = placeholder.strip_prefix("extra:") {
+                FormatToken::ExtraAll(separator.to_string())
+            } else if placeholder.starts_with("extra[") && placeholder.ends_with(']') {
+                let key = &placeholder[6..placeholder.len() - 1];
+                match split_extra_path(key) {
+                    Some((field, path)) => FormatToken::ExtraPath(field.to_string(), path),
+                    None => FormatToken::Extra(key.to_string()),
+                }
+            } else {
+                return None;
+            }
+        }
+    };
+    Some(token)
 }
 
-/// Convert tag name to ANSI escape code (returns static string to avoid allocation)
-fn tag_to_ansi(tag: &str) -> Option<&'static str> {
-    match tag.to_ascii_lowercase().as_str() {
-        "red" => Some("\x1b[31m"),
-        "green" => Some("\x1b[32m"),
-        "yellow" => Some("\x1b[33m"),
-        "blue" => Some("\x1b[34m"),
-        "magenta" => Some("\x1b[35m"),
-        "cyan" => Some("\x1b[36m"),
-        "white" => Some("\x1b[37m"),
-        "black" => Some("\x1b[30m"),
+/// Convert tag name to ANSI escape code.
+/// Named colors and styles borrow a static string; only 256-color/RGB tags allocate.
+fn tag_to_ansi(tag: &str) -> Option<Cow<'static, str>> {
+    let code = match tag.to_ascii_lowercase().as_str() {
+        "red" => "\x1b[31m",
+        "green" => "\x1b[32m",
+        "yellow" => "\x1b[33m",
+        "blue" => "\x1b[34m",
+        "magenta" => "\x1b[35m",
+        "cyan" => "\x1b[36m",
+        "white" => "\x1b[37m",
+        "black" => "\x1b[30m",
+
+        "bright_red" | "light-red" => "\x1b[91m",
+        "bright_green" | "light-green" => "\x1b[92m",
+        "bright_yellow" | "light-yellow" => "\x1b[93m",
+        "bright_blue" | "light-blue" => "\x1b[94m",
+        "bright_magenta" | "light-magenta" => "\x1b[95m",
+        "bright_cyan" | "light-cyan" => "\x1b[96m",
+        "bright_white" | "light-white" => "\x1b[97m",
+
+        "bold" | "b" => "\x1b[1m",
+        "dim" => "\x1b[2m",
+        "italic" | "i" => "\x1b[3m",
+        "underline" | "u" => "\x1b[4m",
+        "strike" | "s" => "\x1b[9m",
+
+        _ => return extended_tag_to_ansi(tag),
+    };
+    Some(Cow::Borrowed(code))
+}
 
-        "bright_red" | "light-red" => Some("\x1b[91m"),
-        "bright_green" | "light-green" => Some("\x1b[92m"),
-        "bright_yellow" | "light-yellow" => Some("\x1b[93m"),
-        "bright_blue" | "light-blue" => Some("\x1b[94m"),
-        "bright_magenta" | "light-magenta" => Some("\x1b[95m"),
-        "bright_cyan" | "light-cyan" => Some("\x1b[96m"),
-        "bright_white" | "light-white" => Some("\x1b[97m"),
+/// Convert a `bg ...`, 256-color or truecolor tag to an ANSI escape code.
+fn extended_tag_to_ansi(tag: &str) -> Option<Cow<'static, str>> {
+    let spec = tag.trim();
+    if let Some(rest) = spec.strip_prefix("bg ") {
+        let rest = rest.trim_start();
+        if let Some(code) = background_to_ansi(&rest.to_ascii_lowercase()) {
+            return Some(Cow::Borrowed(code));
+        }
+        return extended_color_to_ansi(rest, 48, true).map(Cow::Owned);
+    }
+    match spec.strip_prefix("fg ") {
+        Some(rest) => extended_color_to_ansi(rest.trim_start(), 38, true),
+        None => extended_color_to_ansi(spec, 38, false),
+    }
+    .map(Cow::Owned)
+}
 
-        "bold" | "b" => Some("\x1b[1m"),
-        "dim" => Some("\x1b[2m"),
-        "italic" | "i" => Some("\x1b[3m"),
-        "underline" | "u" => Some("\x1b[4m"),
-        "strike" | "s" => Some("\x1b[9m"),
+/// Convert a named background color to an ANSI escape code.
+/// Supports: `<bg red>`, `<bg bright_red>`, `<bg light-red>`, etc.
+fn background_to_ansi(name: &str) -> Option<&'static str> {
+    match name {
+        "black" => Some("\x1b[40m"),
+        "red" => Some("\x1b[41m"),
+        "green" => Some("\x1b[42m"),
+        "yellow" => Some("\x1b[43m"),
+        "blue" => Some("\x1b[44m"),
+        "magenta" => Some("\x1b[45m"),
+        "cyan" => Some("\x1b[46m"),
+        "white" => Some("\x1b[47m"),
+
+        "bright_black" | "light-black" => Some("\x1b[100m"),
+        "bright_red" | "light-red" => Some("\x1b[101m"),
+        "bright_green" | "light-green" => Some("\x1b[102m"),
+        "bright_yellow" | "light-yellow" => Some("\x1b[103m"),
+        "bright_blue" | "light-blue" => Some("\x1b[104m"),
+        "bright_magenta" | "light-magenta" => Some("\x1b[105m"),
+        "bright_cyan" | "light-cyan" => Some("\x1b[106m"),
+        "bright_white" | "light-white" => Some("\x1b[107m"),
 
         _ => None,
     }
 }
 
+/// Convert a 256-color or truecolor spec to an ANSI escape code.
+/// `layer` is 38 for foreground or 48 for background.
+/// Supports: `#ff8800`, `256:214`, and bare `214` when `allow_bare_index` is set.
+fn extended_color_to_ansi(spec: &str, layer: u8, allow_bare_index: bool) -> Option<String> {
+    if let Some(hex) = spec.strip_prefix('#') {
+        let (r, g, b) = parse_hex_rgb(hex)?;
+        return Some(format!("\x1b[{};2;{};{};{}m", layer, r, g, b));
+    }
+
+    // Bare numbers are only colors after "fg "/"bg " so that "<214>" stays literal
+    let index = match spec.strip_prefix("256:") {
+        Some(index) => index,
+        None if allow_bare_index => spec,
+        None => return None,
+    };
+    let n: u8 = index.parse().ok()?;
+    Some(format!("\x1b[{};5;{}m", layer, n))
+}
+
+/// Parse a 6-digit hex color (without the leading '#')
+fn parse_hex_rgb(hex: &str) -> Option<(u8, u8, u8)> {
+    if hex.len() != 6 || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    let r = u8::from_str_radix(&hex[0..2], 16).ok()?;
+    let g = u8::from_str_radix(&hex[2..4], 16).ok()?;
+    let b = u8::from_str_radix(&hex[4..6], 16).ok()?;
+    Some((r, g, b))
+}
+
+/// Substitute `{}` / `{N}` fields and `{{` / `}}` escapes like `str.format(*args)`.
+/// Returns None for anything else (named fields, format specs, conversions, a missing
+/// argument, mixed auto/manual numbering) so the caller can defer to Python's `str.format`.
+pub fn interpolate_positional(template: &str, args: &[String]) -> Option<String> {
+    let mut out =
+        String::with_capacity(template.len() + args.iter().map(String::len).sum::<usize>());
+    let mut rest = template;
+    let mut next_auto = 0;
+    let mut auto_numbering = None;
+
+    while let Some(pos) = rest.find(['{', '}']) {
+        out.push_str(&rest[..pos]);
+        let tail = &rest[pos..];
+        if tail.starts_with("{{") || tail.starts_with("}}") {
+            out.push_str(&tail[..1]);
+            rest = &tail[2..];
+            continue;
+        }
+        if tail.starts_with('}') {
+            return None;
+        }
+
+        let close = tail.find('}')?;
+        let field = &tail[1..close];
+        let index = if field.is_empty() {
+            if auto_numbering == Some(false) {
+                return None;
+            }
+            auto_numbering = Some(true);
+            next_auto += 1;
+            next_auto - 1
+        } else if field.bytes().all(|b| b.is_ascii_digit()) {
+            if auto_numbering == Some(true) {
+                return None;
+            }
+            auto_numbering = Some(false);
+            field.parse().ok()?
+        } else {
+            return None;
+        };
+        out.push_str(args.get(index)?);
+        rest = &tail[close + 1..];
+    }
+    out.push_str(rest);
+    Some(out)
+}
+
 /// Parse and apply color markup tags to text
-/// Supports: <red>, <bold>, <italic>, etc.
+/// Supports: <red>, <bold>, <italic>, <bg red>, <fg #ff8800>, <256:214>, etc.
 pub fn apply_color_markup(text: &str) -> String {
     if !text.contains('<') {
         return text.to_string();
@@ -314,7 +892,7 @@ pub fn apply_color_markup(text: &str) -> String {
 
     let mut result = String::with_capacity(text.len());
     let mut chars = text.chars().peekable();
-    let mut style_stack: Vec<&'static str> = Vec::new();
+    let mut style_stack: Vec<Cow<'static, str>> = Vec::new();
 
     while let Some(c) = chars.next() {
         if c == '<' {
@@ -356,8 +934,8 @@ pub fn apply_color_markup(text: &str) -> String {
                     result.push('>');
                 }
             } else if let Some(ansi) = tag_to_ansi(&tag) {
+                result.push_str(&ansi);
                 style_stack.push(ansi);
-                result.push_str(ansi);
             } else {
                 result.push('<');
                 result.push_str(&tag);
@@ -375,6 +953,61 @@ pub fn apply_color_markup(text: &str) -> String {
     result
 }
 
+/// Message highlighting rule: text matching `pattern` is wrapped in `style` when colorized
+#[derive(Clone, Debug)]
+pub struct MessageColor {
+    pattern: Regex,
+    style: Cow<'static, str>,
+}
+
+impl MessageColor {
+    /// Compile a rule from a regex and a color markup tag (`"green"`, `"bold"`, `"fg #ff8800"`)
+    pub fn new(pattern: &str, color: &str) -> Result<Self, String> {
+        let style = tag_to_ansi(color).ok_or_else(|| format!("unknown color {color:?}"))?;
+        let pattern =
+            Regex::new(pattern).map_err(|e| format!("invalid pattern {pattern:?}: {e}"))?;
+        Ok(MessageColor { pattern, style })
+    }
+}
+
+impl PartialEq for MessageColor {
+    fn eq(&self, other: &Self) -> bool {
+        self.pattern.as_str() == other.pattern.as_str() && self.style == other.style
+    }
+}
+
+/// Wrap the parts of `message` matched by `rules` in their colors.
+/// Where matches overlap, the earlier rule wins.
+fn highlight_message<'a>(message: &'a str, rules: &[MessageColor]) -> Cow<'a, str> {
+    let mut spans: Vec<(usize, usize, &str)> = Vec::new();
+    for rule in rules {
+        for m in rule.pattern.find_iter(message) {
+            let overlaps = spans
+                .iter()
+                .any(|&(start, end, _)| m.start() < end && start < m.end());
+            if !m.is_empty() && !overlaps {
+                spans.push((m.start(), m.end(), &rule.style));
+            }
+        }
+    }
+    if spans.is_empty() {
+        return Cow::Borrowed(message);
+    }
+    spans.sort_unstable_by_key(|&(start, _, _)| start);
+
+    let mut result = String::with_capacity(message.len() + spans.len() * 12);
+    let mut pos = 0;
+    for (start, end, style) in spans {
+        result.push_str(&message[pos..start]);
+        result.push_str(style);
+        result.push_str(&message[start..end]);
+        result.push_str(ANSI_RESET);
+        pos = end;
+    }
+    result.push_str(&message[pos..]);
+    Cow::Owned(result)
+}
+
 /// Format configuration for log output
 #[derive(Clone, Debug)]
 pub struct FormatConfig {
@@ -384,45 +1017,215 @@ pub struct FormatConfig {
     tokens: Vec<FormatToken>,
     /// Whether to serialize as JSON
     pub serialize: bool,
+    /// Wrap JSON output as `{"text": ..., "record": {...}}` (loguru's serialize shape)
+    pub serialize_wrapped: bool,
+    /// Serialize as logfmt `key=value` pairs instead of the template
+    pub logfmt: bool,
+    /// Serialize as a CSV row with the [`CSV_HEADER`] columns instead of the template
+    pub csv: bool,
     /// Time format string
     pub time_format: String,
+    /// Render `{time}` and JSON timestamps in UTC instead of local time
+    pub utc: bool,
     /// Computed requirements based on tokens
     requirements: TokenRequirements,
+    /// Template places `{exception}` itself; otherwise it is appended on a new line
+    exception_in_template: bool,
+    /// Tokens used when a record has no caller info (see [`tokens_without_caller`])
+    tokens_without_caller: Option<Vec<FormatToken>>,
+    /// Color tracebacks when the output is colorized
+    pub colorize_exception: bool,
+    /// Text written before every formatted line (not JSON)
+    pub prefix: String,
+    /// Text written after every formatted line, before the terminator (not JSON)
+    pub suffix: String,
+    /// Fields added at the top level of every JSON record (not text)
+    pub static_fields: Vec<(String, ExtraValue)>,
+    /// Highlighting rules applied to the message in colorized output
+    pub message_colors: Vec<MessageColor>,
 }
 
 impl Default for FormatConfig {
     fn default() -> Self {
-        let template = DEFAULT_FORMAT_TEMPLATE.to_string();
-        let tokens = parse_template(&template);
-        let requirements = compute_requirements(&tokens);
-        FormatConfig {
-            template,
-            tokens,
-            serialize: false,
-            time_format: DEFAULT_TIME_FORMAT.to_string(),
-            requirements,
-        }
+        Self::new(None, false)
     }
 }
 
 impl FormatConfig {
-    /// Create a new format config
+    /// Like [`FormatConfig::new`], but an unrecognized placeholder such as `{levl}` is an
+    /// error instead of literal text
+    pub fn new_strict(template: Option<String>, serialize: bool) -> Result<Self, String> {
+        let config = Self::new(template, serialize);
+        let unrecognized = validate_template(&config.template).unrecognized;
+        if unrecognized.is_empty() {
+            Ok(config)
+        } else {
+            let names: Vec<String> = unrecognized.iter().map(|p| format!("{{{p}}}")).collect();
+            Err(format!(
+                "Unknown format placeholder(s): {}",
+                names.join(", ")
+            ))
+        }
+    }
+
+    /// Create a new format config (without a template, `LOGUST_FORMAT` or the default is used)
     pub fn new(template: Option<String>, serialize: bool) -> Self {
-        let template = template.unwrap_or_else(|| DEFAULT_FORMAT_TEMPLATE.to_string());
+        let template = template.unwrap_or_else(default_template);
         let tokens = parse_template(&template);
         let requirements = compute_requirements(&tokens);
+        let exception_in_template = has_exception_token(&tokens);
+        let tokens_without_caller = tokens_without_caller(&tokens);
         FormatConfig {
             template,
             tokens,
             serialize,
+            serialize_wrapped: false,
+            logfmt: false,
+            csv: false,
             time_format: DEFAULT_TIME_FORMAT.to_string(),
+            utc: false,
             requirements,
+            exception_in_template,
+            tokens_without_caller,
+            colorize_exception: false,
+            prefix: String::new(),
+            suffix: String::new(),
+            static_fields: Vec::new(),
+            message_colors: Vec::new(),
+        }
+    }
+
+    /// Emit loguru-style wrapped JSON (implies `serialize`)
+    pub fn with_serialize_wrapped(mut self, wrapped: bool) -> Self {
+        self.serialize_wrapped = wrapped;
+        self.serialize |= wrapped;
+        self
+    }
+
+    /// Emit logfmt (`ts=... level=info msg=...`); ignored when serializing as JSON
+    pub fn with_logfmt(mut self, logfmt: bool) -> Self {
+        self.logfmt = logfmt;
+        self
+    }
+
+    /// Emit CSV rows (see [`CSV_HEADER`]); ignored when serializing as JSON or logfmt
+    pub fn with_csv(mut self, csv: bool) -> Self {
+        self.csv = csv;
+        self
+    }
+
+    /// Whether output is structured (JSON, logfmt or CSV) rather than the text template
+    pub fn is_structured(&self) -> bool {
+        self.serialize || self.logfmt || self.csv
+    }
+
+    /// Render `{time}` and JSON timestamps in UTC
+    pub fn with_utc(mut self, utc: bool) -> Self {
+        self.utc = utc;
+        self
+    }
+
+    /// Wrap every text line in `prefix` / `suffix`; JSON output is left untouched
+    pub fn with_affixes(mut self, prefix: Option<String>, suffix: Option<String>) -> Self {
+        self.prefix = prefix.unwrap_or_default();
+        self.suffix = suffix.unwrap_or_default();
+        self
+    }
+
+    /// Add `fields` at the top level of every JSON record; text output is left untouched.
+    /// Keys must not collide with [`JSON_RECORD_KEYS`] (checked by the caller).
+    pub fn with_static_fields(mut self, fields: Vec<(String, ExtraValue)>) -> Self {
+        self.static_fields = fields;
+        self
+    }
+
+    /// Color the message parts matched by `rules` (compiled once, applied when colorized)
+    pub fn with_message_colors(mut self, rules: Vec<MessageColor>) -> Self {
+        self.message_colors = rules;
+        self
+    }
+
+    /// Message as written in colorized output: highlighting rules, then `<tag>` markup
+    fn colorize_message(&self, message: &str) -> String {
+        apply_color_markup(&highlight_message(message, &self.message_colors))
+    }
+
+    /// Apply the prefix and suffix to rendered template output
+    fn wrap_line(&self, text: String) -> String {
+        if self.prefix.is_empty() && self.suffix.is_empty() {
+            return text;
+        }
+        let mut line = String::with_capacity(self.prefix.len() + text.len() + self.suffix.len());
+        line.push_str(&self.prefix);
+        line.push_str(&text);
+        line.push_str(&self.suffix);
+        line
+    }
+
+    /// Render fractional seconds at `precision`. Replaces the millisecond specifier of the
+    /// time format, so a format without one is left as is.
+    pub fn with_time_precision(mut self, precision: TimePrecision) -> Self {
+        self.time_format = self
+            .time_format
+            .replace(DEFAULT_TIME_FRACTION, precision.fraction());
+        self
+    }
+
+    /// Use `format` (chrono strftime syntax) for `{time}` and JSON timestamps. It is checked
+    /// here, so an unknown specifier is a config error instead of a panic while rendering.
+    pub fn with_time_format(mut self, format: &str) -> Result<Self, String> {
+        validate_time_format(format)?;
+        self.time_format = format.to_string();
+        Ok(self)
+    }
+
+    /// Timestamp text in the configured time format, converted to UTC if `utc`.
+    /// `time_format` is a public field, so one set without [`Self::with_time_format`] may
+    /// still be invalid: the default format is used then rather than panicking.
+    fn time_text(&self, timestamp: &DateTime<Local>, utc: bool) -> String {
+        let render = |format: &str| {
+            let mut text = String::new();
+            let written = if utc {
+                write!(text, "{}", timestamp.with_timezone(&Utc).format(format))
+            } else {
+                write!(text, "{}", timestamp.format(format))
+            };
+            written.map(|()| text)
+        };
+        render(&self.time_format)
+            .or_else(|_| render(DEFAULT_TIME_FORMAT))
+            .unwrap_or_default()
+    }
+
+    /// Color tracebacks (paths in cyan, the exception line in red) in colorized output
+    pub fn with_colorize_exception(mut self, colorize_exception: bool) -> Self {
+        self.colorize_exception = colorize_exception;
+        self
+    }
+
+    /// Traceback text as written: colored when requested and the output is colorized
+    fn render_exception<'a>(&self, exc: &'a str, colorize: bool) -> Cow<'a, str> {
+        if colorize && self.colorize_exception {
+            Cow::Owned(colorize_traceback(exc))
+        } else {
+            Cow::Borrowed(exc)
         }
     }
 
     /// Get token requirements for this format
     pub fn requirements(&self) -> TokenRequirements {
-        self.requirements
+        if self.serialize_wrapped {
+            // The wrapped record always carries caller, thread and process fields
+            TokenRequirements::all()
+        } else if self.csv {
+            // The name and line columns are filled whatever the template says
+            TokenRequirements {
+                needs_caller: true,
+                ..self.requirements
+            }
+        } else {
+            self.requirements
+        }
     }
 
     /// Format a log record
@@ -435,22 +1238,83 @@ impl FormatConfig {
         exception: &Option<String>,
         colorize: bool,
     ) -> String {
-        if self.serialize {
+        if self.serialize_wrapped {
+            let mut record =
+                LogRecord::with_extra(level, message.to_string(), Arc::new(extra.clone()));
+            record.timestamp = *timestamp;
+            record.exception = exception.clone();
+            self.format_record_json_wrapped(&record)
+        } else if self.serialize {
             self.format_json(timestamp, level, message, extra, exception)
+        } else if self.logfmt {
+            let mut record =
+                LogRecord::with_extra(level, message.to_string(), Arc::new(extra.clone()));
+            record.timestamp = *timestamp;
+            record.exception = exception.clone();
+            self.format_record_logfmt(&record)
+        } else if self.csv {
+            let mut record =
+                LogRecord::with_extra(level, message.to_string(), Arc::new(extra.clone()));
+            record.timestamp = *timestamp;
+            self.format_record_csv(&record)
         } else {
-            self.format_template(timestamp, level, message, extra, exception, colorize)
+            self.wrap_line(
+                self.format_template(timestamp, level, message, extra, exception, colorize),
+            )
         }
     }
 
+    /// Whether this renders every record exactly as `other` does (with or without colors),
+    /// so handlers using the two can share one formatted line
+    pub fn same_output(&self, other: &FormatConfig, colorize: bool) -> bool {
+        let same_colors = !colorize
+            || (self.colorize_exception == other.colorize_exception
+                && self.message_colors == other.message_colors);
+        same_colors
+            && self.template == other.template
+            && self.serialize == other.serialize
+            && self.serialize_wrapped == other.serialize_wrapped
+            && self.logfmt == other.logfmt
+            && self.csv == other.csv
+            && self.time_format == other.time_format
+            && self.utc == other.utc
+            && self.prefix == other.prefix
+            && self.suffix == other.suffix
+            && self.static_fields == other.static_fields
+    }
+
     /// Format a LogRecord (supports both built-in and custom levels)
     pub fn format_record(&self, record: &LogRecord, colorize: bool) -> String {
-        if self.serialize {
+        if self.serialize_wrapped {
+            self.format_record_json_wrapped(record)
+        } else if self.serialize {
             self.format_record_json(record)
+        } else if self.logfmt {
+            self.format_record_logfmt(record)
+        } else if self.csv {
+            self.format_record_csv(record)
         } else {
-            self.format_record_template(record, colorize)
+            self.wrap_line(self.format_record_template(record, colorize))
         }
     }
 
+    /// Format a LogRecord with colors and tint the whole line in the level color.
+    /// JSON, logfmt and CSV output is never tinted.
+    pub fn format_record_tinted(&self, record: &LogRecord) -> String {
+        if self.is_structured() {
+            return self.format_record(record, false);
+        }
+        let level_color = record
+            .level_info
+            .as_ref()
+            .map(|info| info.get_color())
+            .unwrap_or_else(|| record.level.color());
+        self.wrap_line(tint_line(
+            &self.format_record_template(record, true),
+            level_color,
+        ))
+    }
+
     /// Format a LogRecord using pre-parsed tokens (O(n) single pass, thread-safe)
     fn format_record_template(&self, record: &LogRecord, colorize: bool) -> String {
         let reqs = &self.requirements;
@@ -465,7 +1329,7 @@ impl FormatConfig {
 
         // Lazy time formatting - only compute if {time} token is in format
         let time_fmt = if reqs.needs_time {
-            let time_raw = record.timestamp.format(&self.time_format).to_string();
+            let time_raw = self.time_text(&record.timestamp, self.utc);
             if colorize {
                 Some(dim_text(&time_raw))
             } else {
@@ -484,14 +1348,19 @@ impl FormatConfig {
 
         // Lazy message formatting - only for colorized output (non-color uses `record.message` in-token)
         let message_fmt_color = if colorize && reqs.needs_message {
-            Some(apply_color_markup(&record.message))
+            Some(self.colorize_message(&record.message))
         } else {
             None
         };
 
         let mut result = String::with_capacity(self.template.len() + FORMAT_RESULT_CAPACITY);
 
-        for token in &self.tokens {
+        let tokens = if record.caller.is_unknown() {
+            self.tokens_without_caller.as_ref().unwrap_or(&self.tokens)
+        } else {
+            &self.tokens
+        };
+        for token in tokens {
             match token {
                 FormatToken::Static(s) => result.push_str(s),
                 FormatToken::Time => {
@@ -499,6 +1368,10 @@ impl FormatConfig {
                         result.push_str(fmt);
                     }
                 }
+                FormatToken::TimeUtc => {
+                    let utc = self.time_text(&record.timestamp, true);
+                    result.push_str(&if colorize { dim_text(&utc) } else { utc });
+                }
                 FormatToken::Message => {
                     if let Some(ref fmt) = message_fmt_color {
                         result.push_str(fmt);
@@ -506,6 +1379,14 @@ impl FormatConfig {
                         result.push_str(&record.message);
                     }
                 }
+                FormatToken::MessageTruncated(max) => {
+                    let text = truncate_chars(&record.message, *max);
+                    if colorize {
+                        result.push_str(&self.colorize_message(&text));
+                    } else {
+                        result.push_str(&text);
+                    }
+                }
                 FormatToken::Level => {
                     if let Some(ref fmt) = level_fmt_color {
                         result.push_str(fmt);
@@ -513,12 +1394,13 @@ impl FormatConfig {
                         result.push_str(level_name);
                     }
                 }
-                FormatToken::LevelWidth(width) => {
+                FormatToken::LevelWidth(width, align) => {
                     if colorize {
-                        let padded = format!("{:<width$}", level_name, width = width);
+                        let mut padded = String::with_capacity(*width);
+                        write_aligned(&mut padded, level_name, *width, *align);
                         result.push_str(&colorize_text(&padded, level_color, true));
                     } else if reqs.needs_level {
-                        let _ = write!(result, "{:<width$}", level_name, width = width);
+                        write_aligned(&mut result, level_name, *width, *align);
                     }
                 }
                 FormatToken::Extra(key) => {
@@ -526,6 +1408,14 @@ impl FormatConfig {
                         result.push_str(value.as_str());
                     }
                 }
+                FormatToken::ExtraPath(key, path) => {
+                    if let Some(text) = record.extra.get(key).and_then(|v| v.text_at(path)) {
+                        result.push_str(&text);
+                    }
+                }
+                FormatToken::ExtraAll(separator) => {
+                    write_logfmt(&record.extra, separator, &mut result);
+                }
                 FormatToken::Name => {
                     if colorize {
                         result.push_str(&cyan_text(&record.caller.name));
@@ -587,12 +1477,41 @@ impl FormatConfig {
                         result.push_str(&record.caller.name);
                     }
                 }
+                FormatToken::Exception => {
+                    if let Some(ref exc) = record.exception {
+                        result.push_str(&self.render_exception(exc, colorize));
+                    }
+                }
+                FormatToken::ExceptionType => {
+                    if let Some((type_name, _)) = record.exception_parts() {
+                        result.push_str(type_name);
+                    }
+                }
+                FormatToken::ExceptionMessage => {
+                    if let Some((_, message)) = record.exception_parts() {
+                        result.push_str(message);
+                    }
+                }
+                FormatToken::Icon => {
+                    if let Some(icon) = record.level_icon() {
+                        result.push_str(&icon);
+                    }
+                }
+                FormatToken::Seq => {
+                    let _ = write!(result, "{}", record.seq);
+                }
+                FormatToken::Hostname => result.push_str(&HOSTNAME),
+                FormatToken::Pid => {
+                    let _ = write!(result, "{}", cached_pid());
+                }
             }
         }
 
-        if let Some(ref exc) = record.exception {
+        if !self.exception_in_template
+            && let Some(ref exc) = record.exception
+        {
             result.push('\n');
-            result.push_str(exc);
+            result.push_str(&self.render_exception(exc, colorize));
         }
 
         result
@@ -602,6 +1521,8 @@ impl FormatConfig {
     fn format_record_json(&self, record: &LogRecord) -> String {
         #[derive(Serialize)]
         struct JsonRecord<'a> {
+            #[serde(flatten)]
+            static_fields: StaticFields<'a>,
             time: String,
             level: &'a str,
             message: &'a str,
@@ -611,32 +1532,231 @@ impl FormatConfig {
             function: &'a str,
             #[serde(skip_serializing_if = "is_zero")]
             line: u32,
-            #[serde(skip_serializing_if = "HashMap::is_empty")]
+            #[serde(skip_serializing_if = "is_zero")]
+            seq: u64,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            trace_id: Option<&'a ExtraValue>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            span_id: Option<&'a ExtraValue>,
+            #[serde(
+                skip_serializing_if = "has_only_trace_context",
+                serialize_with = "serialize_extra_without_trace_context"
+            )]
             extra: &'a ExtraMap,
             #[serde(skip_serializing_if = "Option::is_none")]
             exception: &'a Option<String>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            exception_type: Option<&'a str>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            exception_message: Option<&'a str>,
         }
 
-        fn is_zero(n: &u32) -> bool {
-            *n == 0
+        fn is_zero<T: Default + PartialEq>(n: &T) -> bool {
+            *n == T::default()
         }
 
+        let exception_parts = record.exception_parts();
         let json_record = JsonRecord {
-            time: record.timestamp.format(&self.time_format).to_string(),
+            static_fields: StaticFields(&self.static_fields),
+            time: self.time_text(&record.timestamp, self.utc),
             level: record.level_name(),
             message: &record.message,
             name: &record.caller.name,
             function: &record.caller.function,
             line: record.caller.line,
+            seq: record.seq,
+            trace_id: record.extra.get("trace_id"),
+            span_id: record.extra.get("span_id"),
             extra: &record.extra,
             exception: &record.exception,
+            exception_type: exception_parts.map(|(type_name, _)| type_name),
+            exception_message: exception_parts.map(|(_, message)| message),
         };
 
-        serde_json::to_string(&json_record).unwrap_or_else(|_| record.message.clone())
+        to_json_line(&json_record, &record.message)
     }
 
-    /// Format using pre-parsed tokens (O(n) single pass, thread-safe)
-    fn format_template(
+    /// Format a LogRecord as logfmt: static fields, `ts`, `level` (lowercase), `msg`, caller
+    /// fields when known, the extra fields in key order, then `exception`
+    fn format_record_logfmt(&self, record: &LogRecord) -> String {
+        let mut out = String::with_capacity(128 + record.message.len());
+        for (key, value) in &self.static_fields {
+            write_logfmt_pair(key, value.as_str(), &mut out);
+            out.push(' ');
+        }
+        write_logfmt_pair("ts", &self.time_text(&record.timestamp, self.utc), &mut out);
+        out.push(' ');
+        write_logfmt_pair("level", &record.level_name().to_lowercase(), &mut out);
+        out.push(' ');
+        write_logfmt_pair("msg", &record.message, &mut out);
+        let caller = &record.caller;
+        if !caller.name.is_empty() {
+            out.push(' ');
+            write_logfmt_pair("name", &caller.name, &mut out);
+        }
+        if !caller.function.is_empty() {
+            out.push(' ');
+            write_logfmt_pair("function", &caller.function, &mut out);
+        }
+        if caller.line != 0 {
+            out.push(' ');
+            write_logfmt_pair("line", &caller.line.to_string(), &mut out);
+        }
+        if !record.extra.is_empty() {
+            out.push(' ');
+            write_logfmt(&record.extra, " ", &mut out);
+        }
+        if let Some(exc) = &record.exception {
+            out.push(' ');
+            write_logfmt_pair("exception", exc.trim_end(), &mut out);
+        }
+        out
+    }
+
+    /// Format a LogRecord as a CSV row: time, level, name, line, message and the extra
+    /// fields as a JSON object (see [`CSV_HEADER`])
+    fn format_record_csv(&self, record: &LogRecord) -> String {
+        let line = match record.caller.line {
+            0 => String::new(),
+            line => line.to_string(),
+        };
+        let extra = serde_json::to_string(&record.extra.iter().collect::<BTreeMap<_, _>>())
+            .unwrap_or_else(|_| "{}".to_string());
+        let time = self.time_text(&record.timestamp, self.utc);
+        let fields = [
+            time.as_str(),
+            record.level_name(),
+            &record.caller.name,
+            &line,
+            &record.message,
+            &extra,
+        ];
+
+        let mut out = String::with_capacity(64 + record.message.len() + extra.len());
+        for (i, field) in fields.into_iter().enumerate() {
+            if i > 0 {
+                out.push(',');
+            }
+            write_csv_field(field, &mut out);
+        }
+        out
+    }
+
+    /// Format a LogRecord as `{"text": ..., "record": {...}}`, mirroring loguru's
+    /// `serialize=True` shape. `text` is the uncolored template output.
+    fn format_record_json_wrapped(&self, record: &LogRecord) -> String {
+        #[derive(Serialize)]
+        struct Wrapped<'a> {
+            #[serde(flatten)]
+            static_fields: StaticFields<'a>,
+            text: String,
+            record: WrappedRecord<'a>,
+        }
+
+        #[derive(Serialize)]
+        struct WrappedRecord<'a> {
+            elapsed: Elapsed,
+            exception: &'a Option<String>,
+            #[serde(serialize_with = "serialize_sorted_extra")]
+            extra: &'a ExtraMap,
+            file: File<'a>,
+            function: &'a str,
+            level: Level<'a>,
+            line: u32,
+            message: &'a str,
+            module: &'a str,
+            name: &'a str,
+            process: IdName<'a, u32>,
+            thread: IdName<'a, u64>,
+            time: Time,
+        }
+
+        #[derive(Serialize)]
+        struct Elapsed {
+            repr: String,
+            seconds: f64,
+        }
+
+        #[derive(Serialize)]
+        struct File<'a> {
+            name: &'a str,
+            path: &'a str,
+        }
+
+        #[derive(Serialize)]
+        struct Level<'a> {
+            icon: Option<Cow<'a, str>>,
+            name: &'a str,
+            no: u32,
+        }
+
+        #[derive(Serialize)]
+        struct IdName<'a, T> {
+            id: T,
+            name: &'a str,
+        }
+
+        #[derive(Serialize)]
+        struct Time {
+            repr: String,
+            timestamp: f64,
+        }
+
+        let path = Path::new(&record.caller.file);
+        let file_name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+        let module = path.file_stem().and_then(|n| n.to_str()).unwrap_or("");
+        let elapsed_millis = (record.timestamp - *LOGGER_START_TIME)
+            .num_milliseconds()
+            .max(0);
+
+        let wrapped = Wrapped {
+            static_fields: StaticFields(&self.static_fields),
+            text: self.format_record_template(record, false),
+            record: WrappedRecord {
+                elapsed: Elapsed {
+                    repr: format_elapsed(&LOGGER_START_TIME, &record.timestamp),
+                    seconds: elapsed_millis as f64 / 1000.0,
+                },
+                exception: &record.exception,
+                extra: &record.extra,
+                file: File {
+                    name: file_name,
+                    path: &record.caller.file,
+                },
+                function: &record.caller.function,
+                level: Level {
+                    icon: record.level_icon(),
+                    name: record.level_name(),
+                    no: record.level_no(),
+                },
+                line: record.caller.line,
+                message: &record.message,
+                module,
+                name: &record.caller.name,
+                process: IdName {
+                    id: record.process.id,
+                    name: &record.process.name,
+                },
+                thread: IdName {
+                    id: record.thread.id,
+                    name: &record.thread.name,
+                },
+                time: Time {
+                    repr: if self.utc {
+                        record.timestamp.with_timezone(&Utc).to_rfc3339()
+                    } else {
+                        record.timestamp.to_rfc3339()
+                    },
+                    timestamp: record.timestamp.timestamp_micros() as f64 / 1_000_000.0,
+                },
+            },
+        };
+
+        to_json_line(&wrapped, &record.message)
+    }
+
+    /// Format using pre-parsed tokens (O(n) single pass, thread-safe)
+    fn format_template(
         &self,
         timestamp: &DateTime<Local>,
         level: LogLevel,
@@ -650,7 +1770,7 @@ impl FormatConfig {
         let level_color = level.color();
 
         let time_fmt = if reqs.needs_time {
-            let time_raw = timestamp.format(&self.time_format).to_string();
+            let time_raw = self.time_text(timestamp, self.utc);
             Some(if colorize {
                 dim_text(&time_raw)
             } else {
@@ -667,14 +1787,15 @@ impl FormatConfig {
         };
 
         let message_fmt_color = if colorize && reqs.needs_message {
-            Some(apply_color_markup(message))
+            Some(self.colorize_message(message))
         } else {
             None
         };
 
         let mut result = String::with_capacity(self.template.len() + FORMAT_RESULT_CAPACITY);
 
-        for token in &self.tokens {
+        let tokens = self.tokens_without_caller.as_ref().unwrap_or(&self.tokens);
+        for token in tokens {
             match token {
                 FormatToken::Static(s) => result.push_str(s),
                 FormatToken::Time => {
@@ -682,6 +1803,10 @@ impl FormatConfig {
                         result.push_str(fmt);
                     }
                 }
+                FormatToken::TimeUtc => {
+                    let utc = self.time_text(timestamp, true);
+                    result.push_str(&if colorize { dim_text(&utc) } else { utc });
+                }
                 FormatToken::Message => {
                     if let Some(ref fmt) = message_fmt_color {
                         result.push_str(fmt);
@@ -689,6 +1814,14 @@ impl FormatConfig {
                         result.push_str(message);
                     }
                 }
+                FormatToken::MessageTruncated(max) => {
+                    let text = truncate_chars(message, *max);
+                    if colorize {
+                        result.push_str(&self.colorize_message(&text));
+                    } else {
+                        result.push_str(&text);
+                    }
+                }
                 FormatToken::Level => {
                     if let Some(ref fmt) = level_fmt_color {
                         result.push_str(fmt);
@@ -696,12 +1829,13 @@ impl FormatConfig {
                         result.push_str(level_name);
                     }
                 }
-                FormatToken::LevelWidth(width) => {
+                FormatToken::LevelWidth(width, align) => {
                     if colorize {
-                        let padded = format!("{:<width$}", level_name, width = width);
+                        let mut padded = String::with_capacity(*width);
+                        write_aligned(&mut padded, level_name, *width, *align);
                         result.push_str(&colorize_text(&padded, level_color, true));
                     } else if reqs.needs_level {
-                        let _ = write!(result, "{:<width$}", level_name, width = width);
+                        write_aligned(&mut result, level_name, *width, *align);
                     }
                 }
                 FormatToken::Extra(key) => {
@@ -709,7 +1843,36 @@ impl FormatConfig {
                         result.push_str(value.as_str());
                     }
                 }
-                // These tokens are not available in this context (no caller/thread/process info)
+                FormatToken::ExtraPath(key, path) => {
+                    if let Some(text) = extra.get(key).and_then(|v| v.text_at(path)) {
+                        result.push_str(&text);
+                    }
+                }
+                FormatToken::ExtraAll(separator) => {
+                    write_logfmt(extra, separator, &mut result);
+                }
+                FormatToken::Exception => {
+                    if let Some(exc) = exception {
+                        result.push_str(&self.render_exception(exc, colorize));
+                    }
+                }
+                FormatToken::ExceptionType => {
+                    if let Some((type_name, _)) = exception.as_deref().and_then(exception_parts) {
+                        result.push_str(type_name);
+                    }
+                }
+                FormatToken::ExceptionMessage => {
+                    if let Some((_, message)) = exception.as_deref().and_then(exception_parts) {
+                        result.push_str(message);
+                    }
+                }
+                FormatToken::Icon => result.push_str(&level.icon()),
+                FormatToken::Hostname => result.push_str(&HOSTNAME),
+                FormatToken::Pid => {
+                    let _ = write!(result, "{}", cached_pid());
+                }
+                // These tokens are not available in this context (no caller/thread/process info);
+                // caller groups are already dropped
                 FormatToken::Name
                 | FormatToken::Function
                 | FormatToken::Line
@@ -717,13 +1880,16 @@ impl FormatConfig {
                 | FormatToken::Thread
                 | FormatToken::Process
                 | FormatToken::File
-                | FormatToken::Module => {}
+                | FormatToken::Module
+                | FormatToken::Seq => {}
             }
         }
 
-        if let Some(exc) = exception {
+        if !self.exception_in_template
+            && let Some(exc) = exception
+        {
             result.push('\n');
-            result.push_str(exc);
+            result.push_str(&self.render_exception(exc, colorize));
         }
 
         result
@@ -740,30 +1906,89 @@ impl FormatConfig {
     ) -> String {
         #[derive(Serialize)]
         struct JsonRecord<'a> {
+            #[serde(flatten)]
+            static_fields: StaticFields<'a>,
             time: String,
             level: &'a str,
             message: &'a str,
-            #[serde(skip_serializing_if = "HashMap::is_empty")]
+            #[serde(skip_serializing_if = "Option::is_none")]
+            trace_id: Option<&'a ExtraValue>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            span_id: Option<&'a ExtraValue>,
+            #[serde(
+                skip_serializing_if = "has_only_trace_context",
+                serialize_with = "serialize_extra_without_trace_context"
+            )]
             extra: &'a ExtraMap,
             #[serde(skip_serializing_if = "Option::is_none")]
             exception: &'a Option<String>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            exception_type: Option<&'a str>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            exception_message: Option<&'a str>,
         }
 
+        let exception_parts = exception.as_deref().and_then(exception_parts);
         let record = JsonRecord {
-            time: timestamp.format(&self.time_format).to_string(),
+            static_fields: StaticFields(&self.static_fields),
+            time: self.time_text(timestamp, self.utc),
             level: level.as_str(),
             message,
+            trace_id: extra.get("trace_id"),
+            span_id: extra.get("span_id"),
             extra,
             exception,
+            exception_type: exception_parts.map(|(type_name, _)| type_name),
+            exception_message: exception_parts.map(|(_, message)| message),
         };
 
-        serde_json::to_string(&record).unwrap_or_else(|_| message.to_string())
+        to_json_line(&record, message)
     }
 }
 
+/// Serialize a JSON record; if that fails, fall back to a minimal `{"message": ...}`
+/// object so serialized sinks never emit a line that is not valid JSON.
+fn to_json_line<T: Serialize>(value: &T, message: &str) -> String {
+    serde_json::to_string(value)
+        .unwrap_or_else(|_| serde_json::json!({ "message": message }).to_string())
+}
+
+/// Serialize `extra` with keys in sorted order so JSON output is byte-stable across runs
+/// (nested dict values are already sorted by `serde_json::Map`).
+fn serialize_sorted_extra<S>(extra: &&ExtraMap, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    serializer.collect_map(extra.iter().collect::<BTreeMap<_, _>>())
+}
+
+/// Like [`serialize_sorted_extra`], leaving out the keys promoted to top-level fields
+fn serialize_extra_without_trace_context<S>(
+    extra: &&ExtraMap,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    serializer.collect_map(
+        extra
+            .iter()
+            .filter(|(key, _)| !TRACE_CONTEXT_KEYS.contains(&key.as_str()))
+            .collect::<BTreeMap<_, _>>(),
+    )
+}
+
+/// True when nothing would be left under `extra` after promoting the trace context keys
+fn has_only_trace_context(extra: &&ExtraMap) -> bool {
+    extra
+        .keys()
+        .all(|key| TRACE_CONTEXT_KEYS.contains(&key.as_str()))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::collections::HashMap;
 
     use crate::handler::empty_context;
     use crate::handler::{CallerInfo, ExtraValue, LogRecord, ProcessInfo, ThreadInfo};
@@ -820,6 +2045,88 @@ mod tests {
         assert_eq!(result, "login - user=123");
     }
 
+    #[test]
+    fn test_extra_all_logfmt() {
+        let now = Local::now();
+        let mut extra = HashMap::new();
+        extra.insert("user".to_string(), ExtraValue::from("alice"));
+        extra.insert("attempt".to_string(), ExtraValue::from(2));
+        extra.insert("note".to_string(), ExtraValue::from("said \"hi\" twice"));
+        extra.insert("empty".to_string(), ExtraValue::from(""));
+
+        let config = FormatConfig::new(Some("{message} {extra}".to_string()), false);
+        assert_eq!(
+            config.format(&now, LogLevel::Info, "login", &extra, &None, false),
+            r#"login attempt=2 empty="" note="said \"hi\" twice" user=alice"#
+        );
+
+        let config = FormatConfig::new(Some("{message} [{extra:, }]".to_string()), false);
+        let mut record = LogRecord::new(LogLevel::Info, "login".into());
+        record.extra = Arc::new(extra);
+        assert_eq!(
+            config.format_record(&record, false),
+            r#"login [attempt=2, empty="", note="said \"hi\" twice", user=alice]"#
+        );
+
+        let config = FormatConfig::new(Some("{message}|{extra}".to_string()), false);
+        let record = LogRecord::new(LogLevel::Info, "bare".into());
+        assert_eq!(config.format_record(&record, false), "bare|");
+    }
+
+    #[test]
+    fn test_logfmt_record() {
+        let mut config = FormatConfig::new(None, false)
+            .with_logfmt(true)
+            .with_static_fields(vec![("_v".to_string(), ExtraValue::from(1))]);
+        config.time_format = "%Y".to_string();
+        let year = Local::now().format("%Y").to_string();
+
+        let mut record = LogRecord::new(LogLevel::Warning, "disk \"almost\" full".into());
+        record.caller.name = "app.disk".to_string();
+        record.caller.line = 7;
+        record.extra = Arc::new(HashMap::from([
+            ("mount".to_string(), ExtraValue::from("/var")),
+            ("free".to_string(), ExtraValue::from("1 GB")),
+        ]));
+        record.exception = Some("Traceback\nOSError: full\n".to_string());
+        assert_eq!(
+            config.format_record(&record, true),
+            format!(
+                r#"_v=1 ts={year} level=warning msg="disk \"almost\" full" name=app.disk line=7 free="1 GB" mount=/var exception="Traceback\nOSError: full""#
+            )
+        );
+        assert!(config.is_structured());
+        assert!(!config.format_record_tinted(&record).contains('\x1b'));
+    }
+
+    #[test]
+    fn test_csv_record() {
+        let mut config = FormatConfig::new(None, false).with_csv(true);
+        config.time_format = "%Y".to_string();
+        let year = Local::now().format("%Y").to_string();
+        assert!(config.requirements().needs_caller);
+
+        let mut record = LogRecord::new(LogLevel::Info, "saved \"a, b\"\nnext".into());
+        record.caller.name = "app".to_string();
+        record.caller.line = 3;
+        record.extra = Arc::new(HashMap::from([
+            ("user".to_string(), ExtraValue::from("alice")),
+            ("n".to_string(), ExtraValue::from(2)),
+        ]));
+        assert_eq!(
+            config.format_record(&record, true),
+            format!(
+                "{year},INFO,app,3,\"saved \"\"a, b\"\"\nnext\",\"{{\"\"n\"\":2,\"\"user\"\":\"\"alice\"\"}}\""
+            )
+        );
+
+        let record = LogRecord::new(LogLevel::Error, "plain".into());
+        assert_eq!(
+            config.format_record(&record, false),
+            format!("{year},ERROR,,,plain,{{}}")
+        );
+    }
+
     #[test]
     fn test_exception_in_template() {
         let config = FormatConfig::new(Some("[{level}] {message}".to_string()), false);
@@ -832,6 +2139,49 @@ mod tests {
         assert!(result.contains("Traceback:"));
     }
 
+    #[test]
+    fn test_exception_token_places_traceback() {
+        let config =
+            FormatConfig::new(Some("{message} | {exception} | {level}".to_string()), false);
+        let now = Local::now();
+        let extra = HashMap::new();
+        let exception = Some("ValueError: bad".to_string());
+
+        let result = config.format(&now, LogLevel::Error, "Failed", &extra, &exception, false);
+        assert_eq!(result, "Failed | ValueError: bad | ERROR");
+
+        let mut record = LogRecord::new(LogLevel::Error, "Failed".to_string());
+        record.exception = exception;
+        assert_eq!(
+            config.format_record(&record, false),
+            "Failed | ValueError: bad | ERROR"
+        );
+
+        record.exception = None;
+        assert_eq!(config.format_record(&record, false), "Failed |  | ERROR");
+    }
+
+    #[test]
+    fn test_colorized_exception() {
+        let traceback = "Traceback (most recent call last):\n  File \"app.py\", line 3, in <module>\n    1 / 0\nZeroDivisionError: division by zero\n";
+        let config =
+            FormatConfig::new(Some("{message}".to_string()), false).with_colorize_exception(true);
+        let mut record = LogRecord::new(LogLevel::Error, "Failed".to_string());
+        record.exception = Some(traceback.to_string());
+
+        let colored = config.format_record(&record, true);
+        assert_eq!(
+            colored,
+            "Failed\nTraceback (most recent call last):\n  File \"\x1b[36mapp.py\x1b[0m\", line 3, in <module>\n    1 / 0\n\x1b[1;31mZeroDivisionError: division by zero\x1b[0m\n"
+        );
+
+        // Uncolored output keeps the traceback as-is
+        assert_eq!(
+            config.format_record(&record, false),
+            format!("Failed\n{traceback}")
+        );
+    }
+
     #[test]
     fn test_exception_in_json() {
         let config = FormatConfig::new(None, true);
@@ -843,6 +2193,434 @@ mod tests {
         assert!(result.contains("\"exception\":\"Traceback\""));
     }
 
+    #[test]
+    fn test_exception_parts() {
+        let traceback = "Traceback (most recent call last):\n  File \"app.py\", line 3, in <module>\n    load()\napp.errors.ConfigError: missing key: port\n";
+        assert_eq!(
+            exception_parts(traceback),
+            Some(("app.errors.ConfigError", "missing key: port"))
+        );
+
+        // The last exception of a chain, with a multi-line message
+        let chained = format!(
+            "{traceback}\nDuring handling of the above exception, another exception occurred:\n\n{}",
+            "Traceback (most recent call last):\n  File \"app.py\", line 5\nRuntimeError: first\nsecond\n"
+        );
+        assert_eq!(
+            exception_parts(&chained),
+            Some(("RuntimeError", "first\nsecond"))
+        );
+
+        assert_eq!(
+            exception_parts("KeyboardInterrupt\n"),
+            Some(("KeyboardInterrupt", ""))
+        );
+        assert_eq!(
+            exception_parts("ValueError: bad"),
+            Some(("ValueError", "bad"))
+        );
+        assert_eq!(exception_parts("disk is full"), None);
+        assert_eq!(
+            exception_parts("Traceback (most recent call last):\n  File \"a.py\""),
+            None
+        );
+    }
+
+    #[test]
+    fn test_exception_type_and_message_fields() {
+        let mut record = LogRecord::new(LogLevel::Error, "Failed".to_string());
+        record.exception = Some("Traceback (most recent call last):\n  File \"a.py\", line 1, in <module>\nKeyError: 'port'\n".to_string());
+
+        let config = FormatConfig::new(
+            Some("{message} [{exception.type}] {exception.message}".to_string()),
+            false,
+        );
+        let text = config.format_record(&record, false);
+        assert!(text.starts_with("Failed [KeyError] 'port'\nTraceback"));
+
+        let json: serde_json::Value =
+            serde_json::from_str(&FormatConfig::new(None, true).format_record(&record, false))
+                .unwrap();
+        assert_eq!(json["exception_type"], "KeyError");
+        assert_eq!(json["exception_message"], "'port'");
+        assert_eq!(json["exception"], record.exception.clone().unwrap());
+
+        record.exception = None;
+        assert_eq!(config.format_record(&record, false), "Failed [] ");
+        let json = FormatConfig::new(None, true).format_record(&record, false);
+        assert!(!json.contains("exception_type"));
+    }
+
+    #[test]
+    fn test_json_escapes_special_characters() {
+        let messages = [
+            "say \"hi\"",
+            r"C:\path\to\file",
+            "line one\nline two\r\n",
+            "tab\there",
+            "bell\u{7} nul\u{0} esc\u{1b}[31m del\u{7f}",
+            "unicode \u{2028} \u{2029} 日本語 🎉",
+            r#"{"looks": "like json"}"#,
+        ];
+        let flat = FormatConfig::new(None, true);
+        let wrapped =
+            FormatConfig::new(Some("{message}".to_string()), false).with_serialize_wrapped(true);
+
+        for message in messages {
+            let mut extra = HashMap::new();
+            extra.insert(message.to_string(), ExtraValue::from(message));
+            let mut record = LogRecord::new(LogLevel::Info, message.to_string());
+            record.extra = Arc::new(extra.clone());
+            record.exception = Some(message.to_string());
+
+            let outputs = [
+                flat.format_record(&record, false),
+                flat.format(
+                    &record.timestamp,
+                    LogLevel::Info,
+                    message,
+                    &extra,
+                    &None,
+                    false,
+                ),
+                wrapped.format_record(&record, false),
+            ];
+            for output in outputs {
+                assert!(!output.contains('\n'), "{output:?}");
+                let value: serde_json::Value = serde_json::from_str(&output).unwrap();
+                let fields = value.get("record").unwrap_or(&value);
+                assert_eq!(fields["message"], message);
+                assert_eq!(fields["extra"][message], message);
+            }
+        }
+    }
+
+    #[test]
+    fn test_json_fallback_is_valid_json() {
+        struct Failing;
+        impl Serialize for Failing {
+            fn serialize<S: serde::Serializer>(&self, _: S) -> Result<S::Ok, S::Error> {
+                Err(serde::ser::Error::custom("unserializable"))
+            }
+        }
+
+        let output = to_json_line(&Failing, "quote \" and\nnewline");
+        let value: serde_json::Value = serde_json::from_str(&output).unwrap();
+        assert_eq!(value["message"], "quote \" and\nnewline");
+    }
+
+    #[test]
+    fn test_json_extra_keys_are_sorted() {
+        let config = FormatConfig::new(None, true);
+        let now = Local::now();
+        let mut extra = HashMap::new();
+        for key in ["zeta", "alpha", "mu", "beta", "omega", "delta", "kappa"] {
+            extra.insert(key.to_string(), ExtraValue::from(key));
+        }
+        let expected = r#""extra":{"alpha":"alpha","beta":"beta","delta":"delta","kappa":"kappa","mu":"mu","omega":"omega","zeta":"zeta"}"#;
+
+        let result = config.format(&now, LogLevel::Info, "msg", &extra, &None, false);
+        assert!(result.contains(expected), "{result}");
+
+        let mut record = LogRecord::new(LogLevel::Info, "msg".into());
+        record.extra = Arc::new(extra);
+        let result = config.format_record(&record, false);
+        assert!(result.contains(expected), "{result}");
+    }
+
+    #[test]
+    fn test_json_string_extra_is_never_parsed() {
+        let config = FormatConfig::new(None, true);
+        let mut extra = HashMap::new();
+        extra.insert("payload".to_string(), ExtraValue::from(r#"{"a":1}"#));
+        let mut record = LogRecord::new(LogLevel::Info, "msg".into());
+        record.extra = Arc::new(extra);
+
+        let result = config.format_record(&record, false);
+        assert!(result.contains(r#""payload":"{\"a\":1}""#), "{result}");
+    }
+
+    #[test]
+    fn test_trace_context_is_promoted() {
+        let mut extra = HashMap::new();
+        extra.insert("trace_id".to_string(), ExtraValue::from("4bf92f35"));
+        extra.insert("span_id".to_string(), ExtraValue::from("00f067aa"));
+        extra.insert("user".to_string(), ExtraValue::from("alice"));
+        let mut record = LogRecord::new(LogLevel::Info, "msg".into());
+        record.extra = Arc::new(extra);
+
+        let config = FormatConfig::new(Some("[{trace_id}/{span_id}] {message}".into()), false);
+        assert_eq!(
+            config.format_record(&record, false),
+            "[4bf92f35/00f067aa] msg"
+        );
+
+        let json = FormatConfig::new(None, true).format_record(&record, false);
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["trace_id"], "4bf92f35");
+        assert_eq!(value["span_id"], "00f067aa");
+        assert_eq!(value["extra"], serde_json::json!({"user": "alice"}));
+
+        // Nothing left under extra: the key is omitted, as for an empty extra
+        let mut record = LogRecord::new(LogLevel::Info, "msg".into());
+        record.extra = Arc::new(HashMap::from([(
+            "trace_id".to_string(),
+            ExtraValue::from("4bf92f35"),
+        )]));
+        let json = FormatConfig::new(None, true).format_record(&record, false);
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["trace_id"], "4bf92f35");
+        assert!(value.get("extra").is_none());
+        assert!(value.get("span_id").is_none());
+    }
+
+    #[test]
+    fn test_time_utc() {
+        let record = LogRecord::new(LogLevel::Info, "msg".into());
+        let utc = record
+            .timestamp
+            .with_timezone(&Utc)
+            .format(DEFAULT_TIME_FORMAT)
+            .to_string();
+        let local = record.timestamp.format(DEFAULT_TIME_FORMAT).to_string();
+
+        let config = FormatConfig::new(Some("{time} | {time:utc}".into()), false);
+        assert_eq!(
+            config.format_record(&record, false),
+            format!("{local} | {utc}")
+        );
+
+        let config = FormatConfig::new(Some("{time}".into()), false).with_utc(true);
+        assert_eq!(config.format_record(&record, false), utc);
+
+        let json = FormatConfig::new(None, true)
+            .with_utc(true)
+            .format_record(&record, false);
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["time"], utc);
+    }
+
+    #[test]
+    fn test_time_precision() {
+        let record = LogRecord::new(LogLevel::Info, "msg".into());
+        let render = |precision| {
+            FormatConfig::new(Some("{time}".into()), false)
+                .with_time_precision(precision)
+                .format_record(&record, false)
+        };
+        let fraction_len = |text: String| text.split_once('.').map(|(_, f)| f.len());
+
+        assert_eq!(fraction_len(render(TimePrecision::Seconds)), None);
+        assert_eq!(fraction_len(render(TimePrecision::Millis)), Some(3));
+        assert_eq!(fraction_len(render(TimePrecision::Micros)), Some(6));
+        assert_eq!(fraction_len(render(TimePrecision::Nanos)), Some(9));
+        assert_eq!(TimePrecision::parse("Micros"), Some(TimePrecision::Micros));
+        assert_eq!(TimePrecision::parse("hours"), None);
+
+        let json = FormatConfig::new(None, true)
+            .with_time_precision(TimePrecision::Micros)
+            .format_record(&record, false);
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            fraction_len(value["time"].as_str().unwrap().into()),
+            Some(6)
+        );
+    }
+
+    #[test]
+    fn test_seq_token_and_json_field() {
+        let mut record = LogRecord::new(LogLevel::Info, "msg".into());
+        record.seq = 42;
+        let config = FormatConfig::new(Some("#{seq} {message}".into()), false);
+        assert_eq!(config.format_record(&record, false), "#42 msg");
+
+        let json = FormatConfig::new(None, true).format_record(&record, false);
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["seq"], 42);
+
+        // Records built outside a logger have no sequence number to write
+        record.seq = 0;
+        let json = FormatConfig::new(None, true).format_record(&record, false);
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert!(value.get("seq").is_none());
+    }
+
+    #[test]
+    fn test_time_format_validated() {
+        let record = LogRecord::new(LogLevel::Info, "msg".into());
+        let config = FormatConfig::new(Some("{time}".into()), false)
+            .with_time_format("%Y/%m/%d")
+            .unwrap();
+        assert_eq!(
+            config.format_record(&record, false),
+            record.timestamp.format("%Y/%m/%d").to_string()
+        );
+
+        for invalid in ["%Q", "%Y-%", "%.9"] {
+            assert!(
+                FormatConfig::new(None, false)
+                    .with_time_format(invalid)
+                    .is_err(),
+                "{invalid}"
+            );
+        }
+
+        // Set directly on the field: rendering falls back to the default format
+        let mut config = FormatConfig::new(Some("{time}".into()), false);
+        config.time_format = "%Q".into();
+        assert_eq!(
+            config.format_record(&record, false),
+            record.timestamp.format(DEFAULT_TIME_FORMAT).to_string()
+        );
+    }
+
+    #[test]
+    fn test_message_truncation() {
+        let config = FormatConfig::new(Some("{message:.5}|".into()), false);
+        let render = |message: &str| {
+            config.format_record(&LogRecord::new(LogLevel::Info, message.into()), false)
+        };
+
+        assert_eq!(render("short"), "short|");
+        assert_eq!(render("shorts"), "shor\u{2026}|");
+        assert_eq!(render("truncated"), "trun\u{2026}|");
+        // Counts characters, not bytes
+        assert_eq!(render("日本語のログです"), "日本語の\u{2026}|");
+
+        // Too narrow for text and an ellipsis: cut without one
+        let render_at = |precision: usize, message: &str| {
+            FormatConfig::new(Some(format!("{{message:.{precision}}}|")), false)
+                .format_record(&LogRecord::new(LogLevel::Info, message.into()), false)
+        };
+        assert_eq!(render_at(0, "text"), "|");
+        assert_eq!(render_at(0, ""), "|");
+        assert_eq!(render_at(1, "text"), "t|");
+        assert_eq!(render_at(1, "日本"), "日|");
+        assert_eq!(render_at(1, "t"), "t|");
+        assert_eq!(render_at(2, "text"), "t\u{2026}|");
+        assert!(matches!(
+            parse_template("{message:.x}")[0],
+            FormatToken::Static(_)
+        ));
+    }
+
+    #[test]
+    fn test_line_affixes() {
+        let record = LogRecord::new(LogLevel::Info, "msg".into());
+        let config = FormatConfig::new(Some("{level} {message}".into()), false)
+            .with_affixes(Some("[web-1] ".into()), Some(" <<".into()));
+        assert_eq!(config.format_record(&record, false), "[web-1] INFO msg <<");
+
+        // JSON output ignores the affixes
+        let json = FormatConfig::new(None, true)
+            .with_affixes(Some("[web-1] ".into()), None)
+            .format_record(&record, false);
+        assert!(json.starts_with('{'), "{json}");
+    }
+
+    #[test]
+    fn test_message_colors() {
+        let record = LogRecord::new(LogLevel::Info, "[OK] saved, [FAIL] retry".into());
+        let config = FormatConfig::new(Some("{message}".into()), false).with_message_colors(vec![
+            MessageColor::new(r"\[OK\]", "green").unwrap(),
+            MessageColor::new(r"\[FAIL\]|retry", "red").unwrap(),
+        ]);
+        assert_eq!(
+            config.format_record(&record, true),
+            "\x1b[32m[OK]\x1b[0m saved, \x1b[31m[FAIL]\x1b[0m \x1b[31mretry\x1b[0m"
+        );
+        // Plain output is untouched
+        assert_eq!(
+            config.format_record(&record, false),
+            "[OK] saved, [FAIL] retry"
+        );
+
+        // The earlier rule wins where matches overlap
+        let overlap = FormatConfig::new(Some("{message}".into()), false).with_message_colors(vec![
+            MessageColor::new("saved", "green").unwrap(),
+            MessageColor::new("ved, ", "red").unwrap(),
+        ]);
+        assert_eq!(
+            overlap.format_record(&record, true),
+            "[OK] \x1b[32msaved\x1b[0m, [FAIL] retry"
+        );
+
+        assert!(MessageColor::new("(", "green").is_err());
+        assert!(MessageColor::new("ok", "nope").is_err());
+    }
+
+    #[test]
+    fn test_static_fields_lead_json_records() {
+        let record = LogRecord::new(LogLevel::Info, "msg".into());
+        let fields = vec![
+            ("_v".to_string(), ExtraValue::from(1)),
+            ("service".to_string(), ExtraValue::from("api")),
+        ];
+
+        let json = FormatConfig::new(None, true)
+            .with_static_fields(fields.clone())
+            .format_record(&record, false);
+        assert!(
+            json.starts_with(r#"{"_v":1,"service":"api","time":"#),
+            "{json}"
+        );
+
+        let wrapped = FormatConfig::new(None, false)
+            .with_serialize_wrapped(true)
+            .with_static_fields(fields.clone());
+        let value: serde_json::Value =
+            serde_json::from_str(&wrapped.format_record(&record, false)).unwrap();
+        assert_eq!(value["_v"], 1);
+        assert_eq!(value["record"]["message"], "msg");
+
+        // Text output ignores them
+        let text = FormatConfig::new(Some("{message}".into()), false).with_static_fields(fields);
+        assert_eq!(text.format_record(&record, false), "msg");
+    }
+
+    #[test]
+    fn test_serialize_wrapped_shape() {
+        let config = FormatConfig::new(Some("{level} | {message}".to_string()), false)
+            .with_serialize_wrapped(true);
+        assert!(config.serialize);
+        assert!(config.requirements().needs_thread);
+
+        let mut record = LogRecord::new(LogLevel::Info, "hello".into());
+        record.caller = CallerInfo::with_file("app".into(), "main".into(), 7, "/srv/app.py".into());
+        record.thread = ThreadInfo {
+            name: "MainThread".into(),
+            id: 11,
+        };
+        record.process = ProcessInfo {
+            name: "MainProcess".into(),
+            id: 22,
+        };
+
+        let value: serde_json::Value =
+            serde_json::from_str(&config.format_record(&record, true)).unwrap();
+        assert_eq!(value["text"], "INFO | hello");
+        let rec = &value["record"];
+        assert_eq!(
+            rec["level"],
+            serde_json::json!({"icon": "\u{2139}", "name": "INFO", "no": 20})
+        );
+        assert_eq!(
+            rec["file"],
+            serde_json::json!({"name": "app.py", "path": "/srv/app.py"})
+        );
+        assert_eq!(rec["module"], "app");
+        assert_eq!(
+            rec["thread"],
+            serde_json::json!({"id": 11, "name": "MainThread"})
+        );
+        assert_eq!(
+            rec["process"],
+            serde_json::json!({"id": 22, "name": "MainProcess"})
+        );
+        assert_eq!(rec["exception"], serde_json::Value::Null);
+        assert_eq!(rec["extra"], serde_json::json!({}));
+    }
+
     #[test]
     fn test_color_markup_basic() {
         let result = apply_color_markup("<red>error</red>");
@@ -883,6 +2661,131 @@ mod tests {
         assert!(underline.contains("\x1b[4m"));
     }
 
+    #[test]
+    fn test_color_markup_truecolor() {
+        let result = apply_color_markup("<fg #ff8800>brand</fg #ff8800> plain");
+        assert_eq!(result, "\x1b[38;2;255;136;0mbrand\x1b[0m plain");
+
+        let bare = apply_color_markup("<#00FF7f>x</#00FF7f>");
+        assert!(bare.starts_with("\x1b[38;2;0;255;127m"));
+    }
+
+    #[test]
+    fn test_color_markup_256() {
+        let result = apply_color_markup("<256:214>warn</256:214>");
+        assert_eq!(result, "\x1b[38;5;214mwarn\x1b[0m");
+
+        let fg = apply_color_markup("<fg 33>x</fg 33>");
+        assert!(fg.starts_with("\x1b[38;5;33m"));
+    }
+
+    #[test]
+    fn test_color_markup_extended_nested_restores_outer() {
+        let result = apply_color_markup("<bold><256:214>a</256:214>b</bold>");
+        assert_eq!(result, "\x1b[1m\x1b[38;5;214ma\x1b[0m\x1b[1mb\x1b[0m");
+    }
+
+    #[test]
+    fn test_color_markup_invalid_extended_left_literal() {
+        for text in [
+            "<fg #ff88zz>x</fg #ff88zz>",
+            "<#fff>x</#fff>",
+            "<256:300>x</256:300>",
+            "<214>x</214>",
+        ] {
+            assert_eq!(apply_color_markup(text), text);
+        }
+    }
+
+    #[test]
+    fn test_color_markup_background() {
+        let result = apply_color_markup("<bg red>CRITICAL</bg red>");
+        assert_eq!(result, "\x1b[41mCRITICAL\x1b[0m");
+
+        let bright = apply_color_markup("<bg bright_blue>x</bg bright_blue>");
+        assert!(bright.starts_with("\x1b[104m"));
+
+        let rgb = apply_color_markup("<bg #102030>x</bg #102030>");
+        assert!(rgb.starts_with("\x1b[48;2;16;32;48m"));
+
+        let indexed = apply_color_markup("<bg 256:214>x</bg 256:214>");
+        assert!(indexed.starts_with("\x1b[48;5;214m"));
+    }
+
+    #[test]
+    fn test_color_markup_background_nested_with_foreground() {
+        let result = apply_color_markup("<bg red><white>a</white>b</bg red>");
+        assert_eq!(result, "\x1b[41m\x1b[37ma\x1b[0m\x1b[41mb\x1b[0m");
+    }
+
+    #[test]
+    fn test_color_markup_invalid_background_left_literal() {
+        assert_eq!(
+            apply_color_markup("<bg nope>x</bg nope>"),
+            "<bg nope>x</bg nope>"
+        );
+    }
+
+    #[test]
+    fn test_tint_line_reapplies_after_resets() {
+        let colored = format!("a {} b", colorize_text("LVL", Color::Red, true));
+        let tinted = tint_line(&colored, Color::Red);
+        assert_eq!(tinted, "\x1b[31ma \x1b[1;31mLVL\x1b[0m\x1b[31m b\x1b[0m");
+    }
+
+    #[test]
+    fn test_tint_line_keeps_markup_colors() {
+        let line = apply_color_markup("x <blue>y</blue> z");
+        let tinted = tint_line(&line, Color::Yellow);
+        assert!(tinted.starts_with("\x1b[33mx \x1b[34my\x1b[0m\x1b[33m z"));
+        assert!(tinted.ends_with("\x1b[0m"));
+    }
+
+    #[test]
+    fn test_record_time_and_elapsed_follow_pinned_clock() {
+        let start = *LOGGER_START_TIME;
+        let _clock =
+            crate::clock::test_clock::set(start + chrono::Duration::milliseconds(3_723_004));
+
+        let record = LogRecord::new(LogLevel::Info, "x".into());
+        assert_eq!(
+            record.timestamp,
+            start + chrono::Duration::milliseconds(3_723_004)
+        );
+
+        let config = FormatConfig::new(Some("{elapsed}".to_string()), false);
+        assert_eq!(config.format_record(&record, false), "01:02:03.004");
+    }
+
+    #[test]
+    fn test_empty_caller_group_is_dropped() {
+        let render = |template: &str, caller: CallerInfo| {
+            let mut record = LogRecord::new(LogLevel::Info, "msg".into());
+            record.caller = caller;
+            FormatConfig::new(Some(template.to_string()), false).format_record(&record, false)
+        };
+        let unknown = CallerInfo::default;
+        assert_eq!(
+            render("{level} | {name}:{function}:{line} - {message}", unknown()),
+            "INFO - msg"
+        );
+        assert_eq!(
+            render("{level} [{name}:{line}] {message}", unknown()),
+            "INFO msg"
+        );
+        assert_eq!(render("{name}:{line} - {message}", unknown()), "msg");
+        assert_eq!(render("[{file}:{line}] {message}", unknown()), "msg");
+        assert_eq!(render("{message} ({function})", unknown()), "msg");
+        // Known callers render as before
+        assert_eq!(
+            render(
+                "{level} | {name}:{function}:{line} - {message}",
+                CallerInfo::new("app".into(), "run".into(), 7)
+            ),
+            "INFO | app:run:7 - msg"
+        );
+    }
+
     #[test]
     fn test_parse_template() {
         let tokens = parse_template(DEFAULT_FORMAT_TEMPLATE);
@@ -890,7 +2793,7 @@ mod tests {
         assert_eq!(tokens.len(), 11);
         assert!(matches!(tokens[0], FormatToken::Time));
         assert!(matches!(&tokens[1], FormatToken::Static(s) if s == " | "));
-        assert!(matches!(tokens[2], FormatToken::LevelWidth(8)));
+        assert!(matches!(tokens[2], FormatToken::LevelWidth(8, Align::Left)));
         assert!(matches!(&tokens[3], FormatToken::Static(s) if s == " | "));
         assert!(matches!(tokens[4], FormatToken::Name));
         assert!(matches!(&tokens[5], FormatToken::Static(s) if s == ":"));
@@ -901,6 +2804,42 @@ mod tests {
         assert!(matches!(tokens[10], FormatToken::Message));
     }
 
+    #[test]
+    fn test_validate_template_reports_unrecognized() {
+        let placeholders =
+            validate_template("{tiem} | {level:<8} | {levl} {extra[user]} {message:.x}");
+        assert_eq!(placeholders.recognized, ["level:<8", "extra[user]"]);
+        assert_eq!(placeholders.unrecognized, ["tiem", "levl", "message:.x"]);
+        assert!(
+            validate_template(DEFAULT_FORMAT_TEMPLATE)
+                .unrecognized
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_new_strict_rejects_unknown_placeholders() {
+        let err = FormatConfig::new_strict(Some("{tiem} {levl} {message}".to_string()), false)
+            .err()
+            .unwrap();
+        assert_eq!(err, "Unknown format placeholder(s): {tiem}, {levl}");
+        assert!(FormatConfig::new_strict(Some("{level:<8} {message}".to_string()), false).is_ok());
+    }
+
+    #[test]
+    fn test_parse_template_escaped_braces() {
+        let tokens = parse_template("{{level}} {{\"msg\": \"{message}\"}}");
+        assert_eq!(tokens.len(), 3);
+        assert!(matches!(&tokens[0], FormatToken::Static(s) if s == "{level} {\"msg\": \""));
+        assert!(matches!(tokens[1], FormatToken::Message));
+        assert!(matches!(&tokens[2], FormatToken::Static(s) if s == "\"}"));
+        assert!(
+            validate_template("{{levl}} {message}")
+                .unrecognized
+                .is_empty()
+        );
+    }
+
     #[test]
     fn test_parse_template_extra() {
         let tokens = parse_template("{message} user={extra[user_id]}");
@@ -910,6 +2849,25 @@ mod tests {
         assert!(matches!(&tokens[2], FormatToken::Extra(k) if k == "user_id"));
     }
 
+    #[test]
+    fn test_nested_extra_path() {
+        let tokens = parse_template("{extra[user][roles][1]}");
+        assert!(
+            matches!(&tokens[0], FormatToken::ExtraPath(k, path) if k == "user" && path == &["roles", "1"])
+        );
+
+        let user =
+            ExtraValue::from(serde_json::json!({"id": 7, "roles": ["a", "b"], "vip": false}));
+        let extra: ExtraMap = [("user".to_string(), user)].into_iter().collect();
+        let mut record = LogRecord::with_extra(LogLevel::Info, "hi".into(), Arc::new(extra));
+        record.caller = CallerInfo::new("app".into(), "run".into(), 1);
+        let config = FormatConfig::new(
+            Some("{extra[user][id]} {extra[user][roles][1]} {extra[user][vip]} [{extra[user][nope]}]".into()),
+            false,
+        );
+        assert_eq!(config.format_record(&record, false), "7 b False []");
+    }
+
     #[test]
     fn test_format_template_message_only_omits_time() {
         let config = FormatConfig::new(Some("{message}".to_string()), false);
@@ -927,6 +2885,38 @@ mod tests {
         assert_eq!(config.format_record(&record, false), "INFO    ");
     }
 
+    #[test]
+    fn test_record_level_width_center_and_right_noncolor() {
+        let record = LogRecord::new(LogLevel::Info, "x".into());
+        let center = FormatConfig::new(Some("[{level:^8}]".to_string()), false);
+        assert_eq!(center.format_record(&record, false), "[  INFO  ]");
+        let right = FormatConfig::new(Some("[{level:>8}]".to_string()), false);
+        assert_eq!(right.format_record(&record, false), "[    INFO]");
+    }
+
+    #[test]
+    fn test_record_level_width_alignment_colorized_after_padding() {
+        let record = LogRecord::new(LogLevel::Error, "x".into());
+        let config = FormatConfig::new(Some("{level:>7}".to_string()), false);
+        assert_eq!(
+            config.format_record(&record, true),
+            "\x1b[1;31m  ERROR\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn test_parse_level_invalid_alignment_left_literal() {
+        let tokens = parse_template("{level:=8}{level:^x}");
+        let text: String = tokens
+            .iter()
+            .map(|t| match t {
+                FormatToken::Static(s) => s.as_str(),
+                other => panic!("unexpected token {:?}", other),
+            })
+            .collect();
+        assert_eq!(text, "{level:=8}{level:^x}");
+    }
+
     #[test]
     fn test_record_level_width_long_level_not_truncated() {
         let info = LevelInfo::new(
@@ -972,4 +2962,37 @@ mod tests {
         let config = FormatConfig::new(Some("L={line}".to_string()), false);
         assert_eq!(config.format_record(&record, false), "L=12345");
     }
+
+    #[test]
+    fn test_interpolate_positional() {
+        let args = vec!["alice".to_string(), "42".to_string()];
+        assert_eq!(
+            interpolate_positional("user {} logged in ({})", &args).as_deref(),
+            Some("user alice logged in (42)")
+        );
+        assert_eq!(
+            interpolate_positional("{1} then {0} then {1}", &args).as_deref(),
+            Some("42 then alice then 42")
+        );
+        assert_eq!(
+            interpolate_positional("{{literal}} {} }}", &args).as_deref(),
+            Some("{literal} alice }")
+        );
+        assert_eq!(
+            interpolate_positional("no fields", &[]).as_deref(),
+            Some("no fields")
+        );
+    }
+
+    #[test]
+    fn test_interpolate_positional_defers_unsupported_fields() {
+        let args = vec!["3.14159".to_string()];
+        assert_eq!(interpolate_positional("{:.2f}", &args), None);
+        assert_eq!(interpolate_positional("{name}", &args), None);
+        assert_eq!(interpolate_positional("{0!r}", &args), None);
+        assert_eq!(interpolate_positional("{} {}", &args), None);
+        assert_eq!(interpolate_positional("{} {0}", &args), None);
+        assert_eq!(interpolate_positional("unbalanced }", &args), None);
+        assert_eq!(interpolate_positional("unclosed {", &args), None);
+    }
 }
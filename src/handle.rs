@@ -0,0 +1,137 @@
+use std::sync::atomic::Ordering;
+
+use pyo3::IntoPyObjectExt;
+use pyo3::basic::CompareOp;
+use pyo3::prelude::*;
+use pyo3::sync::PyOnceLock;
+use pyo3::types::PyDict;
+
+use crate::{PyLogger, extract_level};
+
+/// What a handle refers to: a handler (console/file) or a callback (including callable sinks)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum HandleKind {
+    Handler,
+    Callback,
+}
+
+/// Returned by `add()`, `add_console()`, `add_callback()` and friends.
+/// Holds the id and a reference to the logger's shared handler lists, so the handler can be
+/// re-leveled, inspected or removed directly. It compares, hashes and converts like the
+/// integer id, so code that stores or passes ids keeps working.
+#[pyclass(frozen, module = "logust._logust")]
+pub struct HandlerHandle {
+    /// Handler or callback id (what `add()` used to return)
+    #[pyo3(get)]
+    id: u64,
+    kind: HandleKind,
+    logger: PyLogger,
+    /// Called as `hook(id, removed)` after `remove()` / `set_level()`, so the Python
+    /// `Logger` can update its own per-handler bookkeeping
+    on_change: PyOnceLock<Py<PyAny>>,
+}
+
+impl HandlerHandle {
+    pub(crate) fn new(id: u64, kind: HandleKind, logger: PyLogger) -> Self {
+        HandlerHandle {
+            id,
+            kind,
+            logger,
+            on_change: PyOnceLock::new(),
+        }
+    }
+
+    fn notify(&self, py: Python<'_>, removed: bool) -> PyResult<()> {
+        if let Some(hook) = self.on_change.get(py) {
+            hook.call1(py, (self.id, removed))?;
+        }
+        Ok(())
+    }
+}
+
+#[pymethods]
+impl HandlerHandle {
+    /// Remove the handler (or callback). Returns False if it was already removed.
+    fn remove(&self, py: Python<'_>) -> PyResult<bool> {
+        let removed = match self.kind {
+            HandleKind::Handler => self.logger.remove(Some(self.id)),
+            HandleKind::Callback => self.logger.remove_callback(self.id),
+        };
+        if removed {
+            self.notify(py, true)?;
+        }
+        Ok(removed)
+    }
+
+    /// Change the minimum level (`LogLevel` or name). Returns the handle for chaining.
+    fn set_level(slf: Py<Self>, py: Python<'_>, level: &Bound<'_, PyAny>) -> PyResult<Py<Self>> {
+        let level = extract_level(level)?;
+        let this = slf.get();
+        let found = match this.kind {
+            HandleKind::Handler => this.logger.set_handler_level(this.id, level)?,
+            HandleKind::Callback => this.logger.set_callback_level(this.id, level),
+        };
+        if !found {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "handler {} has been removed",
+                this.id
+            )));
+        }
+        this.notify(py, false)?;
+        Ok(slf)
+    }
+
+    /// Same dict as `handler_stats()`; for callbacks, `records` counts calls.
+    /// None once the handler has been removed.
+    fn stats<'py>(&self, py: Python<'py>) -> PyResult<Option<Bound<'py, PyDict>>> {
+        match self.kind {
+            HandleKind::Handler => self.logger.handler_stats(py, self.id),
+            HandleKind::Callback => {
+                let calls = self
+                    .logger
+                    .callbacks
+                    .read()
+                    .iter()
+                    .find(|entry| entry.id == self.id)
+                    .map(|entry| entry.calls.load(Ordering::Relaxed));
+                calls
+                    .map(|calls| PyLogger::stats_dict(py, calls, None, &[]))
+                    .transpose()
+            }
+        }
+    }
+
+    #[pyo3(name = "_set_on_change")]
+    fn set_on_change(&self, py: Python<'_>, hook: Py<PyAny>) {
+        let _ = self.on_change.set(py, hook);
+    }
+
+    fn __int__(&self) -> u64 {
+        self.id
+    }
+
+    fn __index__(&self) -> u64 {
+        self.id
+    }
+
+    fn __hash__(&self) -> u64 {
+        // Matches hash(int) so a handle and its id are interchangeable as dict keys
+        self.id
+    }
+
+    fn __richcmp__(
+        &self,
+        py: Python<'_>,
+        other: &Bound<'_, PyAny>,
+        op: CompareOp,
+    ) -> PyResult<Py<PyAny>> {
+        match other.extract::<u64>() {
+            Ok(other_id) => op.matches(self.id.cmp(&other_id)).into_py_any(py),
+            Err(_) => Ok(py.NotImplemented()),
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        format!("HandlerHandle(id={})", self.id)
+    }
+}
@@ -1,10 +1,14 @@
+use std::borrow::Cow;
+use std::cell::RefCell;
 use std::collections::{HashMap, HashSet};
 use std::fmt;
-use std::io;
-use std::sync::Arc;
-use std::sync::atomic::{AtomicU64, Ordering};
+use std::io::{self, IsTerminal, Write};
+use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
+use std::sync::{Arc, LazyLock, Mutex};
+use std::thread::{self, JoinHandle};
 
 use chrono::{DateTime, Local};
+use crossbeam_channel::{Receiver, Sender, bounded};
 use pyo3::IntoPyObjectExt;
 use pyo3::prelude::*;
 use pyo3::sync::PyOnceLock;
@@ -15,8 +19,14 @@ use pyo3::types::{
 use serde::{Serialize, Serializer};
 use serde_json::{Map, Number, Value};
 
-use crate::format::{FormatConfig, TokenRequirements};
+use crate::RecordField;
+use crate::clock;
+use crate::filter::ContextFilter;
+use crate::format::{self, FormatConfig, TokenRequirements};
+use crate::journald::JournaldHandler;
 use crate::level::{LevelInfo, LogLevel};
+use crate::rate_limit::RateLimiter;
+use crate::router::SinkRouter;
 use crate::sink::FileSink;
 
 /// Global handler ID counter
@@ -48,8 +58,9 @@ static ENUM_TYPE: PyOnceLock<Py<PyType>> = PyOnceLock::new();
 ///   `{extra[key]}` rendering and Python callbacks (`record["extra"][key]`).
 /// * `json` carries the original Python type (int, float, bool, bytes,
 ///   datetime, list, dict, set, enum values, None) so JSON sinks emit native
-///   types instead of strings.
-#[derive(Clone, Debug)]
+///   types instead of strings. A `str` is always a JSON string, even when its
+///   content is itself JSON; it is never parsed.
+#[derive(Clone, Debug, PartialEq)]
 pub struct ExtraValue {
     text: String,
     json: Value,
@@ -159,6 +170,42 @@ impl ExtraValue {
     pub fn as_json(&self) -> &Value {
         &self.json
     }
+
+    /// Text of the value nested at `path` in a dict- or list-valued field
+    /// (`{extra[user][id]}`), None when the path doesn't exist
+    pub fn text_at(&self, path: &[String]) -> Option<String> {
+        let mut value = &self.json;
+        for key in path {
+            value = match value {
+                Value::Object(map) => map.get(key)?,
+                Value::Array(items) => items.get(key.parse::<usize>().ok()?)?,
+                _ => return None,
+            };
+        }
+        Some(json_text(value))
+    }
+}
+
+/// Text of a JSON value the way Python's `str()` would show the original
+/// (`True`, `None`, strings unquoted); lists and dicts are written as JSON
+fn json_text(value: &Value) -> String {
+    match value {
+        Value::Null => "None".to_string(),
+        Value::Bool(true) => "True".to_string(),
+        Value::Bool(false) => "False".to_string(),
+        Value::String(s) => s.clone(),
+        Value::Number(n) => n.to_string(),
+        Value::Array(_) | Value::Object(_) => value.to_string(),
+    }
+}
+
+/// Split an `{extra[...]}` key like `user][id` (from `{extra[user][id]}`) into the field
+/// and the path inside it; None for a plain key
+pub fn split_extra_path(key: &str) -> Option<(&str, Vec<String>)> {
+    let mut parts = key.split("][");
+    let field = parts.next()?;
+    let path: Vec<String> = parts.map(str::to_string).collect();
+    (!path.is_empty()).then_some((field, path))
 }
 
 impl From<String> for ExtraValue {
@@ -176,6 +223,25 @@ impl From<&str> for ExtraValue {
     }
 }
 
+impl From<i64> for ExtraValue {
+    fn from(value: i64) -> Self {
+        Self {
+            text: value.to_string(),
+            json: Value::Number(Number::from(value)),
+        }
+    }
+}
+
+/// Nested values bound from Rust, e.g. `json!({"id": 1, "name": "x"})`
+impl From<Value> for ExtraValue {
+    fn from(value: Value) -> Self {
+        Self {
+            text: json_text(&value),
+            json: value,
+        }
+    }
+}
+
 impl fmt::Display for ExtraValue {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.write_str(&self.text)
@@ -398,6 +464,9 @@ pub struct CallerInfo {
     pub function: String,
     pub line: u32,
     pub file: String,
+    /// How many frames above the logging call this frame is (1 = the direct caller);
+    /// None when the caller info was not resolved from the stack
+    pub stacklevel: Option<u32>,
 }
 
 impl CallerInfo {
@@ -407,17 +476,53 @@ impl CallerInfo {
             function,
             line,
             file: String::new(),
+            stacklevel: None,
         }
     }
 
+    /// No name, function, line or file was given (e.g. caller collection is off), so
+    /// templates drop their `{name}:{function}:{line}` group
+    pub fn is_unknown(&self) -> bool {
+        self.name.is_empty() && self.function.is_empty() && self.line == 0 && self.file.is_empty()
+    }
+
+    /// When `name` is empty, derive a module-like name from `file` (see [`module_name_from_file`]).
     pub fn with_file(name: String, function: String, line: u32, file: String) -> Self {
+        let name = if name.is_empty() && !file.is_empty() {
+            module_name_from_file(&file).unwrap_or_default()
+        } else {
+            name
+        };
         CallerInfo {
             name,
             function,
             line,
             file,
+            stacklevel: None,
         }
     }
+
+    pub fn with_stacklevel(mut self, stacklevel: Option<u32>) -> Self {
+        self.stacklevel = stacklevel;
+        self
+    }
+}
+
+/// Derive a short module name from a source path: `pkg/util.py` -> `util`,
+/// `pkg/__init__.py` -> `pkg`. Returns `None` when nothing usable remains.
+pub fn module_name_from_file(file: &str) -> Option<String> {
+    let path = std::path::Path::new(file);
+    let stem = path.file_stem()?.to_str()?;
+    let name = if stem == "__init__" {
+        path.parent()?.file_name()?.to_str()?
+    } else {
+        stem
+    };
+    if name.is_empty() {
+        None
+    } else {
+        Some(name.to_string())
+    }
 }
 
 /// Thread information for log records
@@ -427,6 +532,61 @@ pub struct ThreadInfo {
     pub id: u64,
 }
 
+thread_local! {
+    /// `threading.current_thread().name`, read on the thread's first record that needs it
+    static PY_THREAD_NAME: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+impl ThreadInfo {
+    /// Resolve the calling thread natively (no Python lookup).
+    /// The id matches Python's `threading.get_ident()`; the name is the Rust-side name, if any.
+    pub fn current() -> Self {
+        ThreadInfo {
+            name: std::thread::current()
+                .name()
+                .unwrap_or_default()
+                .to_string(),
+            id: native_thread_id(),
+        }
+    }
+
+    /// Like [`ThreadInfo::current`], with the name Python knows the thread by
+    /// (Python-created threads have no Rust-side name). The name is looked up once per
+    /// thread, so renaming a thread after it has logged is not picked up.
+    pub fn current_named() -> Self {
+        let id = native_thread_id();
+        if let Some(name) = PY_THREAD_NAME.with_borrow(Clone::clone) {
+            return ThreadInfo { name, id };
+        }
+        // Not while borrowed: a thread name property could log
+        let name = Python::attach(|py| {
+            py.import("threading")?
+                .call_method0("current_thread")?
+                .getattr("name")?
+                .extract::<String>()
+        });
+        match name {
+            Ok(name) => {
+                PY_THREAD_NAME.set(Some(name.clone()));
+                ThreadInfo { name, id }
+            }
+            Err(_) => ThreadInfo::current(),
+        }
+    }
+}
+
+#[cfg(unix)]
+#[inline]
+fn native_thread_id() -> u64 {
+    unsafe { libc::pthread_self() as u64 }
+}
+
+#[cfg(windows)]
+#[inline]
+fn native_thread_id() -> u64 {
+    unsafe { windows_sys::Win32::System::Threading::GetCurrentThreadId() as u64 }
+}
+
 /// Process information for log records
 #[derive(Clone, Debug, Default)]
 pub struct ProcessInfo {
@@ -434,6 +594,98 @@ pub struct ProcessInfo {
     pub id: u32,
 }
 
+/// Cached pid (0 = not yet resolved); reset in forked children
+static CACHED_PID: AtomicU32 = AtomicU32::new(0);
+
+/// `multiprocessing.current_process().name` and the pid it was read in (a forked child
+/// reads it again)
+static PROCESS_NAME: Mutex<Option<(u32, String)>> = Mutex::new(None);
+
+impl ProcessInfo {
+    /// Resolve the current process natively with a cached pid (name is left empty).
+    pub fn current() -> Self {
+        ProcessInfo {
+            name: String::new(),
+            id: cached_pid(),
+        }
+    }
+
+    /// Like [`ProcessInfo::current`], with the `multiprocessing` process name (read once
+    /// per process)
+    pub fn current_named() -> Self {
+        let id = cached_pid();
+        if let Some((pid, name)) = PROCESS_NAME
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .as_ref()
+            && *pid == id
+        {
+            return ProcessInfo {
+                name: name.clone(),
+                id,
+            };
+        }
+        // Not under the lock: the import may let other threads run
+        let name = Python::attach(|py| {
+            py.import("multiprocessing")?
+                .call_method0("current_process")?
+                .getattr("name")?
+                .extract::<String>()
+        })
+        .unwrap_or_else(|_| "MainProcess".to_string());
+        *PROCESS_NAME.lock().unwrap_or_else(|e| e.into_inner()) = Some((id, name.clone()));
+        ProcessInfo { name, id }
+    }
+}
+
+pub(crate) fn cached_pid() -> u32 {
+    let pid = CACHED_PID.load(Ordering::Relaxed);
+    if pid != 0 {
+        return pid;
+    }
+    let pid = std::process::id();
+    if pid_cache_enabled() {
+        CACHED_PID.store(pid, Ordering::Relaxed);
+    }
+    pid
+}
+
+/// The pid is only cached once a fork hook can invalidate it in children.
+#[cfg(unix)]
+fn pid_cache_enabled() -> bool {
+    static PID_ATFORK_REGISTERED: std::sync::OnceLock<bool> = std::sync::OnceLock::new();
+    *PID_ATFORK_REGISTERED
+        .get_or_init(|| unsafe { libc::pthread_atfork(None, None, Some(reset_cached_pid)) } == 0)
+}
+
+#[cfg(not(unix))]
+fn pid_cache_enabled() -> bool {
+    true
+}
+
+#[cfg(unix)]
+extern "C" fn reset_cached_pid() {
+    CACHED_PID.store(0, Ordering::Relaxed);
+}
+
+/// Name of this host for `{hostname}`, resolved once (empty if it can't be read)
+pub static HOSTNAME: LazyLock<String> = LazyLock::new(resolve_hostname);
+
+#[cfg(unix)]
+fn resolve_hostname() -> String {
+    let mut buf = [0u8; 256];
+    if unsafe { libc::gethostname(buf.as_mut_ptr().cast(), buf.len()) } != 0 {
+        return String::new();
+    }
+    let len = buf.iter().position(|&b| b == 0).unwrap_or(buf.len());
+    String::from_utf8_lossy(&buf[..len]).into_owned()
+}
+
+#[cfg(not(unix))]
+fn resolve_hostname() -> String {
+    std::env::var("COMPUTERNAME").unwrap_or_default()
+}
+
 /// Log record containing all information about a log message
 #[derive(Clone, Debug)]
 pub struct LogRecord {
@@ -446,13 +698,18 @@ pub struct LogRecord {
     pub caller: CallerInfo,
     pub thread: ThreadInfo,
     pub process: ProcessInfo,
+    /// Position among the records emitted by its logger (from 1; 0 when not assigned)
+    pub seq: u64,
+    /// Written after the record instead of the handler's line terminator (`Some("")` leaves
+    /// the line open); `None` keeps the terminator
+    pub end: Option<String>,
 }
 
 impl LogRecord {
     /// Create a new log record
     pub fn new(level: LogLevel, message: String) -> Self {
         LogRecord {
-            timestamp: Local::now(),
+            timestamp: clock::now(),
             level,
             level_info: None,
             message,
@@ -461,13 +718,20 @@ impl LogRecord {
             caller: CallerInfo::default(),
             thread: ThreadInfo::default(),
             process: ProcessInfo::default(),
+            seq: 0,
+            end: None,
         }
     }
 
+    /// Type and message of the record's exception (see [`format::exception_parts`])
+    pub fn exception_parts(&self) -> Option<(&str, &str)> {
+        self.exception.as_deref().and_then(format::exception_parts)
+    }
+
     /// Create a new log record with extra context (Arc reference - zero-copy)
     pub fn with_extra(level: LogLevel, message: String, extra: Arc<ExtraMap>) -> Self {
         LogRecord {
-            timestamp: Local::now(),
+            timestamp: clock::now(),
             level,
             level_info: None,
             message,
@@ -476,6 +740,8 @@ impl LogRecord {
             caller: CallerInfo::default(),
             thread: ThreadInfo::default(),
             process: ProcessInfo::default(),
+            seq: 0,
+            end: None,
         }
     }
 
@@ -488,7 +754,7 @@ impl LogRecord {
         caller: CallerInfo,
     ) -> Self {
         LogRecord {
-            timestamp: Local::now(),
+            timestamp: clock::now(),
             level,
             level_info: None,
             message,
@@ -497,6 +763,8 @@ impl LogRecord {
             caller,
             thread: ThreadInfo::default(),
             process: ProcessInfo::default(),
+            seq: 0,
+            end: None,
         }
     }
 
@@ -511,7 +779,7 @@ impl LogRecord {
         process: ProcessInfo,
     ) -> Self {
         LogRecord {
-            timestamp: Local::now(),
+            timestamp: clock::now(),
             level,
             level_info: None,
             message,
@@ -520,6 +788,8 @@ impl LogRecord {
             caller,
             thread,
             process,
+            seq: 0,
+            end: None,
         }
     }
 
@@ -531,7 +801,7 @@ impl LogRecord {
         exception: Option<String>,
     ) -> Self {
         LogRecord {
-            timestamp: Local::now(),
+            timestamp: clock::now(),
             level,
             level_info: None,
             message,
@@ -540,6 +810,8 @@ impl LogRecord {
             caller: CallerInfo::default(),
             thread: ThreadInfo::default(),
             process: ProcessInfo::default(),
+            seq: 0,
+            end: None,
         }
     }
 
@@ -551,7 +823,7 @@ impl LogRecord {
         exception: Option<String>,
     ) -> Self {
         LogRecord {
-            timestamp: Local::now(),
+            timestamp: clock::now(),
             level: LogLevel::Debug, // Placeholder, not used for custom levels
             level_info: Some(level_info),
             message,
@@ -560,6 +832,8 @@ impl LogRecord {
             caller: CallerInfo::default(),
             thread: ThreadInfo::default(),
             process: ProcessInfo::default(),
+            seq: 0,
+            end: None,
         }
     }
 
@@ -572,7 +846,7 @@ impl LogRecord {
         caller: CallerInfo,
     ) -> Self {
         LogRecord {
-            timestamp: Local::now(),
+            timestamp: clock::now(),
             level: LogLevel::Debug,
             level_info: Some(level_info),
             message,
@@ -581,6 +855,8 @@ impl LogRecord {
             caller,
             thread: ThreadInfo::default(),
             process: ProcessInfo::default(),
+            seq: 0,
+            end: None,
         }
     }
 
@@ -595,7 +871,7 @@ impl LogRecord {
         process: ProcessInfo,
     ) -> Self {
         LogRecord {
-            timestamp: Local::now(),
+            timestamp: clock::now(),
             level: LogLevel::Debug,
             level_info: Some(level_info),
             message,
@@ -604,6 +880,8 @@ impl LogRecord {
             caller,
             thread,
             process,
+            seq: 0,
+            end: None,
         }
     }
 
@@ -616,6 +894,14 @@ impl LogRecord {
         }
     }
 
+    /// Get level icon (None for custom levels registered without one)
+    pub fn level_icon(&self) -> Option<Cow<'_, str>> {
+        match self.level_info {
+            Some(ref info) => info.icon.as_deref().map(Cow::Borrowed),
+            None => Some(Cow::Owned(self.level.icon())),
+        }
+    }
+
     /// Get level numeric value
     pub fn level_no(&self) -> u32 {
         if let Some(ref info) = self.level_info {
@@ -635,6 +921,43 @@ impl LogRecord {
 pub enum HandlerType {
     Console(ConsoleHandler),
     File(FileHandler),
+    /// File path chosen per record from `{extra[key]}` placeholders (boxed: the router
+    /// carries a whole file sink config)
+    RoutedFile(Box<RoutedFileHandler>),
+    /// [`Sink`] implemented in Rust by a crate embedding logust
+    Custom(CustomHandler),
+    /// Native journal entries sent to journald
+    Journald(JournaldHandler),
+}
+
+impl fmt::Display for HandlerType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HandlerType::Console(h) => {
+                let stream = if h.use_stderr { "stderr" } else { "stdout" };
+                write!(f, "Console({}, level={})", stream, h.level.as_str())
+            }
+            HandlerType::File(h) => write!(
+                f,
+                "File('{}', level={})",
+                h.sink.path().display(),
+                h.level.as_str()
+            ),
+            HandlerType::RoutedFile(h) => write!(
+                f,
+                "File('{}', level={})",
+                h.router.template(),
+                h.level.as_str()
+            ),
+            HandlerType::Custom(h) => write!(f, "Custom(level={})", h.level.as_str()),
+            HandlerType::Journald(h) => write!(
+                f,
+                "Journald('{}', level={})",
+                h.identifier,
+                h.level.as_str()
+            ),
+        }
+    }
 }
 
 impl HandlerType {
@@ -643,6 +966,41 @@ impl HandlerType {
         match self {
             HandlerType::Console(h) => h.handle(record),
             HandlerType::File(h) => h.handle(record),
+            HandlerType::RoutedFile(h) => h.handle(record),
+            HandlerType::Custom(h) => h.handle(record),
+            HandlerType::Journald(h) => h.handle(record),
+        }
+    }
+
+    /// Handle a record, reusing a line already formatted the same way for another handler
+    fn handle_shared<'a>(
+        &'a self,
+        record: &LogRecord,
+        formatted: &mut FormattedLines<'a>,
+    ) -> io::Result<()> {
+        match self {
+            HandlerType::Console(h) if level_in_range(record.level_no(), h.level, h.max_level) => {
+                h.records.fetch_add(1, Ordering::Relaxed);
+                h.print(
+                    &formatted.format(&h.format, h.line_style(), record),
+                    record.end.as_deref(),
+                );
+                Ok(())
+            }
+            HandlerType::File(h) if level_in_range(record.level_no(), h.level, h.max_level) => {
+                h.records.fetch_add(1, Ordering::Relaxed);
+                let line = formatted.format(&h.format, LineStyle::Plain, record);
+                h.sink.write_with_end(line, record.end.as_deref())
+            }
+            HandlerType::RoutedFile(h)
+                if level_in_range(record.level_no(), h.level, h.max_level) =>
+            {
+                h.records.fetch_add(1, Ordering::Relaxed);
+                let line = formatted.format(&h.format, LineStyle::Plain, record);
+                h.router
+                    .write_with_end(&record.extra, line, record.end.as_deref())
+            }
+            _ => self.handle(record),
         }
     }
 
@@ -651,6 +1009,48 @@ impl HandlerType {
         match self {
             HandlerType::Console(h) => h.level,
             HandlerType::File(h) => h.level,
+            HandlerType::RoutedFile(h) => h.level,
+            HandlerType::Custom(h) => h.level,
+            HandlerType::Journald(h) => h.level,
+        }
+    }
+
+    /// Get the highest level number this handler writes (inclusive), if capped
+    pub fn max_level(&self) -> Option<u32> {
+        match self {
+            HandlerType::Console(h) => h.max_level,
+            HandlerType::File(h) => h.max_level,
+            HandlerType::RoutedFile(h) => h.max_level,
+            HandlerType::Custom(h) => h.max_level,
+            HandlerType::Journald(h) => h.max_level,
+        }
+    }
+
+    /// Change the minimum log level
+    pub fn set_level(&mut self, level: LogLevel) {
+        match self {
+            HandlerType::Console(h) => h.level = level,
+            HandlerType::File(h) => h.level = level,
+            HandlerType::RoutedFile(h) => h.level = level,
+            HandlerType::Custom(h) => h.level = level,
+            HandlerType::Journald(h) => h.level = level,
+        }
+    }
+
+    /// Check whether a record at `level_no` falls within this handler's level range
+    #[inline]
+    pub fn accepts(&self, level_no: u32) -> bool {
+        level_in_range(level_no, self.level(), self.max_level())
+    }
+
+    /// Number of records this handler has written
+    pub fn records(&self) -> u64 {
+        match self {
+            HandlerType::Console(h) => h.records.load(Ordering::Relaxed),
+            HandlerType::File(h) => h.records.load(Ordering::Relaxed),
+            HandlerType::RoutedFile(h) => h.records.load(Ordering::Relaxed),
+            HandlerType::Custom(h) => h.records.load(Ordering::Relaxed),
+            HandlerType::Journald(h) => h.records.load(Ordering::Relaxed),
         }
     }
 
@@ -659,6 +1059,49 @@ impl HandlerType {
         match self {
             HandlerType::Console(h) => h.format.requirements(),
             HandlerType::File(h) => h.format.requirements(),
+            HandlerType::RoutedFile(h) => h.format.requirements(),
+            HandlerType::Custom(h) => h.sink.requirements(),
+            HandlerType::Journald(h) => h.requirements(),
+        }
+    }
+
+    /// Copy with the same options and record count. The open file (or Rust sink) is
+    /// shared rather than reopened, so `mode="w"` does not empty it again; routed files
+    /// are reopened on first use.
+    fn snapshot(&self) -> HandlerType {
+        let records = AtomicU64::new(self.records());
+        match self {
+            HandlerType::Console(h) => HandlerType::Console(ConsoleHandler {
+                level: h.level,
+                max_level: h.max_level,
+                format: h.format.clone(),
+                colorize: h.colorize,
+                colorize_full: h.colorize_full,
+                use_stderr: h.use_stderr,
+                enqueue: h.enqueue,
+                records,
+            }),
+            HandlerType::File(h) => HandlerType::File(FileHandler {
+                sink: h.sink.clone(),
+                level: h.level,
+                max_level: h.max_level,
+                format: h.format.clone(),
+                records,
+            }),
+            HandlerType::RoutedFile(h) => HandlerType::RoutedFile(Box::new(RoutedFileHandler {
+                router: h.router.fresh_copy(),
+                level: h.level,
+                max_level: h.max_level,
+                format: h.format.clone(),
+                records,
+            })),
+            HandlerType::Custom(h) => HandlerType::Custom(CustomHandler {
+                sink: Arc::clone(&h.sink),
+                level: h.level,
+                max_level: h.max_level,
+                records,
+            }),
+            HandlerType::Journald(h) => HandlerType::Journald(h.snapshot()),
         }
     }
 }
@@ -669,33 +1112,326 @@ pub struct HandlerEntry {
     pub handler: HandlerType,
     /// Optional filter callable (Python lambda/function)
     pub filter: Option<Py<PyAny>>,
+    /// Record keys the filter reads (`filter_fields`); None passes it the full record dict
+    pub filter_fields: Option<Vec<RecordField>>,
+    /// Optional native name/extra filter (`filter="app.db"`, `filter={"component": "db*"}`)
+    pub context_filter: Option<ContextFilter>,
+    /// Optional per-handler rate limit (records over the limit are dropped and counted)
+    pub rate_limit: Option<RateLimiter>,
+    /// Collapse consecutive identical messages into a repeat summary (`dedupe=True`)
+    pub repeats: Option<RepeatCollapser>,
+    /// `(from, to)` level remapping for this handler only (`level_map` option)
+    pub level_map: Vec<(LogLevel, LogLevel)>,
+}
+
+impl HandlerEntry {
+    /// Level this handler treats records of `level_no` as, if it remaps them
+    fn remapped(&self, level_no: u32) -> Option<LogLevel> {
+        self.level_map
+            .iter()
+            .find(|(from, _)| *from as u32 == level_no)
+            .map(|&(_, to)| to)
+    }
+
+    /// Check the level range against the record's level after remapping
+    pub fn accepts(&self, level_no: u32) -> bool {
+        self.handler.accepts(
+            self.remapped(level_no)
+                .map_or(level_no, |level| level as u32),
+        )
+    }
+
+    /// Lowest record level this handler can accept, counting levels promoted into its range
+    pub fn min_level_no(&self) -> u32 {
+        self.level_map
+            .iter()
+            .filter(|&&(_, to)| self.handler.accepts(to as u32))
+            .map(|&(from, _)| from as u32)
+            .fold(self.handler.level() as u32, u32::min)
+    }
+
+    /// Token requirements of the format plus what the filters read (Python filters
+    /// without `filter_fields` are accounted for by the caller, as needing everything)
+    pub fn requirements(&self) -> TokenRequirements {
+        let mut requirements = self.handler.requirements();
+        if let Some(ref filter) = self.context_filter {
+            requirements = requirements.merge(&filter.requirements());
+        }
+        if let Some(ref fields) = self.filter_fields {
+            requirements = requirements.merge(&RecordField::as_token_requirements(fields));
+        }
+        requirements
+    }
+
+    /// Write a record that passed the level and filter checks, with its level remapped
+    /// for this handler (filtering and display). The native filter is checked here, so
+    /// it applies without the GIL.
+    pub fn emit(&self, record: &LogRecord) -> io::Result<()> {
+        self.emit_shared(record, &mut FormattedLines::default())
+    }
+
+    /// [`emit`](Self::emit) for one of several handlers given the same record: file
+    /// handlers whose format matches one already rendered in `formatted` reuse its line
+    pub fn emit_shared<'a>(
+        &'a self,
+        record: &LogRecord,
+        formatted: &mut FormattedLines<'a>,
+    ) -> io::Result<()> {
+        if let Some(ref filter) = self.context_filter
+            && !filter.matches(record)
+        {
+            return Ok(());
+        }
+        match self.remapped(record.level_no()) {
+            Some(level) => {
+                let mut remapped = record.clone();
+                remapped.level = level;
+                remapped.level_info = None;
+                self.emit_record(&remapped, None)
+            }
+            None => self.emit_record(record, Some(formatted)),
+        }
+    }
+
+    /// Repeats are collapsed before the rate limit, so they don't use up tokens.
+    fn emit_record<'a>(
+        &'a self,
+        record: &LogRecord,
+        formatted: Option<&mut FormattedLines<'a>>,
+    ) -> io::Result<()> {
+        if let Some(ref repeats) = self.repeats {
+            match repeats.observe(record) {
+                Observed::Repeat => return Ok(()),
+                Observed::New(Some(summary)) => self.handler.handle(&summary)?,
+                Observed::New(None) => {}
+            }
+        }
+        if !self
+            .rate_limit
+            .as_ref()
+            .is_none_or(|limiter| limiter.try_acquire())
+        {
+            return Ok(());
+        }
+        match formatted {
+            Some(formatted) if formatted.enabled => self.handler.handle_shared(record, formatted),
+            _ => self.handler.handle(record),
+        }
+    }
+
+    /// Copy of this entry with the same id and options (for `save_config()`). Rate limits
+    /// start with a full bucket and repeat tracking starts empty.
+    pub fn snapshot(&self, py: Python<'_>) -> HandlerEntry {
+        HandlerEntry {
+            id: self.id,
+            handler: self.handler.snapshot(),
+            filter: self.filter.as_ref().map(|filter| filter.clone_ref(py)),
+            filter_fields: self.filter_fields.clone(),
+            context_filter: self.context_filter.clone(),
+            rate_limit: self.rate_limit.as_ref().map(RateLimiter::fresh_copy),
+            repeats: self.repeats.as_ref().map(|_| RepeatCollapser::default()),
+            level_map: self.level_map.clone(),
+        }
+    }
+
+    /// Write the pending repeat summary, if any (on complete() and before removal)
+    pub fn flush_repeats(&self) -> io::Result<()> {
+        match self
+            .repeats
+            .as_ref()
+            .and_then(RepeatCollapser::take_summary)
+        {
+            Some(summary) => self.handler.handle(&summary),
+            None => Ok(()),
+        }
+    }
+}
+
+/// How a handler renders a line: plain (files, uncolored consoles), colored, or colored
+/// and tinted in the level color
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum LineStyle {
+    Plain,
+    Colored,
+    Tinted,
+}
+
+impl LineStyle {
+    fn render(self, format: &FormatConfig, record: &LogRecord) -> String {
+        match self {
+            LineStyle::Plain => format.format_record(record, false),
+            LineStyle::Colored => format.format_record(record, true),
+            LineStyle::Tinted => format.format_record_tinted(record),
+        }
+    }
+}
+
+/// Lines formatted for one record, so handlers with the same format and line style (two
+/// files, or a file and an uncolored console) render it once. Only enabled when a handler
+/// list has several file or console handlers; otherwise every handler formats for itself.
+#[derive(Default)]
+pub struct FormattedLines<'a> {
+    enabled: bool,
+    lines: Vec<(&'a FormatConfig, LineStyle, String)>,
+}
+
+impl<'a> FormattedLines<'a> {
+    /// Shared lines for a record sent to `handlers`
+    pub fn for_handlers(handlers: &[HandlerEntry]) -> Self {
+        let formatting_handlers = handlers
+            .iter()
+            .filter(|entry| {
+                !matches!(
+                    entry.handler,
+                    HandlerType::Custom(_) | HandlerType::Journald(_)
+                )
+            })
+            .count();
+        FormattedLines {
+            enabled: formatting_handlers > 1,
+            lines: Vec::new(),
+        }
+    }
+
+    fn format(&mut self, format: &'a FormatConfig, style: LineStyle, record: &LogRecord) -> String {
+        let colorize = style != LineStyle::Plain;
+        if let Some((_, _, line)) = self.lines.iter().find(|(seen, seen_style, _)| {
+            *seen_style == style
+                && (std::ptr::eq(*seen, format) || seen.same_output(format, colorize))
+        }) {
+            return line.clone();
+        }
+        let line = style.render(format, record);
+        self.lines.push((format, style, line.clone()));
+        line
+    }
+}
+
+/// Outcome of checking a record against the previous one
+enum Observed {
+    /// Same level and message as the previous record: counted, not written
+    Repeat,
+    /// A different record, preceded by the summary of the previous one if it repeated
+    New(Option<Box<LogRecord>>),
+}
+
+/// Tracks the last record written by a handler and how often it repeated since,
+/// like syslog's "last message repeated N times".
+#[derive(Default)]
+pub struct RepeatCollapser {
+    last: std::sync::Mutex<Option<(LogRecord, u64)>>,
+}
+
+impl RepeatCollapser {
+    fn observe(&self, record: &LogRecord) -> Observed {
+        let mut last = self.last.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some((previous, count)) = last.as_mut()
+            && previous.level_no() == record.level_no()
+            && previous.message == record.message
+            && previous.exception == record.exception
+        {
+            *count += 1;
+            return Observed::Repeat;
+        }
+        match last.replace((record.clone(), 0)) {
+            Some((previous, count)) if count > 0 => {
+                Observed::New(Some(Box::new(Self::summary(previous, count))))
+            }
+            _ => Observed::New(None),
+        }
+    }
+
+    /// Summary of the repeats so far (the counter restarts, the record stays tracked)
+    fn take_summary(&self) -> Option<LogRecord> {
+        let mut last = self.last.lock().unwrap_or_else(|e| e.into_inner());
+        match last.as_mut() {
+            Some((previous, count)) if *count > 0 => {
+                let count = std::mem::take(count);
+                Some(Self::summary(previous.clone(), count))
+            }
+            _ => None,
+        }
+    }
+
+    /// Summary record: the repeated record's level and context with a fresh time and message
+    fn summary(mut record: LogRecord, count: u64) -> LogRecord {
+        let plural = if count == 1 { "" } else { "s" };
+        record.message = format!("Last message repeated {count} time{plural}");
+        record.timestamp = clock::now();
+        record.exception = None;
+        record.end = None;
+        record
+    }
+}
+
+#[inline]
+pub(crate) fn level_in_range(level_no: u32, min: LogLevel, max: Option<u32>) -> bool {
+    level_no >= min as u32 && max.is_none_or(|max| level_no <= max)
 }
 
 /// Console handler for terminal output
 pub struct ConsoleHandler {
     pub level: LogLevel,
+    /// Highest level number written (inclusive); `None` writes everything from `level` up
+    pub max_level: Option<u32>,
     pub format: FormatConfig,
     pub colorize: bool,
+    /// Tint the whole line in the level color (only when `colorize` is on)
+    pub colorize_full: bool,
     pub use_stderr: bool,
+    /// Hand lines to the console writer thread instead of printing them on the caller's
+    pub enqueue: bool,
+    /// Records that passed the level check and were written
+    pub records: AtomicU64,
+}
+
+/// Check whether the console stream is attached to a terminal.
+pub fn console_is_terminal(use_stderr: bool) -> bool {
+    if use_stderr {
+        io::stderr().is_terminal()
+    } else {
+        io::stdout().is_terminal()
+    }
+}
+
+/// Default `colorize` when none was given explicitly.
+/// `NO_COLOR` (non-empty) disables and `FORCE_COLOR` (non-empty, not "0") enables;
+/// otherwise colors are used only when the stream is a terminal.
+pub fn default_console_colorize(use_stderr: bool) -> bool {
+    if std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty()) {
+        return false;
+    }
+    if std::env::var_os("FORCE_COLOR").is_some_and(|v| !v.is_empty() && v != "0") {
+        return true;
+    }
+    console_is_terminal(use_stderr)
 }
 
 impl ConsoleHandler {
     pub fn new(level: LogLevel) -> Self {
         ConsoleHandler {
             level,
+            max_level: None,
             format: FormatConfig::default(),
-            colorize: true,
+            colorize: default_console_colorize(false),
+            colorize_full: false,
             use_stderr: false,
+            enqueue: false,
+            records: AtomicU64::new(0),
         }
     }
 
     pub fn with_format(level: LogLevel, format: FormatConfig) -> Self {
-        let colorize = !format.serialize;
+        let colorize = !format.is_structured() && default_console_colorize(false);
         ConsoleHandler {
             level,
+            max_level: None,
             format,
             colorize,
+            colorize_full: false,
             use_stderr: false,
+            enqueue: false,
+            records: AtomicU64::new(0),
         }
     }
 
@@ -707,30 +1443,152 @@ impl ConsoleHandler {
     ) -> Self {
         ConsoleHandler {
             level,
+            max_level: None,
             format,
             colorize,
+            colorize_full: false,
             use_stderr,
+            enqueue: false,
+            records: AtomicU64::new(0),
         }
     }
 
     pub fn handle(&self, record: &LogRecord) -> io::Result<()> {
-        if record.level_no() >= self.level as u32 {
-            let output = self.format.format_record(record, self.colorize);
-            if self.use_stderr {
-                eprintln!("{}", output);
-            } else {
-                println!("{}", output);
-            }
+        if level_in_range(record.level_no(), self.level, self.max_level) {
+            self.records.fetch_add(1, Ordering::Relaxed);
+            self.print(
+                &self.line_style().render(&self.format, record),
+                record.end.as_deref(),
+            );
         }
         Ok(())
     }
+
+    fn line_style(&self) -> LineStyle {
+        match (self.colorize, self.colorize_full) {
+            (false, _) => LineStyle::Plain,
+            (true, false) => LineStyle::Colored,
+            (true, true) => LineStyle::Tinted,
+        }
+    }
+
+    /// Write a line followed by `end`, or a newline when it's None
+    fn print(&self, output: &str, end: Option<&str>) {
+        if self.enqueue {
+            // Blocks only while the queue is full, like the file async backend
+            let _ = console_writer_sender().send(ConsoleMessage::Line {
+                text: format!("{output}{}", end.unwrap_or("\n")),
+                use_stderr: self.use_stderr,
+            });
+            return;
+        }
+        match (end, self.use_stderr) {
+            (None, true) => eprintln!("{output}"),
+            (None, false) => println!("{output}"),
+            (Some(end), true) => eprint!("{output}{end}"),
+            (Some(end), false) => {
+                // stdout is line buffered: flush so an unterminated line shows up now
+                let mut stdout = io::stdout().lock();
+                let _ = write!(stdout, "{output}{end}");
+                let _ = stdout.flush();
+            }
+        }
+    }
+}
+
+/// Lines queued for the console writer thread before writers block
+const CONSOLE_QUEUE_CAPACITY: usize = 10_000;
+
+enum ConsoleMessage {
+    /// `text` ends with its terminator
+    Line { text: String, use_stderr: bool },
+    /// Acknowledged once every line queued before it is written
+    Flush(Sender<()>),
+}
+
+/// Writer thread serving every `enqueue=True` console handler of this process
+struct ConsoleWriter {
+    sender: Sender<ConsoleMessage>,
+    handle: JoinHandle<()>,
+    /// Process that spawned the thread (a forked child spawns its own)
+    pid: u32,
+}
+
+static CONSOLE_WRITER: Mutex<Option<ConsoleWriter>> = Mutex::new(None);
+
+/// Queue of the console writer thread, started on first use (or if it has stopped)
+fn console_writer_sender() -> Sender<ConsoleMessage> {
+    let mut writer = CONSOLE_WRITER.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(running) = writer.as_ref()
+        && running.pid == std::process::id()
+        && !running.handle.is_finished()
+    {
+        return running.sender.clone();
+    }
+    if let Some(stale) = writer.take() {
+        std::mem::forget(stale.handle);
+    }
+
+    let (sender, receiver) = bounded(CONSOLE_QUEUE_CAPACITY);
+    let handle = thread::spawn(move || run_console_writer(receiver));
+    *writer = Some(ConsoleWriter {
+        sender: sender.clone(),
+        handle,
+        pid: std::process::id(),
+    });
+    sender
+}
+
+/// Write queued lines, flushing each stream once the queue is drained
+fn run_console_writer(receiver: Receiver<ConsoleMessage>) {
+    let (mut stdout, mut stderr) = (io::stdout(), io::stderr());
+    while let Ok(message) = receiver.recv() {
+        let mut next = Some(message);
+        while let Some(message) = next.take() {
+            match message {
+                ConsoleMessage::Line { text, use_stderr } => {
+                    let _ = if use_stderr {
+                        stderr.write_all(text.as_bytes())
+                    } else {
+                        stdout.write_all(text.as_bytes())
+                    };
+                }
+                ConsoleMessage::Flush(done) => {
+                    let _ = stdout.flush();
+                    let _ = done.send(());
+                }
+            }
+            next = receiver.try_recv().ok();
+        }
+        let _ = stdout.flush();
+    }
+}
+
+/// Wait until the console writer thread has written every line queued so far
+/// (returns at once when no `enqueue=True` console handler has written yet)
+pub fn flush_console_queue() {
+    let sender = {
+        let writer = CONSOLE_WRITER.lock().unwrap_or_else(|e| e.into_inner());
+        match writer.as_ref() {
+            Some(running) if running.pid == std::process::id() => running.sender.clone(),
+            _ => return,
+        }
+    };
+    let (done, wait) = bounded(1);
+    if sender.send(ConsoleMessage::Flush(done)).is_ok() {
+        let _ = wait.recv();
+    }
 }
 
 /// File handler for file output
 pub struct FileHandler {
     pub sink: FileSink,
     pub level: LogLevel,
+    /// Highest level number written (inclusive); `None` writes everything from `level` up
+    pub max_level: Option<u32>,
     pub format: FormatConfig,
+    /// Records that passed the level check and were written
+    pub records: AtomicU64,
 }
 
 impl FileHandler {
@@ -738,7 +1596,9 @@ impl FileHandler {
         FileHandler {
             sink,
             level,
+            max_level: None,
             format: FormatConfig::default(),
+            records: AtomicU64::new(0),
         }
     }
 
@@ -746,17 +1606,244 @@ impl FileHandler {
         FileHandler {
             sink,
             level,
+            max_level: None,
             format,
+            records: AtomicU64::new(0),
         }
     }
 
     #[inline]
     pub fn handle(&self, record: &LogRecord) -> io::Result<()> {
-        if record.level_no() >= self.level as u32 {
+        if level_in_range(record.level_no(), self.level, self.max_level) {
+            self.records.fetch_add(1, Ordering::Relaxed);
             let output = self.format.format_record(record, false);
-            self.sink.write_owned(output)
+            self.sink.write_with_end(output, record.end.as_deref())
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// File handler writing each record to the file its `{extra[key]}` placeholders resolve to
+pub struct RoutedFileHandler {
+    pub router: SinkRouter,
+    pub level: LogLevel,
+    /// Highest level number written (inclusive); `None` writes everything from `level` up
+    pub max_level: Option<u32>,
+    pub format: FormatConfig,
+    /// Records that passed the level check and were written
+    pub records: AtomicU64,
+}
+
+impl RoutedFileHandler {
+    pub fn with_format(router: SinkRouter, level: LogLevel, format: FormatConfig) -> Self {
+        RoutedFileHandler {
+            router,
+            level,
+            max_level: None,
+            format,
+            records: AtomicU64::new(0),
+        }
+    }
+
+    pub fn handle(&self, record: &LogRecord) -> io::Result<()> {
+        if level_in_range(record.level_no(), self.level, self.max_level) {
+            self.records.fetch_add(1, Ordering::Relaxed);
+            let output = self.format.format_record(record, false);
+            self.router
+                .write_with_end(&record.extra, output, record.end.as_deref())
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Output implemented in Rust, for crates that embed logust as a library (e.g. writing to a
+/// ring buffer shared with a C extension without going through Python callbacks).
+/// Register it with [`PyLogger::add_sink`](crate::PyLogger::add_sink).
+pub trait Sink: Send + Sync {
+    /// Write a record that passed the handler's level range and filters
+    fn write(&self, record: &LogRecord) -> io::Result<()>;
+
+    /// Flush buffered output, on `complete()` and at interpreter shutdown
+    fn flush(&self) -> io::Result<()> {
+        Ok(())
+    }
+
+    /// Record details the sink reads. Caller, thread and process info are only collected
+    /// when some handler needs them; the default asks for everything.
+    fn requirements(&self) -> TokenRequirements {
+        TokenRequirements::all()
+    }
+}
+
+/// Handler passing records to a [`Sink`]
+pub struct CustomHandler {
+    pub sink: Arc<dyn Sink>,
+    pub level: LogLevel,
+    /// Highest level number written (inclusive); `None` writes everything from `level` up
+    pub max_level: Option<u32>,
+    /// Records that passed the level check and were written
+    pub records: AtomicU64,
+}
+
+impl CustomHandler {
+    pub fn new(sink: Arc<dyn Sink>, level: LogLevel) -> Self {
+        CustomHandler {
+            sink,
+            level,
+            max_level: None,
+            records: AtomicU64::new(0),
+        }
+    }
+
+    pub fn handle(&self, record: &LogRecord) -> io::Result<()> {
+        if level_in_range(record.level_no(), self.level, self.max_level) {
+            self.records.fetch_add(1, Ordering::Relaxed);
+            self.sink.write(record)
         } else {
             Ok(())
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct MemorySink(Mutex<Vec<String>>);
+
+    impl Sink for MemorySink {
+        fn write(&self, record: &LogRecord) -> io::Result<()> {
+            self.0.lock().unwrap().push(record.message.clone());
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_custom_handler_respects_level_range() {
+        let sink = Arc::new(MemorySink::default());
+        let mut handler = HandlerType::Custom(CustomHandler::new(sink.clone(), LogLevel::Info));
+        if let HandlerType::Custom(ref mut h) = handler {
+            h.max_level = Some(LogLevel::Warning as u32);
+        }
+
+        for (level, message) in [
+            (LogLevel::Debug, "debug"),
+            (LogLevel::Info, "info"),
+            (LogLevel::Warning, "warning"),
+            (LogLevel::Error, "error"),
+        ] {
+            handler
+                .handle(&LogRecord::new(level, message.to_string()))
+                .unwrap();
+        }
+
+        assert_eq!(*sink.0.lock().unwrap(), ["info", "warning"]);
+        assert_eq!(handler.records(), 2);
+        assert_eq!(handler.requirements(), TokenRequirements::all());
+        assert_eq!(handler.to_string(), "Custom(level=INFO)");
+    }
+
+    // `HandlerEntry` holds Python filters, so this needs libpython linked:
+    // run with `cargo test --no-default-features`
+    #[cfg(not(feature = "extension-module"))]
+    #[test]
+    fn test_file_handlers_share_formatted_lines() {
+        use crate::sink::FileSinkConfig;
+
+        let dir = std::env::temp_dir().join(format!(
+            "logust-shared-lines-{}-{}",
+            std::process::id(),
+            next_handler_id()
+        ));
+        let entry = |name: &str, template: &str| HandlerEntry {
+            id: next_handler_id(),
+            handler: HandlerType::File(FileHandler::with_format(
+                FileSink::new(FileSinkConfig {
+                    path: dir.join(name),
+                    ..FileSinkConfig::default()
+                })
+                .unwrap(),
+                LogLevel::Info,
+                FormatConfig::new(Some(template.to_string()), false),
+            )),
+            filter: None,
+            filter_fields: None,
+            context_filter: None,
+            rate_limit: None,
+            repeats: None,
+            level_map: Vec::new(),
+        };
+        let handlers = [
+            entry("a.log", "{level} {message}"),
+            entry("b.log", "{level} {message}"),
+            entry("c.log", "{message}"),
+        ];
+
+        let record = LogRecord::new(LogLevel::Info, "hello".to_string());
+        let mut formatted = FormattedLines::for_handlers(&handlers);
+        for entry in &handlers {
+            entry.emit_shared(&record, &mut formatted).unwrap();
+            if let HandlerType::File(ref h) = entry.handler {
+                h.sink.flush().unwrap();
+            }
+        }
+
+        assert_eq!(formatted.lines.len(), 2);
+        let read = |name| std::fs::read_to_string(dir.join(name)).unwrap();
+        assert_eq!(read("a.log"), "INFO hello\n");
+        assert_eq!(read("b.log"), "INFO hello\n");
+        assert_eq!(read("c.log"), "hello\n");
+
+        drop(handlers);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_formatted_lines_keyed_on_style() {
+        let template = || Some("{level} {message}".to_string());
+        let plain = FormatConfig::new(template(), false);
+        let same = FormatConfig::new(template(), false);
+        let highlighted = FormatConfig::new(template(), false).with_message_colors(vec![
+            crate::format::MessageColor::new("hi", "bold").unwrap(),
+        ]);
+        let record = LogRecord::new(LogLevel::Info, "hi".to_string());
+        let uncolored = plain.format_record(&record, false);
+        let colored = plain.format_record(&record, true);
+        assert_ne!(uncolored, colored);
+
+        let mut formatted = FormattedLines {
+            enabled: true,
+            lines: Vec::new(),
+        };
+        assert_eq!(
+            formatted.format(&plain, LineStyle::Plain, &record),
+            uncolored
+        );
+        assert_eq!(
+            formatted.format(&same, LineStyle::Colored, &record),
+            colored
+        );
+        assert_eq!(
+            formatted.format(&same, LineStyle::Plain, &record),
+            uncolored
+        );
+        assert_eq!(
+            formatted.format(&plain, LineStyle::Colored, &record),
+            colored
+        );
+        // Message colors only matter to colored output
+        assert_eq!(
+            formatted.format(&highlighted, LineStyle::Plain, &record),
+            uncolored
+        );
+        assert_ne!(
+            formatted.format(&highlighted, LineStyle::Colored, &record),
+            colored
+        );
+        assert_eq!(formatted.lines.len(), 3);
+    }
+}
@@ -0,0 +1,276 @@
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+#[cfg(unix)]
+use std::os::unix::net::UnixDatagram;
+
+use crate::format::TokenRequirements;
+use crate::handler::{LogRecord, level_in_range};
+use crate::level::LogLevel;
+
+/// Socket journald reads native entries from
+pub const JOURNALD_SOCKET: &str = "/run/systemd/journal/socket";
+
+/// Longest field name journald accepts
+const MAX_FIELD_NAME: usize = 64;
+
+/// Fields written for every entry; `extra` keys mapping to one of them are skipped
+const RESERVED_FIELDS: [&str; 6] = [
+    "MESSAGE",
+    "PRIORITY",
+    "SYSLOG_IDENTIFIER",
+    "CODE_FILE",
+    "CODE_LINE",
+    "CODE_FUNC",
+];
+
+/// Handler writing native journal entries (one datagram per record) to journald, with
+/// `PRIORITY` mapped from the level and `extra` sent as structured fields
+pub struct JournaldHandler {
+    #[cfg(unix)]
+    socket: Arc<UnixDatagram>,
+    path: PathBuf,
+    /// `SYSLOG_IDENTIFIER` of every entry
+    pub identifier: String,
+    pub level: LogLevel,
+    /// Highest level number written (inclusive); `None` writes everything from `level` up
+    pub max_level: Option<u32>,
+    /// Records that passed the level check and were written
+    pub records: AtomicU64,
+}
+
+impl JournaldHandler {
+    /// Connect to journald's socket; fails when journald isn't running
+    pub fn new(identifier: String, level: LogLevel) -> io::Result<Self> {
+        Self::with_socket(Path::new(JOURNALD_SOCKET), identifier, level)
+    }
+
+    /// Connect to the journal socket at `path`
+    #[cfg(unix)]
+    pub fn with_socket(path: &Path, identifier: String, level: LogLevel) -> io::Result<Self> {
+        let socket = UnixDatagram::unbound()?;
+        socket.connect(path)?;
+        Ok(JournaldHandler {
+            socket: Arc::new(socket),
+            path: path.to_path_buf(),
+            identifier,
+            level,
+            max_level: None,
+            records: AtomicU64::new(0),
+        })
+    }
+
+    #[cfg(not(unix))]
+    pub fn with_socket(_path: &Path, _identifier: String, _level: LogLevel) -> io::Result<Self> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "journald is only available on Unix",
+        ))
+    }
+
+    /// Path of the journal socket
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn handle(&self, record: &LogRecord) -> io::Result<()> {
+        if level_in_range(record.level_no(), self.level, self.max_level) {
+            self.records.fetch_add(1, Ordering::Relaxed);
+            self.send(&journal_entry(record, &self.identifier))
+        } else {
+            Ok(())
+        }
+    }
+
+    #[cfg(unix)]
+    fn send(&self, entry: &[u8]) -> io::Result<()> {
+        self.socket.send(entry).map(|_| ())
+    }
+
+    #[cfg(not(unix))]
+    fn send(&self, _entry: &[u8]) -> io::Result<()> {
+        Ok(())
+    }
+
+    /// Record data an entry reads (`CODE_*` fields come from the caller)
+    pub fn requirements(&self) -> TokenRequirements {
+        TokenRequirements {
+            needs_caller: true,
+            needs_message: true,
+            ..TokenRequirements::default()
+        }
+    }
+
+    /// Copy sharing the socket, with the same options and record count
+    pub(crate) fn snapshot(&self) -> JournaldHandler {
+        JournaldHandler {
+            #[cfg(unix)]
+            socket: Arc::clone(&self.socket),
+            path: self.path.clone(),
+            identifier: self.identifier.clone(),
+            level: self.level,
+            max_level: self.max_level,
+            records: AtomicU64::new(self.records.load(Ordering::Relaxed)),
+        }
+    }
+}
+
+/// syslog priority for a level number: CRITICAL and above are `crit` (2), ERROR and FAIL
+/// `err` (3), WARNING `warning` (4), SUCCESS `notice` (5), INFO `info` (6), lower `debug` (7)
+pub fn journal_priority(level_no: u32) -> u8 {
+    match level_no {
+        no if no >= LogLevel::Critical as u32 => 2,
+        no if no >= LogLevel::Error as u32 => 3,
+        no if no >= LogLevel::Warning as u32 => 4,
+        no if no >= LogLevel::Success as u32 => 5,
+        no if no >= LogLevel::Info as u32 => 6,
+        _ => 7,
+    }
+}
+
+/// Native journal protocol payload for a record: the message (with its traceback),
+/// priority, identifier, caller location and one field per `extra` key
+pub fn journal_entry(record: &LogRecord, identifier: &str) -> Vec<u8> {
+    let mut entry = Vec::with_capacity(128 + record.message.len());
+    match record.exception {
+        Some(ref exception) => {
+            let message = format!("{}\n{}", record.message, exception.trim_end());
+            push_field(&mut entry, "MESSAGE", &message);
+        }
+        None => push_field(&mut entry, "MESSAGE", &record.message),
+    }
+    push_field(
+        &mut entry,
+        "PRIORITY",
+        &journal_priority(record.level_no()).to_string(),
+    );
+    push_field(&mut entry, "SYSLOG_IDENTIFIER", identifier);
+    let caller = &record.caller;
+    if !caller.is_unknown() {
+        push_field(&mut entry, "CODE_FILE", &caller.file);
+        push_field(&mut entry, "CODE_LINE", &caller.line.to_string());
+        push_field(&mut entry, "CODE_FUNC", &caller.function);
+    }
+    for (key, value) in record.extra.iter() {
+        let name = field_name(key);
+        if !name.is_empty() && !RESERVED_FIELDS.contains(&name.as_str()) {
+            push_field(&mut entry, &name, value.as_str());
+        }
+    }
+    entry
+}
+
+/// Journal field name for an `extra` key: uppercased, other characters replaced by `_`,
+/// without the leading underscores and digits journald rejects (empty if nothing is left)
+fn field_name(key: &str) -> String {
+    key.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_uppercase()
+            } else {
+                '_'
+            }
+        })
+        .skip_while(|c| *c == '_' || c.is_ascii_digit())
+        .take(MAX_FIELD_NAME)
+        .collect()
+}
+
+/// Append `NAME=value\n`, or the length-prefixed binary form when the value spans lines
+fn push_field(entry: &mut Vec<u8>, name: &str, value: &str) {
+    entry.extend_from_slice(name.as_bytes());
+    if value.contains('\n') {
+        entry.push(b'\n');
+        entry.extend_from_slice(&(value.len() as u64).to_le_bytes());
+    } else {
+        entry.push(b'=');
+    }
+    entry.extend_from_slice(value.as_bytes());
+    entry.push(b'\n');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handler::{CallerInfo, ExtraValue};
+
+    fn record() -> LogRecord {
+        let extra = [
+            ("user-id", ExtraValue::from("42")),
+            ("_trusted", ExtraValue::from("no")),
+            ("priority", ExtraValue::from("0")),
+        ]
+        .into_iter()
+        .map(|(key, value)| (key.to_string(), value))
+        .collect();
+        let mut record =
+            LogRecord::with_extra(LogLevel::Warning, "disk full".into(), Arc::new(extra));
+        record.caller = CallerInfo {
+            file: "app.py".into(),
+            function: "check".into(),
+            line: 7,
+            ..CallerInfo::default()
+        };
+        record
+    }
+
+    #[test]
+    fn test_journal_priority() {
+        assert_eq!(journal_priority(LogLevel::Trace as u32), 7);
+        assert_eq!(journal_priority(LogLevel::Info as u32), 6);
+        assert_eq!(journal_priority(LogLevel::Success as u32), 5);
+        assert_eq!(journal_priority(LogLevel::Warning as u32), 4);
+        assert_eq!(journal_priority(LogLevel::Fail as u32), 3);
+        assert_eq!(journal_priority(LogLevel::Critical as u32), 2);
+    }
+
+    #[test]
+    fn test_journal_entry_fields() {
+        let mut record = record();
+        let entry = String::from_utf8(journal_entry(&record, "app")).unwrap();
+        let fixed = "MESSAGE=disk full\nPRIORITY=4\nSYSLOG_IDENTIFIER=app\nCODE_FILE=app.py\n\
+                     CODE_LINE=7\nCODE_FUNC=check\n";
+        // extra is unordered; `priority` maps to a reserved field and is skipped
+        let mut extra: Vec<&str> = entry.strip_prefix(fixed).unwrap().lines().collect();
+        extra.sort_unstable();
+        assert_eq!(extra, ["TRUSTED=no", "USER_ID=42"]);
+
+        record.exception = Some("Traceback (most recent call last):\nOSError: full\n".into());
+        let entry = journal_entry(&record, "app");
+        let message = "disk full\nTraceback (most recent call last):\nOSError: full";
+        let mut expected = b"MESSAGE\n".to_vec();
+        expected.extend_from_slice(&(message.len() as u64).to_le_bytes());
+        expected.extend_from_slice(message.as_bytes());
+        expected.push(b'\n');
+        assert!(entry.starts_with(&expected));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_handler_sends_one_datagram_per_record() {
+        let path = std::env::temp_dir().join(format!(
+            "logust-journal-{}-{}.sock",
+            std::process::id(),
+            crate::handler::next_handler_id()
+        ));
+        let journal = UnixDatagram::bind(&path).unwrap();
+        let handler = JournaldHandler::with_socket(&path, "app".into(), LogLevel::Info).unwrap();
+
+        handler
+            .handle(&LogRecord::new(LogLevel::Debug, "skipped".into()))
+            .unwrap();
+        handler
+            .handle(&LogRecord::new(LogLevel::Error, "failed".into()))
+            .unwrap();
+        let mut buf = [0u8; 256];
+        let len = journal.recv(&mut buf).unwrap();
+        assert_eq!(
+            &buf[..len],
+            b"MESSAGE=failed\nPRIORITY=3\nSYSLOG_IDENTIFIER=app\n"
+        );
+        assert_eq!(handler.records.load(Ordering::Relaxed), 1);
+        let _ = std::fs::remove_file(&path);
+    }
+}
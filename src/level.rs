@@ -51,6 +51,45 @@ impl LogLevel {
 }
 
 impl LogLevel {
+    /// Parse a built-in level name (case-insensitive)
+    pub fn from_name(name: &str) -> Option<LogLevel> {
+        match name.to_ascii_uppercase().as_str() {
+            "TRACE" => Some(LogLevel::Trace),
+            "DEBUG" => Some(LogLevel::Debug),
+            "INFO" => Some(LogLevel::Info),
+            "SUCCESS" => Some(LogLevel::Success),
+            "WARNING" => Some(LogLevel::Warning),
+            "ERROR" => Some(LogLevel::Error),
+            "FAIL" => Some(LogLevel::Fail),
+            "CRITICAL" => Some(LogLevel::Critical),
+            _ => None,
+        }
+    }
+
+    /// Built-in level with this number
+    pub fn from_no(no: u32) -> Option<LogLevel> {
+        match no {
+            5 => Some(LogLevel::Trace),
+            10 => Some(LogLevel::Debug),
+            20 => Some(LogLevel::Info),
+            25 => Some(LogLevel::Success),
+            30 => Some(LogLevel::Warning),
+            40 => Some(LogLevel::Error),
+            45 => Some(LogLevel::Fail),
+            50 => Some(LogLevel::Critical),
+            _ => None,
+        }
+    }
+
+    /// Level for handlers added without one: `LOGUST_LEVEL` when it names a built-in level,
+    /// otherwise DEBUG
+    pub fn env_default() -> LogLevel {
+        std::env::var("LOGUST_LEVEL")
+            .ok()
+            .and_then(|name| LogLevel::from_name(name.trim()))
+            .unwrap_or_default()
+    }
+
     /// Get string representation
     pub fn as_str(&self) -> &'static str {
         match self {
@@ -65,17 +104,50 @@ impl LogLevel {
         }
     }
 
-    /// Get associated color for terminal output
+    /// Get associated color for terminal output (as set by `set_level_color()`)
     pub fn color(&self) -> Color {
+        get_color_from_name(&self.color_name())
+    }
+
+    /// Color name for terminal output: the `set_level_color()` override, if any
+    pub fn color_name(&self) -> String {
+        match BUILTIN_COLORS.read().get(self) {
+            Some(name) => name.clone(),
+            None => self.default_color_name().to_string(),
+        }
+    }
+
+    /// Icon for the `{icon}` token: the `set_level_icon()` override, if any
+    pub fn icon(&self) -> String {
+        match BUILTIN_ICONS.read().get(self) {
+            Some(icon) => icon.clone(),
+            None => self.default_icon().to_string(),
+        }
+    }
+
+    fn default_icon(&self) -> &'static str {
         match self {
-            LogLevel::Trace => Color::Cyan,
-            LogLevel::Debug => Color::Blue,
-            LogLevel::Info => Color::Green,
-            LogLevel::Success => Color::BrightGreen,
-            LogLevel::Warning => Color::Yellow,
-            LogLevel::Error => Color::Red,
-            LogLevel::Fail => Color::Magenta,
-            LogLevel::Critical => Color::BrightRed,
+            LogLevel::Trace => "\u{b7}",
+            LogLevel::Debug => "\u{2022}",
+            LogLevel::Info => "\u{2139}",
+            LogLevel::Success => "\u{2713}",
+            LogLevel::Warning => "\u{26a0}",
+            LogLevel::Error => "\u{2717}",
+            LogLevel::Fail => "\u{2718}",
+            LogLevel::Critical => "\u{203c}",
+        }
+    }
+
+    fn default_color_name(&self) -> &'static str {
+        match self {
+            LogLevel::Trace => "cyan",
+            LogLevel::Debug => "blue",
+            LogLevel::Info => "green",
+            LogLevel::Success => "bright_green",
+            LogLevel::Warning => "yellow",
+            LogLevel::Error => "red",
+            LogLevel::Fail => "magenta",
+            LogLevel::Critical => "bright_red",
         }
     }
 }
@@ -114,12 +186,118 @@ static LEVEL_REGISTRY: LazyLock<RwLock<HashMap<String, LevelInfo>>> =
 static LEVEL_NO_REGISTRY: LazyLock<RwLock<HashMap<u32, String>>> =
     LazyLock::new(|| RwLock::new(HashMap::new()));
 
-/// Register a custom level
-pub fn register_level(info: LevelInfo) {
+/// Colors of built-in levels changed with `set_level_color()` (lowercase names)
+static BUILTIN_COLORS: LazyLock<RwLock<HashMap<LogLevel, String>>> =
+    LazyLock::new(|| RwLock::new(HashMap::new()));
+
+/// Change the color of a built-in or custom level
+pub fn set_level_color(name: &str, color: &str) -> Result<(), String> {
+    let color = color.to_ascii_lowercase();
+    if parse_color_name(&color).is_none() {
+        return Err(format!("Unknown color: {color}"));
+    }
+    if let Some(info) = LEVEL_REGISTRY.write().get_mut(&name.to_ascii_uppercase()) {
+        info.color = color;
+        return Ok(());
+    }
+    let level = LogLevel::from_name(name).ok_or_else(|| format!("Invalid log level: {name}"))?;
+    BUILTIN_COLORS.write().insert(level, color);
+    Ok(())
+}
+
+/// Icons of built-in levels changed with `set_level_icon()`
+static BUILTIN_ICONS: LazyLock<RwLock<HashMap<LogLevel, String>>> =
+    LazyLock::new(|| RwLock::new(HashMap::new()));
+
+/// Change the icon of a built-in or custom level
+pub fn set_level_icon(name: &str, icon: &str) -> Result<(), String> {
+    if let Some(info) = LEVEL_REGISTRY.write().get_mut(&name.to_ascii_uppercase()) {
+        info.icon = Some(icon.to_string());
+        return Ok(());
+    }
+    let level = LogLevel::from_name(name).ok_or_else(|| format!("Invalid log level: {name}"))?;
+    BUILTIN_ICONS.write().insert(level, icon.to_string());
+    Ok(())
+}
+
+/// Register a custom level. Fails if a built-in level has its name or number, or another
+/// custom level already has its number, unless `overwrite` is set (lookups then find the
+/// new level). Built-in names and numbers are never taken, even with `overwrite`: the
+/// built-in logging methods would keep their own number.
+pub fn register_level(info: LevelInfo, overwrite: bool) -> Result<(), String> {
+    // Same order as `get_level_by_no`: by number, then by name
+    let mut by_no = LEVEL_NO_REGISTRY.write();
+    let mut by_name = LEVEL_REGISTRY.write();
+    insert_level(&mut by_no, &mut by_name, info, overwrite)
+}
+
+/// Register several custom levels as one change: if any of them is rejected (see
+/// [`register_level`]), none are registered.
+pub fn register_levels(levels: Vec<(LevelInfo, bool)>) -> Result<(), String> {
+    let mut by_no = LEVEL_NO_REGISTRY.write();
+    let mut by_name = LEVEL_REGISTRY.write();
+    let mut new_by_no = by_no.clone();
+    let mut new_by_name = by_name.clone();
+    for (info, overwrite) in levels {
+        insert_level(&mut new_by_no, &mut new_by_name, info, overwrite)?;
+    }
+    *by_no = new_by_no;
+    *by_name = new_by_name;
+    Ok(())
+}
+
+fn insert_level(
+    by_no: &mut HashMap<u32, String>,
+    by_name: &mut HashMap<String, LevelInfo>,
+    info: LevelInfo,
+    overwrite: bool,
+) -> Result<(), String> {
     let name = info.name.to_ascii_uppercase();
     let no = info.no;
-    LEVEL_REGISTRY.write().insert(name.clone(), info);
-    LEVEL_NO_REGISTRY.write().insert(no, name);
+    if LogLevel::from_name(&name).is_some() {
+        return Err(format!("{name} is a built-in level"));
+    }
+    if let Some(builtin) = LogLevel::from_no(no) {
+        return Err(format!(
+            "Level number {no} is already used by {}",
+            builtin.as_str()
+        ));
+    }
+    if let Some(existing) = by_no.get(&no)
+        && *existing != name
+        && !overwrite
+    {
+        return Err(format!("Level number {no} is already used by {existing}"));
+    }
+    // A level registered again with a new number gives up its old one
+    if let Some(previous) = by_name.get(&name)
+        && previous.no != no
+        && by_no.get(&previous.no) == Some(&name)
+    {
+        by_no.remove(&previous.no);
+    }
+    by_name.insert(name.clone(), info);
+    by_no.insert(no, name);
+    Ok(())
+}
+
+/// Custom levels registered so far (for `save_config()`)
+pub fn custom_levels() -> Vec<LevelInfo> {
+    LEVEL_REGISTRY.read().values().cloned().collect()
+}
+
+/// Replace every custom level with `levels` (for `restore_config()`)
+pub fn replace_custom_levels(levels: Vec<LevelInfo>) {
+    // Same order as `get_level_by_no`: by number, then by name
+    let mut by_no = LEVEL_NO_REGISTRY.write();
+    let mut by_name = LEVEL_REGISTRY.write();
+    by_no.clear();
+    by_name.clear();
+    for info in levels {
+        let name = info.name.to_ascii_uppercase();
+        by_no.insert(info.no, name.clone());
+        by_name.insert(name, info);
+    }
 }
 
 /// Look up level by name (checks custom first, then built-in)
@@ -130,47 +308,14 @@ pub fn get_level_info(name: &str) -> Option<LevelInfo> {
         return Some(info.clone());
     }
 
-    match upper.as_str() {
-        "TRACE" => Some(LevelInfo::new("TRACE".into(), 5, Some("cyan".into()), None)),
-        "DEBUG" => Some(LevelInfo::new(
-            "DEBUG".into(),
-            10,
-            Some("blue".into()),
-            None,
-        )),
-        "INFO" => Some(LevelInfo::new(
-            "INFO".into(),
-            20,
-            Some("green".into()),
-            None,
-        )),
-        "SUCCESS" => Some(LevelInfo::new(
-            "SUCCESS".into(),
-            25,
-            Some("bright_green".into()),
-            None,
-        )),
-        "WARNING" => Some(LevelInfo::new(
-            "WARNING".into(),
-            30,
-            Some("yellow".into()),
-            None,
-        )),
-        "ERROR" => Some(LevelInfo::new("ERROR".into(), 40, Some("red".into()), None)),
-        "FAIL" => Some(LevelInfo::new(
-            "FAIL".into(),
-            45,
-            Some("magenta".into()),
-            None,
-        )),
-        "CRITICAL" => Some(LevelInfo::new(
-            "CRITICAL".into(),
-            50,
-            Some("bright_red".into()),
-            None,
-        )),
-        _ => None,
-    }
+    LogLevel::from_name(&upper).map(|level| {
+        LevelInfo::new(
+            level.as_str().into(),
+            level as u32,
+            Some(level.color_name()),
+            Some(level.icon()),
+        )
+    })
 }
 
 /// Look up level by numeric value (O(1) using secondary registry)
@@ -179,22 +324,17 @@ pub fn get_level_by_no(no: u32) -> Option<LevelInfo> {
         return LEVEL_REGISTRY.read().get(name).cloned();
     }
 
-    match no {
-        5 => get_level_info("TRACE"),
-        10 => get_level_info("DEBUG"),
-        20 => get_level_info("INFO"),
-        25 => get_level_info("SUCCESS"),
-        30 => get_level_info("WARNING"),
-        40 => get_level_info("ERROR"),
-        45 => get_level_info("FAIL"),
-        50 => get_level_info("CRITICAL"),
-        _ => None,
-    }
+    LogLevel::from_no(no).and_then(|level| get_level_info(level.as_str()))
 }
 
-/// Convert color name to colored::Color
+/// Convert color name to colored::Color (white for unknown names)
 pub fn get_color_from_name(color_name: &str) -> Color {
-    match color_name.to_ascii_lowercase().as_str() {
+    parse_color_name(color_name).unwrap_or(Color::White)
+}
+
+/// Convert color name to colored::Color, None for unknown names
+pub fn parse_color_name(color_name: &str) -> Option<Color> {
+    let color = match color_name.to_ascii_lowercase().as_str() {
         "cyan" => Color::Cyan,
         "blue" => Color::Blue,
         "green" => Color::Green,
@@ -210,8 +350,10 @@ pub fn get_color_from_name(color_name: &str) -> Color {
         "bright_yellow" => Color::BrightYellow,
         "bright_magenta" => Color::BrightMagenta,
         "bright_white" => Color::BrightWhite,
-        _ => Color::White,
-    }
+        "bright_black" => Color::BrightBlack,
+        _ => return None,
+    };
+    Some(color)
 }
 
 #[cfg(test)]
@@ -259,6 +401,32 @@ mod tests {
         assert_eq!(get_color_from_name("BRIGHT_GREEN"), Color::BrightGreen);
     }
 
+    #[test]
+    fn test_set_level_color_builtin() {
+        assert_eq!(LogLevel::Fail.color(), Color::Magenta);
+        set_level_color("fail", "BRIGHT_BLACK").unwrap();
+        assert_eq!(LogLevel::Fail.color(), Color::BrightBlack);
+        assert_eq!(get_level_info("FAIL").unwrap().color, "bright_black");
+
+        assert!(set_level_color("FAIL", "mauve").is_err());
+        assert!(set_level_color("NO_SUCH_LEVEL", "red").is_err());
+        set_level_color("FAIL", "magenta").unwrap();
+    }
+
+    #[test]
+    fn test_set_level_icon_builtin() {
+        assert_eq!(LogLevel::Success.icon(), "\u{2713}");
+        set_level_icon("success", "+").unwrap();
+        assert_eq!(LogLevel::Success.icon(), "+");
+        assert_eq!(
+            get_level_info("SUCCESS").unwrap().icon.as_deref(),
+            Some("+")
+        );
+
+        assert!(set_level_icon("NO_SUCH_LEVEL", "?").is_err());
+        set_level_icon("SUCCESS", "\u{2713}").unwrap();
+    }
+
     #[test]
     fn test_get_color_from_name_unknown() {
         assert_eq!(get_color_from_name("unknown"), Color::White);
@@ -282,7 +450,7 @@ mod tests {
     #[test]
     fn test_register_and_lookup_custom_level() {
         let custom = LevelInfo::new("NOTICE".into(), 35, Some("cyan".into()), Some("📢".into()));
-        register_level(custom);
+        register_level(custom, false).unwrap();
 
         let info = get_level_info("NOTICE").unwrap();
         assert_eq!(info.name, "NOTICE");
@@ -292,4 +460,70 @@ mod tests {
         assert_eq!(info.name, "NOTICE");
         assert_eq!(info.no, 35);
     }
+
+    #[test]
+    fn test_register_level_rejects_taken_number() {
+        let level = |name: &str, no| LevelInfo::new(name.into(), no, None, None);
+        register_level(level("AUDIT", 61), false).unwrap();
+        assert_eq!(
+            register_level(level("SECURITY", 61), false).unwrap_err(),
+            "Level number 61 is already used by AUDIT"
+        );
+        assert_eq!(get_level_by_no(61).unwrap().name, "AUDIT");
+        assert!(get_level_info("SECURITY").is_none());
+
+        // The same level can be registered again, and moving it frees its old number
+        register_level(level("audit", 61), false).unwrap();
+        register_level(level("AUDIT", 62), false).unwrap();
+        assert!(get_level_by_no(61).is_none());
+        register_level(level("SECURITY", 61), false).unwrap();
+
+        register_level(level("SECURITY", 62), true).unwrap();
+        assert_eq!(get_level_by_no(62).unwrap().name, "SECURITY");
+    }
+
+    #[test]
+    fn test_register_level_rejects_builtin_name_or_number() {
+        let level = |name: &str, no| LevelInfo::new(name.into(), no, None, None);
+        assert_eq!(
+            register_level(level("NOTICE20", 20), false).unwrap_err(),
+            "Level number 20 is already used by INFO"
+        );
+        assert_eq!(
+            register_level(level("info", 63), false).unwrap_err(),
+            "INFO is a built-in level"
+        );
+        assert_eq!(
+            register_level(level("NOTICE20", 20), true).unwrap_err(),
+            "Level number 20 is already used by INFO"
+        );
+        assert_eq!(
+            register_level(level("info", 63), true).unwrap_err(),
+            "INFO is a built-in level"
+        );
+        assert!(get_level_info("NOTICE20").is_none());
+        assert_eq!(get_level_by_no(20).unwrap().name, "INFO");
+        assert_eq!(get_level_info("INFO").unwrap().no, 20);
+    }
+
+    #[test]
+    fn test_register_levels_is_all_or_nothing() {
+        let level = |name: &str, no| LevelInfo::new(name.into(), no, None, None);
+        assert!(
+            register_levels(vec![
+                (level("BATCH_A", 163), false),
+                (level("BATCH_B", 163), false),
+            ])
+            .is_err()
+        );
+        assert!(get_level_info("BATCH_A").is_none());
+        assert!(get_level_by_no(163).is_none());
+
+        register_levels(vec![
+            (level("BATCH_A", 163), false),
+            (level("BATCH_B", 164), false),
+        ])
+        .unwrap();
+        assert_eq!(get_level_by_no(164).unwrap().name, "BATCH_B");
+    }
 }
@@ -1,24 +1,46 @@
+mod clock;
+mod filter;
 mod format;
+mod handle;
 mod handler;
+mod journald;
 mod level;
+mod logger;
+mod rate_limit;
+mod router;
 mod sink;
+mod snapshot;
 
 use std::collections::HashMap;
-use std::path::PathBuf;
-use std::sync::atomic::{AtomicU32, Ordering};
-use std::sync::{Arc, RwLockReadGuard, RwLockWriteGuard};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, RwLockReadGuard, RwLockWriteGuard, Weak};
 
+use chrono::{DateTime, Local, NaiveDateTime, TimeZone};
+use pyo3::conversion::FromPyObjectOwned;
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyTuple};
+use pyo3::types::{PyDict, PyString, PyTuple};
 
-pub use format::{FormatConfig, LOGGER_START_TIME, TokenRequirements, format_elapsed};
+pub use filter::ContextFilter;
+pub use format::{
+    FormatConfig, LOGGER_START_TIME, MessageColor, TemplatePlaceholders, TimePrecision,
+    TokenRequirements, format_elapsed, validate_template, validate_time_format,
+};
+use handle::HandleKind;
+pub use handle::HandlerHandle;
 pub use handler::{
-    CallerInfo, ConsoleHandler, ExtraMap, ExtraValue, FileHandler, HandlerEntry, HandlerType,
-    LogRecord, ProcessInfo, ThreadInfo, empty_context, serde_json_to_py,
+    CallerInfo, ConsoleHandler, CustomHandler, ExtraMap, ExtraValue, FileHandler, FormattedLines,
+    HandlerEntry, HandlerType, LogRecord, ProcessInfo, RepeatCollapser, RoutedFileHandler, Sink,
+    ThreadInfo, empty_context, serde_json_to_py,
 };
+pub use journald::JournaldHandler;
 pub use level::{LevelInfo, LogLevel, get_level_by_no, get_level_info, register_level};
-pub use sink::{FileSink, FileSinkConfig, Rotation};
+pub use logger::Logger;
+pub use rate_limit::RateLimiter;
+pub use router::SinkRouter;
+pub use sink::{FileSink, FileSinkConfig, FileSinkStats, Rotation};
+pub use snapshot::ConfigSnapshot;
 
 struct RwLock<T>(std::sync::RwLock<T>);
 
@@ -36,6 +58,83 @@ impl<T> RwLock<T> {
     }
 }
 
+/// Handler lists of every live `PyLogger` (bound loggers share their parent's), so
+/// `_shutdown()` can flush them even when a logger object is never dropped
+static LIVE_HANDLERS: Mutex<Vec<Weak<RwLock<Vec<HandlerEntry>>>>> = Mutex::new(Vec::new());
+
+/// Remember a logger's handler list for `_shutdown()`, forgetting dropped ones
+fn register_handlers(handlers: &Arc<RwLock<Vec<HandlerEntry>>>) {
+    let mut live = LIVE_HANDLERS.lock().unwrap_or_else(|e| e.into_inner());
+    live.retain(|weak| weak.strong_count() > 0);
+    live.push(Arc::downgrade(handlers));
+}
+
+/// File sinks written by a handler list (routed handlers contribute their open files)
+fn file_sinks<'a>(handlers: impl IntoIterator<Item = &'a HandlerEntry>) -> Vec<FileSink> {
+    handlers
+        .into_iter()
+        .flat_map(|entry| match entry.handler {
+            HandlerType::File(ref h) => vec![h.sink.clone()],
+            HandlerType::RoutedFile(ref h) => h.router.sinks(),
+            HandlerType::Console(_) | HandlerType::Custom(_) | HandlerType::Journald(_) => {
+                Vec::new()
+            }
+        })
+        .collect()
+}
+
+/// Rust [`Sink`]s registered in a handler list (flushed alongside the file sinks)
+fn custom_sinks<'a>(handlers: impl IntoIterator<Item = &'a HandlerEntry>) -> Vec<Arc<dyn Sink>> {
+    handlers
+        .into_iter()
+        .filter_map(|entry| match entry.handler {
+            HandlerType::Custom(ref h) => Some(Arc::clone(&h.sink)),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Whether a handler list has a console handler writing through the console writer thread
+fn enqueues_console<'a>(handlers: impl IntoIterator<Item = &'a HandlerEntry>) -> bool {
+    handlers
+        .into_iter()
+        .any(|entry| matches!(entry.handler, HandlerType::Console(ref h) if h.enqueue))
+}
+
+/// Flush every live logger and join its async writer threads. Registered with `atexit`
+/// by the Python package, so scripts that never call `complete()` don't lose queued records.
+#[pyfunction]
+fn _shutdown(py: Python<'_>) {
+    let live: Vec<_> = LIVE_HANDLERS
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .iter()
+        .filter_map(Weak::upgrade)
+        .collect();
+    for handlers in live {
+        let handlers = handlers.read();
+        for entry in handlers.iter() {
+            let _ = entry.flush_repeats();
+        }
+        let sinks = file_sinks(handlers.iter());
+        let custom = custom_sinks(handlers.iter());
+        drop(handlers);
+        py.detach(|| {
+            for sink in &sinks {
+                if let Err(err) = sink.stop_writer() {
+                    eprintln!("Failed to flush {}: {}", sink.path().display(), err);
+                }
+            }
+            for sink in &custom {
+                if let Err(err) = sink.flush() {
+                    eprintln!("Failed to flush custom sink: {err}");
+                }
+            }
+        });
+    }
+    py.detach(handler::flush_console_queue);
+}
+
 /// Built-in levels used to precompute per-emit-level token requirements (Python passes `level_value`).
 const EMIT_LEVELS: [LogLevel; 8] = [
     LogLevel::Trace,
@@ -62,6 +161,7 @@ pub struct FormattedSinkRequirements {
     pub needs_thread: bool,
     pub needs_process: bool,
     pub needs_message: bool,
+    pub needs_seq: bool,
     pub needs_nested_extra: bool,
     /// Keys referenced as `extra[key]` in the template (empty if none).
     pub extra_keys: Vec<String>,
@@ -72,9 +172,9 @@ impl FormattedSinkRequirements {
         req: &Bound<'_, PyTuple>,
         extra_keys: &Bound<'_, PyTuple>,
     ) -> PyResult<Self> {
-        if req.len() != 11 {
+        if req.len() != 12 {
             return Err(pyo3::exceptions::PyValueError::new_err(
-                "requirements tuple must have 11 bool fields",
+                "requirements tuple must have 12 bool fields",
             ));
         }
         let mut keys = Vec::with_capacity(extra_keys.len());
@@ -92,7 +192,8 @@ impl FormattedSinkRequirements {
             needs_thread: req.get_item(7)?.extract()?,
             needs_process: req.get_item(8)?.extract()?,
             needs_message: req.get_item(9)?.extract()?,
-            needs_nested_extra: req.get_item(10)?.extract()?,
+            needs_seq: req.get_item(10)?.extract()?,
+            needs_nested_extra: req.get_item(11)?.extract()?,
             extra_keys: keys,
         })
     }
@@ -119,13 +220,95 @@ enum RecordExtraView {
     Json,
 }
 
+/// Record dict keys a callback registered with `fields=` can request
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RecordField {
+    Level,
+    LevelNo,
+    Message,
+    Timestamp,
+    Name,
+    Function,
+    Line,
+    File,
+    Stacklevel,
+    ThreadName,
+    ThreadId,
+    ProcessName,
+    ProcessId,
+    Elapsed,
+    Extra,
+    Exception,
+    Seq,
+}
+
+impl RecordField {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "level" => Some(RecordField::Level),
+            "level_no" => Some(RecordField::LevelNo),
+            "message" => Some(RecordField::Message),
+            "timestamp" => Some(RecordField::Timestamp),
+            "name" => Some(RecordField::Name),
+            "function" => Some(RecordField::Function),
+            "line" => Some(RecordField::Line),
+            "file" => Some(RecordField::File),
+            "stacklevel" => Some(RecordField::Stacklevel),
+            "thread_name" => Some(RecordField::ThreadName),
+            "thread_id" => Some(RecordField::ThreadId),
+            "process_name" => Some(RecordField::ProcessName),
+            "process_id" => Some(RecordField::ProcessId),
+            "elapsed" => Some(RecordField::Elapsed),
+            "extra" => Some(RecordField::Extra),
+            "exception" => Some(RecordField::Exception),
+            "seq" => Some(RecordField::Seq),
+            _ => None,
+        }
+    }
+
+    /// Parse requested names, keeping their order and dropping duplicates
+    fn parse_list(names: &[String]) -> PyResult<Vec<RecordField>> {
+        let mut fields = Vec::with_capacity(names.len());
+        for name in names {
+            let field = Self::from_name(name).ok_or_else(|| {
+                pyo3::exceptions::PyValueError::new_err(format!("Unknown record field: {name}"))
+            })?;
+            if !fields.contains(&field) {
+                fields.push(field);
+            }
+        }
+        Ok(fields)
+    }
+
+    fn as_token_requirements(fields: &[RecordField]) -> TokenRequirements {
+        let has = |wanted: &[RecordField]| fields.iter().any(|f| wanted.contains(f));
+        TokenRequirements {
+            needs_caller: has(&[
+                RecordField::Name,
+                RecordField::Function,
+                RecordField::Line,
+                RecordField::File,
+                RecordField::Stacklevel,
+            ]),
+            needs_thread: has(&[RecordField::ThreadName, RecordField::ThreadId]),
+            needs_process: has(&[RecordField::ProcessName, RecordField::ProcessId]),
+            needs_time: has(&[RecordField::Timestamp]),
+            needs_level: has(&[RecordField::Level, RecordField::LevelNo]),
+            needs_message: has(&[RecordField::Message]),
+            needs_elapsed: has(&[RecordField::Elapsed]),
+        }
+    }
+}
+
 /// Raw callbacks receive a full record dict; serialized sinks receive a full record
 /// dict whose nested `extra` mapping uses typed JSON values; formatted sinks receive
-/// a minimal dict for templates.
+/// a minimal dict for templates; field callbacks receive only the requested keys.
+#[derive(Clone)]
 pub enum CallbackKind {
     Raw,
     Serialized,
     FormattedLight(FormattedSinkRequirements),
+    Fields(Vec<RecordField>),
 }
 
 /// Callback entry for log record callbacks
@@ -134,6 +317,49 @@ pub struct CallbackEntry {
     pub callback: Py<PyAny>,
     pub level: LogLevel,
     pub kind: CallbackKind,
+    /// Number of times `callback` was called
+    pub calls: AtomicU64,
+    /// Number of times `callback` raised
+    pub errors: AtomicU64,
+}
+
+impl CallbackEntry {
+    /// Copy with the same id, callable and options (for `save_config()`)
+    fn snapshot(&self, py: Python<'_>) -> CallbackEntry {
+        CallbackEntry {
+            id: self.id,
+            callback: self.callback.clone_ref(py),
+            level: self.level,
+            kind: self.kind.clone(),
+            calls: AtomicU64::new(self.calls.load(Ordering::Relaxed)),
+            errors: AtomicU64::new(self.errors.load(Ordering::Relaxed)),
+        }
+    }
+
+    /// Call the callback with `record`. Exceptions never reach the logging call site: they
+    /// are counted and passed to `on_error(callback_id, exc)` when set, otherwise the first
+    /// one per callback is reported through `sys.unraisablehook`.
+    fn invoke<'py>(
+        &self,
+        py: Python<'py>,
+        record: &Bound<'py, PyDict>,
+        on_error: Option<&Py<PyAny>>,
+    ) {
+        self.calls.fetch_add(1, Ordering::Relaxed);
+        let Err(err) = self.callback.call1(py, (record,)) else {
+            return;
+        };
+        let previous = self.errors.fetch_add(1, Ordering::Relaxed);
+        match on_error {
+            Some(handler) => {
+                if let Err(handler_err) = handler.call1(py, (self.id, err.value(py))) {
+                    handler_err.write_unraisable(py, Some(handler.bind(py)));
+                }
+            }
+            None if previous == 0 => err.write_unraisable(py, Some(self.callback.bind(py))),
+            None => {}
+        }
+    }
 }
 
 /// Merge handler + callback token requirements eligible when emitting at severity `emit_no`
@@ -145,8 +371,8 @@ fn merge_token_requirements_for_emit_no(
 ) -> TokenRequirements {
     let mut combined = TokenRequirements::default();
     for entry in handlers.iter() {
-        if emit_no >= entry.handler.level() as u32 {
-            combined = combined.merge(&entry.handler.requirements());
+        if entry.accepts(emit_no) {
+            combined = combined.merge(&entry.requirements());
         }
     }
 
@@ -162,6 +388,9 @@ fn merge_token_requirements_for_emit_no(
             CallbackKind::FormattedLight(req) => {
                 combined = combined.merge(&req.as_token_requirements());
             }
+            CallbackKind::Fields(fields) => {
+                combined = combined.merge(&RecordField::as_token_requirements(fields));
+            }
         }
     }
 
@@ -169,9 +398,10 @@ fn merge_token_requirements_for_emit_no(
         combined = TokenRequirements::all();
     }
 
+    // Filters that declared `filter_fields` are covered by `HandlerEntry::requirements`
     let has_filter = handlers
         .iter()
-        .any(|e| e.filter.is_some() && emit_no >= e.handler.level() as u32);
+        .any(|e| e.filter.is_some() && e.filter_fields.is_none() && e.accepts(emit_no));
     if has_filter {
         combined = TokenRequirements::all();
     }
@@ -186,19 +416,526 @@ fn merge_handler_only_requirements_for_emit_no(
 ) -> TokenRequirements {
     let mut combined = TokenRequirements::default();
     for entry in handlers.iter() {
-        if emit_no >= entry.handler.level() as u32 {
-            combined = combined.merge(&entry.handler.requirements());
+        if entry.accepts(emit_no) {
+            combined = combined.merge(&entry.requirements());
         }
     }
     combined
 }
 
+/// Fetch an optional typed value from a spec dict (`None` values count as missing).
+fn spec_item<'py, T>(spec: &Bound<'py, PyDict>, key: &str) -> PyResult<Option<T>>
+where
+    T: FromPyObjectOwned<'py>,
+{
+    match spec.get_item(key)? {
+        Some(value) if !value.is_none() => value.extract::<T>().map(Some).map_err(Into::into),
+        _ => Ok(None),
+    }
+}
+
+/// A `filter` option split into a Python callable (with the record fields it declared
+/// through `filter_fields`) or a native [`ContextFilter`]
+type ParsedFilter = (
+    Option<Py<PyAny>>,
+    Option<Vec<RecordField>>,
+    Option<ContextFilter>,
+);
+
+/// Split a `filter` option into a Python callable or a native [`ContextFilter`]: a string
+/// keeps a module and its submodules, a dict matches extra fields against glob patterns.
+/// `filter_fields` limits the dict a callable receives (ValueError for other filters).
+fn parse_filter(
+    filter: Option<Py<PyAny>>,
+    filter_fields: Option<Vec<String>>,
+) -> PyResult<ParsedFilter> {
+    let fields = filter_fields
+        .map(|names| RecordField::parse_list(&names))
+        .transpose()?;
+    let Some(filter) = filter else {
+        return match fields {
+            Some(_) => Err(pyo3::exceptions::PyValueError::new_err(
+                "filter_fields requires a callable filter",
+            )),
+            None => Ok((None, None, None)),
+        };
+    };
+    Python::attach(|py| {
+        let bound = filter.bind(py);
+        if bound.is_callable() {
+            return Ok((Some(filter), fields, None));
+        }
+        let context_filter = if let Ok(name) = bound.extract::<String>() {
+            ContextFilter::Name(name)
+        } else if let Ok(patterns) = bound.cast::<PyDict>() {
+            let patterns = patterns
+                .iter()
+                .map(|(key, pattern)| Ok((key.extract::<String>()?, pattern.extract::<String>()?)))
+                .collect::<PyResult<Vec<_>>>()?;
+            ContextFilter::extra(patterns)
+        } else {
+            return Err(pyo3::exceptions::PyTypeError::new_err(
+                "filter must be a callable, a module name or a dict of extra patterns",
+            ));
+        };
+        if fields.is_some() {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "filter_fields requires a callable filter",
+            ));
+        }
+        Ok((None, None, Some(context_filter)))
+    })
+}
+
+/// Parse an optional `rate_limit` option such as "100/s" (ValueError when malformed).
+fn parse_rate_limit(spec: Option<String>) -> PyResult<Option<RateLimiter>> {
+    spec.map(|spec| {
+        RateLimiter::parse(&spec).ok_or_else(|| {
+            pyo3::exceptions::PyValueError::new_err(format!(
+                "Invalid rate_limit: {spec:?} (expected e.g. \"100/s\", \"10/min\" or \"500/hour\")"
+            ))
+        })
+    })
+    .transpose()
+}
+
+/// Format config for a handler; with `strict_format`, unknown placeholders raise ValueError.
+fn new_format_config(
+    format: Option<String>,
+    serialize: bool,
+    strict_format: Option<bool>,
+) -> PyResult<FormatConfig> {
+    if strict_format.unwrap_or(false) {
+        FormatConfig::new_strict(format, serialize).map_err(pyo3::exceptions::PyValueError::new_err)
+    } else {
+        Ok(FormatConfig::new(format, serialize))
+    }
+}
+
+/// Parse an optional file `mode`: "a" appends (default), "w" truncates (ValueError otherwise).
+fn parse_file_mode(mode: Option<String>) -> PyResult<bool> {
+    match mode.as_deref() {
+        None | Some("a") => Ok(false),
+        Some("w") => Ok(true),
+        Some(mode) => Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "Invalid mode: {mode:?} (expected \"a\" or \"w\")"
+        ))),
+    }
+}
+
+/// Parse `write_retry_backoff` in seconds (ValueError if negative or not finite).
+fn parse_write_retry_backoff(seconds: f64) -> PyResult<std::time::Duration> {
+    std::time::Duration::try_from_secs_f64(seconds).map_err(|_| {
+        pyo3::exceptions::PyValueError::new_err(format!(
+            "Invalid write_retry_backoff: {seconds} (expected seconds >= 0)"
+        ))
+    })
+}
+
+/// Parse `on_error`: true for `"skip"`, false for `"raise"` (the default)
+fn parse_on_error(policy: Option<String>) -> PyResult<bool> {
+    match policy.as_deref() {
+        None | Some("raise") => Ok(false),
+        Some("skip") => Ok(true),
+        Some(policy) => Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "Invalid on_error: {policy:?} (expected \"raise\" or \"skip\")"
+        ))),
+    }
+}
+
+/// Stands in for a file handler whose file could not be opened with `on_error="skip"`
+struct NullSink;
+
+impl Sink for NullSink {
+    fn write(&self, _record: &LogRecord) -> std::io::Result<()> {
+        Ok(())
+    }
+
+    fn requirements(&self) -> TokenRequirements {
+        TokenRequirements::default()
+    }
+}
+
+/// Wrap an `on_rotation` callable (TypeError if it isn't one). Calls are queued to a
+/// thread of their own: the compression thread that reports rotations is joined by code
+/// holding the GIL, so it must never wait for the GIL itself.
+fn rotation_callback(callback: Py<PyAny>) -> PyResult<sink::RotationCallback> {
+    Python::attach(|py| {
+        if !callback.bind(py).is_callable() {
+            return Err(pyo3::exceptions::PyTypeError::new_err(
+                "on_rotation must be callable",
+            ));
+        }
+        Ok(())
+    })?;
+    let callback = Arc::new(callback);
+    Ok(Arc::new(move |path: &Path| {
+        let _ = rotation_callback_sender().send((Arc::clone(&callback), path.to_path_buf()));
+    }))
+}
+
+type RotationCall = (Arc<Py<PyAny>>, PathBuf);
+
+/// Queue of the thread running `on_rotation` callbacks, with the process that started it
+static ROTATION_CALLBACKS: Mutex<Option<(crossbeam_channel::Sender<RotationCall>, u32)>> =
+    Mutex::new(None);
+
+/// Queue of the `on_rotation` thread, started on first use (and again in a forked child)
+fn rotation_callback_sender() -> crossbeam_channel::Sender<RotationCall> {
+    let mut thread = ROTATION_CALLBACKS.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some((sender, pid)) = thread.as_ref()
+        && *pid == std::process::id()
+    {
+        return sender.clone();
+    }
+    let (sender, receiver) = crossbeam_channel::unbounded::<RotationCall>();
+    std::thread::spawn(move || {
+        for (callback, path) in receiver {
+            Python::attach(|py| {
+                if let Err(err) = callback.call1(py, (path,)) {
+                    err.write_unraisable(py, Some(callback.bind(py)));
+                }
+            });
+        }
+    });
+    *thread = Some((sender.clone(), std::process::id()));
+    sender
+}
+
+/// What `add()` does when a file handler for the same path already exists
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum IfExists {
+    /// Raise ValueError
+    Error,
+    /// Remove the existing handler, then add the new one
+    Replace,
+    /// Keep the existing handler and return its handle
+    Ignore,
+}
+
+/// Parse an optional `if_exists` policy (ValueError when unknown).
+fn parse_if_exists(policy: Option<String>) -> PyResult<Option<IfExists>> {
+    policy
+        .map(|policy| match policy.as_str() {
+            "error" => Ok(IfExists::Error),
+            "replace" => Ok(IfExists::Replace),
+            "ignore" => Ok(IfExists::Ignore),
+            _ => Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "Invalid if_exists: {policy:?} (expected \"error\", \"replace\" or \"ignore\")"
+            ))),
+        })
+        .transpose()
+}
+
+/// Absolute path with symlinks and `.`/`..` resolved, so `./app.log` and `app.log` compare
+/// equal. A file that does not exist yet is resolved through its parent directory.
+fn canonical_file_path(path: &Path) -> PathBuf {
+    if let Ok(canonical) = std::fs::canonicalize(path) {
+        return canonical;
+    }
+    let parent = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+    match (std::fs::canonicalize(parent), path.file_name()) {
+        (Ok(parent), Some(name)) => parent.join(name),
+        _ => std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf()),
+    }
+}
+
+/// Parse an optional `time_precision` option such as "micros" (ValueError when unknown).
+fn parse_time_precision(name: Option<String>) -> PyResult<Option<TimePrecision>> {
+    name.map(|name| {
+        TimePrecision::parse(&name).ok_or_else(|| {
+            pyo3::exceptions::PyValueError::new_err(format!(
+                "Invalid time_precision: {name:?} (expected \"seconds\", \"millis\", \"micros\" or \"nanos\")"
+            ))
+        })
+    })
+    .transpose()
+}
+
+/// Parse an optional `level_map` dict (`{"DEBUG": "TRACE"}`, names or `LogLevel`s).
+fn parse_level_map(map: Option<&Bound<'_, PyDict>>) -> PyResult<Vec<(LogLevel, LogLevel)>> {
+    map.map(|map| {
+        map.iter()
+            .map(|(from, to)| Ok((extract_level(&from)?, extract_level(&to)?)))
+            .collect()
+    })
+    .unwrap_or_else(|| Ok(Vec::new()))
+}
+
+/// Parse an optional `static_fields` dict (`{"_v": 1}`) for JSON output. Values keep their
+/// JSON type; keys the JSON formats already write are rejected.
+fn parse_static_fields(fields: Option<&Bound<'_, PyDict>>) -> PyResult<Vec<(String, ExtraValue)>> {
+    let Some(fields) = fields else {
+        return Ok(Vec::new());
+    };
+    fields
+        .iter()
+        .map(|(key, value)| {
+            let key: String = key.extract()?;
+            if format::JSON_RECORD_KEYS.contains(&key.as_str()) {
+                return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                    "static_fields key {key:?} is already a JSON record field"
+                )));
+            }
+            Ok((key, ExtraValue::from_py(&value)?))
+        })
+        .collect()
+}
+
+/// Parse an optional `message_colors` dict (`{r"^\[OK\]": "green"}`) into compiled rules.
+fn parse_message_colors(rules: Option<&Bound<'_, PyDict>>) -> PyResult<Vec<MessageColor>> {
+    let Some(rules) = rules else {
+        return Ok(Vec::new());
+    };
+    rules
+        .iter()
+        .map(|(pattern, color)| {
+            MessageColor::new(&pattern.extract::<String>()?, &color.extract::<String>()?).map_err(
+                |e| pyo3::exceptions::PyValueError::new_err(format!("message_colors: {e}")),
+            )
+        })
+        .collect()
+}
+
+/// Validate an optional `max_level` against the handler's minimum level.
+fn check_max_level(level: LogLevel, max_level: Option<LogLevel>) -> PyResult<Option<u32>> {
+    match max_level {
+        Some(max) if max < level => Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "max_level {} is below level {}",
+            max.as_str(),
+            level.as_str()
+        ))),
+        max => Ok(max.map(|m| m as u32)),
+    }
+}
+
+/// Render a `log_fmt` template: positional fields in Rust, anything else through `str.format`.
+fn render_template(template: &Bound<'_, PyString>, args: &Bound<'_, PyTuple>) -> PyResult<String> {
+    let rendered_args = args
+        .iter()
+        .map(|arg| Ok(arg.str()?.to_cow()?.into_owned()))
+        .collect::<PyResult<Vec<String>>>()?;
+    match format::interpolate_positional(&template.to_cow()?, &rendered_args) {
+        Some(message) => Ok(message),
+        None => template
+            .call_method1(intern!(template.py(), "format"), args)?
+            .extract(),
+    }
+}
+
+/// Placeholders of a format template as `(recognized, unrecognized)` lists
+#[pyfunction]
+fn _validate_format(template: &str) -> (Vec<String>, Vec<String>) {
+    let placeholders = validate_template(template);
+    (placeholders.recognized, placeholders.unrecognized)
+}
+
+/// Icon of a level for `{icon}` in callable sink templates (empty if it has none)
+#[pyfunction]
+fn _level_icon(name: &str) -> String {
+    get_level_info(name)
+        .and_then(|info| info.icon)
+        .unwrap_or_default()
+}
+
+/// Host name for `{hostname}` in callable sink templates (the one file and console
+/// handlers write)
+#[pyfunction]
+fn _hostname() -> &'static str {
+    &handler::HOSTNAME
+}
+
+/// Format an exception object the way `traceback.format_exception` does, under the GIL.
+/// Used by the `*_exc` logging methods so tracebacks are rendered in one place.
+#[pyfunction]
+fn _format_exception(exc: &Bound<'_, PyAny>) -> PyResult<String> {
+    let py = exc.py();
+    if !exc.is_instance_of::<pyo3::exceptions::PyBaseException>() {
+        return Err(pyo3::exceptions::PyTypeError::new_err(format!(
+            "expected an exception instance, got {}",
+            exc.get_type().name()?
+        )));
+    }
+    let lines = py
+        .import(intern!(py, "traceback"))?
+        .call_method1(intern!(py, "format_exception"), (exc,))?;
+    lines
+        .try_iter()?
+        .map(|line| line?.extract::<String>())
+        .collect()
+}
+
+/// Accept either a `LogLevel` or a built-in level name (case-insensitive).
+/// Build the caller info passed in from Python, which resolves frames.
+///
+/// `name`/`function`/`line`/`file` all describe one frame: the one `stacklevel` levels above
+/// the logging call (1 = the code that called the logger). `function` and `line` identify
+/// that frame, so they must be given together, and a `stacklevel` (>= 1) needs them.
+fn caller_info(
+    name: Option<String>,
+    function: Option<String>,
+    line: Option<u32>,
+    file: Option<String>,
+    stacklevel: Option<u32>,
+) -> PyResult<CallerInfo> {
+    if function.is_some() != line.is_some() {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "caller info needs both function and line",
+        ));
+    }
+    match stacklevel {
+        Some(0) => {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "stacklevel must be at least 1",
+            ));
+        }
+        Some(_) if function.is_none() => {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "stacklevel needs caller info (function and line)",
+            ));
+        }
+        _ => {}
+    }
+    Ok(CallerInfo::with_file(
+        name.unwrap_or_default(),
+        function.unwrap_or_default(),
+        line.unwrap_or(0),
+        file.unwrap_or_default(),
+    )
+    .with_stacklevel(stacklevel))
+}
+
+/// Sequence number for the next emitted record (the first is 1)
+pub(crate) fn next_seq(counter: &AtomicU64) -> u64 {
+    counter.fetch_add(1, Ordering::Relaxed) + 1
+}
+
+/// Parse a `timestamp` override for a record: epoch milliseconds or an ISO 8601 string
+/// (read as local time when it has no offset).
+fn parse_timestamp(value: &Bound<'_, PyAny>) -> PyResult<DateTime<Local>> {
+    let parsed = if let Ok(millis) = value.extract::<i64>() {
+        Local.timestamp_millis_opt(millis).single()
+    } else if let Ok(text) = value.extract::<String>() {
+        DateTime::parse_from_rfc3339(&text)
+            .map(|time| time.with_timezone(&Local))
+            .ok()
+            .or_else(|| {
+                NaiveDateTime::parse_from_str(&text, "%Y-%m-%dT%H:%M:%S%.f")
+                    .or_else(|_| NaiveDateTime::parse_from_str(&text, "%Y-%m-%d %H:%M:%S%.f"))
+                    .ok()
+                    .and_then(|time| time.and_local_timezone(Local).earliest())
+            })
+    } else {
+        return Err(pyo3::exceptions::PyTypeError::new_err(
+            "timestamp must be epoch milliseconds (int) or an ISO 8601 string",
+        ));
+    };
+    parsed.ok_or_else(|| {
+        pyo3::exceptions::PyValueError::new_err(format!("Invalid timestamp: {value}"))
+    })
+}
+
+fn extract_level(value: &Bound<'_, PyAny>) -> PyResult<LogLevel> {
+    if let Ok(level) = value.extract::<LogLevel>() {
+        return Ok(level);
+    }
+    let name: String = value.extract()?;
+    LogLevel::from_name(&name).ok_or_else(|| {
+        pyo3::exceptions::PyValueError::new_err(format!("Invalid log level: {name}"))
+    })
+}
+
+/// Resolve a `configure()` sink to a console stream name, or `None` for file sinks.
+fn console_stream_name(py: Python<'_>, sink: &Bound<'_, PyAny>) -> PyResult<Option<&'static str>> {
+    if let Ok(s) = sink.cast::<PyString>() {
+        return Ok(match s.to_str()? {
+            "stdout" => Some("stdout"),
+            "stderr" => Some("stderr"),
+            _ => None,
+        });
+    }
+    let sys = py.import(intern!(py, "sys"))?;
+    if sink.is(&sys.getattr(intern!(py, "stdout"))?) {
+        Ok(Some("stdout"))
+    } else if sink.is(&sys.getattr(intern!(py, "stderr"))?) {
+        Ok(Some("stderr"))
+    } else {
+        Ok(None)
+    }
+}
+
+/// Whether the default console writes to stderr: `stream` ("stdout"/"stderr" or
+/// `sys.stdout`/`sys.stderr`) if given, otherwise `LOGUST_STREAM=stderr`
+fn default_console_stderr(stream: Option<&Bound<'_, PyAny>>) -> PyResult<bool> {
+    match stream {
+        Some(stream) => match console_stream_name(stream.py(), stream)? {
+            Some(name) => Ok(name == "stderr"),
+            None => Err(pyo3::exceptions::PyValueError::new_err(
+                "stream must be 'stdout' or 'stderr'",
+            )),
+        },
+        None => Ok(std::env::var("LOGUST_STREAM")
+            .is_ok_and(|name| name.trim().eq_ignore_ascii_case("stderr"))),
+    }
+}
+
+/// Build a per-record dict on first use and hand out the cached one afterwards.
+/// A failed build is retried by the next consumer (the record is still written).
+#[inline]
+fn lazy_record_dict<'a, 'py>(
+    slot: &'a mut Option<Bound<'py, PyDict>>,
+    build: impl FnOnce() -> PyResult<Bound<'py, PyDict>>,
+) -> Option<&'a Bound<'py, PyDict>> {
+    if slot.is_none() {
+        *slot = build().ok();
+    }
+    slot.as_ref()
+}
+
+/// Run a handler's Python filter, if any. With `filter_fields` it receives a dict of just
+/// those keys, otherwise the full record dict from `full`. A filter that raises (or a dict
+/// that can't be built) keeps the record.
+fn handler_filter_passes<'a, 'py: 'a>(
+    py: Python<'py>,
+    entry: &HandlerEntry,
+    record: &LogRecord,
+    full: impl FnOnce() -> Option<&'a Bound<'py, PyDict>>,
+) -> bool {
+    let Some(ref filter) = entry.filter else {
+        return true;
+    };
+    let result = match entry.filter_fields {
+        Some(ref fields) => match PyLogger::build_fields_record_dict(py, record, fields) {
+            Ok(dict) => filter.call1(py, (dict,)),
+            Err(_) => return true,
+        },
+        None => match full() {
+            Some(dict) => filter.call1(py, (dict,)),
+            None => return true,
+        },
+    };
+    result
+        .and_then(|result| result.is_truthy(py))
+        .unwrap_or(true)
+}
+
 #[pyclass]
 pub struct PyLogger {
     /// All handlers (console + files)
     handlers: Arc<RwLock<Vec<HandlerEntry>>>,
     /// Bound context (extra fields) - immutable after creation for zero-copy sharing
     context: Arc<ExtraMap>,
+    /// Callables passed to `bind()`, called for each record that some handler or callback
+    /// accepts; their results are merged over `context`
+    lazy_context: Arc<Vec<(String, Py<PyAny>)>>,
+    /// `patch()` callables run on each record before dispatch (per logger, kept by `bind()`)
+    patchers: Arc<Vec<Py<PyAny>>>,
+    /// Callable returning the extra of the current context (e.g. read from a `ContextVar`),
+    /// called for each record that some handler or callback accepts (shared via Arc)
+    context_provider: Arc<RwLock<Option<Py<PyAny>>>>,
     /// Registered callbacks
     callbacks: Arc<RwLock<Vec<CallbackEntry>>>,
     /// Cached minimum log level across all handlers and callbacks (shared via Arc)
@@ -207,39 +944,66 @@ pub struct PyLogger {
     cached_requirements_by_level: Arc<RwLock<HashMap<u32, TokenRequirements>>>,
     /// Cached token requirements for handlers only (excludes callbacks)
     cached_handler_requirements: Arc<RwLock<TokenRequirements>>,
+    /// When set, every record is dropped before any handler/callback work (shared via Arc)
+    paused: Arc<AtomicBool>,
+    /// Level number below which records are dropped whatever the handler levels
+    /// (`set_global_level()`, 0 when unset; shared via Arc)
+    global_level: Arc<AtomicU32>,
+    /// Number of records emitted so far, for `{seq}` (shared via Arc)
+    seq: Arc<AtomicU64>,
+    /// Whether any handler/callback formats thread info (native fill when Python omits it)
+    cached_needs_thread: Arc<AtomicBool>,
+    /// Whether any handler/callback formats process info (native fill when Python omits it)
+    cached_needs_process: Arc<AtomicBool>,
+    /// Optional `handler(callback_id, exc)` for exceptions raised by callbacks (shared via Arc)
+    callback_error_handler: Arc<RwLock<Option<Py<PyAny>>>>,
+    /// Id of the stderr half once `split_console()` was used; `enable()` and `set_level()`
+    /// then manage the console as a stdout/stderr pair (shared via Arc)
+    split_console: Arc<RwLock<Option<u64>>>,
+    /// Whether the console handler installed by the constructor, `reset()` and `enable()`
+    /// writes to stderr instead of stdout
+    default_stderr: bool,
 }
 
 #[pymethods]
 impl PyLogger {
     #[new]
-    #[pyo3(signature = (level=None))]
-    fn new(level: Option<LogLevel>) -> Self {
+    #[pyo3(signature = (level=None, stream=None))]
+    fn new(level: Option<LogLevel>, stream: Option<&Bound<'_, PyAny>>) -> PyResult<Self> {
         let logger = PyLogger {
             handlers: Arc::new(RwLock::new(Vec::new())),
             context: empty_context(),
+            lazy_context: Arc::new(Vec::new()),
+            patchers: Arc::new(Vec::new()),
+            context_provider: Arc::new(RwLock::new(None)),
             callbacks: Arc::new(RwLock::new(Vec::new())),
             cached_min_level: Arc::new(AtomicU32::new(u32::MAX)),
             cached_requirements_by_level: Arc::new(RwLock::new(HashMap::new())),
             cached_handler_requirements: Arc::new(RwLock::new(TokenRequirements::default())),
+            paused: Arc::new(AtomicBool::new(false)),
+            global_level: Arc::new(AtomicU32::new(0)),
+            seq: Arc::new(AtomicU64::new(0)),
+            cached_needs_thread: Arc::new(AtomicBool::new(false)),
+            cached_needs_process: Arc::new(AtomicBool::new(false)),
+            callback_error_handler: Arc::new(RwLock::new(None)),
+            split_console: Arc::new(RwLock::new(None)),
+            default_stderr: default_console_stderr(stream)?,
         };
 
-        let console_level = level.unwrap_or_default();
-        let console_handler = ConsoleHandler::new(console_level);
-        let entry = HandlerEntry {
-            id: handler::next_handler_id(),
-            handler: HandlerType::Console(console_handler),
-            filter: None,
-        };
-        logger.handlers.write().push(entry);
+        logger
+            .handlers
+            .write()
+            .push(logger.default_console_entry(level.unwrap_or_else(LogLevel::env_default)));
         logger.update_min_level_cache();
         logger.update_requirements_cache();
+        register_handlers(&logger.handlers);
 
-        logger
+        Ok(logger)
     }
 
     /// Add a file handler
     #[allow(clippy::too_many_arguments)]
-    #[pyo3(signature = (path, level=None, format=None, rotation=None, retention=None, compression=None, serialize=None, filter=None, enqueue=None))]
+    #[pyo3(signature = (path, level=None, format=None, rotation=None, retention=None, compression=None, serialize=None, filter=None, enqueue=None, serialize_wrapped=None, watch=None, line_terminator=None, rate_limit=None, dedupe=None, max_level=None, autoflush=None, fsync=None, max_open_files=None, utc=None, time_format=None, time_precision=None, prefix=None, suffix=None, level_map=None, static_fields=None, logfmt=None, csv=None, csv_header=None, if_exists=None, mode=None, strict_format=None, filter_fields=None, shared_writer=None, on_error=None, on_rotation=None, compress_on_close=None, max_queue_bytes=None, write_retries=None, write_retry_backoff=None))]
     fn add(
         &self,
         path: String,
@@ -251,50 +1015,115 @@ impl PyLogger {
         serialize: Option<bool>,
         filter: Option<Py<PyAny>>,
         enqueue: Option<bool>,
-    ) -> PyResult<u64> {
-        let level = level.unwrap_or(LogLevel::Debug);
-        let serialize = serialize.unwrap_or(false);
-        let format_config = FormatConfig::new(format, serialize);
-
-        let (time_rotation, max_size) = rotation
-            .as_ref()
-            .map(|r| sink::parse_rotation(r))
-            .unwrap_or((Rotation::Never, None));
-
-        let (retention_days, retention_count) = retention
-            .as_ref()
-            .map(|r| sink::parse_retention(r))
-            .unwrap_or((None, None));
-
-        let config = FileSinkConfig {
-            path: PathBuf::from(path),
-            rotation: time_rotation,
-            max_size,
-            retention_days,
-            retention_count,
-            compression: compression.unwrap_or(false),
-            enqueue: enqueue.unwrap_or(false),
-        };
-
-        let sink = FileSink::new(config)
-            .map_err(|e| pyo3::exceptions::PyIOError::new_err(e.to_string()))?;
-
-        let id = handler::next_handler_id();
-        let file_handler = FileHandler::with_format(sink, level, format_config);
-        let entry = HandlerEntry {
-            id,
-            handler: HandlerType::File(file_handler),
+        serialize_wrapped: Option<bool>,
+        watch: Option<bool>,
+        line_terminator: Option<String>,
+        rate_limit: Option<String>,
+        dedupe: Option<bool>,
+        max_level: Option<LogLevel>,
+        autoflush: Option<bool>,
+        fsync: Option<bool>,
+        max_open_files: Option<usize>,
+        utc: Option<bool>,
+        time_format: Option<String>,
+        time_precision: Option<String>,
+        prefix: Option<String>,
+        suffix: Option<String>,
+        level_map: Option<&Bound<'_, PyDict>>,
+        static_fields: Option<&Bound<'_, PyDict>>,
+        logfmt: Option<bool>,
+        csv: Option<bool>,
+        csv_header: Option<bool>,
+        if_exists: Option<String>,
+        mode: Option<String>,
+        strict_format: Option<bool>,
+        filter_fields: Option<Vec<String>>,
+        shared_writer: Option<bool>,
+        on_error: Option<String>,
+        on_rotation: Option<Py<PyAny>>,
+        compress_on_close: Option<bool>,
+        max_queue_bytes: Option<String>,
+        write_retries: Option<u32>,
+        write_retry_backoff: Option<f64>,
+    ) -> PyResult<HandlerHandle> {
+        if let Some(policy) = parse_if_exists(if_exists)?
+            && let Some(id) = self.file_handler_id(Path::new(&path))
+        {
+            match policy {
+                IfExists::Error => {
+                    return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                        "a file handler for {path:?} already exists (id {id})"
+                    )));
+                }
+                IfExists::Ignore => return Ok(self.handle(id, HandleKind::Handler)),
+                IfExists::Replace => {
+                    self.remove(Some(id));
+                }
+            }
+        }
+        let entry = Self::build_file_entry(
+            PathBuf::from(path),
+            level,
+            format,
+            rotation,
+            retention,
+            compression,
+            serialize,
             filter,
-        };
+            enqueue,
+            serialize_wrapped,
+            watch,
+            line_terminator,
+            rate_limit,
+            dedupe,
+            max_level,
+            autoflush,
+            fsync,
+            max_open_files,
+            utc,
+            time_format,
+            time_precision,
+            prefix,
+            suffix,
+            parse_level_map(level_map)?,
+            parse_static_fields(static_fields)?,
+            logfmt,
+            csv,
+            csv_header,
+            mode,
+            strict_format,
+            filter_fields,
+            shared_writer,
+            on_error,
+            on_rotation,
+            compress_on_close,
+            max_queue_bytes,
+            write_retries,
+            write_retry_backoff,
+        )?;
+        let id = entry.id;
 
         self.handlers.write().push(entry);
         self.update_min_level_cache();
         self.update_requirements_cache();
-        Ok(id)
+        Ok(self.handle(id, HandleKind::Handler))
     }
 
-    /// Add a console handler (stdout or stderr)
-    #[pyo3(signature = (stream, level=None, format=None, serialize=None, filter=None, colorize=None))]
+    /// Id of the file handler writing to `path`, if any (paths are compared canonicalized)
+    #[pyo3(name = "file_handler_id")]
+    fn py_file_handler_id(&self, path: PathBuf) -> Option<u64> {
+        self.file_handler_id(&path)
+    }
+
+    /// Whether a file handler writes to `path`, for idempotent setup
+    fn has_file_handler(&self, path: PathBuf) -> bool {
+        self.file_handler_id(&path).is_some()
+    }
+
+    /// Add a console handler (stdout or stderr). With `enqueue`, lines are printed by a
+    /// writer thread shared by all such handlers, so a slow terminal doesn't block callers.
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (stream, level=None, format=None, serialize=None, filter=None, colorize=None, colorize_full=None, serialize_wrapped=None, rate_limit=None, dedupe=None, max_level=None, colorize_exception=None, utc=None, time_format=None, time_precision=None, prefix=None, suffix=None, level_map=None, static_fields=None, message_colors=None, logfmt=None, strict_format=None, filter_fields=None, enqueue=None))]
     fn add_console(
         &self,
         stream: String,
@@ -303,88 +1132,376 @@ impl PyLogger {
         serialize: Option<bool>,
         filter: Option<Py<PyAny>>,
         colorize: Option<bool>,
-    ) -> PyResult<u64> {
-        let level = level.unwrap_or(LogLevel::Debug);
-        let serialize = serialize.unwrap_or(false);
-        let colorize = colorize.unwrap_or(!serialize);
-        let format_config = FormatConfig::new(format, serialize);
-        if stream != "stdout" && stream != "stderr" {
-            return Err(pyo3::exceptions::PyValueError::new_err(
-                "stream must be 'stdout' or 'stderr'",
-            ));
-        }
-        let use_stderr = stream == "stderr";
-
-        let id = handler::next_handler_id();
-        let console_handler =
-            ConsoleHandler::with_options(level, format_config, colorize, use_stderr);
-        let entry = HandlerEntry {
-            id,
-            handler: HandlerType::Console(console_handler),
+        colorize_full: Option<bool>,
+        serialize_wrapped: Option<bool>,
+        rate_limit: Option<String>,
+        dedupe: Option<bool>,
+        max_level: Option<LogLevel>,
+        colorize_exception: Option<bool>,
+        utc: Option<bool>,
+        time_format: Option<String>,
+        time_precision: Option<String>,
+        prefix: Option<String>,
+        suffix: Option<String>,
+        level_map: Option<&Bound<'_, PyDict>>,
+        static_fields: Option<&Bound<'_, PyDict>>,
+        message_colors: Option<&Bound<'_, PyDict>>,
+        logfmt: Option<bool>,
+        strict_format: Option<bool>,
+        filter_fields: Option<Vec<String>>,
+        enqueue: Option<bool>,
+    ) -> PyResult<HandlerHandle> {
+        let entry = Self::build_console_entry(
+            &stream,
+            level,
+            format,
+            serialize,
             filter,
-        };
+            colorize,
+            colorize_full,
+            serialize_wrapped,
+            rate_limit,
+            dedupe,
+            max_level,
+            colorize_exception,
+            utc,
+            time_format,
+            time_precision,
+            prefix,
+            suffix,
+            parse_level_map(level_map)?,
+            parse_static_fields(static_fields)?,
+            parse_message_colors(message_colors)?,
+            logfmt,
+            strict_format,
+            filter_fields,
+            enqueue,
+        )?;
+        let id = entry.id;
 
         self.handlers.write().push(entry);
         self.update_min_level_cache();
         self.update_requirements_cache();
-        Ok(id)
+        Ok(self.handle(id, HandleKind::Handler))
     }
 
-    /// Remove a handler by ID, or remove all handlers if None
-    #[pyo3(signature = (handler_id=None))]
-    fn remove(&self, handler_id: Option<u64>) -> bool {
-        let mut handlers = self.handlers.write();
-
-        let result = if let Some(id) = handler_id {
-            if let Some(pos) = handlers.iter().position(|h| h.id == id) {
-                handlers.remove(pos);
-                true
-            } else {
-                false
-            }
-        } else {
-            handlers.clear();
-            true
+    /// Replace the console handlers with a stdout/stderr pair split at WARNING:
+    /// records below WARNING go to stdout, WARNING and above to stderr.
+    /// Returns the `(stdout, stderr)` handler ids.
+    #[pyo3(signature = (level=None, format=None, serialize=None, colorize=None, colorize_full=None, colorize_exception=None, utc=None))]
+    #[allow(clippy::too_many_arguments)]
+    fn split_console(
+        &self,
+        level: Option<LogLevel>,
+        format: Option<String>,
+        serialize: Option<bool>,
+        colorize: Option<bool>,
+        colorize_full: Option<bool>,
+        colorize_exception: Option<bool>,
+        utc: Option<bool>,
+    ) -> PyResult<(HandlerHandle, HandlerHandle)> {
+        let [stdout, stderr] = Self::build_split_console_entries(
+            level.unwrap_or_else(LogLevel::env_default),
+            format,
+            serialize,
+            colorize,
+            colorize_full,
+            colorize_exception,
+            utc,
+        )?;
+        let ids = (stdout.id, stderr.id);
+
+        let old_entries = {
+            let mut handlers = self.handlers.write();
+            let mut split_console = self.split_console.write();
+            let (old, kept): (Vec<_>, Vec<_>) = std::mem::take(&mut *handlers)
+                .into_iter()
+                .partition(|e| matches!(e.handler, HandlerType::Console(_)));
+            *handlers = kept;
+            handlers.push(stdout);
+            handlers.push(stderr);
+            *split_console = Some(ids.1);
+            old
         };
-        drop(handlers); // Release lock before updating cache
+        for entry in &old_entries {
+            let _ = entry.flush_repeats();
+        }
+        drop(old_entries);
+
         self.update_min_level_cache();
         self.update_requirements_cache();
-        result
+        Ok((
+            self.handle(ids.0, HandleKind::Handler),
+            self.handle(ids.1, HandleKind::Handler),
+        ))
     }
 
-    /// Bind context values and return a new logger (zero-copy when no new keys)
-    fn bind(&self, py: Python, kwargs: Option<&Bound<'_, PyDict>>) -> PyResult<Py<PyLogger>> {
-        let new_context = match kwargs {
-            None => Arc::clone(&self.context),
-            Some(dict) if dict.is_empty() => Arc::clone(&self.context),
-            Some(dict) => {
-                let mut ctx = (*self.context).clone();
-                for (key, value) in dict.iter() {
-                    let key_str: String = key.extract()?;
-                    ctx.insert(key_str, ExtraValue::from_py(&value)?);
-                }
-                Arc::new(ctx)
-            }
-        };
-
-        let new_logger = PyLogger {
-            handlers: Arc::clone(&self.handlers),
-            context: new_context,
-            callbacks: Arc::clone(&self.callbacks),
-            cached_min_level: Arc::clone(&self.cached_min_level),
-            cached_requirements_by_level: Arc::clone(&self.cached_requirements_by_level),
-            cached_handler_requirements: Arc::clone(&self.cached_handler_requirements),
+    /// Add a handler sending native journal entries to journald, tagged with `identifier`
+    /// as `SYSLOG_IDENTIFIER`. Raises OSError when the journal socket can't be reached.
+    #[pyo3(signature = (identifier, level=None, socket_path=None))]
+    fn add_journald(
+        &self,
+        identifier: String,
+        level: Option<LogLevel>,
+        socket_path: Option<PathBuf>,
+    ) -> PyResult<HandlerHandle> {
+        let level = level.unwrap_or_else(LogLevel::env_default);
+        let socket_path = socket_path.unwrap_or_else(|| PathBuf::from(journald::JOURNALD_SOCKET));
+        let handler = JournaldHandler::with_socket(&socket_path, identifier, level)
+            .map_err(|err| pyo3::exceptions::PyIOError::new_err(err.to_string()))?;
+        let entry = HandlerEntry {
+            id: handler::next_handler_id(),
+            handler: HandlerType::Journald(handler),
+            filter: None,
+            filter_fields: None,
+            context_filter: None,
+            rate_limit: None,
+            repeats: None,
+            level_map: Vec::new(),
         };
-        Py::new(py, new_logger)
+        let id = entry.id;
+
+        self.handlers.write().push(entry);
+        self.update_min_level_cache();
+        self.update_requirements_cache();
+        Ok(self.handle(id, HandleKind::Handler))
+    }
+
+    /// Replace all handlers from spec dicts, register custom levels, and merge default extra.
+    ///
+    /// Each handler spec needs a `sink` key: `"stdout"` / `"stderr"` (or `sys.stdout` /
+    /// `sys.stderr`) creates a console handler, anything else is treated as a file path.
+    /// All handler and level specs are checked before anything changes, so an invalid spec
+    /// leaves the current handlers and levels untouched.
+    #[pyo3(signature = (handlers=None, levels=None, extra=None))]
+    fn configure(
+        &mut self,
+        py: Python<'_>,
+        handlers: Option<Vec<Bound<'_, PyDict>>>,
+        levels: Option<Vec<Bound<'_, PyDict>>>,
+        extra: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<Vec<HandlerHandle>> {
+        let mut level_infos = Vec::new();
+        for spec in levels.unwrap_or_default() {
+            let name: String = spec_item(&spec, "name")?.ok_or_else(|| {
+                pyo3::exceptions::PyValueError::new_err("level spec requires a 'name' key")
+            })?;
+            let no: u32 = spec_item(&spec, "no")?.ok_or_else(|| {
+                pyo3::exceptions::PyValueError::new_err("level spec requires a 'no' key")
+            })?;
+            level_infos.push((
+                LevelInfo::new(
+                    name,
+                    no,
+                    spec_item(&spec, "color")?,
+                    spec_item(&spec, "icon")?,
+                ),
+                spec_item(&spec, "overwrite")?.unwrap_or(false),
+            ));
+        }
+
+        let mut entries = Vec::new();
+        for spec in handlers.unwrap_or_default() {
+            entries.push(Self::build_entry_from_spec(py, &spec)?);
+        }
+        let ids: Vec<u64> = entries.iter().map(|e| e.id).collect();
+
+        level::register_levels(level_infos).map_err(pyo3::exceptions::PyValueError::new_err)?;
+
+        // Drop the old handlers outside the lock (file sinks flush and join on drop).
+        let old_entries = std::mem::replace(&mut *self.handlers.write(), entries);
+        *self.split_console.write() = None;
+        for entry in &old_entries {
+            let _ = entry.flush_repeats();
+        }
+        drop(old_entries);
+
+        if let Some(dict) = extra
+            && !dict.is_empty()
+        {
+            let mut ctx = (*self.context).clone();
+            for (key, value) in dict.iter() {
+                let key_str: String = key.extract()?;
+                ctx.insert(key_str, ExtraValue::from_py(&value)?);
+            }
+            self.context = Arc::new(ctx);
+        }
+
+        self.update_min_level_cache();
+        self.update_requirements_cache();
+        Ok(ids
+            .into_iter()
+            .map(|id| self.handle(id, HandleKind::Handler))
+            .collect())
+    }
+
+    /// Return the logger to its constructed state: a single DEBUG console handler and no
+    /// callbacks, context, patchers or callback error handler. Handlers and callbacks are
+    /// shared with bound loggers, so those are reset too.
+    fn reset(&mut self) {
+        let old_entries = std::mem::replace(
+            &mut *self.handlers.write(),
+            vec![self.default_console_entry(LogLevel::env_default())],
+        );
+        *self.split_console.write() = None;
+        for entry in &old_entries {
+            let _ = entry.flush_repeats();
+        }
+        drop(old_entries);
+
+        self.callbacks.write().clear();
+        *self.callback_error_handler.write() = None;
+        *self.context_provider.write() = None;
+        self.paused.store(false, Ordering::Relaxed);
+        self.context = empty_context();
+        self.lazy_context = Arc::new(Vec::new());
+        self.patchers = Arc::new(Vec::new());
+
+        self.update_min_level_cache();
+        self.update_requirements_cache();
+    }
+
+    /// Capture the handlers, callbacks and custom levels for `restore_config()`.
+    /// Saved file handlers keep their files open while the snapshot exists.
+    fn save_config(&self, py: Python<'_>) -> ConfigSnapshot {
+        ConfigSnapshot {
+            handlers: self
+                .handlers
+                .read()
+                .iter()
+                .map(|entry| entry.snapshot(py))
+                .collect(),
+            callbacks: self
+                .callbacks
+                .read()
+                .iter()
+                .map(|entry| entry.snapshot(py))
+                .collect(),
+            levels: level::custom_levels(),
+            split_console: *self.split_console.read(),
+        }
+    }
+
+    /// Replace the handlers, callbacks and custom levels with a `save_config()` snapshot,
+    /// keeping their ids. Handlers and callbacks are shared with bound loggers.
+    fn restore_config(&self, py: Python<'_>, snapshot: &ConfigSnapshot) {
+        let handlers = snapshot
+            .handlers
+            .iter()
+            .map(|entry| entry.snapshot(py))
+            .collect();
+        let old_entries = std::mem::replace(&mut *self.handlers.write(), handlers);
+        *self.split_console.write() = snapshot.split_console;
+        for entry in &old_entries {
+            let _ = entry.flush_repeats();
+        }
+        drop(old_entries);
+
+        *self.callbacks.write() = snapshot
+            .callbacks
+            .iter()
+            .map(|entry| entry.snapshot(py))
+            .collect();
+        level::replace_custom_levels(snapshot.levels.clone());
+
+        self.update_min_level_cache();
+        self.update_requirements_cache();
+    }
+
+    /// Remove a handler by ID, or remove all handlers if None
+    #[pyo3(signature = (handler_id=None))]
+    fn remove(&self, handler_id: Option<u64>) -> bool {
+        let mut handlers = self.handlers.write();
+
+        let result = if let Some(id) = handler_id {
+            if let Some(pos) = handlers.iter().position(|h| h.id == id) {
+                let _ = handlers.remove(pos).flush_repeats();
+                true
+            } else {
+                false
+            }
+        } else {
+            for entry in handlers.drain(..) {
+                let _ = entry.flush_repeats();
+            }
+            true
+        };
+        drop(handlers); // Release lock before updating cache
+        self.update_min_level_cache();
+        self.update_requirements_cache();
+        result
+    }
+
+    /// Bind context values and return a new logger (zero-copy when no new keys).
+    /// Callable values are not stored: they are called at log time, and only for records
+    /// that some handler or callback accepts. With `keep_existing`, keys that are already
+    /// bound keep their value and only new keys are added.
+    #[pyo3(signature = (kwargs=None, keep_existing=false))]
+    fn bind(
+        &self,
+        py: Python,
+        kwargs: Option<&Bound<'_, PyDict>>,
+        keep_existing: bool,
+    ) -> PyResult<Py<PyLogger>> {
+        let Some(kwargs) = kwargs.filter(|k| !k.is_empty()) else {
+            return Py::new(
+                py,
+                self.derive(Arc::clone(&self.context), Arc::clone(&self.patchers)),
+            );
+        };
+
+        let mut context = (*self.context).clone();
+        let mut lazy: Vec<(String, Py<PyAny>)> = self
+            .lazy_context
+            .iter()
+            .map(|(key, func)| (key.clone(), func.clone_ref(py)))
+            .collect();
+        for (key, value) in kwargs.iter() {
+            let key: String = key.extract()?;
+            if keep_existing && (context.contains_key(&key) || lazy.iter().any(|(k, _)| *k == key))
+            {
+                continue;
+            }
+            lazy.retain(|(k, _)| *k != key);
+            if value.is_callable() {
+                context.remove(&key);
+                lazy.push((key, value.unbind()));
+            } else {
+                context.insert(key, ExtraValue::from_py(&value)?);
+            }
+        }
+
+        let mut logger = self.derive(Arc::new(context), Arc::clone(&self.patchers));
+        logger.lazy_context = Arc::new(lazy);
+        Py::new(py, logger)
+    }
+
+    /// Return a new logger that runs `patcher(record)` on every emitted record before
+    /// filters, callbacks and handlers see it. The dict has `level`, `level_no`, `message`,
+    /// `timestamp`, `exception` and `extra`; changes to `message`, `exception` and `extra`
+    /// are applied. Patchers run in order, and only for records that some handler accepts.
+    fn patch(&self, py: Python, patcher: Py<PyAny>) -> PyResult<Py<PyLogger>> {
+        let mut patchers: Vec<Py<PyAny>> = self.patchers.iter().map(|p| p.clone_ref(py)).collect();
+        patchers.push(patcher);
+        Py::new(
+            py,
+            self.derive(Arc::clone(&self.context), Arc::new(patchers)),
+        )
     }
 
     /// Set minimum log level for all console handlers
+    /// (the stderr half of a split console never drops below WARNING)
     fn set_level(&self, level: LogLevel) {
         {
+            let split_stderr = *self.split_console.read();
             let mut handlers = self.handlers.write();
             for entry in handlers.iter_mut() {
                 if let HandlerType::Console(ref mut h) = entry.handler {
-                    h.level = level;
+                    h.level = if split_stderr == Some(entry.id) {
+                        level.max(LogLevel::Warning)
+                    } else {
+                        level
+                    };
                 }
             }
         }
@@ -409,12 +1526,46 @@ impl PyLogger {
         (level as u32) >= m
     }
 
+    /// Lock-free guard: would a record at ``level`` (``LogLevel`` or numeric ``no``) be emitted?
+    /// Only compares against `cached_min_level` (and the pause flag), so it is safe in hot loops.
+    fn would_log(&self, level: &Bound<'_, PyAny>) -> PyResult<bool> {
+        let no = if let Ok(level) = level.extract::<LogLevel>() {
+            level as u32
+        } else if let Ok(no) = level.extract::<u32>() {
+            no
+        } else {
+            return Err(pyo3::exceptions::PyTypeError::new_err(
+                "level must be a LogLevel or a numeric level number",
+            ));
+        };
+        Ok(!self.paused.load(Ordering::Relaxed)
+            && no >= self.cached_min_level.load(Ordering::Relaxed))
+    }
+
     /// Get the cached minimum log level across all handlers and callbacks
     #[getter]
     fn min_level(&self) -> u32 {
         self.cached_min_level.load(Ordering::Relaxed)
     }
 
+    /// Name of `min_level`: its custom or built-in level, else the least severe built-in
+    /// level above it. None when no handler or callback is installed.
+    #[getter]
+    fn min_level_name(&self) -> Option<String> {
+        let no = self.cached_min_level.load(Ordering::Relaxed);
+        if no == u32::MAX {
+            return None;
+        }
+        if let Some(info) = get_level_by_no(no) {
+            return Some(info.name);
+        }
+        let nearest = EMIT_LEVELS
+            .iter()
+            .find(|level| **level as u32 >= no)
+            .unwrap_or(&LogLevel::Critical);
+        Some(nearest.as_str().to_string())
+    }
+
     /// True if a maximal-severity emit would need caller info (merge of all handlers/callbacks).
     #[getter]
     fn needs_caller_info(&self) -> bool {
@@ -526,14 +1677,25 @@ impl PyLogger {
                 .any(|e| matches!(e.handler, HandlerType::Console(_)));
 
             if !has_console {
-                let console_level = level.unwrap_or(LogLevel::Debug);
-                let console_handler = ConsoleHandler::new(console_level);
-                let entry = HandlerEntry {
-                    id: handler::next_handler_id(),
-                    handler: HandlerType::Console(console_handler),
-                    filter: None,
-                };
-                handlers.push(entry);
+                let console_level = level.unwrap_or_else(LogLevel::env_default);
+                let mut split_console = self.split_console.write();
+                if split_console.is_some() {
+                    let [stdout, stderr] = Self::build_split_console_entries(
+                        console_level,
+                        None,
+                        None,
+                        None,
+                        None,
+                        None,
+                        None,
+                    )
+                    .expect("default console options are valid");
+                    *split_console = Some(stderr.id);
+                    handlers.push(stdout);
+                    handlers.push(stderr);
+                } else {
+                    handlers.push(self.default_console_entry(console_level));
+                }
             }
         }
         self.update_min_level_cache();
@@ -548,49 +1710,145 @@ impl PyLogger {
             .any(|e| matches!(e.handler, HandlerType::Console(_)))
     }
 
-    /// Flush all file handlers to ensure pending logs are written
-    fn complete(&self) -> PyResult<()> {
+    /// Drop all records until `resume()` is called (handlers stay registered)
+    fn pause(&self) {
+        self.paused.store(true, Ordering::Relaxed);
+    }
+
+    /// Resume logging after `pause()`
+    fn resume(&self) {
+        self.paused.store(false, Ordering::Relaxed);
+    }
+
+    /// Check if logging is currently paused
+    #[getter]
+    fn is_paused(&self) -> bool {
+        self.paused.load(Ordering::Relaxed)
+    }
+
+    /// Drop records below `level` whatever the handler and callback levels (None removes
+    /// the floor). Shared with every logger derived via `bind()`.
+    #[pyo3(signature = (level=None))]
+    fn set_global_level(&self, level: Option<LogLevel>) {
+        let floor = level.map_or(0, |level| level as u32);
+        self.global_level.store(floor, Ordering::Relaxed);
+        self.update_min_level_cache();
+    }
+
+    /// Level set with `set_global_level()`, None when there is no floor
+    fn get_global_level(&self) -> Option<LogLevel> {
+        let floor = self.global_level.load(Ordering::Relaxed);
+        EMIT_LEVELS.into_iter().find(|&level| level as u32 == floor)
+    }
+
+    /// Debug-friendly summary of handlers and callbacks, e.g.
+    /// `<Logger handlers=[Console(stdout, level=DEBUG), File('app.log', level=INFO)]>`
+    fn __repr__(&self) -> String {
+        let mut parts: Vec<String> = self
+            .handlers
+            .read()
+            .iter()
+            .map(|entry| entry.handler.to_string())
+            .collect();
+        parts.extend(
+            self.callbacks
+                .read()
+                .iter()
+                .map(|entry| format!("Callback(level={})", entry.level.as_str())),
+        );
+
+        let paused = if self.is_paused() { " paused" } else { "" };
+        format!("<Logger handlers=[{}]{}>", parts.join(", "), paused)
+    }
+
+    /// Flush all file handlers (or only `handler_id`) to ensure pending logs are written
+    ///
+    /// Blocks until every `enqueue=True` writer has drained the messages queued
+    /// before this call. The GIL and the handler lock are released while waiting.
+    /// Pending `dedupe` repeat summaries are written first.
+    /// Returns False if `handler_id` is neither a handler nor a callback of this logger.
+    #[pyo3(signature = (handler_id=None))]
+    fn complete(&self, py: Python<'_>, handler_id: Option<u64>) -> PyResult<bool> {
         let handlers = self.handlers.read();
-        for entry in handlers.iter() {
-            if let HandlerType::File(ref h) = entry.handler {
-                h.sink
-                    .flush()
-                    .map_err(|e| pyo3::exceptions::PyIOError::new_err(e.to_string()))?;
-            }
+        let selected: Vec<&HandlerEntry> = handlers
+            .iter()
+            .filter(|entry| handler_id.is_none_or(|id| entry.id == id))
+            .collect();
+        if let Some(id) = handler_id
+            && selected.is_empty()
+        {
+            // Callbacks (including callable sinks) have nothing to flush
+            return Ok(self.callbacks.read().iter().any(|entry| entry.id == id));
         }
-        Ok(())
+        for entry in &selected {
+            entry
+                .flush_repeats()
+                .map_err(|e| pyo3::exceptions::PyIOError::new_err(e.to_string()))?;
+        }
+        let custom = custom_sinks(selected.iter().copied());
+        let console = enqueues_console(selected.iter().copied());
+        let sinks = file_sinks(selected);
+        drop(handlers);
+
+        py.detach(|| {
+            if console {
+                handler::flush_console_queue();
+            }
+            sinks.iter().try_for_each(FileSink::flush)?;
+            custom.iter().try_for_each(|sink| sink.flush())
+        })
+        .map_err(|e| pyo3::exceptions::PyIOError::new_err(e.to_string()))?;
+        Ok(true)
     }
 
     /// Add a callback to receive full log record dicts (raw callback).
-    #[pyo3(signature = (callback, level=None))]
-    fn add_callback(&self, callback: Py<PyAny>, level: Option<LogLevel>) -> u64 {
+    /// With `fields`, the dict holds only those keys (e.g. `["level", "message"]`).
+    #[pyo3(signature = (callback, level=None, fields=None))]
+    fn add_callback(
+        &self,
+        callback: Py<PyAny>,
+        level: Option<LogLevel>,
+        fields: Option<Vec<String>>,
+    ) -> PyResult<HandlerHandle> {
+        let kind = match fields {
+            Some(names) => CallbackKind::Fields(RecordField::parse_list(&names)?),
+            None => CallbackKind::Raw,
+        };
         let id = handler::next_handler_id();
         let entry = CallbackEntry {
             id,
             callback,
-            level: level.unwrap_or(LogLevel::Debug),
-            kind: CallbackKind::Raw,
+            level: level.unwrap_or_else(LogLevel::env_default),
+            kind,
+            calls: AtomicU64::new(0),
+            errors: AtomicU64::new(0),
         };
         self.callbacks.write().push(entry);
         self.update_min_level_cache();
         self.update_requirements_cache();
-        id
+        Ok(self.handle(id, HandleKind::Callback))
     }
 
     /// Add a serialized callable sink callback (full record dict with typed JSON extras).
     #[pyo3(signature = (callback, level=None))]
-    fn add_serialized_callback(&self, callback: Py<PyAny>, level: Option<LogLevel>) -> u64 {
+    fn add_serialized_callback(
+        &self,
+        callback: Py<PyAny>,
+        level: Option<LogLevel>,
+    ) -> HandlerHandle {
         let id = handler::next_handler_id();
         let entry = CallbackEntry {
             id,
             callback,
-            level: level.unwrap_or(LogLevel::Debug),
+            level: level.unwrap_or_else(LogLevel::env_default),
             kind: CallbackKind::Serialized,
+            calls: AtomicU64::new(0),
+            errors: AtomicU64::new(0),
         };
         self.callbacks.write().push(entry);
         self.update_min_level_cache();
         self.update_requirements_cache();
-        id
+        self.handle(id, HandleKind::Callback)
     }
 
     /// Add a formatted callable sink callback (minimal dict + Python `ParsedCallableTemplate`).
@@ -601,19 +1859,101 @@ impl PyLogger {
         requirements: Bound<'_, PyTuple>,
         extra_keys: Bound<'_, PyTuple>,
         level: Option<LogLevel>,
-    ) -> PyResult<u64> {
+    ) -> PyResult<HandlerHandle> {
         let req = FormattedSinkRequirements::from_python_tuples(&requirements, &extra_keys)?;
         let id = handler::next_handler_id();
         let entry = CallbackEntry {
             id,
             callback,
-            level: level.unwrap_or(LogLevel::Debug),
+            level: level.unwrap_or_else(LogLevel::env_default),
             kind: CallbackKind::FormattedLight(req),
+            calls: AtomicU64::new(0),
+            errors: AtomicU64::new(0),
         };
         self.callbacks.write().push(entry);
         self.update_min_level_cache();
         self.update_requirements_cache();
-        Ok(id)
+        Ok(self.handle(id, HandleKind::Callback))
+    }
+
+    /// Set (or clear with None) the handler called as `handler(callback_id, exc)` when a
+    /// callback raises. Without a handler, the first error per callback is reported to stderr.
+    #[pyo3(signature = (handler=None))]
+    fn set_callback_error_handler(&self, handler: Option<Py<PyAny>>) {
+        *self.callback_error_handler.write() = handler;
+    }
+
+    /// Set (or clear with None) the callable returning extra for the current context, such as
+    /// the value of a `ContextVar`. It's called with no arguments once per record that some
+    /// handler or callback accepts and returns a dict (or None); bound and per-call values
+    /// take precedence over its keys.
+    #[pyo3(signature = (provider=None))]
+    fn set_context_provider(&self, provider: Option<Py<PyAny>>) {
+        *self.context_provider.write() = provider;
+    }
+
+    /// Number of exceptions raised by one callback, or by all registered callbacks
+    #[pyo3(signature = (callback_id=None))]
+    fn callback_error_count(&self, callback_id: Option<u64>) -> u64 {
+        self.callbacks
+            .read()
+            .iter()
+            .filter(|entry| callback_id.is_none_or(|id| entry.id == id))
+            .map(|entry| entry.errors.load(Ordering::Relaxed))
+            .sum()
+    }
+
+    /// Number of records passed to one callback, or to all registered callbacks
+    #[pyo3(signature = (callback_id=None))]
+    fn callback_call_count(&self, callback_id: Option<u64>) -> u64 {
+        self.callbacks
+            .read()
+            .iter()
+            .filter(|entry| callback_id.is_none_or(|id| entry.id == id))
+            .map(|entry| entry.calls.load(Ordering::Relaxed))
+            .sum()
+    }
+
+    /// Number of records dropped by the rate limit of one handler, or of all handlers
+    #[pyo3(signature = (handler_id=None))]
+    fn suppressed_count(&self, handler_id: Option<u64>) -> u64 {
+        self.handlers
+            .read()
+            .iter()
+            .filter(|entry| handler_id.is_none_or(|id| entry.id == id))
+            .filter_map(|entry| entry.rate_limit.as_ref())
+            .map(RateLimiter::suppressed)
+            .sum()
+    }
+
+    /// Records written by a handler, plus size and rotation state for file handlers
+    /// (routed handlers sum their open files). None for unknown handlers.
+    fn handler_stats<'py>(
+        &self,
+        py: Python<'py>,
+        handler_id: u64,
+    ) -> PyResult<Option<Bound<'py, PyDict>>> {
+        let (records, path, stats) = {
+            let handlers = self.handlers.read();
+            let Some(entry) = handlers.iter().find(|entry| entry.id == handler_id) else {
+                return Ok(None);
+            };
+            let (path, stats) = match entry.handler {
+                HandlerType::File(ref h) => (
+                    Some(h.sink.path().display().to_string()),
+                    vec![h.sink.stats()],
+                ),
+                HandlerType::RoutedFile(ref h) => (
+                    Some(h.router.template().to_string()),
+                    h.router.sinks().iter().map(FileSink::stats).collect(),
+                ),
+                HandlerType::Console(_) | HandlerType::Custom(_) | HandlerType::Journald(_) => {
+                    (None, Vec::new())
+                }
+            };
+            (entry.handler.records(), path, stats)
+        };
+        Self::stats_dict(py, records, path, &stats).map(Some)
     }
 
     /// Remove a callback by ID
@@ -652,7 +1992,7 @@ impl PyLogger {
     }
 
     #[allow(clippy::too_many_arguments)]
-    #[pyo3(signature = (message, exception=None, name=None, function=None, line=None, file=None, thread_name=None, thread_id=None, process_name=None, process_id=None))]
+    #[pyo3(signature = (message, exception=None, name=None, function=None, line=None, file=None, thread_name=None, thread_id=None, process_name=None, process_id=None, extra=None, stacklevel=None, timestamp=None, end=None))]
     fn trace(
         &self,
         message: String,
@@ -665,7 +2005,11 @@ impl PyLogger {
         thread_id: Option<u64>,
         process_name: Option<String>,
         process_id: Option<u32>,
-    ) {
+        extra: Option<&Bound<'_, PyDict>>,
+        stacklevel: Option<u32>,
+        timestamp: Option<&Bound<'_, PyAny>>,
+        end: Option<String>,
+    ) -> PyResult<()> {
         self._log(
             LogLevel::Trace,
             message,
@@ -678,11 +2022,15 @@ impl PyLogger {
             thread_id,
             process_name,
             process_id,
-        );
+            extra,
+            stacklevel,
+            timestamp,
+            end,
+        )
     }
 
     #[allow(clippy::too_many_arguments)]
-    #[pyo3(signature = (message, exception=None, name=None, function=None, line=None, file=None, thread_name=None, thread_id=None, process_name=None, process_id=None))]
+    #[pyo3(signature = (message, exception=None, name=None, function=None, line=None, file=None, thread_name=None, thread_id=None, process_name=None, process_id=None, extra=None, stacklevel=None, timestamp=None, end=None))]
     fn debug(
         &self,
         message: String,
@@ -695,7 +2043,11 @@ impl PyLogger {
         thread_id: Option<u64>,
         process_name: Option<String>,
         process_id: Option<u32>,
-    ) {
+        extra: Option<&Bound<'_, PyDict>>,
+        stacklevel: Option<u32>,
+        timestamp: Option<&Bound<'_, PyAny>>,
+        end: Option<String>,
+    ) -> PyResult<()> {
         self._log(
             LogLevel::Debug,
             message,
@@ -708,11 +2060,15 @@ impl PyLogger {
             thread_id,
             process_name,
             process_id,
-        );
+            extra,
+            stacklevel,
+            timestamp,
+            end,
+        )
     }
 
     #[allow(clippy::too_many_arguments)]
-    #[pyo3(signature = (message, exception=None, name=None, function=None, line=None, file=None, thread_name=None, thread_id=None, process_name=None, process_id=None))]
+    #[pyo3(signature = (message, exception=None, name=None, function=None, line=None, file=None, thread_name=None, thread_id=None, process_name=None, process_id=None, extra=None, stacklevel=None, timestamp=None, end=None))]
     fn info(
         &self,
         message: String,
@@ -725,7 +2081,11 @@ impl PyLogger {
         thread_id: Option<u64>,
         process_name: Option<String>,
         process_id: Option<u32>,
-    ) {
+        extra: Option<&Bound<'_, PyDict>>,
+        stacklevel: Option<u32>,
+        timestamp: Option<&Bound<'_, PyAny>>,
+        end: Option<String>,
+    ) -> PyResult<()> {
         self._log(
             LogLevel::Info,
             message,
@@ -738,11 +2098,15 @@ impl PyLogger {
             thread_id,
             process_name,
             process_id,
-        );
+            extra,
+            stacklevel,
+            timestamp,
+            end,
+        )
     }
 
     #[allow(clippy::too_many_arguments)]
-    #[pyo3(signature = (message, exception=None, name=None, function=None, line=None, file=None, thread_name=None, thread_id=None, process_name=None, process_id=None))]
+    #[pyo3(signature = (message, exception=None, name=None, function=None, line=None, file=None, thread_name=None, thread_id=None, process_name=None, process_id=None, extra=None, stacklevel=None, timestamp=None, end=None))]
     fn success(
         &self,
         message: String,
@@ -755,7 +2119,11 @@ impl PyLogger {
         thread_id: Option<u64>,
         process_name: Option<String>,
         process_id: Option<u32>,
-    ) {
+        extra: Option<&Bound<'_, PyDict>>,
+        stacklevel: Option<u32>,
+        timestamp: Option<&Bound<'_, PyAny>>,
+        end: Option<String>,
+    ) -> PyResult<()> {
         self._log(
             LogLevel::Success,
             message,
@@ -768,11 +2136,15 @@ impl PyLogger {
             thread_id,
             process_name,
             process_id,
-        );
+            extra,
+            stacklevel,
+            timestamp,
+            end,
+        )
     }
 
     #[allow(clippy::too_many_arguments)]
-    #[pyo3(signature = (message, exception=None, name=None, function=None, line=None, file=None, thread_name=None, thread_id=None, process_name=None, process_id=None))]
+    #[pyo3(signature = (message, exception=None, name=None, function=None, line=None, file=None, thread_name=None, thread_id=None, process_name=None, process_id=None, extra=None, stacklevel=None, timestamp=None, end=None))]
     fn warning(
         &self,
         message: String,
@@ -785,7 +2157,11 @@ impl PyLogger {
         thread_id: Option<u64>,
         process_name: Option<String>,
         process_id: Option<u32>,
-    ) {
+        extra: Option<&Bound<'_, PyDict>>,
+        stacklevel: Option<u32>,
+        timestamp: Option<&Bound<'_, PyAny>>,
+        end: Option<String>,
+    ) -> PyResult<()> {
         self._log(
             LogLevel::Warning,
             message,
@@ -798,11 +2174,15 @@ impl PyLogger {
             thread_id,
             process_name,
             process_id,
-        );
+            extra,
+            stacklevel,
+            timestamp,
+            end,
+        )
     }
 
     #[allow(clippy::too_many_arguments)]
-    #[pyo3(signature = (message, exception=None, name=None, function=None, line=None, file=None, thread_name=None, thread_id=None, process_name=None, process_id=None))]
+    #[pyo3(signature = (message, exception=None, name=None, function=None, line=None, file=None, thread_name=None, thread_id=None, process_name=None, process_id=None, extra=None, stacklevel=None, timestamp=None, end=None))]
     fn error(
         &self,
         message: String,
@@ -815,7 +2195,11 @@ impl PyLogger {
         thread_id: Option<u64>,
         process_name: Option<String>,
         process_id: Option<u32>,
-    ) {
+        extra: Option<&Bound<'_, PyDict>>,
+        stacklevel: Option<u32>,
+        timestamp: Option<&Bound<'_, PyAny>>,
+        end: Option<String>,
+    ) -> PyResult<()> {
         self._log(
             LogLevel::Error,
             message,
@@ -828,11 +2212,15 @@ impl PyLogger {
             thread_id,
             process_name,
             process_id,
-        );
+            extra,
+            stacklevel,
+            timestamp,
+            end,
+        )
     }
 
     #[allow(clippy::too_many_arguments)]
-    #[pyo3(signature = (message, exception=None, name=None, function=None, line=None, file=None, thread_name=None, thread_id=None, process_name=None, process_id=None))]
+    #[pyo3(signature = (message, exception=None, name=None, function=None, line=None, file=None, thread_name=None, thread_id=None, process_name=None, process_id=None, extra=None, stacklevel=None, timestamp=None, end=None))]
     fn fail(
         &self,
         message: String,
@@ -845,7 +2233,11 @@ impl PyLogger {
         thread_id: Option<u64>,
         process_name: Option<String>,
         process_id: Option<u32>,
-    ) {
+        extra: Option<&Bound<'_, PyDict>>,
+        stacklevel: Option<u32>,
+        timestamp: Option<&Bound<'_, PyAny>>,
+        end: Option<String>,
+    ) -> PyResult<()> {
         self._log(
             LogLevel::Fail,
             message,
@@ -858,99 +2250,1119 @@ impl PyLogger {
             thread_id,
             process_name,
             process_id,
+            extra,
+            stacklevel,
+            timestamp,
+            end,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (message, exception=None, name=None, function=None, line=None, file=None, thread_name=None, thread_id=None, process_name=None, process_id=None, extra=None, stacklevel=None, timestamp=None, end=None))]
+    fn critical(
+        &self,
+        message: String,
+        exception: Option<String>,
+        name: Option<String>,
+        function: Option<String>,
+        line: Option<u32>,
+        file: Option<String>,
+        thread_name: Option<String>,
+        thread_id: Option<u64>,
+        process_name: Option<String>,
+        process_id: Option<u32>,
+        extra: Option<&Bound<'_, PyDict>>,
+        stacklevel: Option<u32>,
+        timestamp: Option<&Bound<'_, PyAny>>,
+        end: Option<String>,
+    ) -> PyResult<()> {
+        self._log(
+            LogLevel::Critical,
+            message,
+            exception,
+            name,
+            function,
+            line,
+            file,
+            thread_name,
+            thread_id,
+            process_name,
+            process_id,
+            extra,
+            stacklevel,
+            timestamp,
+            end,
+        )
+    }
+
+    /// Register a custom log level (ValueError for a built-in name or number, or if another
+    /// custom level has the number unless `overwrite`)
+    #[pyo3(signature = (name, no, color=None, icon=None, overwrite=false))]
+    fn level(
+        &self,
+        name: String,
+        no: u32,
+        color: Option<String>,
+        icon: Option<String>,
+        overwrite: bool,
+    ) -> PyResult<()> {
+        let info = LevelInfo::new(name, no, color, icon);
+        register_level(info, overwrite).map_err(pyo3::exceptions::PyValueError::new_err)
+    }
+
+    /// Change the console color of a built-in or custom level
+    fn set_level_color(&self, name: &str, color: &str) -> PyResult<()> {
+        level::set_level_color(name, color).map_err(pyo3::exceptions::PyValueError::new_err)
+    }
+
+    /// Change the `{icon}` of a built-in or custom level
+    fn set_level_icon(&self, name: &str, icon: &str) -> PyResult<()> {
+        level::set_level_icon(name, icon).map_err(pyo3::exceptions::PyValueError::new_err)
+    }
+
+    /// Log at any level (built-in or custom)
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (level_arg, message, exception=None, name=None, function=None, line=None, file=None, thread_name=None, thread_id=None, process_name=None, process_id=None, extra=None, stacklevel=None, timestamp=None, end=None))]
+    fn log(
+        &self,
+        level_arg: &Bound<'_, PyAny>,
+        message: String,
+        exception: Option<String>,
+        name: Option<String>,
+        function: Option<String>,
+        line: Option<u32>,
+        file: Option<String>,
+        thread_name: Option<String>,
+        thread_id: Option<u64>,
+        process_name: Option<String>,
+        process_id: Option<u32>,
+        extra: Option<&Bound<'_, PyDict>>,
+        stacklevel: Option<u32>,
+        timestamp: Option<&Bound<'_, PyAny>>,
+        end: Option<String>,
+    ) -> PyResult<()> {
+        let level_info = if let Ok(lvl_name) = level_arg.extract::<String>() {
+            get_level_info(&lvl_name)
+        } else if let Ok(no) = level_arg.extract::<u32>() {
+            get_level_by_no(no)
+        } else {
+            None
+        };
+
+        let info = level_info
+            .ok_or_else(|| pyo3::exceptions::PyValueError::new_err("Invalid log level"))?;
+
+        self._log_custom(
+            info,
+            message,
+            exception,
+            name,
+            function,
+            line,
+            file,
+            thread_name,
+            thread_id,
+            process_name,
+            process_id,
+            extra,
+            stacklevel,
+            timestamp,
+            end,
+        )
+    }
+
+    /// Log `template.format(*args)` at any level, rendering only when some handler or
+    /// callback accepts the level. `{}` / `{N}` fields are substituted here (with `str()` of
+    /// each argument); other fields fall back to Python's `str.format`.
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (level_arg, template, args, exception=None, name=None, function=None, line=None, file=None, thread_name=None, thread_id=None, process_name=None, process_id=None, extra=None, stacklevel=None))]
+    fn log_fmt(
+        &self,
+        level_arg: &Bound<'_, PyAny>,
+        template: &Bound<'_, PyString>,
+        args: &Bound<'_, PyTuple>,
+        exception: Option<String>,
+        name: Option<String>,
+        function: Option<String>,
+        line: Option<u32>,
+        file: Option<String>,
+        thread_name: Option<String>,
+        thread_id: Option<u64>,
+        process_name: Option<String>,
+        process_id: Option<u32>,
+        extra: Option<&Bound<'_, PyDict>>,
+        stacklevel: Option<u32>,
+    ) -> PyResult<()> {
+        let info = if let Ok(level) = level_arg.extract::<LogLevel>() {
+            get_level_info(level.as_str())
+        } else if let Ok(lvl_name) = level_arg.extract::<String>() {
+            get_level_info(&lvl_name)
+        } else if let Ok(no) = level_arg.extract::<u32>() {
+            get_level_by_no(no)
+        } else {
+            None
+        }
+        .ok_or_else(|| pyo3::exceptions::PyValueError::new_err("Invalid log level"))?;
+
+        // The cached minimum covers every handler and callback, so this is exact
+        if self.paused.load(Ordering::Relaxed)
+            || info.no < self.cached_min_level.load(Ordering::Relaxed)
+        {
+            return Ok(());
+        }
+
+        let message = render_template(template, args)?;
+        match LogLevel::from_name(&info.name) {
+            Some(level) => self._log(
+                level,
+                message,
+                exception,
+                name,
+                function,
+                line,
+                file,
+                thread_name,
+                thread_id,
+                process_name,
+                process_id,
+                extra,
+                stacklevel,
+                None,
+                None,
+            ),
+            None => self._log_custom(
+                info,
+                message,
+                exception,
+                name,
+                function,
+                line,
+                file,
+                thread_name,
+                thread_id,
+                process_name,
+                process_id,
+                extra,
+                stacklevel,
+                None,
+                None,
+            ),
+        }
+    }
+
+    /// Log many messages at one level, sharing one lock acquisition, caller/thread/process info and context.
+    /// Built-in levels take a single pass (one GIL acquisition when callbacks/filters need it);
+    /// custom levels fall back to one emit per message.
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (level_arg, messages, name=None, function=None, line=None, file=None, thread_name=None, thread_id=None, process_name=None, process_id=None, stacklevel=None))]
+    fn log_batch(
+        &self,
+        level_arg: &Bound<'_, PyAny>,
+        messages: Vec<String>,
+        name: Option<String>,
+        function: Option<String>,
+        line: Option<u32>,
+        file: Option<String>,
+        thread_name: Option<String>,
+        thread_id: Option<u64>,
+        process_name: Option<String>,
+        process_id: Option<u32>,
+        stacklevel: Option<u32>,
+    ) -> PyResult<()> {
+        let level = if let Ok(level) = level_arg.extract::<LogLevel>() {
+            level
+        } else {
+            let info = if let Ok(lvl_name) = level_arg.extract::<String>() {
+                get_level_info(&lvl_name)
+            } else if let Ok(no) = level_arg.extract::<u32>() {
+                get_level_by_no(no)
+            } else {
+                None
+            }
+            .ok_or_else(|| pyo3::exceptions::PyValueError::new_err("Invalid log level"))?;
+
+            match LogLevel::from_name(&info.name) {
+                Some(level) => level,
+                None => {
+                    for message in messages {
+                        self._log_custom(
+                            info.clone(),
+                            message,
+                            None,
+                            name.clone(),
+                            function.clone(),
+                            line,
+                            file.clone(),
+                            thread_name.clone(),
+                            thread_id,
+                            process_name.clone(),
+                            process_id,
+                            None,
+                            stacklevel,
+                            None,
+                            None,
+                        )?;
+                    }
+                    return Ok(());
+                }
+            }
+        };
+
+        let caller = caller_info(name, function, line, file, stacklevel)?;
+        if messages.is_empty()
+            || self.paused.load(Ordering::Relaxed)
+            || (level as u32) < self.global_level.load(Ordering::Relaxed)
+        {
+            return Ok(());
+        }
+
+        let handlers = self.handlers.read();
+        let callbacks = self.callbacks.read();
+
+        let Some(needs_gil) = Self::dispatch_plan(level, &handlers, &callbacks) else {
+            return Ok(());
+        };
+
+        let (thread, process) =
+            self.thread_process_info(thread_name, thread_id, process_name, process_id);
+        // Like caller/thread info, lazily bound values are computed once for the batch
+        let context = self.context_with(None)?;
+
+        let records = messages
+            .into_iter()
+            .map(|message| {
+                let mut record = LogRecord::with_all(
+                    level,
+                    message,
+                    Arc::clone(&context),
+                    None,
+                    caller.clone(),
+                    thread.clone(),
+                    process.clone(),
+                );
+                record.seq = next_seq(&self.seq);
+                self.apply_patchers(&mut record)?;
+                Ok(record)
+            })
+            .collect::<PyResult<Vec<LogRecord>>>()?;
+
+        Self::dispatch_records(
+            level,
+            &handlers,
+            &callbacks,
+            &self.callback_error_handler,
+            &records,
+            needs_gil,
         );
+        Ok(())
+    }
+}
+
+impl PyLogger {
+    /// Logger sharing handlers, callbacks and caches with `self`, with its own context and patchers
+    fn derive(&self, context: Arc<ExtraMap>, patchers: Arc<Vec<Py<PyAny>>>) -> PyLogger {
+        PyLogger {
+            handlers: Arc::clone(&self.handlers),
+            context,
+            lazy_context: Arc::clone(&self.lazy_context),
+            patchers,
+            context_provider: Arc::clone(&self.context_provider),
+            callbacks: Arc::clone(&self.callbacks),
+            cached_min_level: Arc::clone(&self.cached_min_level),
+            cached_requirements_by_level: Arc::clone(&self.cached_requirements_by_level),
+            cached_handler_requirements: Arc::clone(&self.cached_handler_requirements),
+            paused: Arc::clone(&self.paused),
+            global_level: Arc::clone(&self.global_level),
+            seq: Arc::clone(&self.seq),
+            cached_needs_thread: Arc::clone(&self.cached_needs_thread),
+            cached_needs_process: Arc::clone(&self.cached_needs_process),
+            callback_error_handler: Arc::clone(&self.callback_error_handler),
+            split_console: Arc::clone(&self.split_console),
+            default_stderr: self.default_stderr,
+        }
+    }
+
+    /// Run the `patch()` callables on a record (see `patch`). Extra values the patchers
+    /// left untouched keep their original text/JSON views.
+    fn apply_patchers(&self, record: &mut LogRecord) -> PyResult<()> {
+        if self.patchers.is_empty() {
+            return Ok(());
+        }
+        Python::attach(|py| {
+            let original_extra = Arc::clone(&record.extra);
+            let mut originals = HashMap::with_capacity(original_extra.len());
+            let extra = PyDict::new(py);
+            for (key, value) in original_extra.iter() {
+                let py_value = serde_json_to_py(py, value.as_json())?.into_bound(py);
+                extra.set_item(key, &py_value)?;
+                originals.insert(key.as_str(), (value, py_value));
+            }
+
+            let dict = PyDict::new(py);
+            dict.set_item(intern!(py, "level"), record.level_name())?;
+            dict.set_item(intern!(py, "level_no"), record.level_no())?;
+            dict.set_item(intern!(py, "message"), &record.message)?;
+            dict.set_item(intern!(py, "timestamp"), record.timestamp.to_rfc3339())?;
+            dict.set_item(intern!(py, "exception"), record.exception.as_deref())?;
+            dict.set_item(intern!(py, "extra"), &extra)?;
+
+            for patcher in self.patchers.iter() {
+                patcher.call1(py, (&dict,))?;
+            }
+
+            if let Some(message) = dict.get_item(intern!(py, "message"))? {
+                record.message = message.str()?.to_cow()?.into_owned();
+            }
+            record.exception = match dict.get_item(intern!(py, "exception"))? {
+                Some(exc) if !exc.is_none() => Some(exc.str()?.to_cow()?.into_owned()),
+                _ => None,
+            };
+
+            let patched_extra = match dict.get_item(intern!(py, "extra"))? {
+                Some(value) if !value.is_none() => value.cast_into::<PyDict>().map_err(|_| {
+                    pyo3::exceptions::PyTypeError::new_err(
+                        "patcher set record['extra'] to a non-dict",
+                    )
+                })?,
+                _ => PyDict::new(py),
+            };
+            let mut unchanged = patched_extra.len() == originals.len();
+            let mut new_extra = ExtraMap::with_capacity(patched_extra.len());
+            for (key, value) in patched_extra.iter() {
+                let key = key.str()?.to_cow()?.into_owned();
+                let extra_value = match originals.get(key.as_str()) {
+                    Some((original, py_value)) if value.is(py_value) => (*original).clone(),
+                    _ => {
+                        unchanged = false;
+                        ExtraValue::from_py(&value)?
+                    }
+                };
+                new_extra.insert(key, extra_value);
+            }
+            drop(originals);
+            if !unchanged {
+                record.extra = Arc::new(new_extra);
+            }
+            Ok(())
+        })
+    }
+
+    /// Context provider values, then the bound context, the lazy values and `extra` merged
+    /// over them (the bound context is shared, not copied, when there are none)
+    fn context_with(&self, extra: Option<&Bound<'_, PyDict>>) -> PyResult<Arc<ExtraMap>> {
+        let extra = extra.filter(|dict| !dict.is_empty());
+        let provided = self.provided_context()?;
+        if provided.is_empty() && self.lazy_context.is_empty() && extra.is_none() {
+            return Ok(Arc::clone(&self.context));
+        }
+
+        let mut ctx = if provided.is_empty() {
+            (*self.context).clone()
+        } else {
+            let mut ctx = provided;
+            ctx.extend(self.context.iter().map(|(k, v)| (k.clone(), v.clone())));
+            ctx
+        };
+        if !self.lazy_context.is_empty() {
+            Python::attach(|py| -> PyResult<()> {
+                for (key, func) in self.lazy_context.iter() {
+                    // A per-call value replaces the bound one, so don't compute it
+                    if let Some(dict) = extra
+                        && dict.contains(key)?
+                    {
+                        continue;
+                    }
+                    let value = func.call0(py)?;
+                    ctx.insert(key.clone(), ExtraValue::from_py(value.bind(py))?);
+                }
+                Ok(())
+            })?;
+        }
+        if let Some(dict) = extra {
+            for (key, value) in dict.iter() {
+                let key_str: String = key.extract()?;
+                ctx.insert(key_str, ExtraValue::from_py(&value)?);
+            }
+        }
+        Ok(Arc::new(ctx))
+    }
+
+    /// Values returned by the context provider (empty without one, or when it returns None)
+    fn provided_context(&self) -> PyResult<ExtraMap> {
+        if self.context_provider.read().is_none() {
+            return Ok(ExtraMap::new());
+        }
+        Python::attach(|py| {
+            // Not called under the lock, so the provider may replace itself
+            let provider = self
+                .context_provider
+                .read()
+                .as_ref()
+                .map(|p| p.clone_ref(py));
+            let Some(provider) = provider else {
+                return Ok(ExtraMap::new());
+            };
+            let value = provider.call0(py)?.into_bound(py);
+            if value.is_none() {
+                return Ok(ExtraMap::new());
+            }
+            let dict = value.cast_into::<PyDict>().map_err(|_| {
+                pyo3::exceptions::PyTypeError::new_err(
+                    "context provider must return a dict or None",
+                )
+            })?;
+            let mut provided = ExtraMap::with_capacity(dict.len());
+            for (key, value) in dict.iter() {
+                let key = key.str()?.to_cow()?.into_owned();
+                provided.insert(key, ExtraValue::from_py(&value)?);
+            }
+            Ok(provided)
+        })
+    }
+
+    /// Build a file handler entry without registering it or touching the caches.
+    #[allow(clippy::too_many_arguments)]
+    fn build_file_entry(
+        path: PathBuf,
+        level: Option<LogLevel>,
+        format: Option<String>,
+        rotation: Option<String>,
+        retention: Option<String>,
+        compression: Option<bool>,
+        serialize: Option<bool>,
+        filter: Option<Py<PyAny>>,
+        enqueue: Option<bool>,
+        serialize_wrapped: Option<bool>,
+        watch: Option<bool>,
+        line_terminator: Option<String>,
+        rate_limit: Option<String>,
+        dedupe: Option<bool>,
+        max_level: Option<LogLevel>,
+        autoflush: Option<bool>,
+        fsync: Option<bool>,
+        max_open_files: Option<usize>,
+        utc: Option<bool>,
+        time_format: Option<String>,
+        time_precision: Option<String>,
+        prefix: Option<String>,
+        suffix: Option<String>,
+        level_map: Vec<(LogLevel, LogLevel)>,
+        static_fields: Vec<(String, ExtraValue)>,
+        logfmt: Option<bool>,
+        csv: Option<bool>,
+        csv_header: Option<bool>,
+        mode: Option<String>,
+        strict_format: Option<bool>,
+        filter_fields: Option<Vec<String>>,
+        shared_writer: Option<bool>,
+        on_error: Option<String>,
+        on_rotation: Option<Py<PyAny>>,
+        compress_on_close: Option<bool>,
+        max_queue_bytes: Option<String>,
+        write_retries: Option<u32>,
+        write_retry_backoff: Option<f64>,
+    ) -> PyResult<HandlerEntry> {
+        let level = level.unwrap_or_else(LogLevel::env_default);
+        let max_level = check_max_level(level, max_level)?;
+        let rate_limit = parse_rate_limit(rate_limit)?;
+        let serialize = serialize.unwrap_or(false);
+        let csv = csv.unwrap_or(false);
+        if csv_header.unwrap_or(false) && !csv {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "csv_header requires csv=True",
+            ));
+        }
+        let skip_on_error = parse_on_error(on_error)?;
+        let on_rotation = on_rotation.map(rotation_callback).transpose()?;
+        let compression = compression.unwrap_or(false);
+        let compress_on_close = compress_on_close.unwrap_or(false);
+        if compress_on_close && !compression {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "compress_on_close requires compression=True",
+            ));
+        }
+        let enqueue = enqueue.unwrap_or(false);
+        let shared_writer = shared_writer.unwrap_or(false);
+        if shared_writer && !enqueue {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "shared_writer requires enqueue=True",
+            ));
+        }
+        let max_queue_bytes =
+            max_queue_bytes
+                .map(|size| {
+                    if !enqueue {
+                        return Err(pyo3::exceptions::PyValueError::new_err(
+                            "max_queue_bytes requires enqueue=True",
+                        ));
+                    }
+                    sink::parse_size(&size).filter(|&bytes| bytes > 0).ok_or_else(|| {
+                    pyo3::exceptions::PyValueError::new_err(format!(
+                        "invalid max_queue_bytes {size:?} (expected e.g. 65536 or \"64 MB\")"
+                    ))
+                })
+                })
+                .transpose()?;
+        let mut format_config = new_format_config(format, serialize, strict_format)?
+            .with_serialize_wrapped(serialize_wrapped.unwrap_or(false))
+            .with_logfmt(logfmt.unwrap_or(false))
+            .with_csv(csv)
+            .with_utc(utc.unwrap_or(false))
+            .with_affixes(prefix, suffix)
+            .with_static_fields(static_fields);
+        if let Some(time_format) = time_format {
+            format_config = format_config
+                .with_time_format(&time_format)
+                .map_err(pyo3::exceptions::PyValueError::new_err)?;
+        }
+        if let Some(precision) = parse_time_precision(time_precision)? {
+            format_config = format_config.with_time_precision(precision);
+        }
+
+        let (time_rotation, max_size) = rotation
+            .as_ref()
+            .map(|r| sink::parse_rotation(r))
+            .unwrap_or((Rotation::Never, None));
+
+        let (retention_days, retention_count) = retention
+            .as_ref()
+            .map(|r| sink::parse_retention(r))
+            .unwrap_or((None, None));
+
+        let defaults = FileSinkConfig::default();
+        let config = FileSinkConfig {
+            path,
+            rotation: time_rotation,
+            max_size,
+            retention_days,
+            retention_count,
+            compression,
+            enqueue,
+            watch: watch.unwrap_or(false),
+            line_terminator: line_terminator.unwrap_or_else(|| "\n".to_string()),
+            autoflush: autoflush.unwrap_or(false),
+            fsync: fsync.unwrap_or(false),
+            header: csv_header
+                .unwrap_or(false)
+                .then(|| format::CSV_HEADER.to_string()),
+            truncate: parse_file_mode(mode)?,
+            shared_writer,
+            on_rotation,
+            compress_on_close,
+            max_queue_bytes,
+            write_retries: write_retries.unwrap_or(defaults.write_retries),
+            write_retry_backoff: write_retry_backoff
+                .map(parse_write_retry_backoff)
+                .transpose()?
+                .unwrap_or(defaults.write_retry_backoff),
+        };
+
+        if max_open_files == Some(0) {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "max_open_files must be at least 1",
+            ));
+        }
+
+        // `{extra[key]}` in the path: files are opened per record on first use
+        let handler = match config.path.to_str().filter(|p| SinkRouter::is_template(p)) {
+            Some(template) => {
+                let router = SinkRouter::new(
+                    template.to_string(),
+                    config.clone(),
+                    max_open_files.unwrap_or(router::DEFAULT_MAX_OPEN_FILES),
+                );
+                let mut routed = RoutedFileHandler::with_format(router, level, format_config);
+                routed.max_level = max_level;
+                HandlerType::RoutedFile(Box::new(routed))
+            }
+            None => match FileSink::new(config.clone()) {
+                Ok(sink) => {
+                    let mut file_handler = FileHandler::with_format(sink, level, format_config);
+                    file_handler.max_level = max_level;
+                    HandlerType::File(file_handler)
+                }
+                Err(err) if skip_on_error => {
+                    eprintln!(
+                        "Skipping file handler for {}: {}",
+                        config.path.display(),
+                        err
+                    );
+                    HandlerType::Custom(CustomHandler::new(Arc::new(NullSink), level))
+                }
+                Err(err) => return Err(pyo3::exceptions::PyIOError::new_err(err.to_string())),
+            },
+        };
+
+        let (filter, filter_fields, context_filter) = parse_filter(filter, filter_fields)?;
+        Ok(HandlerEntry {
+            id: handler::next_handler_id(),
+            handler,
+            filter,
+            filter_fields,
+            context_filter,
+            rate_limit,
+            repeats: dedupe.unwrap_or(false).then(RepeatCollapser::default),
+            level_map,
+        })
+    }
+
+    /// Build the `handler_stats()` dict; `path` is None (and so are the file fields) for
+    /// console handlers and callbacks
+    fn stats_dict<'py>(
+        py: Python<'py>,
+        records: u64,
+        path: Option<String>,
+        stats: &[FileSinkStats],
+    ) -> PyResult<Bound<'py, PyDict>> {
+        let is_file = path.is_some();
+        let dict = PyDict::new(py);
+        dict.set_item(intern!(py, "records"), records)?;
+        dict.set_item(intern!(py, "path"), path)?;
+        dict.set_item(
+            intern!(py, "size"),
+            is_file.then(|| stats.iter().map(|s| s.size).sum::<u64>()),
+        )?;
+        dict.set_item(
+            intern!(py, "file_time"),
+            stats
+                .iter()
+                .map(|s| s.file_time)
+                .min()
+                .map(|t| t.to_rfc3339()),
+        )?;
+        dict.set_item(
+            intern!(py, "next_rotation"),
+            stats
+                .iter()
+                .filter_map(|s| s.next_rotation)
+                .min()
+                .map(|t| t.to_rfc3339()),
+        )?;
+        dict.set_item(
+            intern!(py, "write_failures"),
+            is_file.then(|| stats.iter().map(|s| s.write_failures).sum::<u64>()),
+        )?;
+        dict.set_item(
+            intern!(py, "rotations"),
+            is_file.then(|| stats.iter().map(|s| s.rotations).sum::<u64>()),
+        )?;
+        dict.set_item(
+            intern!(py, "queued_bytes"),
+            is_file.then(|| stats.iter().map(|s| s.queued_bytes).sum::<u64>()),
+        )?;
+        Ok(dict)
+    }
+
+    /// Handle for a handler or callback registered on this logger
+    fn handle(&self, id: u64, kind: HandleKind) -> HandlerHandle {
+        HandlerHandle::new(
+            id,
+            kind,
+            self.derive(Arc::clone(&self.context), Arc::clone(&self.patchers)),
+        )
+    }
+
+    /// Register a [`Sink`] implemented in Rust as a handler and return its id. Records reach
+    /// it after the level check, filters are not supported. The handler is listed, flushed,
+    /// re-leveled and removed (by id, from Python too) like the built-in ones.
+    pub fn add_sink(&self, sink: Arc<dyn Sink>, level: LogLevel) -> u64 {
+        let entry = HandlerEntry {
+            id: handler::next_handler_id(),
+            handler: HandlerType::Custom(CustomHandler::new(sink, level)),
+            filter: None,
+            filter_fields: None,
+            context_filter: None,
+            rate_limit: None,
+            repeats: None,
+            level_map: Vec::new(),
+        };
+        let id = entry.id;
+
+        self.handlers.write().push(entry);
+        self.update_min_level_cache();
+        self.update_requirements_cache();
+        id
+    }
+
+    /// [`Logger`] for Rust code sharing this logger's handlers, bound context and
+    /// `disable()` state, so both sides write to the same configured outputs. Handlers it
+    /// adds or removes are seen here too; callbacks and patchers only run for Python calls.
+    pub fn rust_logger(&self) -> Logger {
+        let this = self.derive(Arc::clone(&self.context), Arc::clone(&self.patchers));
+        Logger::shared(
+            Arc::clone(&self.handlers),
+            Arc::clone(&self.context),
+            Arc::clone(&self.paused),
+            Arc::clone(&self.global_level),
+            Arc::clone(&self.seq),
+            Arc::new(move || {
+                this.update_min_level_cache();
+                this.update_requirements_cache();
+            }),
+        )
+    }
+
+    /// Remove a handler added with [`add_sink`](Self::add_sink). Returns false if it was
+    /// already removed.
+    pub fn remove_sink(&self, id: u64) -> bool {
+        self.remove(Some(id))
+    }
+
+    /// Id of the file handler writing to `path` (compared canonicalized), if any
+    fn file_handler_id(&self, path: &Path) -> Option<u64> {
+        let path = canonical_file_path(path);
+        self.handlers
+            .read()
+            .iter()
+            .find(|entry| match entry.handler {
+                HandlerType::File(ref h) => canonical_file_path(h.sink.path()) == path,
+                HandlerType::RoutedFile(ref h) => {
+                    canonical_file_path(Path::new(h.router.template())) == path
+                }
+                HandlerType::Console(_) | HandlerType::Custom(_) | HandlerType::Journald(_) => {
+                    false
+                }
+            })
+            .map(|entry| entry.id)
     }
 
-    #[allow(clippy::too_many_arguments)]
-    #[pyo3(signature = (message, exception=None, name=None, function=None, line=None, file=None, thread_name=None, thread_id=None, process_name=None, process_id=None))]
-    fn critical(
-        &self,
-        message: String,
-        exception: Option<String>,
-        name: Option<String>,
-        function: Option<String>,
-        line: Option<u32>,
-        file: Option<String>,
-        thread_name: Option<String>,
-        thread_id: Option<u64>,
-        process_name: Option<String>,
-        process_id: Option<u32>,
-    ) {
-        self._log(
-            LogLevel::Critical,
-            message,
-            exception,
-            name,
-            function,
-            line,
-            file,
-            thread_name,
-            thread_id,
-            process_name,
-            process_id,
-        );
+    /// Change one handler's minimum level. Returns false if no handler has `id`.
+    fn set_handler_level(&self, id: u64, level: LogLevel) -> PyResult<bool> {
+        {
+            let mut handlers = self.handlers.write();
+            let Some(entry) = handlers.iter_mut().find(|entry| entry.id == id) else {
+                return Ok(false);
+            };
+            if entry
+                .handler
+                .max_level()
+                .is_some_and(|max| level as u32 > max)
+            {
+                return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                    "level {} is above the handler's max_level",
+                    level.as_str()
+                )));
+            }
+            entry.handler.set_level(level);
+        }
+        self.update_min_level_cache();
+        self.update_requirements_cache();
+        Ok(true)
     }
 
-    /// Register a custom log level
-    #[pyo3(signature = (name, no, color=None, icon=None))]
-    fn level(
-        &self,
-        name: String,
-        no: u32,
-        color: Option<String>,
-        icon: Option<String>,
-    ) -> PyResult<()> {
-        let info = LevelInfo::new(name, no, color, icon);
-        register_level(info);
-        Ok(())
+    /// Change one callback's minimum level. Returns false if no callback has `id`.
+    fn set_callback_level(&self, id: u64, level: LogLevel) -> bool {
+        {
+            let mut callbacks = self.callbacks.write();
+            let Some(entry) = callbacks.iter_mut().find(|entry| entry.id == id) else {
+                return false;
+            };
+            entry.level = level;
+        }
+        self.update_min_level_cache();
+        self.update_requirements_cache();
+        true
     }
 
-    /// Log at any level (built-in or custom)
+    /// The console handler a new logger starts with (and `enable()` restores)
+    fn default_console_entry(&self, level: LogLevel) -> HandlerEntry {
+        let use_stderr = self.default_stderr;
+        HandlerEntry {
+            id: handler::next_handler_id(),
+            handler: HandlerType::Console(ConsoleHandler::with_options(
+                level,
+                FormatConfig::default(),
+                handler::default_console_colorize(use_stderr),
+                use_stderr,
+            )),
+            filter: None,
+            filter_fields: None,
+            context_filter: None,
+            rate_limit: None,
+            repeats: None,
+            level_map: Vec::new(),
+        }
+    }
+
+    /// Build a console handler entry without registering it or touching the caches.
     #[allow(clippy::too_many_arguments)]
-    #[pyo3(signature = (level_arg, message, exception=None, name=None, function=None, line=None, file=None, thread_name=None, thread_id=None, process_name=None, process_id=None))]
-    fn log(
-        &self,
-        level_arg: &Bound<'_, PyAny>,
-        message: String,
-        exception: Option<String>,
-        name: Option<String>,
-        function: Option<String>,
-        line: Option<u32>,
-        file: Option<String>,
-        thread_name: Option<String>,
-        thread_id: Option<u64>,
-        process_name: Option<String>,
-        process_id: Option<u32>,
-    ) -> PyResult<()> {
-        let level_info = if let Ok(lvl_name) = level_arg.extract::<String>() {
-            get_level_info(&lvl_name)
-        } else if let Ok(no) = level_arg.extract::<u32>() {
-            get_level_by_no(no)
-        } else {
-            None
-        };
+    fn build_console_entry(
+        stream: &str,
+        level: Option<LogLevel>,
+        format: Option<String>,
+        serialize: Option<bool>,
+        filter: Option<Py<PyAny>>,
+        colorize: Option<bool>,
+        colorize_full: Option<bool>,
+        serialize_wrapped: Option<bool>,
+        rate_limit: Option<String>,
+        dedupe: Option<bool>,
+        max_level: Option<LogLevel>,
+        colorize_exception: Option<bool>,
+        utc: Option<bool>,
+        time_format: Option<String>,
+        time_precision: Option<String>,
+        prefix: Option<String>,
+        suffix: Option<String>,
+        level_map: Vec<(LogLevel, LogLevel)>,
+        static_fields: Vec<(String, ExtraValue)>,
+        message_colors: Vec<MessageColor>,
+        logfmt: Option<bool>,
+        strict_format: Option<bool>,
+        filter_fields: Option<Vec<String>>,
+        enqueue: Option<bool>,
+    ) -> PyResult<HandlerEntry> {
+        let level = level.unwrap_or_else(LogLevel::env_default);
+        let max_level = check_max_level(level, max_level)?;
+        let rate_limit = parse_rate_limit(rate_limit)?;
+        let mut format_config =
+            new_format_config(format, serialize.unwrap_or(false), strict_format)?
+                .with_serialize_wrapped(serialize_wrapped.unwrap_or(false))
+                .with_logfmt(logfmt.unwrap_or(false))
+                .with_colorize_exception(colorize_exception.unwrap_or(false))
+                .with_utc(utc.unwrap_or(false))
+                .with_affixes(prefix, suffix)
+                .with_static_fields(static_fields)
+                .with_message_colors(message_colors);
+        if let Some(time_format) = time_format {
+            format_config = format_config
+                .with_time_format(&time_format)
+                .map_err(pyo3::exceptions::PyValueError::new_err)?;
+        }
+        if let Some(precision) = parse_time_precision(time_precision)? {
+            format_config = format_config.with_time_precision(precision);
+        }
+        let serialize = format_config.is_structured();
+        if stream != "stdout" && stream != "stderr" {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "stream must be 'stdout' or 'stderr'",
+            ));
+        }
+        let use_stderr = stream == "stderr";
+        // Explicit colorize wins; otherwise NO_COLOR/FORCE_COLOR, then TTY detection
+        let colorize =
+            colorize.unwrap_or_else(|| !serialize && handler::default_console_colorize(use_stderr));
 
-        let info = level_info
-            .ok_or_else(|| pyo3::exceptions::PyValueError::new_err("Invalid log level"))?;
+        let mut console_handler =
+            ConsoleHandler::with_options(level, format_config, colorize, use_stderr);
+        console_handler.colorize_full = colorize_full.unwrap_or(false);
+        console_handler.max_level = max_level;
+        console_handler.enqueue = enqueue.unwrap_or(false);
+        let (filter, filter_fields, context_filter) = parse_filter(filter, filter_fields)?;
+        Ok(HandlerEntry {
+            id: handler::next_handler_id(),
+            handler: HandlerType::Console(console_handler),
+            filter,
+            filter_fields,
+            context_filter,
+            rate_limit,
+            repeats: dedupe.unwrap_or(false).then(RepeatCollapser::default),
+            level_map,
+        })
+    }
 
-        self._log_custom(
-            info,
-            message,
-            exception,
-            name,
-            function,
-            line,
-            file,
-            thread_name,
-            thread_id,
-            process_name,
-            process_id,
-        );
-        Ok(())
+    /// Build the stdout (below WARNING) and stderr (WARNING and above) console entries.
+    fn build_split_console_entries(
+        level: LogLevel,
+        format: Option<String>,
+        serialize: Option<bool>,
+        colorize: Option<bool>,
+        colorize_full: Option<bool>,
+        colorize_exception: Option<bool>,
+        utc: Option<bool>,
+    ) -> PyResult<[HandlerEntry; 2]> {
+        let mut stdout = Self::build_console_entry(
+            "stdout",
+            Some(level),
+            format.clone(),
+            serialize,
+            None,
+            colorize,
+            colorize_full,
+            None,
+            None,
+            None,
+            None,
+            colorize_exception,
+            utc,
+            None,
+            None,
+            None,
+            None,
+            Vec::new(),
+            Vec::new(),
+            Vec::new(),
+            None,
+            None,
+            None,
+            None,
+        )?;
+        if let HandlerType::Console(ref mut h) = stdout.handler {
+            h.max_level = Some(LogLevel::Warning as u32 - 1);
+        }
+        let stderr = Self::build_console_entry(
+            "stderr",
+            Some(level.max(LogLevel::Warning)),
+            format,
+            serialize,
+            None,
+            colorize,
+            colorize_full,
+            None,
+            None,
+            None,
+            None,
+            colorize_exception,
+            utc,
+            None,
+            None,
+            None,
+            None,
+            Vec::new(),
+            Vec::new(),
+            Vec::new(),
+            None,
+            None,
+            None,
+            None,
+        )?;
+        Ok([stdout, stderr])
+    }
+
+    /// Build a handler entry from a `configure()` spec dict (console vs file by `sink`).
+    fn build_entry_from_spec(py: Python<'_>, spec: &Bound<'_, PyDict>) -> PyResult<HandlerEntry> {
+        let sink = spec
+            .get_item("sink")?
+            .filter(|s| !s.is_none())
+            .ok_or_else(|| {
+                pyo3::exceptions::PyValueError::new_err("handler spec requires a 'sink' key")
+            })?;
+
+        let level = spec
+            .get_item("level")?
+            .filter(|l| !l.is_none())
+            .map(|l| extract_level(&l))
+            .transpose()?;
+        let format: Option<String> = spec_item(spec, "format")?;
+        let strict_format: Option<bool> = spec_item(spec, "strict_format")?;
+        let serialize: Option<bool> = spec_item(spec, "serialize")?;
+        let serialize_wrapped: Option<bool> = spec_item(spec, "serialize_wrapped")?;
+        let logfmt: Option<bool> = spec_item(spec, "logfmt")?;
+        let filter: Option<Py<PyAny>> = spec_item(spec, "filter")?;
+        let filter_fields: Option<Vec<String>> = spec_item(spec, "filter_fields")?;
+        let rate_limit: Option<String> = spec_item(spec, "rate_limit")?;
+        let dedupe: Option<bool> = spec_item(spec, "dedupe")?;
+        let max_level = spec
+            .get_item("max_level")?
+            .filter(|l| !l.is_none())
+            .map(|l| extract_level(&l))
+            .transpose()?;
+
+        let utc: Option<bool> = spec_item(spec, "utc")?;
+        let time_format: Option<String> = spec_item(spec, "time_format")?;
+        let time_precision: Option<String> = spec_item(spec, "time_precision")?;
+        let prefix: Option<String> = spec_item(spec, "prefix")?;
+        let suffix: Option<String> = spec_item(spec, "suffix")?;
+        let level_map = spec
+            .get_item("level_map")?
+            .filter(|m| !m.is_none())
+            .map(|m| parse_level_map(Some(m.cast::<PyDict>()?)))
+            .transpose()?
+            .unwrap_or_default();
+        let static_fields = spec
+            .get_item("static_fields")?
+            .filter(|m| !m.is_none())
+            .map(|m| parse_static_fields(Some(m.cast::<PyDict>()?)))
+            .transpose()?
+            .unwrap_or_default();
+        if let Some(stream) = console_stream_name(py, &sink)? {
+            let colorize: Option<bool> = spec_item(spec, "colorize")?;
+            let colorize_full: Option<bool> = spec_item(spec, "colorize_full")?;
+            let colorize_exception: Option<bool> = spec_item(spec, "colorize_exception")?;
+            let message_colors = spec
+                .get_item("message_colors")?
+                .filter(|m| !m.is_none())
+                .map(|m| parse_message_colors(Some(m.cast::<PyDict>()?)))
+                .transpose()?
+                .unwrap_or_default();
+            return Self::build_console_entry(
+                stream,
+                level,
+                format,
+                serialize,
+                filter,
+                colorize,
+                colorize_full,
+                serialize_wrapped,
+                rate_limit,
+                dedupe,
+                max_level,
+                colorize_exception,
+                utc,
+                time_format,
+                time_precision,
+                prefix,
+                suffix,
+                level_map,
+                static_fields,
+                message_colors,
+                logfmt,
+                strict_format,
+                filter_fields,
+                spec_item(spec, "enqueue")?,
+            );
+        }
+
+        let retention = match spec.get_item("retention")? {
+            Some(r) if !r.is_none() => Some(r.str()?.to_string()),
+            _ => None,
+        };
+        let max_queue_bytes = match spec.get_item("max_queue_bytes")? {
+            Some(m) if !m.is_none() => Some(m.str()?.to_string()),
+            _ => None,
+        };
+        Self::build_file_entry(
+            sink.extract::<PathBuf>()?,
+            level,
+            format,
+            spec_item(spec, "rotation")?,
+            retention,
+            spec_item(spec, "compression")?,
+            serialize,
+            filter,
+            spec_item(spec, "enqueue")?,
+            serialize_wrapped,
+            spec_item(spec, "watch")?,
+            spec_item(spec, "line_terminator")?,
+            rate_limit,
+            dedupe,
+            max_level,
+            spec_item(spec, "autoflush")?,
+            spec_item(spec, "fsync")?,
+            spec_item(spec, "max_open_files")?,
+            utc,
+            time_format,
+            time_precision,
+            prefix,
+            suffix,
+            level_map,
+            static_fields,
+            logfmt,
+            spec_item(spec, "csv")?,
+            spec_item(spec, "csv_header")?,
+            spec_item(spec, "mode")?,
+            strict_format,
+            filter_fields,
+            spec_item(spec, "shared_writer")?,
+            spec_item(spec, "on_error")?,
+            spec_item(spec, "on_rotation")?,
+            spec_item(spec, "compress_on_close")?,
+            max_queue_bytes,
+            spec_item(spec, "write_retries")?,
+            spec_item(spec, "write_retry_backoff")?,
+        )
     }
-}
 
-impl PyLogger {
     /// Update the cached minimum level across all handlers and callbacks
     fn update_min_level_cache(&self) {
         let handlers = self.handlers.read();
@@ -958,7 +3370,7 @@ impl PyLogger {
 
         let min_handler = handlers
             .iter()
-            .map(|e| e.handler.level() as u32)
+            .map(HandlerEntry::min_level_no)
             .min()
             .unwrap_or(u32::MAX);
 
@@ -968,8 +3380,10 @@ impl PyLogger {
             .min()
             .unwrap_or(u32::MAX);
 
+        // The global floor raises the minimum, so every O(1) check below it honors the floor
+        let floor = self.global_level.load(Ordering::Relaxed);
         self.cached_min_level
-            .store(min_handler.min(min_callback), Ordering::Relaxed);
+            .store(min_handler.min(min_callback).max(floor), Ordering::Relaxed);
     }
 
     /// Update the cached token requirements per built-in emit level (handlers + eligible callbacks).
@@ -981,7 +3395,7 @@ impl PyLogger {
 
         // Merge requirements from all handlers (this is the handler-only requirements)
         for entry in handlers.iter() {
-            let req = entry.handler.requirements();
+            let req = entry.requirements();
             handler_only = handler_only.merge(&req);
         }
 
@@ -997,6 +3411,50 @@ impl PyLogger {
         }
 
         *self.cached_requirements_by_level.write() = map;
+
+        let all = merge_token_requirements_for_emit_no(&handlers, &callbacks, u32::MAX);
+        self.cached_needs_thread
+            .store(all.needs_thread, Ordering::Relaxed);
+        self.cached_needs_process
+            .store(all.needs_process, Ordering::Relaxed);
+    }
+
+    /// Build thread/process info from the Python-supplied values.
+    /// When both fields of one are omitted but some handler formats it, resolve it here
+    /// (thread id via the OS, cached pid, names looked up once per thread / process)
+    /// instead of requiring a Python-side lookup per call.
+    fn thread_process_info(
+        &self,
+        thread_name: Option<String>,
+        thread_id: Option<u64>,
+        process_name: Option<String>,
+        process_id: Option<u32>,
+    ) -> (ThreadInfo, ProcessInfo) {
+        let thread = if thread_name.is_none()
+            && thread_id.is_none()
+            && self.cached_needs_thread.load(Ordering::Relaxed)
+        {
+            ThreadInfo::current_named()
+        } else {
+            ThreadInfo {
+                name: thread_name.unwrap_or_default(),
+                id: thread_id.unwrap_or(0),
+            }
+        };
+
+        let process = if process_name.is_none()
+            && process_id.is_none()
+            && self.cached_needs_process.load(Ordering::Relaxed)
+        {
+            ProcessInfo::current_named()
+        } else {
+            ProcessInfo {
+                name: process_name.unwrap_or_default(),
+                id: process_id.unwrap_or(0),
+            }
+        };
+
+        (thread, process)
     }
 
     /// Merge result for `emit_no`, using the precomputed map and memoizing misses.
@@ -1036,14 +3494,64 @@ impl PyLogger {
         thread_id: Option<u64>,
         process_name: Option<String>,
         process_id: Option<u32>,
-    ) {
+        extra: Option<&Bound<'_, PyDict>>,
+        stacklevel: Option<u32>,
+        timestamp: Option<&Bound<'_, PyAny>>,
+        end: Option<String>,
+    ) -> PyResult<()> {
+        if (level as u32) < self.global_level.load(Ordering::Relaxed) {
+            return Ok(());
+        }
+        let caller = caller_info(name, function, line, file, stacklevel)?;
+        let timestamp = timestamp.map(parse_timestamp).transpose()?;
+        if self.paused.load(Ordering::Relaxed) {
+            return Ok(());
+        }
+
         let handlers = self.handlers.read();
         let callbacks = self.callbacks.read();
 
+        let Some(needs_gil) = Self::dispatch_plan(level, &handlers, &callbacks) else {
+            return Ok(());
+        };
+
+        let extra = self.context_with(extra)?;
+
+        let (thread, process) =
+            self.thread_process_info(thread_name, thread_id, process_name, process_id);
+
+        let mut record =
+            LogRecord::with_all(level, message, extra, exception, caller, thread, process);
+        if let Some(timestamp) = timestamp {
+            record.timestamp = timestamp;
+        }
+        record.end = end;
+        record.seq = next_seq(&self.seq);
+        self.apply_patchers(&mut record)?;
+
+        Self::dispatch_records(
+            level,
+            &handlers,
+            &callbacks,
+            &self.callback_error_handler,
+            std::slice::from_ref(&record),
+            needs_gil,
+        );
+        Ok(())
+    }
+
+    /// Decide whether a record at `level` reaches any handler/callback.
+    /// Returns `None` when nothing is eligible, else whether the GIL is needed.
+    #[inline]
+    fn dispatch_plan(
+        level: LogLevel,
+        handlers: &[HandlerEntry],
+        callbacks: &[CallbackEntry],
+    ) -> Option<bool> {
         let mut has_eligible_handler = false;
         let mut has_eligible_filtered_handler = false;
         for e in handlers.iter() {
-            if level >= e.handler.level() {
+            if e.accepts(level as u32) {
                 has_eligible_handler = true;
                 if e.filter.is_some() {
                     has_eligible_filtered_handler = true;
@@ -1053,99 +3561,106 @@ impl PyLogger {
         let has_eligible_callback = callbacks.iter().any(|e| level >= e.level);
 
         if !has_eligible_handler && !has_eligible_callback {
-            return;
+            return None;
         }
 
         let has_callbacks = !callbacks.is_empty() && has_eligible_callback;
-        let needs_gil = has_callbacks || has_eligible_filtered_handler;
-
-        let extra = Arc::clone(&self.context);
-
-        let caller = CallerInfo::with_file(
-            name.unwrap_or_default(),
-            function.unwrap_or_default(),
-            line.unwrap_or(0),
-            file.unwrap_or_default(),
-        );
-
-        let thread = ThreadInfo {
-            name: thread_name.unwrap_or_default(),
-            id: thread_id.unwrap_or(0),
-        };
-
-        let process = ProcessInfo {
-            name: process_name.unwrap_or_default(),
-            id: process_id.unwrap_or(0),
-        };
-
-        let record = LogRecord::with_all(level, message, extra, exception, caller, thread, process);
+        Some(has_callbacks || has_eligible_filtered_handler)
+    }
 
+    /// Deliver built-in level records to callbacks and handlers (one GIL acquisition for all).
+    fn dispatch_records(
+        level: LogLevel,
+        handlers: &[HandlerEntry],
+        callbacks: &[CallbackEntry],
+        callback_error_handler: &RwLock<Option<Py<PyAny>>>,
+        records: &[LogRecord],
+        needs_gil: bool,
+    ) {
         if needs_gil {
             Python::attach(|py| {
-                let need_text_full_dict = has_eligible_filtered_handler
-                    || callbacks
-                        .iter()
-                        .any(|e| level >= e.level && matches!(&e.kind, CallbackKind::Raw));
-                let need_json_full_dict = callbacks
-                    .iter()
-                    .any(|e| level >= e.level && matches!(&e.kind, CallbackKind::Serialized));
-
-                let shared_text_full: Option<Bound<'_, PyDict>> = if need_text_full_dict {
-                    Self::build_record_dict(py, level, &record, RecordExtraView::Text).ok()
-                } else {
-                    None
-                };
-                let shared_json_full: Option<Bound<'_, PyDict>> = if need_json_full_dict {
-                    Self::build_record_dict(py, level, &record, RecordExtraView::Json).ok()
-                } else {
-                    None
-                };
-
-                for entry in callbacks.iter() {
-                    if level < entry.level {
-                        continue;
-                    }
-                    match &entry.kind {
-                        CallbackKind::Raw => {
-                            if let Some(full) = shared_text_full.as_ref() {
-                                let _ = entry.callback.call1(py, (full.clone(),));
-                            }
+                // Cloned out so a callback may replace the handler without deadlocking
+                let on_error = callback_error_handler
+                    .read()
+                    .as_ref()
+                    .map(|handler| handler.clone_ref(py));
+                // Each view's dict is built on the first eligible consumer of a record (never if
+                // none fires) and borrowed by every later callback/filter: no per-call copy or
+                // refcount churn, and mutations are visible to later consumers.
+                for record in records {
+                    let mut shared_text_full: Option<Bound<'_, PyDict>> = None;
+                    let mut shared_json_full: Option<Bound<'_, PyDict>> = None;
+
+                    for entry in callbacks.iter() {
+                        if level < entry.level {
+                            continue;
                         }
-                        CallbackKind::Serialized => {
-                            if let Some(full) = shared_json_full.as_ref() {
-                                let _ = entry.callback.call1(py, (full.clone(),));
+                        match &entry.kind {
+                            CallbackKind::Raw => {
+                                if let Some(full) = lazy_record_dict(&mut shared_text_full, || {
+                                    Self::build_record_dict(
+                                        py,
+                                        level,
+                                        record,
+                                        RecordExtraView::Text,
+                                    )
+                                }) {
+                                    entry.invoke(py, full, on_error.as_ref());
+                                }
                             }
-                        }
-                        CallbackKind::FormattedLight(req) => {
-                            if let Ok(mini) = Self::build_mini_record_dict(py, level, &record, req)
-                            {
-                                let _ = entry.callback.call1(py, (mini,));
+                            CallbackKind::Serialized => {
+                                if let Some(full) = lazy_record_dict(&mut shared_json_full, || {
+                                    Self::build_record_dict(
+                                        py,
+                                        level,
+                                        record,
+                                        RecordExtraView::Json,
+                                    )
+                                }) {
+                                    entry.invoke(py, full, on_error.as_ref());
+                                }
+                            }
+                            CallbackKind::FormattedLight(req) => {
+                                if let Ok(mini) =
+                                    Self::build_mini_record_dict(py, level, record, req)
+                                {
+                                    entry.invoke(py, &mini, on_error.as_ref());
+                                }
+                            }
+                            CallbackKind::Fields(fields) => {
+                                if let Ok(subset) =
+                                    Self::build_fields_record_dict(py, record, fields)
+                                {
+                                    entry.invoke(py, &subset, on_error.as_ref());
+                                }
                             }
                         }
                     }
-                }
 
-                for entry in handlers.iter() {
-                    if level < entry.handler.level() {
-                        continue;
-                    }
-                    if let Some(ref filter) = entry.filter
-                        && let Some(full) = shared_text_full.as_ref()
-                    {
-                        let passes = filter
-                            .call1(py, (full.clone(),))
-                            .and_then(|result| result.is_truthy(py))
-                            .unwrap_or(true);
-                        if !passes {
+                    let mut formatted = FormattedLines::for_handlers(handlers);
+                    for entry in handlers.iter() {
+                        if !entry.accepts(level as u32) {
                             continue;
                         }
+                        let passes = handler_filter_passes(py, entry, record, || {
+                            lazy_record_dict(&mut shared_text_full, || {
+                                Self::build_record_dict(py, level, record, RecordExtraView::Text)
+                            })
+                        });
+                        if passes {
+                            let _ = entry.emit_shared(record, &mut formatted);
+                        }
                     }
-                    let _ = entry.handler.handle(&record);
                 }
             });
         } else {
-            for entry in handlers.iter() {
-                let _ = entry.handler.handle(&record);
+            for record in records {
+                let mut formatted = FormattedLines::for_handlers(handlers);
+                for entry in handlers.iter() {
+                    if entry.accepts(level as u32) {
+                        let _ = entry.emit_shared(record, &mut formatted);
+                    }
+                }
             }
         }
     }
@@ -1172,12 +3687,14 @@ impl PyLogger {
         let _ = dict.set_item(intern!(py, "level"), level.as_str());
         let _ = dict.set_item(intern!(py, "message"), &record.message);
         let _ = dict.set_item(intern!(py, "timestamp"), record.timestamp.to_rfc3339());
+        let _ = dict.set_item(intern!(py, "seq"), record.seq);
 
         // Caller info
         let _ = dict.set_item(intern!(py, "name"), &record.caller.name);
         let _ = dict.set_item(intern!(py, "function"), &record.caller.function);
         let _ = dict.set_item(intern!(py, "line"), record.caller.line);
         let _ = dict.set_item(intern!(py, "file"), &record.caller.file);
+        let _ = dict.set_item(intern!(py, "stacklevel"), record.caller.stacklevel);
 
         // Thread/process info
         let _ = dict.set_item(intern!(py, "thread_name"), &record.thread.name);
@@ -1267,6 +3784,9 @@ impl PyLogger {
         if req.needs_message {
             let _ = dict.set_item(intern!(py, "message"), &record.message);
         }
+        if req.needs_seq {
+            let _ = dict.set_item(intern!(py, "seq"), record.seq);
+        }
         if req.needs_nested_extra {
             let extra_dict = PyDict::new(py);
             if req.extra_keys.is_empty() {
@@ -1277,6 +3797,11 @@ impl PyLogger {
                 for key in &req.extra_keys {
                     if let Some(value) = record.extra.get(key) {
                         let _ = extra_dict.set_item(key.as_str(), value.as_str());
+                    } else if let Some((field, path)) = handler::split_extra_path(key)
+                        && let Some(text) = record.extra.get(field).and_then(|v| v.text_at(&path))
+                    {
+                        // `{extra[user][id]}` in a callable sink template
+                        let _ = extra_dict.set_item(key.as_str(), text);
                     }
                 }
             }
@@ -1302,15 +3827,30 @@ impl PyLogger {
         thread_id: Option<u64>,
         process_name: Option<String>,
         process_id: Option<u32>,
-    ) {
+        extra: Option<&Bound<'_, PyDict>>,
+        stacklevel: Option<u32>,
+        timestamp: Option<&Bound<'_, PyAny>>,
+        end: Option<String>,
+    ) -> PyResult<()> {
+        // The cached minimum covers every handler and callback (and the global floor), so a
+        // level below it can't reach anything: skip the handler scan (and caller parsing)
+        let level_no = level_info.no;
+        if level_no < self.cached_min_level.load(Ordering::Relaxed) {
+            return Ok(());
+        }
+        let caller = caller_info(name, function, line, file, stacklevel)?;
+        let timestamp = timestamp.map(parse_timestamp).transpose()?;
+        if self.paused.load(Ordering::Relaxed) {
+            return Ok(());
+        }
+
         let handlers = self.handlers.read();
         let callbacks = self.callbacks.read();
 
-        let level_no = level_info.no;
         let mut has_eligible_handler = false;
         let mut has_eligible_filtered_handler = false;
         for e in handlers.iter() {
-            if level_no >= e.handler.level() as u32 {
+            if e.accepts(level_no) {
                 has_eligible_handler = true;
                 if e.filter.is_some() {
                     has_eligible_filtered_handler = true;
@@ -1320,32 +3860,18 @@ impl PyLogger {
         let has_eligible_callback = callbacks.iter().any(|e| level_no >= e.level as u32);
 
         if !has_eligible_handler && !has_eligible_callback {
-            return;
+            return Ok(());
         }
 
         let has_callbacks = !callbacks.is_empty() && has_eligible_callback;
         let needs_gil = has_callbacks || has_eligible_filtered_handler;
 
-        let extra = Arc::clone(&self.context);
-
-        let caller = CallerInfo::with_file(
-            name.unwrap_or_default(),
-            function.unwrap_or_default(),
-            line.unwrap_or(0),
-            file.unwrap_or_default(),
-        );
-
-        let thread = ThreadInfo {
-            name: thread_name.unwrap_or_default(),
-            id: thread_id.unwrap_or(0),
-        };
+        let extra = self.context_with(extra)?;
 
-        let process = ProcessInfo {
-            name: process_name.unwrap_or_default(),
-            id: process_id.unwrap_or(0),
-        };
+        let (thread, process) =
+            self.thread_process_info(thread_name, thread_id, process_name, process_id);
 
-        let record = LogRecord::with_custom_level_full(
+        let mut record = LogRecord::with_custom_level_full(
             level_info.clone(),
             message,
             extra,
@@ -1354,63 +3880,135 @@ impl PyLogger {
             thread,
             process,
         );
+        if let Some(timestamp) = timestamp {
+            record.timestamp = timestamp;
+        }
+        record.end = end;
+        record.seq = next_seq(&self.seq);
+        self.apply_patchers(&mut record)?;
 
         if needs_gil {
             Python::attach(|py| {
-                let need_text_full_dict = has_eligible_filtered_handler
-                    || callbacks.iter().any(|e| {
-                        level_no >= e.level as u32 && !matches!(&e.kind, CallbackKind::Serialized)
-                    });
-                let need_json_full_dict = callbacks.iter().any(|e| {
-                    level_no >= e.level as u32 && matches!(&e.kind, CallbackKind::Serialized)
-                });
-
-                let shared_text_full: Option<Bound<'_, PyDict>> = if need_text_full_dict {
-                    Self::build_custom_record_dict(py, &record, RecordExtraView::Text).ok()
-                } else {
-                    None
-                };
-                let shared_json_full: Option<Bound<'_, PyDict>> = if need_json_full_dict {
-                    Self::build_custom_record_dict(py, &record, RecordExtraView::Json).ok()
-                } else {
-                    None
-                };
+                let on_error = self
+                    .callback_error_handler
+                    .read()
+                    .as_ref()
+                    .map(|handler| handler.clone_ref(py));
+                // Built on first eligible consumer; never built if none fires for this level.
+                let mut shared_text_full: Option<Bound<'_, PyDict>> = None;
+                let mut shared_json_full: Option<Bound<'_, PyDict>> = None;
 
                 for entry in callbacks.iter() {
                     if level_no >= entry.level as u32 {
+                        if let CallbackKind::Fields(fields) = &entry.kind {
+                            if let Ok(subset) = Self::build_fields_record_dict(py, &record, fields)
+                            {
+                                entry.invoke(py, &subset, on_error.as_ref());
+                            }
+                            continue;
+                        }
                         let full = match &entry.kind {
-                            CallbackKind::Serialized => shared_json_full.as_ref(),
-                            _ => shared_text_full.as_ref(),
+                            CallbackKind::Serialized => {
+                                lazy_record_dict(&mut shared_json_full, || {
+                                    Self::build_custom_record_dict(
+                                        py,
+                                        &record,
+                                        RecordExtraView::Json,
+                                    )
+                                })
+                            }
+                            _ => lazy_record_dict(&mut shared_text_full, || {
+                                Self::build_custom_record_dict(py, &record, RecordExtraView::Text)
+                            }),
                         };
                         if let Some(full) = full {
-                            let _ = entry.callback.call1(py, (full.clone(),));
+                            entry.invoke(py, full, on_error.as_ref());
                         }
                     }
                 }
 
+                let mut formatted = FormattedLines::for_handlers(&handlers);
                 for entry in handlers.iter() {
-                    if level_no < entry.handler.level() as u32 {
+                    if !entry.accepts(level_no) {
                         continue;
                     }
-                    if let Some(ref filter) = entry.filter
-                        && let Some(full) = shared_text_full.as_ref()
-                    {
-                        let passes = filter
-                            .call1(py, (full.clone(),))
-                            .and_then(|result| result.is_truthy(py))
-                            .unwrap_or(true);
-                        if !passes {
-                            continue;
-                        }
+                    let passes = handler_filter_passes(py, entry, &record, || {
+                        lazy_record_dict(&mut shared_text_full, || {
+                            Self::build_custom_record_dict(py, &record, RecordExtraView::Text)
+                        })
+                    });
+                    if passes {
+                        let _ = entry.emit_shared(&record, &mut formatted);
                     }
-                    let _ = entry.handler.handle(&record);
                 }
             });
         } else {
+            let mut formatted = FormattedLines::for_handlers(&handlers);
             for entry in handlers.iter() {
-                let _ = entry.handler.handle(&record);
+                if entry.accepts(level_no) {
+                    let _ = entry.emit_shared(&record, &mut formatted);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Dict with only the requested keys (text view of `extra`; `exception` may be None)
+    fn build_fields_record_dict<'py>(
+        py: Python<'py>,
+        record: &LogRecord,
+        fields: &[RecordField],
+    ) -> PyResult<Bound<'py, PyDict>> {
+        let dict = PyDict::new(py);
+        for field in fields {
+            match field {
+                RecordField::Level => dict.set_item(intern!(py, "level"), record.level_name())?,
+                RecordField::LevelNo => {
+                    dict.set_item(intern!(py, "level_no"), record.level_no())?
+                }
+                RecordField::Message => dict.set_item(intern!(py, "message"), &record.message)?,
+                RecordField::Timestamp => {
+                    dict.set_item(intern!(py, "timestamp"), record.timestamp.to_rfc3339())?
+                }
+                RecordField::Name => dict.set_item(intern!(py, "name"), &record.caller.name)?,
+                RecordField::Function => {
+                    dict.set_item(intern!(py, "function"), &record.caller.function)?
+                }
+                RecordField::Line => dict.set_item(intern!(py, "line"), record.caller.line)?,
+                RecordField::File => dict.set_item(intern!(py, "file"), &record.caller.file)?,
+                RecordField::Stacklevel => {
+                    dict.set_item(intern!(py, "stacklevel"), record.caller.stacklevel)?
+                }
+                RecordField::ThreadName => {
+                    dict.set_item(intern!(py, "thread_name"), &record.thread.name)?
+                }
+                RecordField::ThreadId => {
+                    dict.set_item(intern!(py, "thread_id"), record.thread.id)?
+                }
+                RecordField::ProcessName => {
+                    dict.set_item(intern!(py, "process_name"), &record.process.name)?
+                }
+                RecordField::ProcessId => {
+                    dict.set_item(intern!(py, "process_id"), record.process.id)?
+                }
+                RecordField::Elapsed => dict.set_item(
+                    intern!(py, "elapsed"),
+                    format_elapsed(&LOGGER_START_TIME, &record.timestamp),
+                )?,
+                RecordField::Extra => {
+                    let extra_dict = PyDict::new(py);
+                    for (key, value) in record.extra.iter() {
+                        extra_dict.set_item(key, value.as_str())?;
+                    }
+                    dict.set_item(intern!(py, "extra"), extra_dict)?
+                }
+                RecordField::Exception => {
+                    dict.set_item(intern!(py, "exception"), record.exception.as_deref())?
+                }
+                RecordField::Seq => dict.set_item(intern!(py, "seq"), record.seq)?,
             }
         }
+        Ok(dict)
     }
 
     /// Build a Python dict from custom level record for callbacks/filters
@@ -1428,6 +4026,7 @@ impl PyLogger {
         }
         let _ = dict.set_item(intern!(py, "message"), &record.message);
         let _ = dict.set_item(intern!(py, "timestamp"), record.timestamp.to_rfc3339());
+        let _ = dict.set_item(intern!(py, "seq"), record.seq);
 
         let extra_dict = PyDict::new(py);
         for (key, value) in record.extra.iter() {
@@ -1438,6 +4037,7 @@ impl PyLogger {
         let _ = dict.set_item(intern!(py, "function"), &record.caller.function);
         let _ = dict.set_item(intern!(py, "line"), record.caller.line);
         let _ = dict.set_item(intern!(py, "file"), &record.caller.file);
+        let _ = dict.set_item(intern!(py, "stacklevel"), record.caller.stacklevel);
         let _ = dict.set_item(intern!(py, "thread_name"), &record.thread.name);
         let _ = dict.set_item(intern!(py, "thread_id"), record.thread.id);
         let _ = dict.set_item(intern!(py, "process_name"), &record.process.name);
@@ -1463,7 +4063,20 @@ fn _logust(py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
 
     m.add_class::<PyLogger>()?;
 
-    let default_logger = Py::new(py, PyLogger::new(None))?;
+    m.add_class::<HandlerHandle>()?;
+
+    m.add_class::<ConfigSnapshot>()?;
+
+    m.add_function(wrap_pyfunction!(_shutdown, m)?)?;
+
+    m.add_function(wrap_pyfunction!(_format_exception, m)?)?;
+
+    m.add_function(wrap_pyfunction!(_validate_format, m)?)?;
+
+    m.add_function(wrap_pyfunction!(_level_icon, m)?)?;
+    m.add_function(wrap_pyfunction!(_hostname, m)?)?;
+
+    let default_logger = Py::new(py, PyLogger::new(None, None)?)?;
     m.add("logger", default_logger)?;
 
     Ok(())
@@ -0,0 +1,365 @@
+use std::io;
+use std::panic::Location;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering};
+
+use pyo3::prelude::*;
+
+use crate::format::FormatConfig;
+use crate::handler::{
+    self, CallerInfo, ConsoleHandler, CustomHandler, ExtraMap, ExtraValue, FileHandler,
+    FormattedLines, HandlerEntry, HandlerType, LogRecord, ProcessInfo, Sink, ThreadInfo,
+    empty_context,
+};
+use crate::level::LogLevel;
+use crate::router::{self, SinkRouter};
+use crate::sink::{FileSink, FileSinkConfig};
+use crate::{
+    PyLogger, RecordExtraView, RwLock, custom_sinks, enqueues_console, file_sinks,
+    handler_filter_passes, lazy_record_dict, next_seq,
+};
+
+/// Logger for Rust code: handlers are added and records logged with native types, no
+/// Python involved. Cloning (and `bind()`) shares the handlers.
+///
+/// [`PyLogger::rust_logger`] returns one sharing a Python logger's handlers, so mixed
+/// Rust/Python projects configure logging once and log to the same files from both sides.
+#[derive(Clone)]
+pub struct Logger {
+    handlers: Arc<RwLock<Vec<HandlerEntry>>>,
+    context: Arc<ExtraMap>,
+    paused: Arc<AtomicBool>,
+    /// Level number below which records are dropped (0 when unset)
+    global_level: Arc<AtomicU32>,
+    /// Number of records logged so far, for `{seq}`
+    seq: Arc<AtomicU64>,
+    /// Called after handlers are added or removed (refreshes a sharing `PyLogger`'s caches)
+    on_change: Option<Arc<dyn Fn() + Send + Sync>>,
+}
+
+impl Default for Logger {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Logger {
+    /// Logger without handlers
+    pub fn new() -> Self {
+        Logger {
+            handlers: Arc::new(RwLock::new(Vec::new())),
+            context: empty_context(),
+            paused: Arc::new(AtomicBool::new(false)),
+            global_level: Arc::new(AtomicU32::new(0)),
+            seq: Arc::new(AtomicU64::new(0)),
+            on_change: None,
+        }
+    }
+
+    /// View over a `PyLogger`'s handlers, context, paused flag, global level and sequence
+    /// counter
+    pub(crate) fn shared(
+        handlers: Arc<RwLock<Vec<HandlerEntry>>>,
+        context: Arc<ExtraMap>,
+        paused: Arc<AtomicBool>,
+        global_level: Arc<AtomicU32>,
+        seq: Arc<AtomicU64>,
+        on_change: Arc<dyn Fn() + Send + Sync>,
+    ) -> Self {
+        Logger {
+            handlers,
+            context,
+            paused,
+            global_level,
+            seq,
+            on_change: Some(on_change),
+        }
+    }
+
+    /// Add a file handler; `config.path` may hold `{extra[key]}` routing placeholders
+    pub fn add_file(
+        &self,
+        config: FileSinkConfig,
+        level: LogLevel,
+        format: FormatConfig,
+    ) -> io::Result<u64> {
+        let handler = match config.path.to_str().filter(|p| SinkRouter::is_template(p)) {
+            Some(template) => {
+                let router = SinkRouter::new(
+                    template.to_string(),
+                    config.clone(),
+                    router::DEFAULT_MAX_OPEN_FILES,
+                );
+                HandlerType::RoutedFile(Box::new(handler::RoutedFileHandler::with_format(
+                    router, level, format,
+                )))
+            }
+            None => HandlerType::File(FileHandler::with_format(
+                FileSink::new(config)?,
+                level,
+                format,
+            )),
+        };
+        Ok(self.push(handler))
+    }
+
+    /// Add a stdout (or stderr) handler
+    pub fn add_console(
+        &self,
+        use_stderr: bool,
+        level: LogLevel,
+        format: FormatConfig,
+        colorize: bool,
+    ) -> u64 {
+        self.push(HandlerType::Console(ConsoleHandler::with_options(
+            level, format, colorize, use_stderr,
+        )))
+    }
+
+    /// Add a [`Sink`] implemented in Rust
+    pub fn add_sink(&self, sink: Arc<dyn Sink>, level: LogLevel) -> u64 {
+        self.push(HandlerType::Custom(CustomHandler::new(sink, level)))
+    }
+
+    fn push(&self, handler: HandlerType) -> u64 {
+        let entry = HandlerEntry {
+            id: handler::next_handler_id(),
+            handler,
+            filter: None,
+            filter_fields: None,
+            context_filter: None,
+            rate_limit: None,
+            repeats: None,
+            level_map: Vec::new(),
+        };
+        let id = entry.id;
+        self.handlers.write().push(entry);
+        self.changed();
+        id
+    }
+
+    /// Drop records below `level` whatever the handler levels (None removes the floor).
+    /// Shared with the `PyLogger` this logger views, if any.
+    pub fn set_global_level(&self, level: Option<LogLevel>) {
+        let floor = level.map_or(0, |level| level as u32);
+        self.global_level.store(floor, Ordering::Relaxed);
+        self.changed();
+    }
+
+    /// Remove a handler by id. Returns false if no handler has it.
+    pub fn remove(&self, id: u64) -> bool {
+        let removed = {
+            let mut handlers = self.handlers.write();
+            match handlers.iter().position(|entry| entry.id == id) {
+                Some(pos) => {
+                    let _ = handlers.remove(pos).flush_repeats();
+                    true
+                }
+                None => false,
+            }
+        };
+        if removed {
+            self.changed();
+        }
+        removed
+    }
+
+    fn changed(&self) {
+        if let Some(on_change) = &self.on_change {
+            on_change();
+        }
+    }
+
+    /// Logger sharing the handlers, with `fields` added to every record's extra
+    pub fn bind(&self, fields: impl IntoIterator<Item = (String, ExtraValue)>) -> Logger {
+        let mut context = (*self.context).clone();
+        context.extend(fields);
+        Logger {
+            context: Arc::new(context),
+            ..self.clone()
+        }
+    }
+
+    /// Like [`Logger::bind`], but keys that are already bound keep their value
+    pub fn bind_defaults(&self, fields: impl IntoIterator<Item = (String, ExtraValue)>) -> Logger {
+        let mut context = (*self.context).clone();
+        for (key, value) in fields {
+            context.entry(key).or_insert(value);
+        }
+        Logger {
+            context: Arc::new(context),
+            ..self.clone()
+        }
+    }
+
+    /// Log a message. The caller's file and line are recorded.
+    #[track_caller]
+    pub fn log(&self, level: LogLevel, message: impl Into<String>) {
+        let location = Location::caller();
+        if self.paused.load(Ordering::Relaxed)
+            || (level as u32) < self.global_level.load(Ordering::Relaxed)
+        {
+            return;
+        }
+        let mut record = LogRecord::with_extra(level, message.into(), Arc::clone(&self.context));
+        record.caller = CallerInfo {
+            file: location.file().to_string(),
+            line: location.line(),
+            ..CallerInfo::default()
+        };
+        record.thread = ThreadInfo::current();
+        record.process = ProcessInfo::current();
+        record.seq = next_seq(&self.seq);
+        self.log_record(&record);
+    }
+
+    /// Write a prepared record to every handler whose level range (and filter) accepts it
+    pub fn log_record(&self, record: &LogRecord) {
+        let level_no = record.level_no();
+        if level_no < self.global_level.load(Ordering::Relaxed) {
+            return;
+        }
+        let handlers = self.handlers.read();
+        let filtered = handlers
+            .iter()
+            .any(|entry| entry.filter.is_some() && entry.accepts(level_no));
+        let mut formatted = FormattedLines::for_handlers(&handlers);
+        if !filtered {
+            for entry in handlers.iter().filter(|entry| entry.accepts(level_no)) {
+                let _ = entry.emit_shared(record, &mut formatted);
+            }
+            return;
+        }
+
+        // Filters added from Python see the same dict as for Python-logged records
+        Python::attach(|py| {
+            let mut dict = None;
+            for entry in handlers.iter().filter(|entry| entry.accepts(level_no)) {
+                let passes = handler_filter_passes(py, entry, record, || {
+                    lazy_record_dict(&mut dict, || {
+                        PyLogger::build_record_dict(py, record.level, record, RecordExtraView::Text)
+                    })
+                });
+                if passes {
+                    let _ = entry.emit_shared(record, &mut formatted);
+                }
+            }
+        });
+    }
+
+    #[track_caller]
+    pub fn trace(&self, message: impl Into<String>) {
+        self.log(LogLevel::Trace, message);
+    }
+
+    #[track_caller]
+    pub fn debug(&self, message: impl Into<String>) {
+        self.log(LogLevel::Debug, message);
+    }
+
+    #[track_caller]
+    pub fn info(&self, message: impl Into<String>) {
+        self.log(LogLevel::Info, message);
+    }
+
+    #[track_caller]
+    pub fn warning(&self, message: impl Into<String>) {
+        self.log(LogLevel::Warning, message);
+    }
+
+    #[track_caller]
+    pub fn error(&self, message: impl Into<String>) {
+        self.log(LogLevel::Error, message);
+    }
+
+    /// Write pending repeat summaries and flush every file and custom sink
+    pub fn complete(&self) -> io::Result<()> {
+        let handlers = self.handlers.read();
+        for entry in handlers.iter() {
+            entry.flush_repeats()?;
+        }
+        let sinks = file_sinks(handlers.iter());
+        let custom = custom_sinks(handlers.iter());
+        let console = enqueues_console(handlers.iter());
+        drop(handlers);
+        if console {
+            handler::flush_console_queue();
+        }
+        sinks.iter().try_for_each(FileSink::flush)?;
+        custom.iter().try_for_each(|sink| sink.flush())
+    }
+}
+
+// Logging can reach Python (filters), so the test binary must link libpython:
+// run with `cargo test --no-default-features`
+#[cfg(all(test, not(feature = "extension-module")))]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_log_to_file_with_native_types() {
+        let path = std::env::temp_dir().join(format!(
+            "logust-rust-logger-{}-{}.log",
+            std::process::id(),
+            handler::next_handler_id()
+        ));
+        let logger = Logger::new();
+        let id = logger
+            .add_file(
+                FileSinkConfig {
+                    path: path.clone(),
+                    ..FileSinkConfig::default()
+                },
+                LogLevel::Info,
+                FormatConfig::new(Some("{level} {message} {extra}".to_string()), false),
+            )
+            .unwrap();
+
+        let bound = logger.bind([("job".to_string(), ExtraValue::from("nightly"))]);
+        bound.debug("skipped");
+        bound.info("started");
+        logger.warning("unbound");
+        bound
+            .bind_defaults([("job".to_string(), ExtraValue::from("hourly"))])
+            .info("kept");
+        logger.complete().unwrap();
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "INFO started job=nightly\nWARNING unbound \nINFO kept job=nightly\n"
+        );
+
+        assert!(logger.remove(id));
+        assert!(!logger.remove(id));
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_global_level_drops_records_below_it() {
+        let path = std::env::temp_dir().join(format!(
+            "logust-rust-global-level-{}-{}.log",
+            std::process::id(),
+            handler::next_handler_id()
+        ));
+        let logger = Logger::new();
+        logger
+            .add_file(
+                FileSinkConfig {
+                    path: path.clone(),
+                    ..FileSinkConfig::default()
+                },
+                LogLevel::Debug,
+                FormatConfig::new(Some("{message}".to_string()), false),
+            )
+            .unwrap();
+
+        logger.set_global_level(Some(LogLevel::Warning));
+        logger.info("dropped");
+        logger.error("kept");
+        logger.set_global_level(None);
+        logger.debug("back");
+        logger.complete().unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "kept\nback\n");
+        let _ = fs::remove_file(&path);
+    }
+}
@@ -0,0 +1,143 @@
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use chrono::{DateTime, Local};
+
+use crate::clock;
+
+/// Token bucket limiting how many records a handler writes per period.
+/// Bursts up to the full count are allowed; tokens refill evenly over the period.
+pub struct RateLimiter {
+    capacity: f64,
+    refill_per_sec: f64,
+    bucket: Mutex<Bucket>,
+    suppressed: AtomicU64,
+}
+
+struct Bucket {
+    tokens: f64,
+    last_refill: DateTime<Local>,
+}
+
+impl RateLimiter {
+    /// Allow `count` records every `period_secs` seconds
+    pub fn new(count: u32, period_secs: f64) -> Self {
+        let capacity = count as f64;
+        Self {
+            capacity,
+            refill_per_sec: capacity / period_secs,
+            bucket: Mutex::new(Bucket {
+                tokens: capacity,
+                last_refill: clock::now(),
+            }),
+            suppressed: AtomicU64::new(0),
+        }
+    }
+
+    /// Parse a limit like "100/s", "10/min" or "5000/hour"
+    pub fn parse(spec: &str) -> Option<Self> {
+        let (count, unit) = spec.split_once('/')?;
+        let count: u32 = count.trim().parse().ok().filter(|&n| n > 0)?;
+        let period_secs = match unit.trim().to_lowercase().as_str() {
+            "s" | "sec" | "second" => 1.0,
+            "m" | "min" | "minute" => 60.0,
+            "h" | "hour" => 3600.0,
+            _ => return None,
+        };
+        Some(Self::new(count, period_secs))
+    }
+
+    /// Take a token; records refused here are counted as suppressed
+    pub fn try_acquire(&self) -> bool {
+        let now = clock::now();
+        let mut bucket = self.bucket.lock().unwrap_or_else(|e| e.into_inner());
+        // A wall clock moving backwards refills nothing rather than draining the bucket
+        let elapsed = (now - bucket.last_refill)
+            .to_std()
+            .map(|d| d.as_secs_f64())
+            .unwrap_or(0.0);
+        bucket.tokens = (bucket.tokens + elapsed * self.refill_per_sec).min(self.capacity);
+        bucket.last_refill = now;
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            true
+        } else {
+            drop(bucket);
+            self.suppressed.fetch_add(1, Ordering::Relaxed);
+            false
+        }
+    }
+
+    /// Same limit with a full bucket and nothing suppressed yet
+    pub fn fresh_copy(&self) -> Self {
+        Self {
+            capacity: self.capacity,
+            refill_per_sec: self.refill_per_sec,
+            bucket: Mutex::new(Bucket {
+                tokens: self.capacity,
+                last_refill: clock::now(),
+            }),
+            suppressed: AtomicU64::new(0),
+        }
+    }
+
+    /// Number of records refused so far
+    pub fn suppressed(&self) -> u64 {
+        self.suppressed.load(Ordering::Relaxed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::test_clock;
+    use chrono::{Duration, TimeZone};
+
+    fn pin_clock() -> test_clock::ClockGuard {
+        test_clock::set(Local.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap())
+    }
+
+    #[test]
+    fn test_parse_rate_limit() {
+        assert_eq!(RateLimiter::parse("100/s").unwrap().capacity, 100.0);
+        assert_eq!(
+            RateLimiter::parse(" 10 / min ").unwrap().refill_per_sec,
+            10.0 / 60.0
+        );
+        assert_eq!(RateLimiter::parse("3600/hour").unwrap().refill_per_sec, 1.0);
+        assert!(RateLimiter::parse("0/s").is_none());
+        assert!(RateLimiter::parse("100").is_none());
+        assert!(RateLimiter::parse("100/day").is_none());
+        assert!(RateLimiter::parse("x/s").is_none());
+    }
+
+    #[test]
+    fn test_bucket_allows_burst_then_suppresses() {
+        let _clock = pin_clock();
+        let limiter = RateLimiter::new(3, 1.0);
+
+        let admitted = (0..5).filter(|_| limiter.try_acquire()).count();
+        assert_eq!(admitted, 3);
+        assert_eq!(limiter.suppressed(), 2);
+    }
+
+    #[test]
+    fn test_bucket_refills_over_time() {
+        let _clock = pin_clock();
+        let limiter = RateLimiter::new(2, 1.0);
+        assert!(limiter.try_acquire());
+        assert!(limiter.try_acquire());
+        assert!(!limiter.try_acquire());
+
+        test_clock::advance(Duration::milliseconds(500));
+        assert!(limiter.try_acquire());
+        assert!(!limiter.try_acquire());
+
+        // Refill is capped at the burst size
+        test_clock::advance(Duration::seconds(10));
+        let admitted = (0..5).filter(|_| limiter.try_acquire()).count();
+        assert_eq!(admitted, 2);
+        assert_eq!(limiter.suppressed(), 5);
+    }
+}
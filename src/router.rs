@@ -0,0 +1,301 @@
+use std::collections::{HashMap, HashSet};
+use std::io;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use crate::handler::{ExtraMap, ExtraValue};
+use crate::sink::{FileSink, FileSinkConfig};
+
+/// Default cap on files a routed handler keeps open at once
+pub const DEFAULT_MAX_OPEN_FILES: usize = 64;
+
+/// Piece of a routed path template
+#[derive(Debug, PartialEq)]
+enum PathSegment {
+    Literal(String),
+    /// `{extra[key]}` placeholder
+    Extra(String),
+}
+
+/// File sinks chosen per record from a path template such as `logs/{extra[tenant]}/app.log`.
+/// Sinks are opened on first use and the least recently used one is closed once more than
+/// `max_open` files would be open.
+pub struct SinkRouter {
+    template: String,
+    segments: Vec<PathSegment>,
+    /// Options shared by every routed file (`path` is replaced per route)
+    config: FileSinkConfig,
+    max_open: usize,
+    state: Mutex<RouterState>,
+}
+
+#[derive(Default)]
+struct RouterState {
+    sinks: HashMap<PathBuf, OpenSink>,
+    /// Monotonic use counter for LRU eviction
+    tick: u64,
+    /// Routes opened so far, with `truncate`: only the first open empties a file, not a
+    /// reopen after eviction
+    opened: HashSet<PathBuf>,
+}
+
+struct OpenSink {
+    sink: FileSink,
+    last_used: u64,
+}
+
+impl SinkRouter {
+    /// Check whether a file path contains `{extra[...]}` routing placeholders
+    pub fn is_template(path: &str) -> bool {
+        parse_path_template(path)
+            .iter()
+            .any(|s| matches!(s, PathSegment::Extra(_)))
+    }
+
+    pub fn new(template: String, config: FileSinkConfig, max_open: usize) -> Self {
+        SinkRouter {
+            segments: parse_path_template(&template),
+            template,
+            config,
+            max_open: max_open.max(1),
+            state: Mutex::new(RouterState::default()),
+        }
+    }
+
+    /// Router with the same template and options and no files open yet. Files it opens
+    /// are appended to, even with `truncate`.
+    pub fn fresh_copy(&self) -> Self {
+        SinkRouter::new(
+            self.template.clone(),
+            FileSinkConfig {
+                truncate: false,
+                ..self.config.clone()
+            },
+            self.max_open,
+        )
+    }
+
+    /// The unresolved path template
+    pub fn template(&self) -> &str {
+        &self.template
+    }
+
+    /// Resolve the template against a record's extra fields
+    fn resolve(&self, extra: &ExtraMap) -> PathBuf {
+        let mut path = String::new();
+        for segment in &self.segments {
+            match segment {
+                PathSegment::Literal(text) => path.push_str(text),
+                PathSegment::Extra(key) => path.push_str(&path_component(extra.get(key))),
+            }
+        }
+        PathBuf::from(path)
+    }
+
+    /// Write a formatted record to the file its extra fields route to
+    pub fn write_owned(&self, extra: &ExtraMap, message: String) -> io::Result<()> {
+        self.write_with_end(extra, message, None)
+    }
+
+    /// Like [`SinkRouter::write_owned`], with `end` in place of the line terminator
+    pub fn write_with_end(
+        &self,
+        extra: &ExtraMap,
+        message: String,
+        end: Option<&str>,
+    ) -> io::Result<()> {
+        self.sink_for(extra)?.write_with_end(message, end)
+    }
+
+    /// Sink for a record, opening it (and closing the least recently used one) if needed
+    fn sink_for(&self, extra: &ExtraMap) -> io::Result<FileSink> {
+        let path = self.resolve(extra);
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        state.tick += 1;
+        let tick = state.tick;
+
+        if let Some(open) = state.sinks.get_mut(&path) {
+            open.last_used = tick;
+            return Ok(open.sink.clone());
+        }
+
+        let truncate = self.config.truncate && state.opened.insert(path.clone());
+        let sink = FileSink::new(FileSinkConfig {
+            path: path.clone(),
+            truncate,
+            ..self.config.clone()
+        })?;
+        let evicted = if state.sinks.len() >= self.max_open {
+            let lru = state
+                .sinks
+                .iter()
+                .min_by_key(|(_, open)| open.last_used)
+                .map(|(path, _)| path.clone());
+            lru.and_then(|lru| state.sinks.remove(&lru))
+        } else {
+            None
+        };
+        state.sinks.insert(
+            path,
+            OpenSink {
+                sink: sink.clone(),
+                last_used: tick,
+            },
+        );
+        drop(state);
+
+        // Closing flushes (and joins an async writer), so do it outside the lock
+        drop(evicted);
+        Ok(sink)
+    }
+
+    /// Currently open sinks (for `complete()`)
+    pub fn sinks(&self) -> Vec<FileSink> {
+        let state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        state.sinks.values().map(|open| open.sink.clone()).collect()
+    }
+}
+
+/// Split a path template into literals and `{extra[key]}` placeholders
+/// (any other braces are kept as literal text).
+fn parse_path_template(template: &str) -> Vec<PathSegment> {
+    let mut segments = Vec::new();
+    let mut rest = template;
+    let mut literal = String::new();
+
+    while let Some(start) = rest.find("{extra[") {
+        let after = &rest[start + "{extra[".len()..];
+        let Some(end) = after.find("]}") else {
+            break;
+        };
+        literal.push_str(&rest[..start]);
+        if !literal.is_empty() {
+            segments.push(PathSegment::Literal(std::mem::take(&mut literal)));
+        }
+        segments.push(PathSegment::Extra(after[..end].to_string()));
+        rest = &after[end + "]}".len()..];
+    }
+
+    literal.push_str(rest);
+    if !literal.is_empty() {
+        segments.push(PathSegment::Literal(literal));
+    }
+    segments
+}
+
+/// Render an extra value as a single path component. Separators are replaced so a value
+/// cannot escape the template's directory; missing, empty and dot values become `_`.
+fn path_component(value: Option<&ExtraValue>) -> String {
+    let cleaned: String = value
+        .map(ExtraValue::as_str)
+        .unwrap_or("")
+        .chars()
+        .map(|c| {
+            if matches!(c, '/' | '\\' | '\0') {
+                '_'
+            } else {
+                c
+            }
+        })
+        .collect();
+    match cleaned.as_str() {
+        "" | "." | ".." => "_".to_string(),
+        _ => cleaned,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    fn unique_temp_dir(prefix: &str) -> PathBuf {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        std::env::temp_dir().join(format!("logust-{prefix}-{}-{nanos}", std::process::id()))
+    }
+
+    fn extra(pairs: &[(&str, &str)]) -> ExtraMap {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), ExtraValue::from(*v)))
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_path_template() {
+        assert_eq!(
+            parse_path_template("logs/{extra[tenant]}/{extra[app]}.log"),
+            vec![
+                PathSegment::Literal("logs/".to_string()),
+                PathSegment::Extra("tenant".to_string()),
+                PathSegment::Literal("/".to_string()),
+                PathSegment::Extra("app".to_string()),
+                PathSegment::Literal(".log".to_string()),
+            ]
+        );
+        assert!(SinkRouter::is_template("{extra[tenant]}.log"));
+        assert!(!SinkRouter::is_template("logs/{time}.log"));
+        assert!(!SinkRouter::is_template("logs/{extra[tenant.log"));
+    }
+
+    #[test]
+    fn test_resolve_sanitizes_values() {
+        let router = SinkRouter::new(
+            "logs/{extra[tenant]}/app.log".to_string(),
+            FileSinkConfig::default(),
+            DEFAULT_MAX_OPEN_FILES,
+        );
+        let resolve = |pairs: &[(&str, &str)]| router.resolve(&extra(pairs));
+
+        assert_eq!(
+            resolve(&[("tenant", "acme")]),
+            PathBuf::from("logs/acme/app.log")
+        );
+        assert_eq!(
+            resolve(&[("tenant", "../etc")]),
+            PathBuf::from("logs/.._etc/app.log")
+        );
+        assert_eq!(
+            resolve(&[("tenant", "..")]),
+            PathBuf::from("logs/_/app.log")
+        );
+        assert_eq!(resolve(&[]), PathBuf::from("logs/_/app.log"));
+    }
+
+    #[test]
+    fn test_least_recently_used_sink_is_closed() {
+        let dir = unique_temp_dir("router-lru");
+        let router = SinkRouter::new(
+            format!("{}/{{extra[tenant]}}.log", dir.display()),
+            FileSinkConfig::default(),
+            2,
+        );
+
+        for tenant in ["a", "b", "a", "c"] {
+            router
+                .write_owned(&extra(&[("tenant", tenant)]), format!("to {tenant}"))
+                .unwrap();
+        }
+
+        let mut open: Vec<_> = router
+            .sinks()
+            .iter()
+            .map(|s| s.path().to_path_buf())
+            .collect();
+        open.sort();
+        assert_eq!(open, vec![dir.join("a.log"), dir.join("c.log")]);
+        // The evicted sink was flushed when it was closed
+        assert_eq!(fs::read_to_string(dir.join("b.log")).unwrap(), "to b\n");
+
+        drop(router);
+        assert_eq!(
+            fs::read_to_string(dir.join("a.log")).unwrap(),
+            "to a\nto a\n"
+        );
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
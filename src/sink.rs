@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::fs::{self, File, OpenOptions};
 use std::io::{self, BufWriter, Write};
 #[cfg(unix)]
@@ -16,8 +17,8 @@ use std::sync::{LazyLock, OnceLock, Weak};
 use std::thread::{self, JoinHandle};
 use std::time::{Duration, Instant};
 
-use chrono::{DateTime, Local, Timelike};
-use crossbeam_channel::{RecvTimeoutError, Sender, bounded};
+use chrono::{DateTime, Local, TimeZone, Timelike};
+use crossbeam_channel::{Receiver, RecvTimeoutError, Sender, bounded, unbounded};
 use flate2::Compression;
 use flate2::write::GzEncoder;
 use pyo3::prelude::*;
@@ -31,12 +32,24 @@ use windows_sys::Win32::Storage::FileSystem::{
 #[cfg(windows)]
 use windows_sys::Win32::System::IO::OVERLAPPED;
 
+use crate::clock;
+
 /// Capacity of the async message queue
 const ASYNC_QUEUE_CAPACITY: usize = 10_000;
 
 /// Flush interval for async writer in milliseconds
 const ASYNC_FLUSH_INTERVAL_MS: u64 = 100;
 
+/// Retries after a transient write error before the record is dropped (off by default:
+/// on the synchronous path a retry blocks the logging call)
+const DEFAULT_WRITE_RETRIES: u32 = 0;
+
+/// Wait before the first retry in milliseconds, doubled for each further one
+const DEFAULT_WRITE_RETRY_BACKOFF_MS: u64 = 10;
+
+/// Days per retention unit: a month counts as 30 days and a year as 365
+const RETENTION_UNITS: [(&str, u32); 4] = [("day", 1), ("week", 7), ("month", 30), ("year", 365)];
+
 /// Size unit multipliers for parsing size strings
 const KB: u64 = 1024;
 const MB: u64 = KB * 1024;
@@ -71,6 +84,9 @@ pub enum RetentionPolicy {
     Forever = 0,
 }
 
+/// Called with the path of each rotated file (the `.gz` archive when compressed)
+pub type RotationCallback = Arc<dyn Fn(&Path) + Send + Sync>;
+
 /// File sink configuration
 #[derive(Clone)]
 pub struct FileSinkConfig {
@@ -83,6 +99,39 @@ pub struct FileSinkConfig {
     /// If true, writes are queued and processed asynchronously (thread-safe)
     /// If false, writes are synchronous (faster for single-threaded use)
     pub enqueue: bool,
+    /// Reopen the file when the path is deleted or replaced externally (e.g. logrotate)
+    pub watch: bool,
+    /// Appended after every record (`"\n"` by default; e.g. `"\r\n"` or `"\0"`)
+    pub line_terminator: String,
+    /// Flush after every record instead of on buffer-full / every 100ms (async).
+    /// Keeps the tail of the log on a crash at the cost of one write syscall per record.
+    pub autoflush: bool,
+    /// `fsync` the file after flushing so records reach stable storage: after every record
+    /// with `autoflush`, otherwise on `flush()`/`complete()`, before rotation and on drop.
+    pub fsync: bool,
+    /// Written (with the line terminator) before the first record of a new or empty file,
+    /// including the fresh file started by each rotation
+    pub header: Option<String>,
+    /// Empty an existing file when the sink is created (`mode="w"`). Files reopened after
+    /// rotation or by `watch` are still appended to.
+    pub truncate: bool,
+    /// Retries after a transient write error (interrupted, would block or timed out, e.g. on
+    /// a network mount) before the record is dropped and counted in
+    /// [`FileSinkStats::write_failures`]. Other errors, like a full disk, are not retried.
+    pub write_retries: u32,
+    /// Wait before the first retry, doubled for each further one
+    pub write_retry_backoff: Duration,
+    /// With `enqueue`, queue records to one writer thread shared by every such sink instead
+    /// of a thread per sink
+    pub shared_writer: bool,
+    /// Called on the compression thread once a rotated file is renamed (and compressed)
+    pub on_rotation: Option<RotationCallback>,
+    /// With `compression`, also gzip the active file when the sink is dropped or its writer
+    /// is stopped (at interpreter exit), for files that never rotate
+    pub compress_on_close: bool,
+    /// With `enqueue`, bytes the queue may hold before writers wait for the writer thread
+    /// (on top of the message count cap)
+    pub max_queue_bytes: Option<u64>,
 }
 
 impl Default for FileSinkConfig {
@@ -95,14 +144,296 @@ impl Default for FileSinkConfig {
             retention_count: None,
             compression: false,
             enqueue: false,
+            watch: false,
+            line_terminator: "\n".to_string(),
+            autoflush: false,
+            fsync: false,
+            header: None,
+            truncate: false,
+            write_retries: DEFAULT_WRITE_RETRIES,
+            write_retry_backoff: Duration::from_millis(DEFAULT_WRITE_RETRY_BACKOFF_MS),
+            shared_writer: false,
+            on_rotation: None,
+            compress_on_close: false,
+            max_queue_bytes: None,
+        }
+    }
+}
+
+/// Retry policy for record writes, shared with the async writer thread
+#[derive(Clone, Default)]
+struct WriteRetry {
+    retries: u32,
+    backoff: Duration,
+    /// Records dropped because the last retry failed too
+    failures: Arc<AtomicU64>,
+}
+
+impl WriteRetry {
+    fn new(config: &FileSinkConfig) -> Self {
+        WriteRetry {
+            retries: config.write_retries,
+            backoff: config.write_retry_backoff,
+            failures: Arc::new(AtomicU64::new(0)),
+        }
+    }
+
+    /// Run `write` until it succeeds, fails with an error that is not transient, or the
+    /// retries are used up (then counted as a failure)
+    fn run<T>(&self, mut write: impl FnMut() -> io::Result<T>) -> io::Result<T> {
+        let mut backoff = self.backoff;
+        let mut retries = self.retries;
+        loop {
+            match write() {
+                Ok(value) => return Ok(value),
+                Err(err) if retries > 0 && is_transient(&err) => {
+                    thread::sleep(backoff);
+                    backoff = backoff.saturating_mul(2);
+                    retries -= 1;
+                }
+                Err(err) => {
+                    self.failures.fetch_add(1, Ordering::Relaxed);
+                    return Err(err);
+                }
+            }
         }
     }
 }
 
-/// Async message for file writer thread
+/// Errors a retry can fix; anything else (a full disk, a permission error) fails the same
+/// way again
+fn is_transient(err: &io::Error) -> bool {
+    matches!(
+        err.kind(),
+        io::ErrorKind::Interrupted | io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut
+    )
+}
+
+/// Buffer `message` and flush it, retrying each step on its own: the buffer keeps a record
+/// whose flush failed, so writing it again would duplicate it
+fn write_and_flush(writer: &mut impl Write, message: &str, retry: &WriteRetry) -> io::Result<()> {
+    retry.run(|| writer.write_all(message.as_bytes()))?;
+    retry.run(|| writer.flush())
+}
+
+/// Async message for file writer thread, addressed to one of its targets
 enum WriterMessage {
+    /// Start serving a sink (sinks on the shared writer thread send this first)
+    Open(Box<AsyncTarget>),
     Write(String),
-    Flush { ack: Sender<()> },
+    Flush {
+        ack: Sender<()>,
+    },
+    /// Flush and drop the target's file, then acknowledge
+    Close {
+        ack: Sender<()>,
+    },
+}
+
+/// Queue of a writer thread, tagging messages with the sink's target id
+#[derive(Clone)]
+struct TargetSender {
+    sender: Sender<(u64, WriterMessage)>,
+    id: u64,
+}
+
+impl TargetSender {
+    /// Queue a message; gives it back if the writer thread has stopped
+    fn send(&self, message: WriterMessage) -> Result<(), WriterMessage> {
+        self.sender
+            .send((self.id, message))
+            .map_err(|err| err.into_inner().1)
+    }
+}
+
+/// Ids telling apart the sinks served by one writer thread
+static NEXT_WRITER_TARGET: AtomicU64 = AtomicU64::new(1);
+
+/// Bytes queued for an async writer thread, shared with it; capped by `max_queue_bytes`
+#[derive(Clone, Default)]
+struct QueueBudget {
+    limit: Option<u64>,
+    bytes: Arc<AtomicU64>,
+}
+
+impl QueueBudget {
+    fn new(config: &FileSinkConfig) -> Self {
+        QueueBudget {
+            limit: config.max_queue_bytes,
+            bytes: Arc::new(AtomicU64::new(0)),
+        }
+    }
+
+    /// Count `len` more queued bytes, first waiting while they would exceed the limit. A
+    /// record larger than the whole limit waits for an empty queue. Writers racing past the
+    /// check together can overshoot the limit by their own records.
+    fn acquire(&self, len: u64) {
+        if let Some(limit) = self.limit {
+            loop {
+                let queued = self.bytes.load(Ordering::Acquire);
+                if queued == 0 || queued + len <= limit {
+                    break;
+                }
+                thread::sleep(Duration::from_millis(1));
+            }
+        }
+        self.bytes.fetch_add(len, Ordering::AcqRel);
+    }
+
+    /// Stop counting `len` bytes (written, or never queued)
+    fn release(&self, len: u64) {
+        let _ = self
+            .bytes
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |queued| {
+                Some(queued.saturating_sub(len))
+            });
+    }
+}
+
+/// Writer thread serving every `shared_writer` sink of this process
+struct SharedWriter {
+    sender: Sender<(u64, WriterMessage)>,
+    handle: JoinHandle<()>,
+    /// Process that spawned the thread (a forked child spawns its own)
+    pid: u32,
+}
+
+static SHARED_WRITER: StdMutex<Option<SharedWriter>> = StdMutex::new(None);
+
+/// Queue of the shared writer thread, started on first use (or if it has stopped)
+fn shared_writer_sender() -> Sender<(u64, WriterMessage)> {
+    let mut shared = SHARED_WRITER.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(writer) = shared.as_ref()
+        && writer.pid == std::process::id()
+        && !writer.handle.is_finished()
+    {
+        return writer.sender.clone();
+    }
+    if let Some(stale) = shared.take() {
+        std::mem::forget(stale.handle);
+    }
+
+    let (sender, receiver) = bounded(ASYNC_QUEUE_CAPACITY);
+    let handle = thread::spawn(move || run_async_writer(receiver, HashMap::new()));
+    *shared = Some(SharedWriter {
+        sender: sender.clone(),
+        handle,
+        pid: std::process::id(),
+    });
+    sender
+}
+
+/// A sink's file as written by an async writer thread
+struct AsyncTarget {
+    path: PathBuf,
+    writer: RotatingFileWriter,
+    coordinate_rotation: bool,
+    autoflush: bool,
+    fsync: bool,
+    write_retry: WriteRetry,
+    queue_budget: QueueBudget,
+    /// Rotation lock held while a batch of coordinated writes is buffered
+    batch_lock: Option<FileLockGuard>,
+}
+
+impl AsyncTarget {
+    fn write(&mut self, msg: &str) {
+        let mut result = self.write_retry.run(|| {
+            if self.coordinate_rotation {
+                self.writer
+                    .write_line_buffered(&self.path, msg, &mut self.batch_lock)
+            } else {
+                self.writer.write_line_unlocked(msg)
+            }
+        });
+
+        if result.is_ok() && self.autoflush {
+            // The record is buffered now, so only the flush is retried
+            result = self.write_retry.run(|| {
+                if self.coordinate_rotation {
+                    self.writer.flush_buffered(&self.path, &mut self.batch_lock)
+                } else {
+                    self.writer.flush_without_lock()
+                }
+            });
+            if self.fsync {
+                let _ = self.writer.sync_all();
+            }
+        }
+
+        if let Err(err) = result {
+            eprintln!("Failed to write to log: {}", err);
+        }
+    }
+
+    /// Flush buffered records (and fsync if configured)
+    fn flush(&mut self) {
+        let _ = if self.coordinate_rotation {
+            self.writer.flush_buffered(&self.path, &mut self.batch_lock)
+        } else {
+            self.writer.flush_without_lock()
+        };
+        if self.fsync {
+            let _ = self.writer.sync_all();
+        }
+    }
+
+    /// Flush every `ASYNC_FLUSH_INTERVAL_MS`, releasing the rotation lock of a batch
+    fn periodic_flush(&mut self) {
+        if !self.coordinate_rotation {
+            let _ = self.writer.flush_without_lock();
+        } else if self.batch_lock.is_some() {
+            let _ = self.writer.flush_buffered(&self.path, &mut self.batch_lock);
+        }
+    }
+}
+
+/// Writer thread loop. Records are buffered per target and flushed every
+/// `ASYNC_FLUSH_INTERVAL_MS`, on `Flush`/`Close`, and for every target once all senders
+/// are gone.
+fn run_async_writer(
+    receiver: Receiver<(u64, WriterMessage)>,
+    mut targets: HashMap<u64, AsyncTarget>,
+) {
+    let flush_interval = Duration::from_millis(ASYNC_FLUSH_INTERVAL_MS);
+    let mut last_flush = Instant::now();
+
+    loop {
+        let timeout = flush_interval.saturating_sub(last_flush.elapsed());
+        match receiver.recv_timeout(timeout) {
+            Ok((id, WriterMessage::Open(target))) => {
+                targets.insert(id, *target);
+            }
+            Ok((id, WriterMessage::Write(msg))) => {
+                if let Some(target) = targets.get_mut(&id) {
+                    target.write(&msg);
+                    target.queue_budget.release(msg.len() as u64);
+                }
+            }
+            Ok((id, WriterMessage::Flush { ack })) => {
+                if let Some(target) = targets.get_mut(&id) {
+                    target.flush();
+                }
+                let _ = ack.send(());
+            }
+            Ok((id, WriterMessage::Close { ack })) => {
+                if let Some(mut target) = targets.remove(&id) {
+                    target.flush();
+                }
+                let _ = ack.send(());
+            }
+            Err(RecvTimeoutError::Timeout) => {}
+            Err(RecvTimeoutError::Disconnected) => {
+                targets.values_mut().for_each(AsyncTarget::flush);
+                break;
+            }
+        }
+
+        if last_flush.elapsed() >= flush_interval {
+            targets.values_mut().for_each(AsyncTarget::periodic_flush);
+            last_flush = Instant::now();
+        }
+    }
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -394,14 +725,14 @@ impl RotatingFileWriter {
         writer
     }
 
-    fn write_line(&mut self, path: &Path, message: &str) -> io::Result<()> {
-        let _lock = self.acquire_shared_lock(path)?;
-        writeln!(self.writer, "{}", message)?;
-        self.writer.flush()
+    /// `message` already ends with the sink's line terminator
+    fn write_line(&mut self, path: &Path, message: &str, retry: &WriteRetry) -> io::Result<()> {
+        let _lock = retry.run(|| self.acquire_shared_lock(path))?;
+        write_and_flush(&mut self.writer, message, retry)
     }
 
     fn write_line_unlocked(&mut self, message: &str) -> io::Result<()> {
-        writeln!(self.writer, "{}", message)
+        self.writer.write_all(message.as_bytes())
     }
 
     fn write_line_buffered(
@@ -414,7 +745,7 @@ impl RotatingFileWriter {
             *batch_lock = Some(self.acquire_shared_lock(path)?);
         }
 
-        writeln!(self.writer, "{}", message)
+        self.writer.write_all(message.as_bytes())
     }
 
     fn flush_buffered(
@@ -448,6 +779,11 @@ impl RotatingFileWriter {
         self.writer.flush()
     }
 
+    /// Push flushed data to stable storage (`fsync`); call after a flush
+    fn sync_all(&self) -> io::Result<()> {
+        self.writer.get_ref().sync_all()
+    }
+
     fn reopen_if_rotated(&mut self, path: &Path) -> io::Result<bool> {
         #[cfg(any(unix, windows))]
         {
@@ -482,9 +818,12 @@ impl RotatingFileWriter {
 }
 
 struct AsyncWriterState {
-    sender: Option<Sender<WriterMessage>>,
+    sender: Option<TargetSender>,
+    /// The sink's own writer thread (`None` on the shared writer thread)
     handle: Option<JoinHandle<()>>,
     file_identity: Arc<SharedFileIdentity>,
+    /// Served by the shared writer thread (`FileSinkConfig::shared_writer`)
+    shared: bool,
 }
 
 struct SyncWriterState {
@@ -503,6 +842,34 @@ struct FileSinkState {
     backend: WriterBackend,
 }
 
+/// Work queued by rotation for the compression thread
+enum CompressionJob {
+    /// Gzip a rotated file, then apply retention
+    Compress(PathBuf),
+    /// Pass an uncompressed rotated file to `on_rotation`
+    Notify(PathBuf),
+    /// Acknowledge once every earlier job has finished
+    Barrier(Sender<()>),
+}
+
+/// Background thread that gzips rotated files so `rotate()` returns right after the rename.
+/// Jobs run one at a time, and retention only runs after the gzip before it has finished.
+struct CompressionWorker {
+    sender: Option<Sender<CompressionJob>>,
+    handle: Option<JoinHandle<()>>,
+    /// Process that spawned the thread (a forked child spawns its own)
+    pid: u32,
+}
+
+/// Rotated files the compression thread could not gzip: they are queued again on the next
+/// rotation, and the error is returned by the next `flush()`
+#[derive(Default)]
+struct FailedCompressions {
+    paths: Vec<PathBuf>,
+    /// First error not yet returned by `flush()`
+    error: Option<io::Error>,
+}
+
 #[derive(Clone)]
 struct PendingRotation {
     rotated_path: PathBuf,
@@ -523,16 +890,50 @@ struct FileSinkInner {
     /// PID of the process that currently owns the live backend.
     /// Child processes created via fork() lazily reopen/recreate the backend on first use.
     creation_pid: AtomicU32,
+    /// Process that created the sink: forked children writing the same file never
+    /// compress it on close
+    owner_pid: u32,
     pending_rotation: StdMutex<Option<PendingRotation>>,
     pending_rotation_active: AtomicBool,
+    /// Started on the first rotation that needs compression or `on_rotation`
+    compression_worker: StdMutex<Option<CompressionWorker>>,
+    /// Shared with the compression thread
+    failed_compressions: Arc<StdMutex<FailedCompressions>>,
+    write_retry: WriteRetry,
+    queue_budget: QueueBudget,
+    /// Files rotated away by this sink
+    rotations: AtomicU64,
+}
+
+/// Snapshot of a file sink's size and rotation state
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileSinkStats {
+    /// Bytes in the active file, counting records still queued for the async writer
+    pub size: u64,
+    /// When the active file was started, or last rotated
+    pub file_time: DateTime<Local>,
+    /// Next time-based rotation, if the rotation policy has one
+    pub next_rotation: Option<DateTime<Local>>,
+    /// Records dropped because writing them failed (after any retries)
+    pub write_failures: u64,
+    /// Rotations since the sink was opened
+    pub rotations: u64,
+    /// Bytes queued for the async writer and not yet written
+    pub queued_bytes: u64,
 }
 
 /// File sink with optional async writing support
+#[derive(Clone)]
 pub struct FileSink {
     inner: Arc<FileSinkInner>,
 }
 
 impl FileSink {
+    /// Path of the active log file
+    pub fn path(&self) -> &Path {
+        &self.inner.config.path
+    }
+
     /// Create a new file sink
     pub fn new(config: FileSinkConfig) -> io::Result<Self> {
         let path = config.path.clone();
@@ -543,17 +944,29 @@ impl FileSink {
             fs::create_dir_all(parent)?;
         }
 
-        let current_size = fs::metadata(&path).map(|m| m.len()).unwrap_or(0);
+        let current_size = if config.truncate {
+            File::create(&path)?;
+            0
+        } else {
+            fs::metadata(&path).map(|m| m.len()).unwrap_or(0)
+        };
 
         #[cfg(unix)]
         if config.enqueue {
             ensure_atfork_registered()?;
         }
 
+        let write_retry = WriteRetry::new(&config);
+        let queue_budget = QueueBudget::new(&config);
         let backend = if config.enqueue {
             WriterBackend::Async(FileSinkInner::create_async_writer_state(
                 &path,
                 FileSinkInner::rotation_coordination_enabled_for_config(&config),
+                config.autoflush,
+                config.fsync,
+                write_retry.clone(),
+                queue_budget.clone(),
+                config.shared_writer,
             )?)
         } else {
             WriterBackend::Sync(SyncWriterState {
@@ -561,7 +974,7 @@ impl FileSink {
             })
         };
 
-        let now = Local::now();
+        let now = clock::now();
         let next_boundary = FileSinkInner::calculate_next_rotation_boundary(&config.rotation, &now);
 
         let inner = Arc::new(FileSinkInner {
@@ -573,8 +986,14 @@ impl FileSink {
                 next_boundary.map(|b| b.timestamp_millis()).unwrap_or(0),
             ),
             creation_pid: AtomicU32::new(std::process::id()),
+            owner_pid: std::process::id(),
             pending_rotation: StdMutex::new(None),
             pending_rotation_active: AtomicBool::new(false),
+            compression_worker: StdMutex::new(None),
+            failed_compressions: Arc::default(),
+            write_retry,
+            queue_budget,
+            rotations: AtomicU64::new(0),
         });
 
         #[cfg(unix)]
@@ -594,18 +1013,53 @@ impl FileSink {
     /// Write a message to the file (takes ownership, avoids clone in async mode)
     #[inline]
     pub fn write_owned(&self, message: String) -> io::Result<()> {
-        self.inner.write_owned(message)
+        self.inner.write_owned(message, None)
+    }
+
+    /// Write a message followed by `end` instead of the line terminator (None keeps it)
+    #[inline]
+    pub fn write_with_end(&self, message: String, end: Option<&str>) -> io::Result<()> {
+        self.inner.write_owned(message, end)
     }
 
     /// Flush pending writes
     pub fn flush(&self) -> io::Result<()> {
         self.inner.flush()
     }
+
+    /// Flush, then stop and join the `enqueue=True` writer thread (for interpreter shutdown).
+    /// A later write starts a new writer thread.
+    pub fn stop_writer(&self) -> io::Result<()> {
+        self.inner.flush()?;
+        self.inner.stop_writer();
+        Ok(())
+    }
+
+    /// Current size and rotation boundary (no filesystem access)
+    pub fn stats(&self) -> FileSinkStats {
+        let boundary_millis = self.inner.next_rotation_boundary.load(Ordering::Relaxed);
+        FileSinkStats {
+            size: self.inner.current_size.load(Ordering::Relaxed),
+            file_time: *self
+                .inner
+                .current_file_time
+                .lock()
+                .unwrap_or_else(|e| e.into_inner()),
+            next_rotation: (boundary_millis > 0)
+                .then(|| Local.timestamp_millis_opt(boundary_millis).single())
+                .flatten(),
+            write_failures: self.inner.write_retry.failures.load(Ordering::Relaxed),
+            rotations: self.inner.rotations.load(Ordering::Relaxed),
+            queued_bytes: self.inner.queue_budget.bytes.load(Ordering::Relaxed),
+        }
+    }
 }
 
 impl FileSinkInner {
+    /// Coordinated sinks check the path's identity under the rotation lock and reopen
+    /// the file when it changed, which is also what `watch` needs.
     fn rotation_coordination_enabled_for_config(config: &FileSinkConfig) -> bool {
-        config.rotation != Rotation::Never || config.max_size.is_some()
+        config.rotation != Rotation::Never || config.max_size.is_some() || config.watch
     }
 
     fn rotation_coordination_enabled(&self) -> bool {
@@ -640,98 +1094,70 @@ impl FileSinkInner {
     fn create_async_writer_state(
         path: &Path,
         coordinate_rotation: bool,
+        autoflush: bool,
+        fsync: bool,
+        write_retry: WriteRetry,
+        queue_budget: QueueBudget,
+        shared: bool,
     ) -> io::Result<AsyncWriterState> {
         let file_identity = Arc::new(SharedFileIdentity::default());
         let writer = RotatingFileWriter::open(path, Some(Arc::clone(&file_identity)))?;
-        Ok(Self::spawn_async_writer(
-            path.to_path_buf(),
+        let target = AsyncTarget {
+            path: path.to_path_buf(),
             writer,
-            file_identity,
             coordinate_rotation,
-        ))
-    }
-
-    fn spawn_async_writer(
-        path: PathBuf,
-        mut writer: RotatingFileWriter,
-        file_identity: Arc<SharedFileIdentity>,
-        coordinate_rotation: bool,
-    ) -> AsyncWriterState {
-        let (sender, receiver) = bounded::<WriterMessage>(ASYNC_QUEUE_CAPACITY);
-
-        let writer_handle = thread::spawn(move || {
-            let flush_interval = Duration::from_millis(ASYNC_FLUSH_INTERVAL_MS);
-            let mut last_flush = Instant::now();
-            let mut batch_lock = None;
-
-            loop {
-                let timeout = if coordinate_rotation {
-                    flush_interval
-                        .checked_sub(last_flush.elapsed())
-                        .unwrap_or(Duration::ZERO)
-                } else {
-                    flush_interval
-                };
-
-                match receiver.recv_timeout(timeout) {
-                    Ok(WriterMessage::Write(msg)) => {
-                        let result = if coordinate_rotation {
-                            writer.write_line_buffered(&path, &msg, &mut batch_lock)
-                        } else {
-                            writer.write_line_unlocked(&msg)
-                        };
-
-                        if let Err(err) = result {
-                            eprintln!("Failed to write to log: {}", err);
-                        }
-
-                        if coordinate_rotation && last_flush.elapsed() >= flush_interval {
-                            let _ = writer.flush_buffered(&path, &mut batch_lock);
-                            last_flush = Instant::now();
-                        }
-                    }
-                    Ok(WriterMessage::Flush { ack }) => {
-                        if coordinate_rotation {
-                            let _ = writer.flush_buffered(&path, &mut batch_lock);
-                            last_flush = Instant::now();
-                        } else {
-                            let _ = writer.flush_without_lock();
-                        }
-                        let _ = ack.send(());
-                    }
-                    Err(RecvTimeoutError::Timeout) => {
-                        if coordinate_rotation {
-                            if batch_lock.is_some() {
-                                let _ = writer.flush_buffered(&path, &mut batch_lock);
-                            }
-                            last_flush = Instant::now();
-                        } else {
-                            let _ = writer.flush_without_lock();
-                        }
-                    }
-                    Err(RecvTimeoutError::Disconnected) => {
-                        if coordinate_rotation {
-                            let _ = writer.flush_buffered(&path, &mut batch_lock);
-                        } else {
-                            let _ = writer.flush_without_lock();
-                        }
-                        break;
-                    }
-                }
-            }
-        });
+            autoflush,
+            fsync,
+            write_retry,
+            queue_budget,
+            batch_lock: None,
+        };
+        let id = NEXT_WRITER_TARGET.fetch_add(1, Ordering::Relaxed);
+
+        if !shared {
+            let (sender, receiver) = bounded(ASYNC_QUEUE_CAPACITY);
+            let targets = HashMap::from([(id, target)]);
+            let handle = thread::spawn(move || run_async_writer(receiver, targets));
+            return Ok(AsyncWriterState {
+                sender: Some(TargetSender { sender, id }),
+                handle: Some(handle),
+                file_identity,
+                shared,
+            });
+        }
 
-        AsyncWriterState {
+        let sender = TargetSender {
+            sender: shared_writer_sender(),
+            id,
+        };
+        sender
+            .send(WriterMessage::Open(Box::new(target)))
+            .map_err(|_| io::Error::other("shared log writer thread stopped"))?;
+        Ok(AsyncWriterState {
             sender: Some(sender),
-            handle: Some(writer_handle),
+            handle: None,
             file_identity,
-        }
+            shared,
+        })
     }
 
-    fn write_owned(&self, message: String) -> io::Result<()> {
+    fn write_owned(&self, mut message: String, end: Option<&str>) -> io::Result<()> {
         self.maybe_rotate()?;
 
-        let msg_len = message.len() as u64 + 1;
+        message.push_str(end.unwrap_or(&self.config.line_terminator));
+        let msg_len = message.len() as u64;
+        if let Some(header) = &self.config.header {
+            // Claiming the empty file by bumping its size lets exactly one writer add the header
+            let header_len = (header.len() + self.config.line_terminator.len()) as u64;
+            if self
+                .current_size
+                .compare_exchange(0, header_len, Ordering::Relaxed, Ordering::Relaxed)
+                .is_ok()
+            {
+                message.insert_str(0, &self.config.line_terminator);
+                message.insert_str(0, header);
+            }
+        }
         let coordinate_rotation = self.rotation_coordination_enabled();
 
         let maybe_sender = {
@@ -752,9 +1178,15 @@ impl FileSinkInner {
                         .as_mut()
                         .ok_or_else(|| io::Error::other("sync backend writer missing"))?;
                     if coordinate_rotation {
-                        writer.write_line(&self.config.path, &message)?;
+                        writer.write_line(&self.config.path, &message, &self.write_retry)?;
+                    } else if self.config.autoflush {
+                        write_and_flush(&mut writer.writer, &message, &self.write_retry)?;
                     } else {
-                        writer.write_line_unlocked(&message)?;
+                        self.write_retry
+                            .run(|| writer.write_line_unlocked(&message))?;
+                    }
+                    if self.config.autoflush && self.config.fsync {
+                        writer.sync_all()?;
                     }
                     None
                 }
@@ -762,7 +1194,10 @@ impl FileSinkInner {
         };
 
         if let Some(sender) = maybe_sender {
-            self.send_with_retry(WriterMessage::Write(message), sender)?;
+            let queued_len = message.len() as u64;
+            self.queue_budget.acquire(queued_len);
+            self.send_with_retry(WriterMessage::Write(message), sender)
+                .inspect_err(|_| self.queue_budget.release(queued_len))?;
         }
 
         self.current_size.fetch_add(msg_len, Ordering::Relaxed);
@@ -792,6 +1227,9 @@ impl FileSinkInner {
                         } else {
                             writer.flush_without_lock()?;
                         }
+                        if self.config.fsync {
+                            writer.sync_all()?;
+                        }
                     }
                     None
                 }
@@ -802,23 +1240,23 @@ impl FileSinkInner {
             self.flush_async_sender(sender)?;
         }
 
-        Ok(())
+        self.wait_for_compression()
     }
 
     fn send_with_retry(
         &self,
         mut message: WriterMessage,
-        mut sender: Sender<WriterMessage>,
+        mut sender: TargetSender,
     ) -> io::Result<()> {
         for attempt in 0..2 {
             match sender.send(message) {
                 Ok(()) => return Ok(()),
-                Err(err) => {
+                Err(returned) => {
                     if attempt == 1 {
-                        return Err(io::Error::other(err.to_string()));
+                        return Err(io::Error::other("sending on a closed channel"));
                     }
 
-                    message = err.0;
+                    message = returned;
                     let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
                     self.reset_async_backend_locked(&mut state);
                     self.ensure_backend_ready_locked(&mut state)?;
@@ -841,7 +1279,7 @@ impl FileSinkInner {
         unreachable!("send_with_retry must return from the loop")
     }
 
-    fn flush_async_sender(&self, sender: Sender<WriterMessage>) -> io::Result<()> {
+    fn flush_async_sender(&self, sender: TargetSender) -> io::Result<()> {
         let (ack_tx, ack_rx) = bounded(0);
         self.send_with_retry(WriterMessage::Flush { ack: ack_tx }, sender)?;
         ack_rx.recv().map_err(|e| io::Error::other(e.to_string()))
@@ -880,7 +1318,9 @@ impl FileSinkInner {
 
         match &mut state.backend {
             WriterBackend::Async(async_state) => {
-                if async_state.sender.is_none() || async_state.handle.is_none() {
+                if async_state.sender.is_none()
+                    || (async_state.handle.is_none() && !async_state.shared)
+                {
                     self.restart_async_writer_locked(async_state, current_pid)?;
                 } else if coordinate_rotation
                     && self.path_identity_changed(async_state.file_identity.load())?
@@ -909,9 +1349,16 @@ impl FileSinkInner {
         async_state: &mut AsyncWriterState,
         current_pid: u32,
     ) -> io::Result<()> {
+        // Records queued to the old thread (e.g. the parent's, after a fork) are never written
+        self.queue_budget.bytes.store(0, Ordering::Release);
         *async_state = Self::create_async_writer_state(
             &self.config.path,
             self.rotation_coordination_enabled(),
+            self.config.autoflush,
+            self.config.fsync,
+            self.write_retry.clone(),
+            self.queue_budget.clone(),
+            self.config.shared_writer,
         )?;
         self.creation_pid.store(current_pid, Ordering::Release);
         self.sync_rotation_state_from_path();
@@ -926,7 +1373,16 @@ impl FileSinkInner {
     }
 
     fn stop_async_writer_locked(async_state: &mut AsyncWriterState, can_join: bool) {
-        async_state.sender.take();
+        if let Some(sender) = async_state.sender.take()
+            && async_state.shared
+            && can_join
+        {
+            // The shared thread keeps serving other sinks: only close this one's file
+            let (ack_tx, ack_rx) = bounded(0);
+            if sender.send(WriterMessage::Close { ack: ack_tx }).is_ok() {
+                let _ = ack_rx.recv();
+            }
+        }
 
         if let Some(handle) = async_state.handle.take() {
             if can_join {
@@ -939,6 +1395,44 @@ impl FileSinkInner {
         async_state.file_identity.store(None);
     }
 
+    fn stop_writer(&self) {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        if let WriterBackend::Async(async_state) = &mut state.backend {
+            let can_join = std::process::id() == self.creation_pid.load(Ordering::Acquire);
+            Self::stop_async_writer_locked(async_state, can_join);
+        }
+        if self.owner_pid == std::process::id() {
+            Self::compress_on_close_locked(&self.config, &mut state);
+        }
+    }
+
+    /// With `compress_on_close`, close the active file and gzip it to `<name>.gz`, unless
+    /// it is empty. A later write starts a new file.
+    fn compress_on_close_locked(config: &FileSinkConfig, state: &mut FileSinkState) {
+        if !(config.compression && config.compress_on_close) {
+            return;
+        }
+        match &mut state.backend {
+            WriterBackend::Async(async_state) => Self::stop_async_writer_locked(async_state, true),
+            WriterBackend::Sync(sync_state) => {
+                if let Some(mut writer) = sync_state.writer.take() {
+                    let _ = writer.flush_without_lock();
+                }
+            }
+        }
+        if !fs::metadata(&config.path).is_ok_and(|m| m.len() > 0) {
+            return;
+        }
+
+        let result = Self::open_rotation_lock_file(&config.path).and_then(|lock_file| {
+            let _lock = FileLockGuard::exclusive(&lock_file)?;
+            Self::compress_closed_file(&config.path)
+        });
+        if let Err(err) = result {
+            eprintln!("Failed to compress {}: {}", config.path.display(), err);
+        }
+    }
+
     #[cfg(unix)]
     fn pause_for_fork_prepare(&self) {
         let Some(mut state) = try_lock_or_recover(&self.state) else {
@@ -958,7 +1452,7 @@ impl FileSinkInner {
                 let file_time = metadata
                     .modified()
                     .map(DateTime::<Local>::from)
-                    .unwrap_or_else(|_| Local::now());
+                    .unwrap_or_else(|_| clock::now());
                 *self
                     .current_file_time
                     .lock()
@@ -1093,7 +1587,7 @@ impl FileSinkInner {
 
         let boundary_millis = self.next_rotation_boundary.load(Ordering::Relaxed);
         if boundary_millis > 0 {
-            Local::now().timestamp_millis() >= boundary_millis
+            clock::now().timestamp_millis() >= boundary_millis
         } else {
             false
         }
@@ -1165,6 +1659,9 @@ impl FileSinkInner {
             WriterBackend::Sync(sync_state) => {
                 if let Some(writer) = sync_state.writer.as_mut() {
                     writer.flush_without_lock()?;
+                    if self.config.fsync {
+                        writer.sync_all()?;
+                    }
                 }
             }
         }
@@ -1176,15 +1673,13 @@ impl FileSinkInner {
             self.reopen_backend_locked(state)?;
 
             if pending.needs_compression && pending.rotated_path.exists() {
-                if let Err(err) = self.compress_file(&pending.rotated_path) {
-                    self.store_pending_rotation(pending);
-                    return Err(err);
-                }
+                self.queue_compression(pending.rotated_path.clone());
                 pending.needs_compression = false;
+                pending.needs_retention = false;
             }
 
             if pending.needs_retention {
-                if let Err(err) = self.apply_retention() {
+                if let Err(err) = Self::apply_retention(&self.config) {
                     self.store_pending_rotation(pending);
                     return Err(err);
                 }
@@ -1201,8 +1696,8 @@ impl FileSinkInner {
             return self.reopen_backend_locked(state);
         }
 
-        let now = Local::now();
-        let rotated_path = self.generate_rotated_path(&now);
+        let now = clock::now();
+        let rotated_path = Self::unused_rotated_path(self.generate_rotated_path(&now));
         let mut rename_error = None;
 
         if self.config.path.exists()
@@ -1220,6 +1715,9 @@ impl FileSinkInner {
         if let Some(err) = rename_error {
             return Err(err);
         }
+        if rotated_path.exists() {
+            self.rotations.fetch_add(1, Ordering::Relaxed);
+        }
 
         let mut pending = PendingRotation {
             rotated_path: rotated_path.clone(),
@@ -1230,15 +1728,17 @@ impl FileSinkInner {
         };
 
         if pending.needs_compression {
-            if let Err(err) = self.compress_file(&rotated_path) {
-                self.store_pending_rotation(pending);
-                return Err(err);
-            }
+            // The worker applies retention after the gzip, so it never deletes a file
+            // that is still being compressed
+            self.queue_compression(rotated_path);
             pending.needs_compression = false;
+            pending.needs_retention = false;
+        } else if self.config.on_rotation.is_some() && rotated_path.exists() {
+            self.queue_job(CompressionJob::Notify(rotated_path));
         }
 
         if pending.needs_retention {
-            if let Err(err) = self.apply_retention() {
+            if let Err(err) = Self::apply_retention(&self.config) {
                 self.store_pending_rotation(pending);
                 return Err(err);
             }
@@ -1287,52 +1787,246 @@ impl FileSinkInner {
             .unwrap_or_else(|| PathBuf::from(&filename))
     }
 
-    /// Compress a file using gzip (streaming to avoid loading entire file into memory)
-    fn compress_file(&self, path: &Path) -> io::Result<()> {
-        let gz_path = path.with_extension(format!(
-            "{}.gz",
-            path.extension().and_then(|e| e.to_str()).unwrap_or("")
-        ));
+    /// `path`, or `<name>.N.<ext>` with the first N that is free when a rotation at the same
+    /// microsecond (or its `.gz`) already took the name, so renaming never replaces it
+    fn unused_rotated_path(path: PathBuf) -> PathBuf {
+        let taken = |path: &Path| path.exists() || Self::gz_path(path).exists();
+        if !taken(&path) {
+            return path;
+        }
+        let ext = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .unwrap_or("log")
+            .to_string();
+        (1u32..)
+            .map(|n| path.with_extension(format!("{n}.{ext}")))
+            .find(|candidate| !taken(candidate))
+            .expect("some suffix is free")
+    }
+
+    /// Hand a rotated file to the compression thread, after the ones it failed to compress
+    /// before
+    fn queue_compression(&self, rotated_path: PathBuf) {
+        let failed = std::mem::take(
+            &mut self
+                .failed_compressions
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .paths,
+        );
+        for path in failed.into_iter().filter(|path| path.exists()) {
+            self.queue_job(CompressionJob::Compress(path));
+        }
+        self.queue_job(CompressionJob::Compress(rotated_path));
+    }
 
-        let input_file = File::open(path)?;
-        let mut reader = io::BufReader::new(input_file);
+    /// Queue work for the compression thread, starting it if needed
+    fn queue_job(&self, job: CompressionJob) {
+        let current_pid = std::process::id();
+        let mut worker = self
+            .compression_worker
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
 
-        let output_file = File::create(&gz_path)?;
-        let mut encoder = GzEncoder::new(output_file, Compression::default());
+        if let Some(inherited) = worker.take_if(|w| w.pid != current_pid) {
+            // The parent's thread does not exist in a forked child
+            std::mem::forget(inherited);
+        }
 
-        io::copy(&mut reader, &mut encoder)?;
-        encoder.finish()?;
+        let worker = worker.get_or_insert_with(|| self.spawn_compression_worker(current_pid));
+        if let Some(sender) = &worker.sender {
+            let _ = sender.send(job);
+        }
+    }
 
-        fs::remove_file(path)?;
+    fn spawn_compression_worker(&self, pid: u32) -> CompressionWorker {
+        let (sender, receiver) = unbounded::<CompressionJob>();
+        let config = self.config.clone();
+        let failed_compressions = Arc::clone(&self.failed_compressions);
+
+        let handle = thread::spawn(move || {
+            for job in receiver {
+                match job {
+                    CompressionJob::Compress(path) => {
+                        if let Err(err) = Self::compress_rotated_file(&config, &path) {
+                            // Left uncompressed and out of retention until a retry succeeds
+                            let mut failed = failed_compressions
+                                .lock()
+                                .unwrap_or_else(|e| e.into_inner());
+                            failed.error.get_or_insert(io::Error::new(
+                                err.kind(),
+                                format!("failed to compress {}: {err}", path.display()),
+                            ));
+                            failed.paths.push(path);
+                            continue;
+                        }
+                        let rotated = Self::gz_path(&path);
+                        if let Err(err) = Self::apply_retention_locked(&config) {
+                            eprintln!("Failed to apply log retention: {}", err);
+                        }
+                        if let Some(on_rotation) = &config.on_rotation
+                            && rotated.exists()
+                        {
+                            on_rotation(&rotated);
+                        }
+                    }
+                    CompressionJob::Notify(path) => {
+                        if let Some(on_rotation) = &config.on_rotation {
+                            on_rotation(&path);
+                        }
+                    }
+                    CompressionJob::Barrier(ack) => {
+                        let _ = ack.send(());
+                    }
+                }
+            }
+        });
 
-        Ok(())
+        CompressionWorker {
+            sender: Some(sender),
+            handle: Some(handle),
+            pid,
+        }
     }
 
-    /// Apply retention policy (O(n log n) instead of O(n²))
-    fn apply_retention(&self) -> io::Result<()> {
-        use std::time::SystemTime;
+    /// Block until every queued compression (and its retention pass) has finished, then
+    /// return the first compression error since the last call
+    fn wait_for_compression(&self) -> io::Result<()> {
+        let sender = {
+            let worker = self
+                .compression_worker
+                .lock()
+                .unwrap_or_else(|e| e.into_inner());
+            match worker.as_ref() {
+                Some(w) if w.pid == std::process::id() => w.sender.clone(),
+                _ => None,
+            }
+        };
 
-        let parent = self.config.path.parent().unwrap_or(Path::new("."));
-        let stem = self
-            .config
-            .path
-            .file_stem()
-            .and_then(|s| s.to_str())
-            .unwrap_or("log");
-        let extension = self
-            .config
-            .path
-            .extension()
-            .and_then(|e| e.to_str())
-            .unwrap_or("log");
+        if let Some(sender) = sender {
+            let (ack_tx, ack_rx) = bounded(1);
+            if sender.send(CompressionJob::Barrier(ack_tx)).is_ok() {
+                ack_rx.recv().map_err(|e| io::Error::other(e.to_string()))?;
+            }
+        }
+        match self
+            .failed_compressions
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .error
+            .take()
+        {
+            Some(err) => Err(err),
+            None => Ok(()),
+        }
+    }
 
-        let current_filename = self
-            .config
+    /// Gzip a rotated file under the shared rotation lock, so other writers keep going
+    /// but no process rotates or applies retention until it is done
+    fn compress_rotated_file(config: &FileSinkConfig, path: &Path) -> io::Result<()> {
+        if !path.exists() {
+            return Ok(());
+        }
+        let lock_file = Self::open_rotation_lock_file(&config.path)?;
+        let _lock = FileLockGuard::shared(&lock_file)?;
+        Self::compress_file(path)
+    }
+
+    fn apply_retention_locked(config: &FileSinkConfig) -> io::Result<()> {
+        if config.retention_count.is_none() && config.retention_days.is_none() {
+            return Ok(());
+        }
+        let lock_file = Self::open_rotation_lock_file(&config.path)?;
+        let _lock = FileLockGuard::exclusive(&lock_file)?;
+        Self::apply_retention(config)
+    }
+
+    /// Compress a file using gzip (streaming to avoid loading entire file into memory).
+    ///
+    /// The archive is written to `<name>.gz.tmp`, synced and renamed into place before the
+    /// source is removed, so a crash or write error never leaves only a truncated `.gz`.
+    fn compress_file(path: &Path) -> io::Result<()> {
+        let gz_path = Self::gz_path(path);
+        let mut tmp_path = gz_path.clone().into_os_string();
+        tmp_path.push(".tmp");
+        let tmp_path = PathBuf::from(tmp_path);
+
+        if let Err(err) = Self::write_archive(path, &tmp_path, &gz_path) {
+            let _ = fs::remove_file(&tmp_path);
+            return Err(err);
+        }
+
+        fs::remove_file(path)
+    }
+
+    /// Gzip the closed active file. An archive left by an earlier run gets this one appended
+    /// as another gzip member, which `gunzip` and `zcat` read back as a single file.
+    fn compress_closed_file(path: &Path) -> io::Result<()> {
+        let gz_path = Self::gz_path(path);
+        if !gz_path.exists() {
+            return Self::compress_file(path);
+        }
+        let mut tmp_path = gz_path.clone().into_os_string();
+        tmp_path.push(".tmp");
+        let tmp_path = PathBuf::from(tmp_path);
+
+        let result = Self::write_gzip(path, &tmp_path).and_then(|()| {
+            let mut archive = OpenOptions::new().append(true).open(&gz_path)?;
+            io::copy(&mut File::open(&tmp_path)?, &mut archive)?;
+            archive.sync_all()
+        });
+        let _ = fs::remove_file(&tmp_path);
+        result?;
+        fs::remove_file(path)
+    }
+
+    /// `<name>.gz` archive path for a rotated file
+    fn gz_path(path: &Path) -> PathBuf {
+        path.with_extension(format!(
+            "{}.gz",
+            path.extension().and_then(|e| e.to_str()).unwrap_or("")
+        ))
+    }
+
+    fn write_archive(source: &Path, tmp_path: &Path, gz_path: &Path) -> io::Result<()> {
+        Self::write_gzip(source, tmp_path)?;
+        fs::rename(tmp_path, gz_path)
+    }
+
+    fn write_gzip(source: &Path, dest: &Path) -> io::Result<()> {
+        let input_file = File::open(source)?;
+        let mut reader = io::BufReader::new(input_file);
+
+        let output_file = File::create(dest)?;
+        let mut encoder = GzEncoder::new(output_file, Compression::default());
+
+        io::copy(&mut reader, &mut encoder)?;
+        encoder.finish()?.sync_all()
+    }
+
+    /// Apply retention policy (O(n log n) instead of O(n²))
+    fn apply_retention(config: &FileSinkConfig) -> io::Result<()> {
+        use std::time::SystemTime;
+
+        let parent = config.path.parent().unwrap_or(Path::new("."));
+        let stem = config
+            .path
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or("log");
+        let extension = config
+            .path
+            .extension()
+            .and_then(|e| e.to_str())
+            .unwrap_or("log");
+
+        let current_filename = config
             .path
             .file_name()
             .and_then(|f| f.to_str())
             .unwrap_or("");
-        let lock_filename = Self::format_lock_filename(&self.config.path);
+        let lock_filename = Self::format_lock_filename(&config.path);
 
         let mut rotated_files: Vec<(PathBuf, SystemTime)> = fs::read_dir(parent)?
             .filter_map(|e| e.ok())
@@ -1354,15 +2048,15 @@ impl FileSinkInner {
 
         rotated_files.sort_by_key(|(_, time)| *time);
 
-        if let Some(max_count) = self.config.retention_count {
+        if let Some(max_count) = config.retention_count {
             let excess = rotated_files.len().saturating_sub(max_count as usize);
             for (path, _) in rotated_files.drain(..excess) {
                 let _ = fs::remove_file(&path);
             }
         }
 
-        if let Some(days) = self.config.retention_days {
-            let cutoff = Local::now() - chrono::Duration::days(days as i64);
+        if let Some(days) = config.retention_days {
+            let cutoff = clock::now() - chrono::Duration::days(days as i64);
             let cutoff_time: SystemTime = cutoff.into();
 
             for (path, modified) in &rotated_files {
@@ -1394,7 +2088,13 @@ impl FileSinkInner {
         let Some((timestamp, pid)) = rotation_id.rsplit_once(".pid") else {
             return false;
         };
-        if pid.is_empty() || !pid.chars().all(|c| c.is_ascii_digit()) {
+        // A `.N` suffix follows the pid when an earlier rotation took the same name
+        let is_number = |s: &str| !s.is_empty() && s.chars().all(|c| c.is_ascii_digit());
+        let pid_matches = match pid.split_once('.') {
+            Some((pid, n)) => is_number(pid) && is_number(n),
+            None => is_number(pid),
+        };
+        if !pid_matches {
             return false;
         }
 
@@ -1421,6 +2121,23 @@ impl FileSinkInner {
 
 impl Drop for FileSinkInner {
     fn drop(&mut self) {
+        let worker = self
+            .compression_worker
+            .get_mut()
+            .unwrap_or_else(|e| e.into_inner())
+            .take();
+        if let Some(mut worker) = worker {
+            if worker.pid == std::process::id() {
+                // Closing the channel lets the thread finish the queued jobs and exit
+                worker.sender.take();
+                if let Some(handle) = worker.handle.take() {
+                    let _ = handle.join();
+                }
+            } else {
+                std::mem::forget(worker);
+            }
+        }
+
         // If we're in a child process after fork(), inherited backend state belongs
         // to the parent process. The child either lazily rebuilt the backend and
         // updated `creation_pid`, or it never touched the sink and should drop it
@@ -1452,9 +2169,15 @@ impl Drop for FileSinkInner {
             WriterBackend::Sync(sync_state) => {
                 if let Some(writer) = sync_state.writer.as_mut() {
                     let _ = writer.flush_without_lock();
+                    if self.config.fsync {
+                        let _ = writer.sync_all();
+                    }
                 }
             }
         }
+        if self.owner_pid == std::process::id() {
+            Self::compress_on_close_locked(&self.config, state);
+        }
     }
 }
 
@@ -1560,17 +2283,20 @@ pub fn parse_rotation(rotation_str: &str) -> (Rotation, Option<u64>) {
     }
 }
 
-/// Parse retention string like "10 days" or number
+/// Parse retention string like "10 days", "2 weeks", "3 months" or number
 pub fn parse_retention(retention_str: &str) -> (Option<u32>, Option<u32>) {
     let retention_str = retention_str.trim().to_lowercase();
 
-    if retention_str.contains("day") {
+    for (unit, unit_days) in RETENTION_UNITS {
+        if !retention_str.contains(unit) {
+            continue;
+        }
         let num_part: String = retention_str
             .chars()
             .filter(|c| c.is_ascii_digit())
             .collect();
-        if let Ok(days) = num_part.parse::<u32>() {
-            return (Some(days), None);
+        if let Ok(count) = num_part.parse::<u32>() {
+            return (Some(count.saturating_mul(unit_days)), None);
         }
     }
 
@@ -1615,6 +2341,10 @@ mod tests {
     fn test_parse_retention() {
         assert_eq!(parse_retention("10 days"), (Some(10), None));
         assert_eq!(parse_retention("5"), (None, Some(5)));
+        assert_eq!(parse_retention("1 week"), (Some(7), None));
+        assert_eq!(parse_retention("2 Weeks"), (Some(14), None));
+        assert_eq!(parse_retention("3 months"), (Some(90), None));
+        assert_eq!(parse_retention("1 year"), (Some(365), None));
     }
 
     #[test]
@@ -1629,6 +2359,16 @@ mod tests {
             "app",
             "log"
         ));
+        assert!(FileSinkInner::is_generated_rotated_log_filename(
+            "app.2000-01-01_00-00-00_000000.pid123.2.log",
+            "app",
+            "log"
+        ));
+        assert!(!FileSinkInner::is_generated_rotated_log_filename(
+            "app.2000-01-01_00-00-00_000000.pid123.x.log",
+            "app",
+            "log"
+        ));
         assert!(!FileSinkInner::is_generated_rotated_log_filename(
             "app.keep", "app", "log"
         ));
@@ -1683,6 +2423,466 @@ mod tests {
         let _ = fs::remove_dir_all(&dir);
     }
 
+    #[cfg(unix)]
+    #[test]
+    fn test_watch_reopens_deleted_file() {
+        for enqueue in [false, true] {
+            let dir = unique_temp_path("watch-reopen");
+            fs::create_dir_all(&dir).unwrap();
+            let path = dir.join("app.log");
+
+            let sink = FileSink::new(FileSinkConfig {
+                path: path.clone(),
+                enqueue,
+                watch: true,
+                ..FileSinkConfig::default()
+            })
+            .unwrap();
+
+            sink.write("before").unwrap();
+            sink.flush().unwrap();
+            fs::remove_file(&path).unwrap();
+
+            sink.write("after").unwrap();
+            sink.flush().unwrap();
+            assert_eq!(
+                fs::read_to_string(&path).unwrap(),
+                "after\n",
+                "enqueue={enqueue}"
+            );
+
+            fs::rename(&path, dir.join("app.log.1")).unwrap();
+            sink.write("renamed").unwrap();
+            sink.flush().unwrap();
+            assert_eq!(
+                fs::read_to_string(&path).unwrap(),
+                "renamed\n",
+                "enqueue={enqueue}"
+            );
+
+            drop(sink);
+            let _ = fs::remove_dir_all(&dir);
+        }
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_without_watch_deleted_file_is_not_recreated() {
+        let dir = unique_temp_path("watch-disabled");
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("app.log");
+
+        let sink = FileSink::new(FileSinkConfig {
+            path: path.clone(),
+            ..FileSinkConfig::default()
+        })
+        .unwrap();
+
+        sink.write("before").unwrap();
+        sink.flush().unwrap();
+        fs::remove_file(&path).unwrap();
+        sink.write("lost").unwrap();
+        sink.flush().unwrap();
+        assert!(!path.exists());
+
+        drop(sink);
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_autoflush_writes_through_without_flush() {
+        for enqueue in [false, true] {
+            let dir = unique_temp_path("autoflush");
+            let path = dir.join("app.log");
+
+            let sink = FileSink::new(FileSinkConfig {
+                path: path.clone(),
+                enqueue,
+                autoflush: true,
+                ..FileSinkConfig::default()
+            })
+            .unwrap();
+
+            sink.write("first").unwrap();
+            sink.write("second").unwrap();
+
+            // No flush(): sync writes are on disk on return, async ones once the writer ran
+            let deadline = Instant::now() + Duration::from_secs(5);
+            while enqueue && fs::read_to_string(&path).unwrap().len() < 13 {
+                assert!(
+                    Instant::now() < deadline,
+                    "async write never reached the file"
+                );
+                thread::sleep(Duration::from_millis(1));
+            }
+            assert_eq!(
+                fs::read_to_string(&path).unwrap(),
+                "first\nsecond\n",
+                "enqueue={enqueue}"
+            );
+
+            drop(sink);
+            let _ = fs::remove_dir_all(&dir);
+        }
+    }
+
+    #[test]
+    fn test_fsync_sink_writes_and_flushes() {
+        for (enqueue, autoflush) in [(false, false), (false, true), (true, false), (true, true)] {
+            let dir = unique_temp_path("fsync");
+            let path = dir.join("audit.log");
+
+            let sink = FileSink::new(FileSinkConfig {
+                path: path.clone(),
+                enqueue,
+                autoflush,
+                fsync: true,
+                ..FileSinkConfig::default()
+            })
+            .unwrap();
+
+            sink.write("first").unwrap();
+            sink.write("second").unwrap();
+            sink.flush().unwrap();
+
+            assert_eq!(
+                fs::read_to_string(&path).unwrap(),
+                "first\nsecond\n",
+                "enqueue={enqueue} autoflush={autoflush}"
+            );
+
+            drop(sink);
+            let _ = fs::remove_dir_all(&dir);
+        }
+    }
+
+    #[test]
+    fn test_shared_writer_serves_several_sinks() {
+        let dir = unique_temp_path("shared-writer");
+        let sinks: Vec<_> = ["a.log", "b.log", "c.log"]
+            .iter()
+            .map(|name| {
+                FileSink::new(FileSinkConfig {
+                    path: dir.join(name),
+                    enqueue: true,
+                    shared_writer: true,
+                    ..FileSinkConfig::default()
+                })
+                .unwrap()
+            })
+            .collect();
+
+        for i in 0..100 {
+            for sink in &sinks {
+                sink.write(&i.to_string()).unwrap();
+            }
+        }
+        sinks[0].flush().unwrap();
+        // Stopping one sink closes only its file; the others keep the thread
+        sinks[1].stop_writer().unwrap();
+        sinks[2].write("after").unwrap();
+        sinks[2].flush().unwrap();
+
+        let expected: String = (0..100).map(|i| format!("{i}\n")).collect();
+        assert_eq!(fs::read_to_string(dir.join("a.log")).unwrap(), expected);
+        assert_eq!(fs::read_to_string(dir.join("b.log")).unwrap(), expected);
+        assert_eq!(
+            fs::read_to_string(dir.join("c.log")).unwrap(),
+            format!("{expected}after\n")
+        );
+
+        drop(sinks);
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_custom_line_terminator() {
+        for enqueue in [false, true] {
+            let dir = unique_temp_path("line-terminator");
+            let path = dir.join("app.log");
+
+            let sink = FileSink::new(FileSinkConfig {
+                path: path.clone(),
+                enqueue,
+                line_terminator: "\r\n".to_string(),
+                ..FileSinkConfig::default()
+            })
+            .unwrap();
+
+            sink.write("first").unwrap();
+            sink.write_owned("second".to_string()).unwrap();
+            sink.flush().unwrap();
+
+            let content = fs::read_to_string(&path).unwrap();
+            assert_eq!(content, "first\r\nsecond\r\n", "enqueue={enqueue}");
+            assert_eq!(
+                sink.inner.current_size.load(Ordering::Relaxed),
+                content.len() as u64
+            );
+
+            drop(sink);
+            let _ = fs::remove_dir_all(&dir);
+        }
+    }
+
+    #[test]
+    fn test_line_terminator_counts_toward_size_rotation() {
+        let dir = unique_temp_path("line-terminator-size");
+        let path = dir.join("app.log");
+
+        // "abcd" + NUL terminator is 5 bytes, so the third record triggers rotation
+        let sink = FileSink::new(FileSinkConfig {
+            path: path.clone(),
+            max_size: Some(10),
+            line_terminator: "\0".to_string(),
+            ..FileSinkConfig::default()
+        })
+        .unwrap();
+
+        for _ in 0..3 {
+            sink.write("abcd").unwrap();
+        }
+        sink.flush().unwrap();
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "abcd\0");
+        let rotated: Vec<_> = fs::read_dir(&dir)
+            .unwrap()
+            .filter_map(|e| e.ok())
+            .map(|e| e.path())
+            .filter(|p| p != &path && p.extension().is_some_and(|ext| ext == "log"))
+            .collect();
+        assert_eq!(rotated.len(), 1);
+        assert_eq!(fs::read_to_string(&rotated[0]).unwrap(), "abcd\0abcd\0");
+
+        drop(sink);
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_write_with_end_replaces_line_terminator() {
+        let dir = unique_temp_path("write-end");
+        let path = dir.join("app.log");
+        let sink = FileSink::new(FileSinkConfig {
+            path: path.clone(),
+            ..FileSinkConfig::default()
+        })
+        .unwrap();
+
+        sink.write_with_end("working".to_string(), Some(""))
+            .unwrap();
+        sink.write_with_end("...".to_string(), Some(" ")).unwrap();
+        sink.write_with_end("done".to_string(), None).unwrap();
+        sink.flush().unwrap();
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "working... done\n");
+        assert_eq!(sink.stats().size, 16);
+
+        drop(sink);
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_background_compression_finishes_before_flush_returns() {
+        use std::io::Read;
+
+        let dir = unique_temp_path("background-compression");
+        let path = dir.join("app.log");
+
+        let sink = FileSink::new(FileSinkConfig {
+            path: path.clone(),
+            max_size: Some(10),
+            compression: true,
+            retention_count: Some(2),
+            ..FileSinkConfig::default()
+        })
+        .unwrap();
+
+        for i in 0..5 {
+            sink.write(&format!("record-{i:03}")).unwrap();
+        }
+        sink.flush().unwrap();
+
+        let rotated: Vec<_> = fs::read_dir(&dir)
+            .unwrap()
+            .filter_map(|e| e.ok())
+            .map(|e| e.path())
+            .filter(|p| p != &path && !p.to_string_lossy().ends_with(".lock"))
+            .collect();
+        // Retention ran after compression: only finished .gz files, and only two of them
+        assert_eq!(rotated.len(), 2, "{rotated:?}");
+        for gz in &rotated {
+            assert!(gz.to_string_lossy().ends_with(".log.gz"), "{gz:?}");
+            let mut content = String::new();
+            flate2::read::GzDecoder::new(File::open(gz).unwrap())
+                .read_to_string(&mut content)
+                .unwrap();
+            assert!(content.starts_with("record-"), "{content:?}");
+        }
+        assert_eq!(fs::read_to_string(&path).unwrap(), "record-004\n");
+
+        drop(sink);
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_failed_compression_keeps_original() {
+        let dir = unique_temp_path("compression-failure");
+        fs::create_dir_all(&dir).unwrap();
+        let rotated = dir.join("app.2000-01-01_00-00-00_000000.pid0.log");
+        fs::write(&rotated, "keep me\n").unwrap();
+        // A directory where the archive should go makes the final rename fail
+        fs::create_dir(dir.join("app.2000-01-01_00-00-00_000000.pid0.log.gz")).unwrap();
+
+        assert!(FileSinkInner::compress_file(&rotated).is_err());
+
+        assert_eq!(fs::read_to_string(&rotated).unwrap(), "keep me\n");
+        assert!(
+            !dir.join("app.2000-01-01_00-00-00_000000.pid0.log.gz.tmp")
+                .exists(),
+            "temporary archive was left behind"
+        );
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_failed_compression_reported_by_flush_and_retried() {
+        let dir = unique_temp_path("compression-retry");
+        let path = dir.join("app.log");
+        let sink = FileSink::new(FileSinkConfig {
+            path: path.clone(),
+            compression: true,
+            ..FileSinkConfig::default()
+        })
+        .unwrap();
+
+        let rotated = dir.join("app.2000-01-01_00-00-00_000000.pid0.log");
+        let archive = dir.join("app.2000-01-01_00-00-00_000000.pid0.log.gz");
+        fs::write(&rotated, "keep me\n").unwrap();
+        fs::create_dir(&archive).unwrap();
+
+        sink.inner.queue_compression(rotated.clone());
+        let err = sink.flush().unwrap_err();
+        assert!(err.to_string().contains("failed to compress"), "{err}");
+        assert_eq!(fs::read_to_string(&rotated).unwrap(), "keep me\n");
+        // The error is returned once
+        sink.flush().unwrap();
+
+        // The next rotation queues the failed file again
+        fs::remove_dir(&archive).unwrap();
+        let next = dir.join("app.2000-01-01_00-00-01_000000.pid0.log");
+        fs::write(&next, "next\n").unwrap();
+        sink.inner.queue_compression(next.clone());
+        sink.flush().unwrap();
+
+        assert!(!rotated.exists() && !next.exists());
+        assert!(archive.is_file());
+        assert!(
+            dir.join("app.2000-01-01_00-00-01_000000.pid0.log.gz")
+                .is_file()
+        );
+
+        drop(sink);
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_on_rotation_reports_rotated_files() {
+        for compression in [false, true] {
+            let dir = unique_temp_path("on-rotation");
+            let path = dir.join("app.log");
+            let rotated = Arc::new(StdMutex::new(Vec::new()));
+            let seen = Arc::clone(&rotated);
+
+            let sink = FileSink::new(FileSinkConfig {
+                path: path.clone(),
+                max_size: Some(10),
+                compression,
+                on_rotation: Some(Arc::new(move |path: &Path| {
+                    seen.lock().unwrap().push(path.to_path_buf());
+                })),
+                ..FileSinkConfig::default()
+            })
+            .unwrap();
+
+            for i in 0..3 {
+                sink.write(&format!("record-{i:03}")).unwrap();
+            }
+            sink.flush().unwrap();
+
+            let rotated = rotated.lock().unwrap();
+            assert_eq!(rotated.len(), 2, "compression={compression}");
+            for file in rotated.iter() {
+                assert!(file.exists(), "{file:?}");
+                assert_eq!(
+                    file.to_string_lossy().ends_with(".log.gz"),
+                    compression,
+                    "{file:?}"
+                );
+            }
+
+            drop(sink);
+            let _ = fs::remove_dir_all(&dir);
+        }
+    }
+
+    #[test]
+    fn test_compress_on_close_appends_to_existing_archive() {
+        use std::io::Read;
+
+        let dir = unique_temp_path("compress-on-close");
+        let path = dir.join("job.log");
+        let config = FileSinkConfig {
+            path: path.clone(),
+            compression: true,
+            compress_on_close: true,
+            ..FileSinkConfig::default()
+        };
+
+        // An empty file is not compressed
+        drop(FileSink::new(config.clone()).unwrap());
+        assert!(path.exists());
+        assert!(!dir.join("job.log.gz").exists());
+
+        for run in ["first", "second"] {
+            let sink = FileSink::new(config.clone()).unwrap();
+            sink.write(run).unwrap();
+            drop(sink);
+        }
+
+        assert!(!path.exists());
+        let mut content = String::new();
+        flate2::read::MultiGzDecoder::new(File::open(dir.join("job.log.gz")).unwrap())
+            .read_to_string(&mut content)
+            .unwrap();
+        assert_eq!(content, "first\nsecond\n");
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_compression_replaces_source_with_archive() {
+        use std::io::Read;
+
+        let dir = unique_temp_path("compression-success");
+        fs::create_dir_all(&dir).unwrap();
+        let rotated = dir.join("app.2000-01-01_00-00-00_000000.pid0.log");
+        fs::write(&rotated, "archived\n").unwrap();
+
+        FileSinkInner::compress_file(&rotated).unwrap();
+
+        assert!(!rotated.exists());
+        let mut content = String::new();
+        flate2::read::GzDecoder::new(File::open(rotated.with_extension("log.gz")).unwrap())
+            .read_to_string(&mut content)
+            .unwrap();
+        assert_eq!(content, "archived\n");
+        let leftovers: Vec<_> = fs::read_dir(&dir).unwrap().collect();
+        assert_eq!(leftovers.len(), 1);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
     #[test]
     fn test_pending_rotation_forces_retry_even_before_boundary() {
         let path = unique_temp_path("pending-rotation").join("app.log");
@@ -1713,12 +2913,325 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_next_rotation_boundary_is_deterministic() {
+        use chrono::TimeZone;
+
+        let from = Local.with_ymd_and_hms(2024, 3, 10, 13, 45, 12).unwrap();
+        let hourly = FileSinkInner::calculate_next_rotation_boundary(&Rotation::Hourly, &from);
+        assert_eq!(
+            hourly,
+            Some(Local.with_ymd_and_hms(2024, 3, 10, 14, 0, 0).unwrap())
+        );
+        let daily = FileSinkInner::calculate_next_rotation_boundary(&Rotation::Daily, &from);
+        assert_eq!(
+            daily,
+            Some(Local.with_ymd_and_hms(2024, 3, 11, 0, 0, 0).unwrap())
+        );
+
+        let late = Local.with_ymd_and_hms(2024, 3, 10, 23, 30, 0).unwrap();
+        let wrapped = FileSinkInner::calculate_next_rotation_boundary(&Rotation::Hourly, &late);
+        assert_eq!(
+            wrapped,
+            Some(Local.with_ymd_and_hms(2024, 3, 11, 0, 0, 0).unwrap())
+        );
+        assert_eq!(
+            FileSinkInner::calculate_next_rotation_boundary(&Rotation::Never, &from),
+            None
+        );
+    }
+
+    #[test]
+    fn test_stats_track_size_and_boundary() {
+        let dir = unique_temp_path("sink-stats");
+        let path = dir.join("app.log");
+        let _clock =
+            clock::test_clock::set(Local.with_ymd_and_hms(2024, 3, 10, 13, 45, 12).unwrap());
+
+        let sink = FileSink::new(FileSinkConfig {
+            path: path.clone(),
+            rotation: Rotation::Hourly,
+            ..FileSinkConfig::default()
+        })
+        .unwrap();
+        sink.write("one").unwrap();
+        sink.write("two").unwrap();
+
+        let stats = sink.stats();
+        assert_eq!(stats.size, 8);
+        assert_eq!(stats.file_time, clock::now());
+        assert_eq!(
+            stats.next_rotation,
+            Some(Local.with_ymd_and_hms(2024, 3, 10, 14, 0, 0).unwrap())
+        );
+
+        drop(sink);
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_hourly_rotation_follows_pinned_clock() {
+        let dir = unique_temp_path("pinned-clock-rotation");
+        let path = dir.join("app.log");
+        let _clock = clock::test_clock::set(Local::now());
+
+        let sink = FileSink::new(FileSinkConfig {
+            path: path.clone(),
+            rotation: Rotation::Hourly,
+            ..FileSinkConfig::default()
+        })
+        .unwrap();
+        let boundary = sink.inner.next_rotation_boundary.load(Ordering::Relaxed);
+        assert!(boundary > 0);
+
+        let until_boundary = boundary - clock::now().timestamp_millis();
+        clock::test_clock::advance(chrono::Duration::milliseconds(until_boundary - 1));
+        sink.write("before boundary").unwrap();
+        assert!(!sink.inner.check_rotation_needed());
+
+        clock::test_clock::advance(chrono::Duration::milliseconds(1));
+        assert!(sink.inner.check_rotation_needed());
+        sink.write("after boundary").unwrap();
+        sink.flush().unwrap();
+
+        let rotated: Vec<_> = fs::read_dir(&dir)
+            .unwrap()
+            .filter_map(|e| e.ok())
+            .map(|e| e.path())
+            .filter(|p| p != &path && p.extension().is_some_and(|ext| ext == "log"))
+            .collect();
+        assert_eq!(rotated.len(), 1, "expected exactly one rotated file");
+        assert_eq!(
+            fs::read_to_string(&rotated[0]).unwrap().trim(),
+            "before boundary"
+        );
+        assert_eq!(fs::read_to_string(&path).unwrap().trim(), "after boundary");
+        assert!(sink.inner.next_rotation_boundary.load(Ordering::Relaxed) > boundary);
+
+        drop(sink);
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_rotations_at_the_same_time_keep_every_file() {
+        let dir = unique_temp_path("same-time-rotation");
+        let path = dir.join("app.log");
+        let _clock = clock::test_clock::set(Local::now());
+
+        // Every record fills the file, so each later write rotates at the same pinned time
+        let sink = FileSink::new(FileSinkConfig {
+            path: path.clone(),
+            max_size: Some(5),
+            ..FileSinkConfig::default()
+        })
+        .unwrap();
+        for i in 0..3 {
+            sink.write(&format!("record-{i}")).unwrap();
+        }
+        sink.flush().unwrap();
+
+        let mut rotated: Vec<_> = fs::read_dir(&dir)
+            .unwrap()
+            .filter_map(|e| e.ok())
+            .map(|e| e.path())
+            .filter(|p| p != &path && p.extension().is_some_and(|ext| ext == "log"))
+            .collect();
+        rotated.sort();
+        assert_eq!(rotated.len(), 2, "{rotated:?}");
+        assert!(rotated[0].to_string_lossy().ends_with(".1.log"));
+        assert_eq!(fs::read_to_string(&rotated[0]).unwrap(), "record-1\n");
+        assert_eq!(fs::read_to_string(&rotated[1]).unwrap(), "record-0\n");
+        assert_eq!(fs::read_to_string(&path).unwrap(), "record-2\n");
+        assert_eq!(sink.stats().rotations, 2);
+
+        drop(sink);
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_header_written_once_per_new_file() {
+        let dir = unique_temp_path("header");
+        let path = dir.join("app.csv");
+        let config = FileSinkConfig {
+            path: path.clone(),
+            max_size: Some(12),
+            header: Some("a,b".to_string()),
+            ..FileSinkConfig::default()
+        };
+
+        let sink = FileSink::new(config.clone()).unwrap();
+        sink.write("1,2").unwrap();
+        drop(sink);
+        // Reopening a file that already has records does not repeat the header
+        let sink = FileSink::new(config).unwrap();
+        sink.write("3,4").unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "a,b\n1,2\n3,4\n");
+
+        // The file started by rotation gets its own header
+        sink.write("5,6").unwrap();
+        sink.flush().unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "a,b\n5,6\n");
+
+        drop(sink);
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_truncate_starts_empty_and_rotates() {
+        let dir = unique_temp_path("truncate");
+        let path = dir.join("app.log");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(&path, "previous run\n").unwrap();
+
+        let sink = FileSink::new(FileSinkConfig {
+            path: path.clone(),
+            max_size: Some(6),
+            truncate: true,
+            ..FileSinkConfig::default()
+        })
+        .unwrap();
+        assert_eq!(sink.stats().size, 0);
+        sink.write("first").unwrap();
+        sink.flush().unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "first\n");
+
+        // Size counting starts from the truncated file, so rotation triggers as usual
+        sink.write("second").unwrap();
+        sink.flush().unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "second\n");
+
+        drop(sink);
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_queue_budget_waits_for_room() {
+        let budget = QueueBudget {
+            limit: Some(100),
+            ..QueueBudget::default()
+        };
+        budget.acquire(60);
+        budget.release(60);
+        // Over the limit alone, but an empty queue always takes it
+        budget.acquire(150);
+        budget.release(150);
+
+        budget.acquire(60);
+        let writer = {
+            let budget = budget.clone();
+            thread::spawn(move || {
+                thread::sleep(Duration::from_millis(20));
+                budget.release(60);
+            })
+        };
+        let started = Instant::now();
+        budget.acquire(60);
+        assert!(started.elapsed() >= Duration::from_millis(15));
+        assert_eq!(budget.bytes.load(Ordering::Relaxed), 60);
+        writer.join().unwrap();
+    }
+
+    #[test]
+    fn test_write_retry_backs_off_then_counts_failure() {
+        let retry = WriteRetry {
+            retries: 2,
+            backoff: Duration::from_millis(1),
+            failures: Arc::new(AtomicU64::new(0)),
+        };
+
+        let mut calls = 0;
+        let result = retry.run(|| {
+            calls += 1;
+            if calls < 3 {
+                Err(io::Error::from(io::ErrorKind::Interrupted))
+            } else {
+                Ok(())
+            }
+        });
+        assert!(result.is_ok());
+        assert_eq!(calls, 3);
+        assert_eq!(retry.failures.load(Ordering::Relaxed), 0);
+
+        calls = 0;
+        let result = retry.run(|| {
+            calls += 1;
+            Err::<(), _>(io::Error::from(io::ErrorKind::TimedOut))
+        });
+        assert!(result.is_err());
+        assert_eq!(calls, 3);
+        assert_eq!(retry.failures.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn test_write_retry_skips_persistent_errors() {
+        let retry = WriteRetry {
+            retries: 3,
+            backoff: Duration::from_secs(10),
+            failures: Arc::new(AtomicU64::new(0)),
+        };
+
+        let mut calls = 0;
+        let result = retry.run(|| {
+            calls += 1;
+            Err::<(), _>(io::Error::from(io::ErrorKind::StorageFull))
+        });
+        assert!(result.is_err());
+        assert_eq!(calls, 1);
+        assert_eq!(retry.failures.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn test_flush_retry_writes_record_once() {
+        /// Fails its first write, which `BufWriter` only issues on flush
+        struct FlakyWriter {
+            written: Vec<u8>,
+            failed: bool,
+        }
+
+        impl Write for FlakyWriter {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                if !self.failed {
+                    self.failed = true;
+                    return Err(io::Error::from(io::ErrorKind::WouldBlock));
+                }
+                self.written.extend_from_slice(buf);
+                Ok(buf.len())
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let retry = WriteRetry {
+            retries: 2,
+            backoff: Duration::from_millis(1),
+            failures: Arc::new(AtomicU64::new(0)),
+        };
+        let mut writer = BufWriter::new(FlakyWriter {
+            written: Vec::new(),
+            failed: false,
+        });
+
+        write_and_flush(&mut writer, "record\n", &retry).unwrap();
+        assert_eq!(writer.get_ref().written, b"record\n");
+        assert_eq!(retry.failures.load(Ordering::Relaxed), 0);
+    }
+
     #[test]
     fn test_async_writer_state_open_error_surfaces_immediately() {
         let path = unique_temp_path("async-open-error");
         fs::create_dir_all(&path).unwrap();
 
-        let err = match FileSinkInner::create_async_writer_state(&path, false) {
+        let err = match FileSinkInner::create_async_writer_state(
+            &path,
+            false,
+            false,
+            false,
+            WriteRetry::default(),
+            QueueBudget::default(),
+            false,
+        ) {
             Ok(_) => panic!("async writer state unexpectedly opened a directory path"),
             Err(err) => err,
         };
@@ -0,0 +1,28 @@
+use pyo3::prelude::*;
+
+use crate::CallbackEntry;
+use crate::handler::HandlerEntry;
+use crate::level::LevelInfo;
+
+/// Returned by `save_config()`: the handlers, callbacks and custom levels to bring back
+/// with `restore_config()`. Opaque to Python; it can be restored any number of times.
+#[pyclass(frozen, module = "logust._logust")]
+pub struct ConfigSnapshot {
+    pub(crate) handlers: Vec<HandlerEntry>,
+    pub(crate) callbacks: Vec<CallbackEntry>,
+    pub(crate) levels: Vec<LevelInfo>,
+    /// Stderr half of a `split_console()` pair, if one was active
+    pub(crate) split_console: Option<u64>,
+}
+
+#[pymethods]
+impl ConfigSnapshot {
+    fn __repr__(&self) -> String {
+        format!(
+            "ConfigSnapshot(handlers={}, callbacks={}, levels={})",
+            self.handlers.len(),
+            self.callbacks.len(),
+            self.levels.len()
+        )
+    }
+}
@@ -64,6 +64,20 @@ def logger_with_file(
     session_logger.remove(handler_id)
 
 
+@pytest.fixture(autouse=True)
+def restore_custom_levels() -> Generator[None, None, None]:
+    """Drop the custom levels a test registers, so every test starts from the same registry.
+
+    Level names and numbers are process-wide, so without this one test's levels would
+    collide with another's depending on the order they run in.
+    """
+    registry = PyLogger(LogLevel.Trace)
+    registry.remove()
+    saved = registry.save_config()
+    yield
+    registry.restore_config(saved)
+
+
 @pytest.fixture
 def tmp_log_dir(tmp_path: Path) -> Path:
     """Provide a temporary directory for log files."""
@@ -390,6 +390,7 @@ class TestLightweightRequirementsForRust:
             False,
             True,
             False,
+            False,
         )
 
     def test_extra_nested_flag(self) -> None:
@@ -2,6 +2,7 @@
 
 from __future__ import annotations
 
+import sys
 from pathlib import Path
 from typing import Any
 
@@ -113,6 +114,190 @@ class TestRemoveCallback:
         assert result is False
 
 
+class TestFieldCallbacks:
+    """Test add_callback(fields=...) delivering only the requested keys."""
+
+    def test_only_requested_keys(self) -> None:
+        """Test that the dict contains exactly the requested fields."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.disable()
+        records: list[dict[str, Any]] = []
+        logger.add_callback(records.append, fields=["level", "message", "level_no"])
+
+        logger.bind(user="alice").warning("disk low")
+
+        assert records == [{"level": "WARNING", "message": "disk low", "level_no": 30}]
+
+    def test_custom_level_and_optional_fields(self) -> None:
+        """Test custom levels, extra text view and a missing exception."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.disable()
+        logger.level("NOTICE", no=22)
+        records: list[dict[str, Any]] = []
+        logger.add_callback(records.append, fields=["level", "level_no", "extra", "exception"])
+
+        logger.bind(count=3).log("NOTICE", "custom")
+
+        assert records == [
+            {"level": "NOTICE", "level_no": 22, "extra": {"count": "3"}, "exception": None}
+        ]
+
+    def test_caller_fields_are_collected(self) -> None:
+        """Test that requesting caller fields collects caller info."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.disable()
+        records: list[dict[str, Any]] = []
+        logger.add_callback(records.append, fields=["function", "line"])
+
+        logger.info("where")
+
+        assert records[0]["function"] == "test_caller_fields_are_collected"
+        assert records[0]["line"] > 0
+
+    def test_unknown_field_raises(self) -> None:
+        """Test that an unknown key is rejected at registration."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.disable()
+        with pytest.raises(ValueError, match="Unknown record field: colour"):
+            logger.add_callback(lambda record: None, fields=["level", "colour"])
+
+    def test_remove_field_callback(self) -> None:
+        """Test that field callbacks are removed by remove() and remove_callback()."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.disable()
+        records: list[dict[str, Any]] = []
+        first = logger.add_callback(records.append, fields=["message"])
+        logger.add_callback(records.append, fields=["message"])
+
+        assert logger.remove_callback(first)
+        logger.info("once")
+        logger.remove()
+        logger.info("never")
+
+        assert records == [{"message": "once"}]
+
+
+class TestCallbackErrors:
+    """Test reporting of exceptions raised by callbacks."""
+
+    @staticmethod
+    def _failing(record: dict[str, Any]) -> None:
+        raise RuntimeError(f"broken: {record['message']}")
+
+    def test_errors_are_counted_not_raised(self, monkeypatch: pytest.MonkeyPatch) -> None:
+        """Test that a raising callback never breaks the logging call and is counted."""
+        monkeypatch.setattr(sys, "unraisablehook", lambda unraisable: None)
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.disable()
+
+        failing_id = logger.add_callback(self._failing)
+        ok_id = logger.add_callback(lambda record: None)
+
+        logger.info("one")
+        logger.log("ERROR", "two")
+        logger.level("NOTICE", no=22)
+        logger.log("NOTICE", "three")
+
+        assert logger.callback_errors(failing_id) == 3
+        assert logger.callback_errors(ok_id) == 0
+        assert logger.callback_errors() == 3
+
+    def test_first_error_per_callback_reported(self, monkeypatch: pytest.MonkeyPatch) -> None:
+        """Test that only the first error of each callback goes to sys.unraisablehook."""
+        reported: list[BaseException | None] = []
+        monkeypatch.setattr(sys, "unraisablehook", lambda u: reported.append(u.exc_value))
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.disable()
+        logger.add_callback(self._failing)
+
+        logger.info("first")
+        logger.info("second")
+
+        assert len(reported) == 1
+        assert isinstance(reported[0], RuntimeError)
+        assert str(reported[0]) == "broken: first"
+
+    def test_on_callback_error_receives_every_error(self) -> None:
+        """Test that a registered handler gets (callback_id, exc) for every error."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.disable()
+        seen: list[tuple[int, str]] = []
+        logger.on_callback_error(lambda cid, exc: seen.append((cid, str(exc))))
+
+        callback_id = logger.add_callback(self._failing)
+        logger.info("a")
+        logger.bind(user="x").info("b")
+
+        assert seen == [(callback_id, "broken: a"), (callback_id, "broken: b")]
+        logger.on_callback_error(None)
+
+    def test_callable_sink_errors_are_reported(self) -> None:
+        """Test that exceptions from callable sinks are no longer swallowed silently."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.disable()
+        seen: list[int] = []
+        logger.on_callback_error(lambda cid, exc: seen.append(cid))
+
+        def bad_sink(message: str) -> None:
+            raise OSError("disk full")
+
+        sink_id = logger.add(bad_sink, format="{message}")
+        logger.info("dropped")
+
+        assert seen == [sink_id]
+        assert logger.callback_errors(sink_id) == 1
+
+    def test_failing_error_handler_is_reported(self, monkeypatch: pytest.MonkeyPatch) -> None:
+        """Test that an exception inside the error handler itself is not propagated."""
+        reported: list[BaseException | None] = []
+        monkeypatch.setattr(sys, "unraisablehook", lambda u: reported.append(u.exc_value))
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.disable()
+
+        def bad_handler(callback_id: int, exc: BaseException) -> None:
+            raise ValueError("handler broke")
+
+        logger.on_callback_error(bad_handler)
+        logger.add_callback(self._failing)
+        logger.info("x")
+
+        assert [type(e) for e in reported] == [ValueError]
+
+
+class TestCallbackCalls:
+    """Test per-callback call counts."""
+
+    def test_calls_counted_per_callback(self) -> None:
+        """Test that each callback counts the records at or above its level."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.disable()
+        all_id = logger.add_callback(lambda record: None)
+        error_id = logger.add_callback(lambda record: None, level="ERROR")
+
+        logger.info("one")
+        logger.error("two")
+
+        assert logger.callback_calls(all_id) == 2
+        assert logger.callback_calls(error_id) == 1
+        assert logger.callback_calls() == 3
+
+    def test_raising_calls_are_counted(self, monkeypatch: pytest.MonkeyPatch) -> None:
+        """Test that calls are counted even when the callback raises."""
+        monkeypatch.setattr(sys, "unraisablehook", lambda unraisable: None)
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.disable()
+
+        def failing(record: dict[str, Any]) -> None:
+            raise RuntimeError("broken")
+
+        callback_id = logger.add_callback(failing)
+        logger.info("a")
+        logger.info("b")
+
+        assert logger.callback_calls(callback_id) == 2
+        assert logger.callback_errors(callback_id) == 2
+
+
 class TestCatchDecorator:
     """Test @logger.catch() decorator."""
 
@@ -1,6 +1,7 @@
 """Tests for caller information feature."""
 
 import json
+import os
 import subprocess
 import sys
 
@@ -48,6 +49,41 @@ logger.complete()
         assert record["line"] > 0
 
 
+class TestModuleNameFromFile:
+    """Tests for deriving {name} from the file path when no name is passed."""
+
+    def _render(self, tmp_path, **caller):
+        from logust import LogLevel, PyLogger
+
+        log_file = tmp_path / "derived.log"
+        inner = PyLogger(LogLevel.Trace)
+        inner.remove()
+        inner.add(str(log_file), format="{name}|{module}|{message}")
+        inner.info("msg", **caller)
+        inner.complete()
+        return log_file.read_text().strip()
+
+    def test_name_derived_from_file(self, tmp_path):
+        """Test that an empty name falls back to the file stem."""
+        assert self._render(tmp_path, file="/srv/app/handlers.py") == "handlers|handlers|msg"
+
+    def test_init_maps_to_package(self, tmp_path):
+        """Test that __init__.py maps to its directory name."""
+        assert self._render(tmp_path, file="/srv/app/payments/__init__.py") == (
+            "payments|payments|msg"
+        )
+
+    def test_explicit_name_wins(self, tmp_path):
+        """Test that a passed name is never overridden."""
+        assert self._render(tmp_path, name="custom", file="/srv/app/handlers.py") == (
+            "custom|custom|msg"
+        )
+
+    def test_bare_init_without_directory(self, tmp_path):
+        """Test that a bare __init__.py leaves the name empty."""
+        assert self._render(tmp_path, file="__init__.py") == "||msg"
+
+
 class TestCallerDepth:
     """Tests for depth adjustment in caller info."""
 
@@ -150,6 +186,68 @@ logger.complete()
         assert "caller_of_risky -" in content
         assert "An error occurred: oops" in content
 
+    def test_record_stacklevel(self):
+        """Records report how many frames above the logging call they point to."""
+        code = """
+import json
+from logust import logger
+logger.remove()
+records = []
+logger.add_callback(lambda r: records.append((r["function"], r["stacklevel"])))
+def log_helper(message):
+    logger.opt(depth=1).info(message)
+def user_code():
+    logger.info("direct")
+    log_helper("helper")
+    logger.opt(depth=1).log("INFO", "via log")
+    logger.log_fmt("INFO", "{}", "fmt")
+    try:
+        raise ValueError("x")
+    except ValueError:
+        logger.exception("exc")
+user_code()
+print(json.dumps(records))
+"""
+        result = subprocess.run(
+            [sys.executable, "-c", code], capture_output=True, text=True, check=True
+        )
+
+        assert json.loads(result.stdout) == [
+            ["user_code", 1],
+            ["user_code", 2],
+            ["<module>", 2],
+            ["user_code", 1],
+            ["user_code", 1],
+        ]
+
+
+class TestCallerContract:
+    """Rust-side validation of caller info passed to the log methods."""
+
+    def test_inconsistent_caller_info_is_rejected(self):
+        """function and line come together; stacklevel needs them and starts at 1."""
+        code = """
+from logust import LogLevel, PyLogger
+inner = PyLogger(LogLevel.Trace)
+inner.remove()
+for kwargs in ({"function": "f"}, {"line": 3}, {"stacklevel": 1}, {"function": "f", "line": 3, "stacklevel": 0}):
+    try:
+        inner.info("bad", **kwargs)
+    except ValueError as e:
+        print(e)
+inner.info("ok", name="m", function="f", line=3, stacklevel=2)
+"""
+        result = subprocess.run(
+            [sys.executable, "-c", code], capture_output=True, text=True, check=True
+        )
+
+        assert result.stdout.splitlines() == [
+            "caller info needs both function and line",
+            "caller info needs both function and line",
+            "stacklevel needs caller info (function and line)",
+            "stacklevel must be at least 1",
+        ]
+
 
 class TestConsoleSink:
     """Tests for stdout/stderr as sinks."""
@@ -263,6 +361,226 @@ logger.info("no color test")
         assert "\x1b[" not in result.stdout
         assert "no color test" in result.stdout
 
+    def test_default_colorize_disabled_when_piped(self):
+        """Test that colorize defaults to off when stdout is not a terminal."""
+        code = """
+from logust import LogLevel, PyLogger
+inner = PyLogger(LogLevel.Info)
+inner.remove()
+inner.add_console("stdout")
+inner.info("piped test")
+"""
+        result = subprocess.run(
+            [sys.executable, "-c", code],
+            capture_output=True,
+            text=True,
+        )
+        assert "\x1b[" not in result.stdout
+        assert "piped test" in result.stdout
+
+    def test_default_console_handler_no_ansi_when_piped(self):
+        """Test that PyLogger's built-in console handler skips ANSI codes when piped."""
+        code = """
+from logust import LogLevel, PyLogger
+PyLogger(LogLevel.Info).info("builtin piped test")
+"""
+        result = subprocess.run(
+            [sys.executable, "-c", code],
+            capture_output=True,
+            text=True,
+        )
+        assert "\x1b[" not in result.stdout
+        assert "builtin piped test" in result.stdout
+
+    def test_explicit_colorize_forces_ansi_when_piped(self):
+        """Test that colorize=True on add_console still forces colors."""
+        code = """
+from logust import LogLevel, PyLogger
+inner = PyLogger(LogLevel.Info)
+inner.remove()
+inner.add_console("stdout", colorize=True)
+inner.info("forced test")
+"""
+        result = subprocess.run(
+            [sys.executable, "-c", code],
+            capture_output=True,
+            text=True,
+        )
+        assert "\x1b[" in result.stdout
+
+    def test_force_color_enables_ansi_when_piped(self):
+        """Test that FORCE_COLOR enables colors for a piped stream."""
+        code = """
+import sys
+from logust import logger
+logger.remove()
+logger.add(sys.stdout)
+logger.info("force color test")
+"""
+        env = {k: v for k, v in os.environ.items() if k != "NO_COLOR"}
+        env["FORCE_COLOR"] = "1"
+        result = subprocess.run(
+            [sys.executable, "-c", code],
+            capture_output=True,
+            text=True,
+            env=env,
+        )
+        assert "\x1b[" in result.stdout
+
+    def test_no_color_beats_force_color(self):
+        """Test that NO_COLOR disables colors even when FORCE_COLOR is set."""
+        code = """
+import sys
+from logust import logger
+logger.remove()
+logger.add(sys.stdout)
+logger.info("no color env test")
+"""
+        env = {**os.environ, "NO_COLOR": "1", "FORCE_COLOR": "1"}
+        result = subprocess.run(
+            [sys.executable, "-c", code],
+            capture_output=True,
+            text=True,
+            env=env,
+        )
+        assert "\x1b[" not in result.stdout
+        assert "no color env test" in result.stdout
+
+    def test_explicit_colorize_beats_no_color(self):
+        """Test that colorize=True overrides NO_COLOR."""
+        code = """
+import sys
+from logust import logger
+logger.remove()
+logger.add(sys.stdout, colorize=True)
+logger.info("explicit test")
+"""
+        env = {**os.environ, "NO_COLOR": "1"}
+        result = subprocess.run(
+            [sys.executable, "-c", code],
+            capture_output=True,
+            text=True,
+            env=env,
+        )
+        assert "\x1b[" in result.stdout
+
+    def test_colorize_full_tints_whole_line(self):
+        """Test that colorize_full wraps the whole line in the level color."""
+        code = """
+import sys
+from logust import logger
+logger.remove()
+logger.add(sys.stdout, colorize=True, colorize_full=True, format="{level} | {message}")
+logger.error("full <blue>tint</blue> test")
+"""
+        result = subprocess.run(
+            [sys.executable, "-c", code],
+            capture_output=True,
+            text=True,
+        )
+        line = result.stdout.rstrip("\n")
+        assert line.startswith("\x1b[31m\x1b[1;31mERROR")
+        # Tint is restored after the level token and after message markup
+        assert "\x1b[0m\x1b[31m | full \x1b[34mtint\x1b[0m\x1b[31m test" in line
+        assert line.endswith("\x1b[0m")
+
+    def test_colorize_full_ignored_without_colors(self):
+        """Test that colorize_full has no effect when colorize is off."""
+        code = """
+import sys
+from logust import logger
+logger.remove()
+logger.add(sys.stdout, colorize=False, colorize_full=True, format="{message}")
+logger.error("plain line")
+"""
+        result = subprocess.run(
+            [sys.executable, "-c", code],
+            capture_output=True,
+            text=True,
+        )
+        assert result.stdout == "plain line\n"
+
+    def test_colorize_exception_colors_traceback(self):
+        """Test that colorize_exception colors file paths and the exception line."""
+        code = """
+import sys
+from logust import logger
+logger.remove()
+logger.add(sys.stdout, colorize=True, colorize_exception=True, format="{message}")
+try:
+    1 / 0
+except ZeroDivisionError:
+    logger.exception("failed")
+"""
+        result = subprocess.run(
+            [sys.executable, "-c", code],
+            capture_output=True,
+            text=True,
+        )
+        assert 'File "\x1b[36m<string>\x1b[0m", line' in result.stdout
+        assert "\x1b[1;31mZeroDivisionError: division by zero\x1b[0m" in result.stdout
+        assert "Traceback (most recent call last):\n" in result.stdout
+
+    def test_colorize_exception_ignored_without_colors(self):
+        """Test that tracebacks stay plain when colorize is off."""
+        code = """
+import sys
+from logust import logger
+logger.remove()
+logger.add(sys.stdout, colorize=False, colorize_exception=True, format="{message}")
+logger.error("failed", exception="ValueError: bad")
+"""
+        result = subprocess.run(
+            [sys.executable, "-c", code],
+            capture_output=True,
+            text=True,
+        )
+        assert result.stdout == "failed\nValueError: bad\n"
+
+    def test_message_colors_color_matching_parts(self):
+        """Test that message_colors wraps regex matches in their colors."""
+        code = r"""
+import sys
+from logust import logger
+logger.remove()
+logger.add(
+    sys.stdout,
+    colorize=True,
+    format="{message}",
+    message_colors={r"^\[OK\]": "green", r"\[FAIL\]": "red"},
+)
+logger.info("[OK] saved <bold>twice</bold>")
+logger.info("retry after [FAIL]")
+"""
+        result = subprocess.run(
+            [sys.executable, "-c", code],
+            capture_output=True,
+            text=True,
+        )
+        assert result.stdout.splitlines() == [
+            "\x1b[32m[OK]\x1b[0m saved \x1b[1mtwice\x1b[0m",
+            "retry after \x1b[31m[FAIL]\x1b[0m",
+        ]
+
+    def test_message_colors_rejects_bad_rules(self):
+        """Test that invalid patterns and unknown colors raise when the handler is added."""
+        code = """
+import sys
+from logust import logger
+for rules in ({"(": "green"}, {"ok": "not_a_color"}):
+    try:
+        logger.add(sys.stdout, colorize=True, message_colors=rules)
+    except ValueError as e:
+        print(e)
+"""
+        result = subprocess.run(
+            [sys.executable, "-c", code],
+            capture_output=True,
+            text=True,
+        )
+        assert result.stdout.startswith('message_colors: invalid pattern "("')
+        assert 'message_colors: unknown color "not_a_color"\n' in result.stdout
+
     def test_serialize_no_ansi(self):
         """Test that serialize=True outputs plain JSON without ANSI."""
         code = """
@@ -6,6 +6,8 @@ and remove_callback() cleanup.
 
 from __future__ import annotations
 
+import os
+import threading
 from pathlib import Path
 from typing import Any
 
@@ -287,6 +289,55 @@ class TestThreadProcessCollectOptions:
         content = log_file.read_text()
         assert "FixedProcess:99999" in content
 
+    def test_thread_false_keeps_empty_values(self, tmp_path: Path) -> None:
+        """thread=False/process=False must not be filled natively by Rust."""
+        inner = PyLogger(LogLevel.Trace)
+        logger = Logger(inner)
+        logger.remove()
+
+        log_file = tmp_path / "test.log"
+        logger.add(
+            str(log_file),
+            format="{thread}|{process}|{message}",
+            collect=CollectOptions(thread=False, process=False),
+        )
+
+        logger.info("Opted out")
+        logger.complete()
+
+        assert log_file.read_text().strip() == ":0|:0|Opted out"
+
+
+class TestNativeThreadProcessInfo:
+    """Rust resolves thread/process ids when the caller omits them."""
+
+    def test_pylogger_fills_ids_natively(self, tmp_path: Path) -> None:
+        """Direct PyLogger calls without thread/process args get native ids."""
+        inner = PyLogger(LogLevel.Trace)
+        inner.remove()
+        log_file = tmp_path / "native.log"
+        inner.add(str(log_file), format="{thread}|{process}|{message}")
+
+        inner.info("Native")
+        inner.complete()
+
+        thread, process, message = log_file.read_text().strip().split("|")
+        assert thread.rsplit(":", 1)[1] == str(threading.get_ident())
+        assert process.rsplit(":", 1)[1] == str(os.getpid())
+        assert message == "Native"
+
+    def test_explicit_values_win_over_native(self, tmp_path: Path) -> None:
+        """Explicit thread/process values are used as-is."""
+        inner = PyLogger(LogLevel.Trace)
+        inner.remove()
+        log_file = tmp_path / "explicit.log"
+        inner.add(str(log_file), format="{thread}|{process}")
+
+        inner.info("x", thread_name="T", thread_id=7, process_name="P", process_id=8)
+        inner.complete()
+
+        assert log_file.read_text().strip() == "T:7|P:8"
+
 
 class TestSerializeWithCollectOptions:
     """Test serialize=True with CollectOptions."""
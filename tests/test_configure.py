@@ -5,6 +5,8 @@ from __future__ import annotations
 from pathlib import Path
 from typing import Any
 
+import pytest
+
 from logust import Logger, LogLevel
 from logust._logust import PyLogger
 
@@ -48,6 +50,30 @@ class TestConfigureHandlers:
         content = log_file.read_text()
         assert "Full config message" in content
 
+    def test_configure_twice_replaces_handlers(self, tmp_path: Path) -> None:
+        """Test that a second configure() leaves only its own handlers."""
+        inner = PyLogger(LogLevel.Trace)
+        logger = Logger(inner)
+        first_file = tmp_path / "first.log"
+        second_file = tmp_path / "second.log"
+        records: list[str] = []
+
+        logger.configure(
+            handlers=[{"sink": str(first_file)}, {"sink": lambda msg: records.append(msg)}]
+        )
+        handler_ids = logger.configure(
+            handlers=[{"sink": str(second_file), "format": "{message}"}]
+        )
+
+        assert len(handler_ids) == 1
+        assert inner.handler_count == 1
+        logger.info("Second only")
+        logger.complete()
+
+        assert second_file.read_text() == "Second only\n"
+        assert first_file.read_text() == ""
+        assert records == []
+
 
 class TestConfigureLevels:
     """Test configure() with custom levels."""
@@ -56,7 +82,7 @@ class TestConfigureLevels:
         """Test configuring custom log levels."""
         logger, log_file = logger_with_file
 
-        logger.level("NOTICE", no=25, color="cyan")
+        logger.level("NOTICE", no=22, color="cyan")
         logger.level("ALERT", no=35, color="red", icon="!")
 
         logger.log("NOTICE", "Notice message")
@@ -77,6 +103,25 @@ class TestConfigureLevels:
 
         assert handler_ids == []
 
+    def test_configure_invalid_handler_keeps_levels_unregistered(self, tmp_path: Path) -> None:
+        """Test that a bad handler spec undoes the levels and handlers already applied."""
+        inner = PyLogger(LogLevel.Trace)
+        logger = Logger(inner)
+        logger.disable()
+        logger.remove()
+
+        with pytest.raises(ValueError):
+            logger.configure(
+                handlers=[
+                    {"sink": str(tmp_path / "a.log")},
+                    {"sink": str(tmp_path / "b.log"), "mode": "x"},
+                ],
+                levels=[{"name": "WRAPPER_ROLLBACK", "no": 64}],
+            )
+
+        assert inner.try_resolve_emit_level_no("WRAPPER_ROLLBACK") is None
+        assert inner.handler_count == 0
+
 
 class TestConfigureExtra:
     """Test configure() with extra fields."""
@@ -120,7 +165,7 @@ class TestConfigureComplete:
         """Test logging with all options."""
         logger, log_file = logger_with_file
 
-        logger.level("AUDIT", no=45, color="magenta")
+        logger.level("AUDIT", no=46, color="magenta")
 
         logger.info("Info message")
         logger.log("AUDIT", "Audit message")
@@ -150,3 +195,86 @@ class TestConfigureComplete:
         content = log_file.read_text()
         assert "Info message" in content
         assert "Error message" in content
+
+
+class TestPyLoggerConfigure:
+    """Test PyLogger.configure() bulk setup."""
+
+    def test_configure_replaces_handlers(self, tmp_path: Path) -> None:
+        """Test that configure drops existing handlers and adds the specs."""
+        inner = PyLogger(LogLevel.Trace)
+        old_file = tmp_path / "old.log"
+        new_file = tmp_path / "new.log"
+        inner.add(str(old_file))
+
+        handler_ids = inner.configure(
+            handlers=[{"sink": str(new_file), "level": "INFO", "format": "{level} {message}"}]
+        )
+
+        assert len(handler_ids) == 1
+        assert inner.handler_count == 1
+        assert not inner.is_enabled()
+
+        inner.debug("Dropped by level")
+        inner.info("Configured message")
+        inner.complete()
+
+        assert new_file.read_text() == "INFO Configured message\n"
+        assert old_file.read_text() == ""
+
+    def test_configure_console_sink(self) -> None:
+        """Test that stdout/stderr sinks become console handlers."""
+        inner = PyLogger(LogLevel.Trace)
+
+        handler_ids = inner.configure(handlers=[{"sink": "stderr", "level": LogLevel.Error}])
+
+        assert len(handler_ids) == 1
+        assert inner.is_enabled()
+        assert inner.min_level == LogLevel.Error.value
+        assert inner.min_level_name == "ERROR"
+
+    def test_configure_levels_and_extra(self, tmp_path: Path) -> None:
+        """Test registering levels and merging default extra."""
+        inner = PyLogger(LogLevel.Trace).bind({"service": "api"})
+        log_file = tmp_path / "extra.log"
+
+        inner.configure(
+            handlers=[{"sink": log_file, "format": "{level} {extra[service]} {extra[env]}"}],
+            levels=[{"name": "CONFIGURED", "no": 27}],
+            extra={"env": "prod"},
+        )
+        inner.log("CONFIGURED", "Custom level")
+        inner.complete()
+
+        assert log_file.read_text() == "CONFIGURED api prod\n"
+
+    def test_configure_invalid_spec_keeps_handlers(self, tmp_path: Path) -> None:
+        """Test that a bad spec leaves the existing handlers in place."""
+        inner = PyLogger(LogLevel.Trace)
+        inner.disable()
+        inner.add(str(tmp_path / "keep.log"))
+
+        with pytest.raises(ValueError, match="sink"):
+            inner.configure(handlers=[{"sink": str(tmp_path / "a.log")}, {"level": "INFO"}])
+
+        assert inner.handler_count == 1
+
+    def test_configure_invalid_spec_keeps_levels_unregistered(self, tmp_path: Path) -> None:
+        """Test that no level is registered when a handler or level spec is invalid."""
+        inner = PyLogger(LogLevel.Trace)
+        inner.disable()
+        inner.add(str(tmp_path / "keep.log"))
+
+        with pytest.raises(ValueError, match="sink"):
+            inner.configure(
+                handlers=[{"level": "INFO"}],
+                levels=[{"name": "UNCONFIGURED", "no": 63}],
+            )
+        with pytest.raises(ValueError, match="built-in"):
+            inner.configure(
+                handlers=[{"sink": str(tmp_path / "a.log")}],
+                levels=[{"name": "UNCONFIGURED", "no": 63}, {"name": "INFO", "no": 65}],
+            )
+
+        assert inner.try_resolve_emit_level_no("UNCONFIGURED") is None
+        assert inner.handler_count == 1
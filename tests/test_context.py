@@ -2,10 +2,16 @@
 
 from __future__ import annotations
 
+import asyncio
+import json
+from contextvars import ContextVar
 from pathlib import Path
 from typing import Any
 
-from logust import Logger
+import pytest
+
+from logust import Logger, LogLevel
+from logust._logust import PyLogger
 
 
 class TestBind:
@@ -58,6 +64,65 @@ class TestBind:
         assert "Original logger" in content
         assert "Bound logger" in content
 
+    def test_bind_callable_is_computed_per_record(self, tmp_path: Path) -> None:
+        """Test that a bound callable runs only for records that are emitted."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.remove()
+        log_file = tmp_path / "lazy.json"
+        logger.add(log_file, level="INFO", serialize=True)
+        calls: list[int] = []
+
+        def counter() -> int:
+            calls.append(1)
+            return len(calls)
+
+        bound = logger.bind(seq=counter, host=lambda: "web-1")
+        bound.debug("filtered out")
+        bound.info("first")
+        bound.info("second", extra={"seq": 99})
+        bound.bind(seq="fixed").info("third")
+        bound.log_batch("INFO", ["fourth", "fifth"])
+        logger.complete()
+
+        records = [json.loads(line) for line in log_file.read_text().splitlines()]
+        # A batch computes bound values once and shares them
+        assert [r["extra"]["seq"] for r in records] == [1, 99, "fixed", 2, 2]
+        assert {r["extra"]["host"] for r in records} == {"web-1"}
+        assert len(calls) == 2
+
+    def test_bind_callable_seen_by_patchers(self, tmp_path: Path) -> None:
+        """Test that patchers receive the computed value, not the callable."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.remove()
+        log_file = tmp_path / "patched.json"
+        logger.add(log_file, serialize=True)
+        seen: list[Any] = []
+
+        bound = logger.bind(host=lambda: "web-1").patch(
+            lambda record: seen.append(record["extra"]["host"])
+        )
+        bound.info("patched")
+        logger.complete()
+
+        assert seen == ["web-1"]
+        assert json.loads(log_file.read_text())["extra"] == {"host": "web-1"}
+
+    def test_bind_override_false_keeps_existing(self, tmp_path: Path) -> None:
+        """Test that override=False only adds keys that are not bound yet."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.remove()
+        log_file = tmp_path / "keep.json"
+        logger.add(log_file, serialize=True)
+
+        bound = logger.bind(user="alice", host=lambda: "web-1")
+        bound.bind(user="guest", host="default", region="eu", override=False).info("kept")
+        bound.bind(user="bob").info("replaced")
+        logger.complete()
+
+        records = [json.loads(line) for line in log_file.read_text().splitlines()]
+        assert records[0]["extra"] == {"user": "alice", "host": "web-1", "region": "eu"}
+        assert records[1]["extra"] == {"user": "bob", "host": "web-1"}
+
 
 class TestContextualize:
     """Test contextualize() context manager."""
@@ -112,6 +177,64 @@ class TestContextualize:
         assert "After exception" in content
 
 
+class TestContextProvider:
+    """Test set_context_provider()."""
+
+    def test_provider_reads_context_of_each_task(self, tmp_path: Path) -> None:
+        """Test that each asyncio task logs the contextvar value it set."""
+        request_ctx: ContextVar[dict[str, Any] | None] = ContextVar("request_ctx", default=None)
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.remove()
+        log_file = tmp_path / "tasks.json"
+        logger.add(log_file, serialize=True)
+        logger.set_context_provider(request_ctx.get)
+
+        async def handle(request_id: str) -> None:
+            request_ctx.set({"request_id": request_id, "user": "anon"})
+            await asyncio.sleep(0)
+            logger.bind(user="alice").info(request_id, extra={"step": 1})
+
+        async def main() -> None:
+            await asyncio.gather(handle("a"), handle("b"))
+
+        asyncio.run(main())
+        logger.info("outside")
+        logger.set_context_provider(None)
+        request_ctx.set({"request_id": "removed"})
+        logger.info("no provider")
+        logger.complete()
+
+        records = [json.loads(line) for line in log_file.read_text().splitlines()]
+        extras = {record["message"]: record.get("extra", {}) for record in records}
+        assert extras["a"] == {"request_id": "a", "user": "alice", "step": 1}
+        assert extras["b"] == {"request_id": "b", "user": "alice", "step": 1}
+        assert extras["outside"] == {}
+        assert extras["no provider"] == {}
+
+    def test_provider_skipped_for_filtered_levels(self) -> None:
+        """Test that the provider is not called when no handler accepts the record."""
+        calls: list[int] = []
+        logger = Logger(PyLogger(LogLevel.Info))
+        logger.remove()
+        logger.add(lambda message: None, level="INFO")
+        logger.set_context_provider(lambda: calls.append(1))
+
+        logger.debug("dropped")
+        logger.info("kept")
+
+        assert calls == [1]
+
+    def test_provider_must_return_dict(self) -> None:
+        """Test that a non-dict result raises TypeError."""
+        logger = Logger(PyLogger(LogLevel.Info))
+        logger.remove()
+        logger.add(lambda message: None, level="INFO")
+        logger.set_context_provider(lambda: ["not", "a", "dict"])
+
+        with pytest.raises(TypeError, match="dict or None"):
+            logger.info("bad")
+
+
 class TestPatch:
     """Test patch() method for record modification."""
 
@@ -244,3 +367,122 @@ class TestPatch:
         content = log_file.read_text()
         assert "Original" in content
         assert "Patched" in content
+
+
+class TestNativePatch:
+    """Test PyLogger.patch() running patchers on the Rust side."""
+
+    @staticmethod
+    def _capture(inner: PyLogger) -> list[dict[str, Any]]:
+        records: list[dict[str, Any]] = []
+        inner.add_callback(records.append)
+        return records
+
+    def test_patcher_adds_extra_and_rewrites_message(self) -> None:
+        """Test that extra and message changes reach callbacks."""
+        inner = PyLogger(LogLevel.Trace)
+        inner.remove()
+        records = self._capture(inner)
+
+        def add_request_id(record: dict[str, Any]) -> None:
+            record["extra"]["request_id"] = "req-1"
+            record["message"] = f"[{record['level']}] {record['message']}"
+
+        inner.patch(add_request_id).info("handled")
+
+        assert records[0]["message"] == "[INFO] handled"
+        assert records[0]["extra"] == {"request_id": "req-1"}
+
+    def test_patch_composes_with_bind(self) -> None:
+        """Test that bind() keeps patchers and patchers see bound context."""
+        inner = PyLogger(LogLevel.Trace)
+        inner.remove()
+        records = self._capture(inner)
+        seen: list[dict[str, Any]] = []
+
+        def snapshot(record: dict[str, Any]) -> None:
+            seen.append(dict(record["extra"]))
+            record["extra"]["patched"] = True
+
+        inner.patch(snapshot).bind({"user": "alice"}).warning("one")
+        inner.bind({"user": "bob"}).patch(snapshot).warning("two")
+        inner.warning("unpatched")
+
+        assert seen == [{"user": "alice"}, {"user": "bob"}]
+        assert [r["extra"] for r in records] == [
+            {"user": "alice", "patched": "True"},
+            {"user": "bob", "patched": "True"},
+            {},
+        ]
+
+    def test_patchers_run_in_order_and_can_remove_keys(self) -> None:
+        """Test chained patchers, key removal and exception rewriting."""
+        inner = PyLogger(LogLevel.Trace)
+        inner.remove()
+        records = self._capture(inner)
+
+        def first(record: dict[str, Any]) -> None:
+            record["extra"]["step"] = "first"
+
+        def second(record: dict[str, Any]) -> None:
+            record["extra"]["step"] += "+second"
+            del record["extra"]["token"]
+            record["exception"] = "redacted"
+
+        inner.bind({"token": "SECRET"}).patch(first).patch(second).error("x", exception="boom")
+
+        assert records[0]["extra"] == {"step": "first+second"}
+        assert records[0]["exception"] == "redacted"
+
+    def test_patched_record_reaches_file_handler_and_filters(self, tmp_path: Path) -> None:
+        """Test that handler filters and formatting see patched values."""
+        inner = PyLogger(LogLevel.Trace)
+        inner.remove()
+        log_file = tmp_path / "patched.json"
+        inner.add(
+            str(log_file),
+            serialize=True,
+            filter=lambda record: record["extra"].get("request_id") == "req-7",
+        )
+
+        def add_request_id(record: dict[str, Any]) -> None:
+            record["extra"]["request_id"] = "req-7"
+
+        inner.patch(add_request_id).info("kept", extra={"attempt": 2})
+        inner.info("filtered out")
+        inner.complete()
+
+        [line] = log_file.read_text().splitlines()
+        record = json.loads(line)
+        assert record["message"] == "kept"
+        assert record["extra"] == {"attempt": 2, "request_id": "req-7"}
+
+    def test_patchers_skip_disabled_levels_and_cover_custom_levels(self) -> None:
+        """Test that patchers only run for emitted records, custom levels included."""
+        inner = PyLogger(LogLevel.Trace)
+        inner.remove()
+        records: list[dict[str, Any]] = []
+        inner.add_callback(records.append, LogLevel.Info)
+        inner.level("AUDIT", 23)
+        calls: list[str] = []
+
+        patched = inner.patch(lambda record: calls.append(record["level"]))
+        patched.debug("dropped")
+        patched.log("AUDIT", "custom")
+        patched.log_batch("INFO", ["a", "b"])
+
+        assert calls == ["AUDIT", "INFO", "INFO"]
+        assert len(records) == 3
+
+    def test_patcher_errors_propagate(self) -> None:
+        """Test that an exception raised by a patcher reaches the caller."""
+        inner = PyLogger(LogLevel.Trace)
+        inner.remove()
+        records = self._capture(inner)
+
+        def broken(record: dict[str, Any]) -> None:
+            raise RuntimeError("patcher failed")
+
+        with pytest.raises(RuntimeError, match="patcher failed"):
+            inner.patch(broken).info("x")
+        assert records == []
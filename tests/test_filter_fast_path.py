@@ -4,6 +4,8 @@ from __future__ import annotations
 
 from pathlib import Path
 
+import pytest
+
 from logust import Logger, LogLevel
 from logust._logust import PyLogger
 
@@ -118,6 +120,30 @@ class TestFilterFastPathBuiltin:
 
         logger.complete()
 
+    def test_critical_callback_and_debug_filter_share_lazy_dict(self, tmp_path: Path) -> None:
+        """Dict is built by the first eligible consumer and reused by later ones."""
+        inner = PyLogger(LogLevel.Trace)
+        inner.remove()
+        log_file = tmp_path / "shared.log"
+
+        callback_dicts: list[dict] = []
+        filter_dicts: list[dict] = []
+
+        def filt(record: dict) -> bool:
+            filter_dicts.append(record)
+            return True
+
+        inner.add(str(log_file), level=LogLevel.Debug, format="{message}", filter=filt)
+        inner.add_callback(callback_dicts.append, level=LogLevel.Critical)
+
+        inner.debug("chatty")
+        assert callback_dicts == []
+        assert [r["message"] for r in filter_dicts] == ["chatty"]
+
+        inner.critical("rare")
+        assert callback_dicts[0] is filter_dicts[1]
+        inner.complete()
+
 
 class TestFilterFastPathCustomLevel:
     """Custom level path (_log_custom) matches built-in eligibility rules."""
@@ -152,3 +178,83 @@ class TestFilterFastPathCustomLevel:
         assert count >= 1
 
         logger.complete()
+
+
+class TestNativeFilters:
+    """Module name and extra pattern filters are checked without Python callables."""
+
+    def test_extra_pattern_filter(self, tmp_path: Path) -> None:
+        """Only records whose extra fields match every pattern are written."""
+        logger = _fresh_logger()
+        db_log = tmp_path / "db.log"
+        seen: list[str] = []
+        handle = logger.add(
+            db_log, format="{message}", filter={"component": "db*", "region": "eu-?"}
+        )
+        logger.add(seen.append, format="{message}", filter={"component": "db*"})
+
+        logger.bind(component="db.pool", region="eu-1").info("pool")
+        logger.bind(component="web", region="eu-1").info("web")
+        logger.bind(component="db", region="eu-12").info("far")
+        logger.info("plain")
+        logger.complete()
+
+        assert db_log.read_text() == "pool\n"
+        assert seen == ["pool", "far"]
+        # Native filters don't force full records like Python filters do
+        assert handle.id not in logger._filter_ids
+
+    def test_module_name_filter(self, tmp_path: Path) -> None:
+        """A string keeps records from that module and its submodules."""
+        logger = _fresh_logger()
+        here = tmp_path / "here.log"
+        elsewhere = tmp_path / "elsewhere.log"
+        logger.add(here, format="{message}", filter=__name__)
+        logger.add(elsewhere, format="{message}", filter=__name__ + "x")
+
+        logger.info("hello")
+        logger.complete()
+
+        assert here.read_text() == "hello\n"
+        assert elsewhere.read_text() == ""
+
+    def test_invalid_filter(self, tmp_path: Path) -> None:
+        """Filters other than callables, strings and dicts are rejected."""
+        logger = _fresh_logger()
+        with pytest.raises(TypeError, match="filter must be"):
+            logger.add(tmp_path / "bad.log", filter=42)  # type: ignore[arg-type]
+        with pytest.raises(TypeError, match="filter must be"):
+            logger.add(print, filter=42)  # type: ignore[arg-type]
+
+
+class TestFilterFields:
+    """filter_fields limits the dict a callable filter gets and the data collected for it."""
+
+    def test_filter_receives_only_declared_fields(self, tmp_path: Path) -> None:
+        """The filter sees just the named keys and the handler doesn't force full records."""
+        logger = _fresh_logger()
+        log_file = tmp_path / "app.log"
+        seen: list[dict] = []
+
+        def keep(record: dict) -> bool:
+            seen.append(record)
+            return "secret" not in record["message"]
+
+        handle = logger.add(log_file, format="{message}", filter=keep, filter_fields=["message"])
+        logger.info("hello")
+        logger.info("secret token")
+        logger.complete()
+
+        assert log_file.read_text() == "hello\n"
+        assert seen == [{"message": "hello"}, {"message": "secret token"}]
+        assert handle.id not in logger._filter_ids
+
+    def test_filter_fields_requires_callable_filter(self, tmp_path: Path) -> None:
+        """filter_fields without a callable filter, or with unknown keys, is rejected."""
+        logger = _fresh_logger()
+        with pytest.raises(ValueError, match="requires a callable filter"):
+            logger.add(tmp_path / "a.log", filter_fields=["message"])
+        with pytest.raises(ValueError, match="requires a callable filter"):
+            logger.add(tmp_path / "b.log", filter="app", filter_fields=["message"])
+        with pytest.raises(ValueError, match="Unknown record field"):
+            logger.add(tmp_path / "c.log", filter=bool, filter_fields=["mesage"])
@@ -2,10 +2,18 @@
 
 from __future__ import annotations
 
+import json
+import os
 import re
+import socket
+import subprocess
+import sys
 import threading
+from datetime import datetime, timezone
 from pathlib import Path
 
+import pytest
+
 from logust import Logger, LogLevel
 from logust._logust import PyLogger
 
@@ -191,6 +199,59 @@ class TestModuleToken:
         assert "Test message" in content
 
 
+class TestExceptionToken:
+    """Test {exception} format token."""
+
+    def test_exception_token_places_traceback(self, tmp_path: Path) -> None:
+        """Test that {exception} renders in place instead of being appended."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.disable()
+
+        log_file = tmp_path / "exception.log"
+        logger.add(str(log_file), format="{level} | {exception} | {message}")
+
+        logger.error("failed", exception="ValueError: bad")
+        logger.info("no error")
+        logger.complete()
+
+        assert log_file.read_text() == "ERROR | ValueError: bad | failed\nINFO |  | no error\n"
+
+    def test_exception_appended_without_token(self, tmp_path: Path) -> None:
+        """Test that templates without {exception} still append it on a new line."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.disable()
+
+        log_file = tmp_path / "appended.log"
+        logger.add(str(log_file), format="{level} | {message}")
+
+        logger.error("failed", exception="ValueError: bad")
+        logger.complete()
+
+        assert log_file.read_text() == "ERROR | failed\nValueError: bad\n"
+
+    def test_exception_type_and_message(self, tmp_path: Path) -> None:
+        """Test {exception.type} / {exception.message} and the matching JSON keys."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.disable()
+
+        text_file = tmp_path / "types.log"
+        json_file = tmp_path / "types.json"
+        logger.add(str(text_file), format="{exception.type}: {message} ({exception.message})")
+        logger.add(str(json_file), serialize=True)
+
+        try:
+            {}["port"]
+        except KeyError as e:
+            logger.error_exc("config failed", e)
+        logger.complete()
+
+        assert text_file.read_text().splitlines()[0] == "KeyError: config failed ('port')"
+        record = json.loads(json_file.read_text())
+        assert record["exception_type"] == "KeyError"
+        assert record["exception_message"] == "'port'"
+        assert record["exception"].startswith("Traceback (most recent call last):")
+
+
 class TestCombinedFormatTokens:
     """Test multiple format tokens together."""
 
@@ -232,3 +293,499 @@ class TestCombinedFormatTokens:
         content = log_file.read_text()
         assert "INFO" in content
         assert "Mixed format test" in content
+
+
+class TestTraceContext:
+    """Test {trace_id}/{span_id} tokens and their promotion in JSON output."""
+
+    def test_tokens_read_extra(self, tmp_path: Path) -> None:
+        """Test that {trace_id} and {span_id} render the extra fields."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.disable()
+
+        log_file = tmp_path / "trace.log"
+        logger.add(str(log_file), format="[{trace_id}:{span_id}] {message}")
+
+        logger.bind(trace_id="4bf92f35", span_id="00f067aa").info("traced")
+        logger.info("untraced")
+        logger.complete()
+
+        assert log_file.read_text() == "[4bf92f35:00f067aa] traced\n[:] untraced\n"
+
+    def test_json_promotes_trace_context(self, tmp_path: Path) -> None:
+        """Test that serialized records carry trace_id/span_id at the top level."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.disable()
+
+        log_file = tmp_path / "trace.json"
+        logger.add(str(log_file), serialize=True)
+
+        logger.bind(trace_id="4bf92f35", span_id="00f067aa", user="alice").info("traced")
+        logger.complete()
+
+        record = json.loads(log_file.read_text())
+        assert record["trace_id"] == "4bf92f35"
+        assert record["span_id"] == "00f067aa"
+        assert record["extra"] == {"user": "alice"}
+
+    def test_callable_sink_matches_file_output(self) -> None:
+        """Test that callable sinks render the tokens and promote the keys too."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.disable()
+        lines: list[str] = []
+        payloads: list[str] = []
+        logger.add(lines.append, format="{trace_id} {message}")
+        logger.add(payloads.append, serialize=True)
+
+        logger.info("traced", trace_id="4bf92f35")
+
+        assert lines == ["4bf92f35 traced"]
+        record = json.loads(payloads[0])
+        assert record["trace_id"] == "4bf92f35"
+        assert "extra" not in record
+
+
+class TestUtcTime:
+    """Test the {time:utc} token and the utc handler option."""
+
+    def test_time_utc_token(self, tmp_path: Path) -> None:
+        """Test that {time:utc} renders the timestamp in UTC next to local {time}."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.disable()
+
+        log_file = tmp_path / "utc.log"
+        logger.add(str(log_file), format="{time} | {time:utc} | {message}")
+
+        before = datetime.now(timezone.utc)
+        logger.info("stamped")
+        logger.complete()
+
+        local, utc, message = log_file.read_text().rstrip("\n").split(" | ")
+        assert message == "stamped"
+        stamped = datetime.strptime(utc, "%Y-%m-%d %H:%M:%S.%f").replace(tzinfo=timezone.utc)
+        assert abs((stamped - before).total_seconds()) < 5
+        assert re.match(r"\d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}\.\d{3}$", local)
+
+    def test_utc_option_applies_to_json(self, tmp_path: Path) -> None:
+        """Test that utc=True renders serialized timestamps in UTC."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.disable()
+
+        log_file = tmp_path / "utc.json"
+        logger.add(str(log_file), serialize=True, utc=True)
+
+        before = datetime.now(timezone.utc)
+        logger.info("stamped")
+        logger.complete()
+
+        record = json.loads(log_file.read_text())
+        stamped = datetime.strptime(record["time"], "%Y-%m-%d %H:%M:%S.%f").replace(
+            tzinfo=timezone.utc
+        )
+        assert abs((stamped - before).total_seconds()) < 5
+
+    def test_callable_sink_time_utc(self) -> None:
+        """Test that callable sinks convert {time:utc} to UTC."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.disable()
+        lines: list[str] = []
+        logger.add(lines.append, format="{time:utc}")
+
+        logger.info("stamped")
+
+        stamped = datetime.fromisoformat(lines[0])
+        assert stamped.utcoffset() is not None
+        assert stamped.utcoffset().total_seconds() == 0
+
+    def test_callable_sink_utc_option(self) -> None:
+        """Test that utc=True converts {time} and JSON time for callable sinks."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.disable()
+        lines: list[str] = []
+        payloads: list[str] = []
+        logger.add(lines.append, format="{time}", utc=True)
+        logger.add(payloads.append, serialize=True, utc=True)
+
+        logger.info("stamped")
+
+        for stamp in (lines[0], json.loads(payloads[0])["time"]):
+            assert datetime.fromisoformat(stamp).utcoffset().total_seconds() == 0
+
+    def test_per_handler_timezone(self, tmp_path: Path) -> None:
+        """Test local and UTC handlers side by side, with rotation still in local time."""
+        code = f"""
+import sys
+from pathlib import Path
+from logust import Logger, LogLevel
+from logust._logust import PyLogger
+
+tmp = Path({str(tmp_path)!r})
+logger = Logger(PyLogger(LogLevel.Trace))
+logger.disable()
+logger.add(sys.stdout, format="{{time}}", colorize=False)
+logger.add(str(tmp / "utc.log"), format="{{time}}", utc=True)
+logger.add(str(tmp / "rotating.log"), format="{{message}}", rotation="1 KB", utc=True)
+logger.info("x" * 2048)
+logger.info("rotate")
+logger.complete()
+"""
+        # Fixed +09:00 offset, so local and UTC always differ
+        env = {**os.environ, "TZ": "JST-9"}
+        result = subprocess.run(
+            [sys.executable, "-c", code], capture_output=True, text=True, env=env
+        )
+        assert result.returncode == 0, result.stderr
+
+        fmt = "%Y-%m-%d %H:%M:%S.%f"
+        local = datetime.strptime(result.stdout.splitlines()[0], fmt)
+        utc = datetime.strptime((tmp_path / "utc.log").read_text().splitlines()[0], fmt)
+        assert round((local - utc).total_seconds() / 3600) == 9
+
+        # Rotated file names are stamped in local time regardless of utc=True
+        rotated = [p.name for p in tmp_path.glob("rotating.*_*.log")]
+        assert len(rotated) == 1
+        stamp = datetime.strptime(rotated[0].split(".")[1][:19], "%Y-%m-%d_%H-%M-%S")
+        assert abs((stamp - local).total_seconds()) < 60
+
+
+class TestTimePrecision:
+    """Test the time_precision handler option."""
+
+    def test_micros_in_text(self, tmp_path: Path) -> None:
+        """Test that time_precision="micros" renders six fractional digits."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.disable()
+
+        log_file = tmp_path / "micros.log"
+        logger.add(str(log_file), format="{time} | {message}", time_precision="micros")
+
+        logger.info("precise")
+        logger.complete()
+
+        assert re.match(
+            r"\d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}\.\d{6} \| precise$", log_file.read_text()
+        )
+
+    def test_seconds_in_json(self, tmp_path: Path) -> None:
+        """Test that time_precision="seconds" drops the fraction from JSON time."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.disable()
+
+        log_file = tmp_path / "seconds.json"
+        logger.add(str(log_file), serialize=True, time_precision="seconds")
+
+        logger.info("coarse")
+        logger.complete()
+
+        record = json.loads(log_file.read_text())
+        assert re.fullmatch(r"\d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}", record["time"])
+
+    def test_invalid_precision_rejected(self, tmp_path: Path) -> None:
+        """Test that an unknown precision raises ValueError."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.disable()
+
+        with pytest.raises(ValueError, match="time_precision"):
+            logger.add(str(tmp_path / "bad.log"), time_precision="hours")
+
+
+class TestTimeFormat:
+    """Test the time_format handler option."""
+
+    def test_custom_format_in_text_and_json(self, tmp_path: Path) -> None:
+        """Test that time_format applies to {time} and the JSON time field."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.disable()
+
+        text_file = tmp_path / "custom.log"
+        json_file = tmp_path / "custom.json"
+        logger.add(str(text_file), format="{time} | {message}", time_format="%d/%m/%Y %H:%M")
+        logger.add(str(json_file), serialize=True, time_format="%Y%m%dT%H%M%S")
+
+        logger.info("custom")
+        logger.complete()
+
+        assert re.fullmatch(r"\d{2}/\d{2}/\d{4} \d{2}:\d{2} \| custom\n", text_file.read_text())
+        record = json.loads(json_file.read_text())
+        assert re.fullmatch(r"\d{8}T\d{6}", record["time"])
+
+    def test_precision_applies_to_custom_format(self, tmp_path: Path) -> None:
+        """Test that time_precision replaces the %.3f of a custom format."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.disable()
+
+        log_file = tmp_path / "micros.log"
+        logger.add(
+            str(log_file), format="{time}", time_format="%H:%M:%S%.3f", time_precision="micros"
+        )
+
+        logger.info("precise")
+        logger.complete()
+
+        assert re.fullmatch(r"\d{2}:\d{2}:\d{2}\.\d{6}\n", log_file.read_text())
+
+    def test_configure_accepts_time_format(self, tmp_path: Path) -> None:
+        """Test that configure() handler specs take time_format."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+
+        log_file = tmp_path / "configured.log"
+        logger.configure(
+            handlers=[{"sink": str(log_file), "format": "{time}", "time_format": "%Y"}]
+        )
+        logger.info("configured")
+        logger.complete()
+
+        assert re.fullmatch(r"\d{4}\n", log_file.read_text())
+
+    def test_invalid_format_rejected(self, tmp_path: Path) -> None:
+        """Test that an unknown strftime specifier raises ValueError for every entry point."""
+        inner = PyLogger(LogLevel.Trace)
+        logger = Logger(inner)
+        logger.remove()
+
+        with pytest.raises(ValueError, match="Invalid time format"):
+            logger.add(str(tmp_path / "bad.log"), time_format="%Y %Q")
+        with pytest.raises(ValueError, match="Invalid time format"):
+            logger.add(sys.stderr, time_format="%Q")
+        with pytest.raises(ValueError, match="Invalid time format"):
+            logger.configure(handlers=[{"sink": str(tmp_path / "bad.log"), "time_format": "%Q"}])
+
+        assert inner.handler_count == 0
+
+
+class TestMessageTruncation:
+    """Test the {message:.N} truncation spec."""
+
+    def test_long_message_truncated(self, tmp_path: Path) -> None:
+        """Test that {message:.N} cuts to N characters, the last an ellipsis."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.disable()
+
+        log_file = tmp_path / "truncated.log"
+        logger.add(str(log_file), format="{message:.10}")
+
+        logger.info("short")
+        logger.info("x" * 500)
+        logger.info("日本語のメッセージはとても長い")
+        logger.complete()
+
+        assert log_file.read_text().splitlines() == [
+            "short",
+            "x" * 9 + "\u2026",
+            "日本語のメッセージ\u2026",
+        ]
+
+    def test_precision_too_narrow_for_ellipsis(self, tmp_path: Path) -> None:
+        """Test that .0 renders nothing and .1 keeps one character without an ellipsis."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.disable()
+
+        empty_file = tmp_path / "empty.log"
+        one_file = tmp_path / "one.log"
+        logger.add(str(empty_file), format="[{message:.0}]")
+        logger.add(str(one_file), format="[{message:.1}]")
+        lines: list[str] = []
+        logger.add(lines.append, format="[{message:.0}|{message:.1}]")
+
+        logger.info("payload")
+        logger.info("p")
+        logger.complete()
+
+        assert empty_file.read_text().splitlines() == ["[]", "[]"]
+        assert one_file.read_text().splitlines() == ["[p]", "[p]"]
+        assert lines == ["[|p]", "[|p]"]
+
+    def test_other_handlers_keep_full_message(self, tmp_path: Path) -> None:
+        """Test that truncation is per handler."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.disable()
+
+        short_file = tmp_path / "short.log"
+        full_file = tmp_path / "full.log"
+        logger.add(str(short_file), format="{message:.3}")
+        logger.add(str(full_file), format="{message}")
+
+        logger.info("payload")
+        logger.complete()
+
+        assert short_file.read_text() == "pa\u2026\n"
+        assert full_file.read_text() == "payload\n"
+
+    def test_callable_sink_matches_file_output(self) -> None:
+        """Test that callable sinks truncate the same way."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.disable()
+        lines: list[str] = []
+        logger.add(lines.append, format="{message:.3}")
+
+        logger.info("payload")
+
+        assert lines == ["pa\u2026"]
+
+
+class TestExtraAllToken:
+    """Test the bare {extra} token that renders every field logfmt-style."""
+
+    def test_file_output_is_logfmt(self, tmp_path: Path) -> None:
+        """Test that fields are sorted and values needing it are quoted."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.disable()
+
+        log_file = tmp_path / "logfmt.log"
+        logger.add(str(log_file), format="{message} {extra}")
+
+        logger.bind(user="alice", request_id=7).info("hello", note='say "hi"')
+        logger.info("bare")
+        logger.complete()
+
+        assert log_file.read_text().splitlines() == [
+            'hello note="say \\"hi\\"" request_id=7 user=alice',
+            "bare ",
+        ]
+
+    def test_custom_separator(self, tmp_path: Path) -> None:
+        """Test that {extra:SEP} joins pairs with SEP."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.disable()
+
+        log_file = tmp_path / "sep.log"
+        logger.add(str(log_file), format="{extra:, }")
+
+        logger.info("x", a=1, b="two words")
+        logger.complete()
+
+        assert log_file.read_text() == 'a=1, b="two words"\n'
+
+    def test_callable_sink_matches_file_output(self, tmp_path: Path) -> None:
+        """Test that callable sinks render {extra} the same way."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.disable()
+        lines: list[str] = []
+        log_file = tmp_path / "file.log"
+        fmt = "{extra:|}"
+        logger.add(lines.append, format=fmt)
+        logger.add(str(log_file), format=fmt)
+
+        logger.info("x", empty="", key="a=b", n=3)
+        logger.complete()
+
+        assert lines == ['empty=""|key="a=b"|n=3']
+        assert log_file.read_text() == lines[0] + "\n"
+
+
+class TestValidateFormat:
+    """Test validate_format()."""
+
+    def test_reports_unrecognized_placeholders(self) -> None:
+        """Test that typos are listed separately from known placeholders."""
+        result = Logger.validate_format("{tiem} | {level:<8} | {levl} {extra[user]} {message}")
+
+        assert result == {
+            "recognized": ["level:<8", "extra[user]", "message"],
+            "unrecognized": ["tiem", "levl"],
+        }
+
+    def test_default_format_is_valid(self) -> None:
+        """Test that the module-level shortcut accepts the default format."""
+        import logust
+
+        template = "{time} | {level:<8} | {name}:{function}:{line} - {message}"
+        assert logust.validate_format(template)["unrecognized"] == []
+
+
+class TestStrictFormat:
+    """Test the strict_format option of add()."""
+
+    def test_unknown_placeholder_raises(self, tmp_path: Path) -> None:
+        """Test that file and console handlers reject typo'd placeholders."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.remove()
+
+        with pytest.raises(ValueError, match=r"Unknown format placeholder\(s\): \{levl\}"):
+            logger.add(tmp_path / "app.log", format="{levl} {message}", strict_format=True)
+        with pytest.raises(ValueError, match=r"\{tiem\}"):
+            logger.add(sys.stderr, format="{tiem} {message}", strict_format=True)
+        assert not (tmp_path / "app.log").exists()
+
+    def test_lenient_by_default(self, tmp_path: Path) -> None:
+        """Test that unknown placeholders stay literal text without strict_format."""
+        log_file = tmp_path / "app.log"
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.remove()
+
+        logger.add(log_file, format="{levl} {message}")
+        logger.add(tmp_path / "ok.log", format="{level:<8} {message}", strict_format=True)
+        logger.info("hello")
+        logger.complete()
+
+        assert log_file.read_text() == "{levl} hello\n"
+
+
+class TestEscapedBraces:
+    """Test {{ and }} as literal braces in templates."""
+
+    def test_file_and_callable_sinks(self, tmp_path: Path) -> None:
+        """Test that {{level}} renders literally while {level} is replaced."""
+        log_file = tmp_path / "app.log"
+        lines: list[str] = []
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.remove()
+
+        template = '{{"level": "{level}", "text": "{message}"}} {{level}}'
+        logger.add(log_file, format=template)
+        logger.add(lines.append, format=template)
+        logger.info("hi")
+        logger.complete()
+
+        expected = '{"level": "INFO", "text": "hi"} {level}'
+        assert log_file.read_text() == expected + "\n"
+        assert lines == [expected]
+        assert Logger.validate_format("{{levl}} {message}")["unrecognized"] == []
+
+
+class TestSeqToken:
+    """Test {seq} format token."""
+
+    def test_seq_counts_records_across_sinks_and_bound_loggers(self, tmp_path: Path) -> None:
+        """Every emitted record gets the next number, whichever logger copy emitted it."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.disable()
+        logger.level("SEQTEST", no=33)
+
+        text_file = tmp_path / "seq.log"
+        json_file = tmp_path / "seq.json"
+        lines: list[str] = []
+        logger.add(text_file, format="{seq} {message}", level="INFO")
+        logger.add(json_file, serialize=True, level="INFO")
+        logger.add(lines.append, format="{seq}:{message}", level="INFO")
+
+        logger.debug("dropped")
+        logger.info("first")
+        logger.bind(user="alice").warning("second")
+        logger.log("SEQTEST", "third")
+        logger.complete()
+
+        assert text_file.read_text().splitlines() == ["1 first", "2 second", "3 third"]
+        assert lines == ["1:first", "2:second", "3:third"]
+        records = [json.loads(line) for line in json_file.read_text().splitlines()]
+        assert [r["seq"] for r in records] == [1, 2, 3]
+
+
+class TestHostTokens:
+    """Test {hostname} and {pid} format tokens."""
+
+    def test_hostname_and_pid(self, tmp_path: Path) -> None:
+        """File and callable sinks write this host's name and the current pid."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.disable()
+        log_file = tmp_path / "host.log"
+        lines: list[str] = []
+        logger.add(log_file, format="{hostname} {pid} {message}")
+        logger.add(lines.append, format="{hostname} {pid} {message}")
+
+        logger.info("hello")
+        logger.complete()
+
+        expected = f"{socket.gethostname()} {os.getpid()} hello"
+        assert log_file.read_text() == expected + "\n"
+        assert lines == [expected]
@@ -2,6 +2,14 @@
 
 from __future__ import annotations
 
+import csv
+import gzip
+import json
+import os
+import socket
+import subprocess
+import sys
+import time
 from pathlib import Path
 
 import pytest
@@ -112,6 +120,33 @@ class TestRotation:
         content = log_file.read_text()
         assert "Message number 00000" in content
 
+    def test_on_rotation_receives_rotated_paths(self, tmp_path: Path) -> None:
+        """on_rotation is called from a background thread with each rotated file."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.remove()
+        rotated: list[Path] = []
+        logger.add(
+            str(tmp_path / "app.log"),
+            format="{message}",
+            rotation="10 B",
+            on_rotation=rotated.append,
+        )
+
+        for i in range(3):
+            logger.info(f"record-{i:03}")
+        logger.complete()
+
+        deadline = time.monotonic() + 5
+        while len(rotated) < 2 and time.monotonic() < deadline:
+            time.sleep(0.01)
+        assert len(rotated) == 2
+        assert [path.read_text() for path in rotated] == ["record-000\n", "record-001\n"]
+
+    def test_on_rotation_must_be_callable(self, tmp_path: Path) -> None:
+        logger = Logger(PyLogger(LogLevel.Trace))
+        with pytest.raises(TypeError, match="on_rotation must be callable"):
+            logger.add(str(tmp_path / "app.log"), on_rotation="upload")
+
 
 class TestRetention:
     """Test log retention."""
@@ -141,6 +176,48 @@ class TestCompression:
         content = log_file.read_text()
         assert "Compressed message test" in content
 
+    def test_compress_on_close(self, tmp_path: Path) -> None:
+        """compress_on_close gzips the active file when the handler is removed."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.remove()
+        log_file = tmp_path / "job.log"
+        handle = logger.add(
+            str(log_file),
+            format="{message}",
+            compression=True,
+            compress_on_close=True,
+            enqueue=True,
+        )
+
+        logger.info("done")
+        handle.remove()
+
+        assert not log_file.exists()
+        assert gzip.decompress((tmp_path / "job.log.gz").read_bytes()) == b"done\n"
+
+    def test_rotation_with_compression(self, tmp_path: Path) -> None:
+        """complete() returns once the rotated files are gzipped."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.remove()
+        log_file = tmp_path / "app.log"
+        logger.add(str(log_file), format="{message}", rotation="10 B", compression=True)
+
+        for i in range(4):
+            logger.info(f"record-{i:03}")
+        logger.complete()
+
+        rotated = sorted(p for p in tmp_path.iterdir() if p != log_file and p.suffix != ".lock")
+        assert len(rotated) == 3
+        assert all(p.name.endswith(".log.gz") for p in rotated)
+        contents = sorted(gzip.decompress(p.read_bytes()) for p in rotated)
+        assert contents == [b"record-000\n", b"record-001\n", b"record-002\n"]
+        assert log_file.read_text() == "record-003\n"
+
+    def test_compress_on_close_requires_compression(self, tmp_path: Path) -> None:
+        logger = Logger(PyLogger(LogLevel.Trace))
+        with pytest.raises(ValueError, match="compress_on_close requires compression=True"):
+            logger.add(str(tmp_path / "job.log"), compress_on_close=True)
+
 
 class TestSerialization:
     """Test JSON serialization."""
@@ -181,6 +258,1251 @@ class TestEnqueue:
         with pytest.raises(OSError):
             logger.add(str(invalid_path), enqueue=True)
 
+    def test_shared_writer(self, tmp_path: Path) -> None:
+        """shared_writer=True sinks write their own files through one thread."""
+        inner = PyLogger(LogLevel.Trace)
+        logger = Logger(inner)
+        logger.remove()
+        paths = [tmp_path / f"{name}.log" for name in ("a", "b")]
+        handles = [
+            logger.add(str(path), format="{message}", enqueue=True, shared_writer=True)
+            for path in paths
+        ]
+
+        for i in range(500):
+            logger.info(str(i))
+        handles[0].remove()
+        logger.info("after")
+        logger.complete()
+
+        expected = [str(i) for i in range(500)]
+        assert paths[0].read_text().splitlines() == expected
+        assert paths[1].read_text().splitlines() == [*expected, "after"]
+
+    def test_shared_writer_requires_enqueue(self, tmp_path: Path) -> None:
+        logger = Logger(PyLogger(LogLevel.Trace))
+        with pytest.raises(ValueError, match="shared_writer requires enqueue=True"):
+            logger.add(str(tmp_path / "app.log"), shared_writer=True)
+
+    def test_max_queue_bytes(self, tmp_path: Path) -> None:
+        """Test that a byte-capped queue still writes every record in order."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.remove()
+        log_file = tmp_path / "capped.log"
+        handle = logger.add(log_file, format="{message}", enqueue=True, max_queue_bytes="4 KB")
+
+        payload = "x" * 1000
+        for i in range(200):
+            logger.info(f"{i} {payload}")
+        logger.complete()
+
+        assert [line.split()[0] for line in log_file.read_text().splitlines()] == [
+            str(i) for i in range(200)
+        ]
+        stats = logger.handler_stats(handle)
+        assert stats is not None
+        assert stats["queued_bytes"] == 0
+
+    def test_max_queue_bytes_validation(self, tmp_path: Path) -> None:
+        logger = Logger(PyLogger(LogLevel.Trace))
+        with pytest.raises(ValueError, match="max_queue_bytes requires enqueue=True"):
+            logger.add(tmp_path / "a.log", max_queue_bytes=1024)
+        with pytest.raises(ValueError, match="invalid max_queue_bytes"):
+            logger.add(tmp_path / "b.log", enqueue=True, max_queue_bytes="lots")
+
+    @pytest.mark.skipif(not os.path.exists("/dev/full"), reason="needs /dev/full")
+    def test_write_retries_skip_persistent_errors(self) -> None:
+        """Test that a full disk fails the record at once instead of waiting for retries."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.remove()
+        handle = logger.add(
+            "/dev/full", format="{message}", autoflush=True, write_retries=3, write_retry_backoff=5
+        )
+
+        started = time.monotonic()
+        logger.info("lost")
+        assert time.monotonic() - started < 1
+
+        stats = logger.handler_stats(handle)
+        assert stats is not None
+        assert stats["write_failures"] == 1
+
+    def test_write_retries_options(self, tmp_path: Path) -> None:
+        """Test that the retry options are accepted and records still write normally."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.remove()
+        log_file = tmp_path / "retry.log"
+        handle = logger.add(log_file, format="{message}", write_retries=2, write_retry_backoff=0)
+
+        logger.info("written")
+        logger.complete()
+
+        assert log_file.read_text() == "written\n"
+        stats = logger.handler_stats(handle)
+        assert stats is not None
+        assert stats["write_failures"] == 0
+        with pytest.raises(ValueError, match="write_retry_backoff"):
+            logger.add(tmp_path / "a.log", write_retry_backoff=-1)
+        with pytest.raises(ValueError, match="write_retry_backoff"):
+            logger.add(tmp_path / "b.log", write_retry_backoff=float("nan"))
+
+    def test_exit_without_complete_keeps_queued_records(self, tmp_path: Path) -> None:
+        """Test that the atexit hook drains enqueue=True writers of every logger."""
+        code = f"""
+import logust
+from logust import Logger, LogLevel, PyLogger
+
+logust.logger.remove()
+logust.logger.add({str(tmp_path / "global.log")!r}, format="{{message}}", enqueue=True)
+own = Logger(PyLogger(LogLevel.Trace))
+own.remove()
+own.add({str(tmp_path / "own.log")!r}, format="{{message}}", enqueue=True)
+for i in range(2000):
+    logust.logger.info(str(i))
+    own.bind(i=i).info(str(i))
+"""
+        result = subprocess.run([sys.executable, "-c", code], capture_output=True, text=True)
+        assert result.returncode == 0, result.stderr
+
+        expected = [str(i) for i in range(2000)]
+        assert (tmp_path / "global.log").read_text().splitlines() == expected
+        assert (tmp_path / "own.log").read_text().splitlines() == expected
+
+
+@pytest.mark.skipif(sys.platform == "win32", reason="open files cannot be removed on Windows")
+class TestWatch:
+    """Test watch=True reopening externally deleted or rotated files."""
+
+    @pytest.mark.parametrize("enqueue", [False, True])
+    def test_watch_recreates_deleted_file(self, tmp_path: Path, enqueue: bool) -> None:
+        """Test that logging continues into a new file after the old one is removed."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.remove()
+        log_file = tmp_path / "app.log"
+        logger.add(log_file, format="{message}", watch=True, enqueue=enqueue)
+
+        logger.info("before")
+        logger.complete()
+        log_file.unlink()
+
+        logger.info("after")
+        logger.complete()
+        assert log_file.read_text() == "after\n"
+
+    def test_watch_follows_logrotate_rename(self, tmp_path: Path) -> None:
+        """Test that a renamed file keeps its content and a fresh file is started."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.remove()
+        log_file = tmp_path / "app.log"
+        logger.configure(handlers=[{"sink": log_file, "format": "{message}", "watch": True}])
+
+        logger.info("first")
+        log_file.rename(tmp_path / "app.log.1")
+        logger.info("second")
+        logger.complete()
+
+        assert (tmp_path / "app.log.1").read_text() == "first\n"
+        assert log_file.read_text() == "second\n"
+
+
+class TestLineTerminator:
+    """Test the line_terminator option for file sinks."""
+
+    @pytest.mark.parametrize("enqueue", [False, True])
+    def test_crlf_terminator(self, tmp_path: Path, enqueue: bool) -> None:
+        """Test that records end with CRLF when requested."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.remove()
+        log_file = tmp_path / f"crlf-{enqueue}.log"
+        logger.add(log_file, format="{message}", line_terminator="\r\n", enqueue=enqueue)
+
+        logger.info("one")
+        logger.info("two")
+        logger.complete()
+
+        assert log_file.read_bytes() == b"one\r\ntwo\r\n"
+
+    def test_nul_terminator_via_configure(self, tmp_path: Path) -> None:
+        """Test that configure() accepts line_terminator."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.remove()
+        log_file = tmp_path / "nul.log"
+        logger.configure(
+            handlers=[{"sink": log_file, "format": "{message}", "line_terminator": "\0"}]
+        )
+
+        logger.info("a\nb")
+        logger.info("c")
+        logger.complete()
+
+        assert log_file.read_bytes().split(b"\0") == [b"a\nb", b"c", b""]
+
+
+class TestLineAffixes:
+    """Test the prefix/suffix handler options."""
+
+    def test_prefix_and_suffix_wrap_lines(self, tmp_path: Path) -> None:
+        """Test that the affixes go around the rendered line, before the terminator."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.remove()
+        log_file = tmp_path / "affixes.log"
+        logger.add(log_file, format="{level} {message}", prefix="[web-1] ", suffix=" ;")
+
+        logger.info("one")
+        logger.warning("two")
+        logger.complete()
+
+        assert log_file.read_text() == "[web-1] INFO one ;\n[web-1] WARNING two ;\n"
+
+    def test_json_ignores_affixes(self, tmp_path: Path) -> None:
+        """Test that serialized handlers keep one JSON document per line."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.remove()
+        log_file = tmp_path / "affixes.json"
+        logger.configure(handlers=[{"sink": log_file, "serialize": True, "prefix": "[web-1] "}])
+
+        logger.info("one")
+        logger.complete()
+
+        assert log_file.read_text().startswith('{"time"')
+
+    def test_callable_sink(self) -> None:
+        """Test that callable sinks apply the affixes too."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.remove()
+        lines: list[str] = []
+        logger.add(lines.append, format="{message}", prefix="> ")
+
+        logger.info("one")
+
+        assert lines == ["> one"]
+
+
+class TestEnvironmentDefaults:
+    """Test the LOGUST_FORMAT / LOGUST_LEVEL / LOGUST_STREAM fallbacks."""
+
+    def _run(self, tmp_path: Path, env: dict[str, str]) -> subprocess.CompletedProcess[str]:
+        code = f"""
+import logust
+logust.logger.add({str(tmp_path / "env.log")!r})
+logust.logger.add({str(tmp_path / "explicit.log")!r}, format="{{message}}", level="TRACE")
+logust.logger.info("info")
+logust.logger.warning("warning")
+logust.logger.complete()
+"""
+        env = {**os.environ, **env}
+        env.pop("NO_COLOR", None)
+        return subprocess.run([sys.executable, "-c", code], capture_output=True, text=True, env=env)
+
+    def test_env_format_and_level(self, tmp_path: Path) -> None:
+        """Test that handlers without format/level use the environment."""
+        result = self._run(
+            tmp_path, {"LOGUST_FORMAT": "[{level}] {message}", "LOGUST_LEVEL": "warning"}
+        )
+        assert result.returncode == 0, result.stderr
+
+        assert result.stdout == "[WARNING] warning\n"
+        assert (tmp_path / "env.log").read_text() == "[WARNING] warning\n"
+        # Explicit options win over the environment
+        assert (tmp_path / "explicit.log").read_text() == "info\nwarning\n"
+
+    def test_invalid_env_level_falls_back(self, tmp_path: Path) -> None:
+        """Test that an unknown LOGUST_LEVEL is ignored."""
+        result = self._run(tmp_path, {"LOGUST_FORMAT": "{message}", "LOGUST_LEVEL": "loud"})
+        assert result.returncode == 0, result.stderr
+
+        assert (tmp_path / "env.log").read_text() == "info\nwarning\n"
+
+    def test_env_stream_moves_default_console_to_stderr(self, tmp_path: Path) -> None:
+        """Test that LOGUST_STREAM=stderr keeps stdout clean."""
+        result = self._run(tmp_path, {"LOGUST_FORMAT": "{message}", "LOGUST_STREAM": "stderr"})
+        assert result.returncode == 0, result.stderr
+
+        assert result.stdout == ""
+        assert result.stderr == "info\nwarning\n"
+
+
+class TestDefaultConsoleStream:
+    """Test choosing the default console stream when constructing a logger."""
+
+    def test_stream_survives_reset_and_enable(self) -> None:
+        """Test that reset() and enable() recreate the console on the chosen stream."""
+        code = """
+import sys
+from logust import Logger, LogLevel
+from logust._logust import PyLogger
+logger = Logger(PyLogger(LogLevel.Info, stream=sys.stderr))
+logger.info("constructed")
+logger.disable()
+logger.enable()
+logger.info("enabled")
+logger.reset()
+logger.info("reset")
+"""
+        env = {**os.environ, "LOGUST_FORMAT": "{message}"}
+        env.pop("LOGUST_STREAM", None)
+        result = subprocess.run(
+            [sys.executable, "-c", code], capture_output=True, text=True, env=env
+        )
+        assert result.returncode == 0, result.stderr
+
+        assert result.stdout == ""
+        assert result.stderr == "constructed\nenabled\nreset\n"
+
+    def test_invalid_stream(self) -> None:
+        """Test that streams other than stdout/stderr are rejected."""
+        with pytest.raises(ValueError, match="stream must be 'stdout' or 'stderr'"):
+            PyLogger(LogLevel.Info, stream="stdin")
+
+    def test_enqueued_console_writes_every_line(self) -> None:
+        """Test that enqueue=True console lines are written by complete() and at exit."""
+        code = """
+import sys
+from logust import Logger, LogLevel
+from logust._logust import PyLogger
+logger = Logger(PyLogger(LogLevel.Info))
+logger.remove()
+logger.add(sys.stdout, format="{message}", enqueue=True)
+logger.add(sys.stderr, format="{message}", enqueue=True, level="ERROR")
+for i in range(500):
+    logger.info(f"line {i}")
+logger.complete()
+sys.stderr.write("completed\\n")
+logger.error("after complete")
+"""
+        result = subprocess.run([sys.executable, "-c", code], capture_output=True, text=True)
+        assert result.returncode == 0, result.stderr
+
+        assert result.stdout.splitlines() == [f"line {i}" for i in range(500)] + [
+            "after complete"
+        ]
+        assert result.stderr == "completed\nafter complete\n"
+
+
+class TestAutoflush:
+    """Test the autoflush option for file sinks."""
+
+    def test_records_are_on_disk_without_complete(self, tmp_path: Path) -> None:
+        """Test that each record is flushed as soon as it is logged."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.remove()
+        log_file = tmp_path / "durable.log"
+        logger.add(log_file, format="{message}", autoflush=True)
+
+        logger.info("first")
+        assert log_file.read_text() == "first\n"
+        logger.info("second")
+        assert log_file.read_text() == "first\nsecond\n"
+
+    def test_autoflush_via_configure(self, tmp_path: Path) -> None:
+        """Test that configure() accepts autoflush."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.remove()
+        log_file = tmp_path / "configured.log"
+        logger.configure(handlers=[{"sink": log_file, "format": "{message}", "autoflush": True}])
+
+        logger.info("kept")
+
+        assert log_file.read_text() == "kept\n"
+
+
+class TestFsync:
+    """Test the fsync option for file sinks."""
+
+    @pytest.mark.parametrize(
+        ("enqueue", "autoflush"), [(False, False), (False, True), (True, False), (True, True)]
+    )
+    def test_fsync_writes_records(self, tmp_path: Path, enqueue: bool, autoflush: bool) -> None:
+        """Test that fsync sinks write every record, with or without autoflush."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.remove()
+        log_file = tmp_path / f"audit-{enqueue}-{autoflush}.log"
+        logger.add(
+            log_file, format="{message}", fsync=True, autoflush=autoflush, enqueue=enqueue
+        )
+
+        logger.info("first")
+        logger.info("second")
+        logger.complete()
+
+        assert log_file.read_text() == "first\nsecond\n"
+
+
+class TestRoutedFiles:
+    """Test file paths with {extra[key]} routing placeholders."""
+
+    def test_records_are_routed_by_extra(self, tmp_path: Path) -> None:
+        """Test that each record goes to the file named by its extra field."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.remove()
+        logger.add(str(tmp_path / "{extra[tenant]}" / "app.log"), format="{message}")
+
+        logger.bind(tenant="acme").info("one")
+        logger.info("two", extra={"tenant": "globex"})
+        logger.bind(tenant="acme").info("three")
+        logger.complete()
+
+        assert (tmp_path / "acme" / "app.log").read_text() == "one\nthree\n"
+        assert (tmp_path / "globex" / "app.log").read_text() == "two\n"
+
+    def test_values_cannot_escape_directory(self, tmp_path: Path) -> None:
+        """Test that separators are replaced and missing values use "_"."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.remove()
+        logger.add(str(tmp_path / "logs" / "{extra[tenant]}.log"), format="{message}")
+
+        logger.bind(tenant="../evil").info("escaped?")
+        logger.info("no tenant")
+        logger.complete()
+
+        assert (tmp_path / "logs" / ".._evil.log").read_text() == "escaped?\n"
+        assert (tmp_path / "logs" / "_.log").read_text() == "no tenant\n"
+        assert not (tmp_path / "evil.log").exists()
+
+    def test_max_open_files_closes_least_recently_used(self, tmp_path: Path) -> None:
+        """Test that evicted files are flushed and reopened on demand."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.remove()
+        logger.add(str(tmp_path / "{extra[n]}.log"), format="{message}", max_open_files=1)
+
+        for n in ["a", "b", "a"]:
+            logger.info(n, extra={"n": n})
+        logger.complete()
+
+        assert (tmp_path / "a.log").read_text() == "a\na\n"
+        assert (tmp_path / "b.log").read_text() == "b\n"
+
+    def test_max_open_files_must_be_positive(self, tmp_path: Path) -> None:
+        """Test that max_open_files=0 is rejected."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        with pytest.raises(ValueError, match="max_open_files"):
+            logger.add(str(tmp_path / "{extra[n]}.log"), max_open_files=0)
+
+
+class TestHandlerStats:
+    """Test handler_stats() for file handlers."""
+
+    def test_stats_track_size_and_records(self, tmp_path: Path) -> None:
+        """Test that size and record count grow with each record."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.remove()
+        log_file = tmp_path / "stats.log"
+        handler_id = logger.add(log_file, format="{message}", rotation="daily")
+
+        logger.info("abc")
+        logger.info("defgh")
+        stats = logger.handler_stats(handler_id)
+
+        assert stats is not None
+        assert stats["path"] == str(log_file)
+        assert stats["size"] == 10
+        assert stats["records"] == 2
+        assert stats["file_time"] is not None
+        assert stats["next_rotation"] is not None
+        assert stats["next_rotation"] > stats["file_time"]
+
+    def test_no_next_rotation_without_time_rotation(self, tmp_path: Path) -> None:
+        """Test that size-based and unrotated files report no rotation boundary."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.remove()
+        handler_id = logger.add(tmp_path / "sized.log", rotation="1 MB")
+
+        stats = logger.handler_stats(handler_id)
+
+        assert stats is not None
+        assert stats["next_rotation"] is None
+
+    def test_stats_count_rotations(self, tmp_path: Path) -> None:
+        """Test that each size rotation is counted."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.remove()
+        handler_id = logger.add(tmp_path / "rotating.log", format="{message}", rotation="5 B")
+
+        # Every record fills the file, so each one after the first rotates it
+        for i in range(5):
+            logger.info(f"record-{i}")
+        stats = logger.handler_stats(handler_id)
+
+        assert stats is not None
+        assert stats["records"] == 5
+        assert stats["rotations"] == 4
+
+    def test_routed_handler_sums_open_files(self, tmp_path: Path) -> None:
+        """Test that routed handlers add up the files they have open."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.remove()
+        template = str(tmp_path / "{extra[n]}.log")
+        handler_id = logger.add(template, format="{message}")
+
+        logger.info("aa", extra={"n": "a"})
+        logger.info("bbb", extra={"n": "b"})
+        stats = logger.handler_stats(handler_id)
+
+        assert stats is not None
+        assert stats["path"] == template
+        assert stats["size"] == 7
+        assert stats["records"] == 2
+
+    def test_records_count_after_level_and_filter(self, tmp_path: Path) -> None:
+        """Test that records rejected by level or filter are not counted."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.remove()
+        handler_id = logger.add(
+            tmp_path / "filtered.log",
+            level="INFO",
+            filter=lambda record: record["message"] != "skip",
+        )
+
+        logger.debug("below level")
+        logger.info("skip")
+        logger.info("kept")
+        logger.warning("kept too")
+        stats = logger.handler_stats(handler_id)
+
+        assert stats is not None
+        assert stats["records"] == 2
+
+    def test_console_handler_reports_records(self) -> None:
+        """Test that console handlers report records with no file fields."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.remove()
+        handler_id = logger.add(sys.stderr, level="ERROR")
+
+        logger.info("not counted")
+        stats = logger.handler_stats(handler_id)
+
+        assert stats == {
+            "records": 0,
+            "path": None,
+            "size": None,
+            "file_time": None,
+            "next_rotation": None,
+            "write_failures": None,
+            "rotations": None,
+            "queued_bytes": None,
+        }
+
+    def test_unknown_handler(self) -> None:
+        """Test that unknown IDs return None."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        assert logger.handler_stats(99999) is None
+
+
+class TestHandlerHandle:
+    """Test the HandlerHandle returned by add()."""
+
+    def test_remove_through_handle(self, tmp_path: Path) -> None:
+        """Test that handle.remove() stops writes and reports repeat removals."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.remove()
+        log_file = tmp_path / "handle.log"
+        handle = logger.add(log_file, format="{message}")
+
+        logger.info("kept")
+        assert handle.remove() is True
+        assert handle.remove() is False
+        logger.info("dropped")
+        logger.complete()
+
+        assert log_file.read_text() == "kept\n"
+        assert logger._inner.handler_count == 0
+
+    def test_set_level_chains(self, tmp_path: Path) -> None:
+        """Test that set_level() returns the handle and filters records."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.remove()
+        log_file = tmp_path / "level.log"
+        handle = logger.add(log_file, format="{message}").set_level("WARNING")
+
+        logger.info("skipped")
+        logger.warning("written")
+        handle.set_level(LogLevel.Debug)
+        logger.debug("now written")
+        logger.complete()
+
+        assert log_file.read_text() == "written\nnow written\n"
+
+    def test_set_level_above_max_level_is_rejected(self, tmp_path: Path) -> None:
+        """Test that set_level() keeps level <= max_level."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        handle = logger.add(tmp_path / "capped.log", max_level="INFO")
+
+        with pytest.raises(ValueError, match="max_level"):
+            handle.set_level("ERROR")
+
+    def test_set_level_after_remove_raises(self, tmp_path: Path) -> None:
+        """Test that a removed handle cannot be re-leveled."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        handle = logger.add(tmp_path / "gone.log")
+        logger.remove(handle)
+
+        with pytest.raises(ValueError, match="removed"):
+            handle.set_level("INFO")
+        assert handle.stats() is None
+
+    def test_stats_match_handler_stats(self, tmp_path: Path) -> None:
+        """Test that handle.stats() returns the handler_stats() dict."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.remove()
+        handle = logger.add(tmp_path / "stats.log", format="{message}")
+
+        logger.info("abc")
+
+        assert handle.stats() == logger.handler_stats(handle)
+        stats = handle.stats()
+        assert stats is not None
+        assert stats["records"] == 1
+
+    def test_behaves_like_int_id(self, tmp_path: Path) -> None:
+        """Test that handles compare, hash and convert like the integer ID."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        handle = logger.add(tmp_path / "int.log")
+
+        assert handle == handle.id
+        assert int(handle) == handle.id
+        assert hash(handle) == hash(handle.id)
+        assert {handle.id: "x"}[handle] == "x"
+        assert repr(handle) == f"HandlerHandle(id={handle.id})"
+        assert logger.remove(handle) is True
+
+    def test_callback_handle(self) -> None:
+        """Test remove(), set_level() and stats() on callback handles."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.remove()
+        messages: list[str] = []
+        handle = logger.add_callback(lambda r: messages.append(r["message"]))
+
+        handle.set_level("WARNING")
+        logger.info("skipped")
+        logger.warning("seen")
+        stats = handle.stats()
+
+        assert messages == ["seen"]
+        assert stats is not None
+        assert stats["records"] == 1
+        assert stats["path"] is None
+        assert handle.remove() is True
+        logger.warning("after")
+        assert messages == ["seen"]
+
+    def test_callable_sink_remove_updates_bookkeeping(self) -> None:
+        """Test that removing a callable sink through its handle clears tracking."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.remove()
+        handle = logger.add(lambda msg: None, filter=lambda r: True)
+
+        assert handle.remove() is True
+        assert handle.id not in logger._collect_options
+        assert handle.id not in logger._callback_ids
+        assert handle.id not in logger._filter_ids
+
+
+class TestMaxLevel:
+    """Test the max_level handler option."""
+
+    def test_records_above_max_level_are_skipped(self, tmp_path: Path) -> None:
+        """Test that a handler only writes records within [level, max_level]."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.remove()
+        log_file = tmp_path / "info_only.log"
+        logger.add(log_file, format="{level} | {message}", level="INFO", max_level="SUCCESS")
+
+        logger.debug("debug")
+        logger.info("info")
+        logger.success("success")
+        logger.warning("warning")
+        logger.error("error")
+        logger.complete()
+
+        assert log_file.read_text().splitlines() == ["INFO | info", "SUCCESS | success"]
+
+    def test_max_level_below_level_is_rejected(self, tmp_path: Path) -> None:
+        """Test that max_level lower than level raises ValueError."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        with pytest.raises(ValueError, match="max_level"):
+            logger.add(tmp_path / "bad.log", level="ERROR", max_level="INFO")
+
+    def test_max_level_in_configure(self, tmp_path: Path) -> None:
+        """Test that configure() passes max_level through to the handler."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.remove()
+        log_file = tmp_path / "configured.log"
+        logger.configure(
+            handlers=[{"sink": str(log_file), "format": "{message}", "max_level": "INFO"}]
+        )
+
+        logger.info("kept")
+        logger.warning("dropped")
+        logger.complete()
+
+        assert log_file.read_text().splitlines() == ["kept"]
+
+
+class TestLevelMap:
+    """Test the per-handler level_map option."""
+
+    def test_demoted_level_only_affects_its_handler(self, tmp_path: Path) -> None:
+        """Test that DEBUG mapped to TRACE drops below one handler's threshold only."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.remove()
+        quiet = tmp_path / "quiet.log"
+        full = tmp_path / "full.log"
+        logger.add(quiet, format="{level} {message}", level="DEBUG", level_map={"DEBUG": "TRACE"})
+        logger.add(full, format="{level} {message}", level="DEBUG")
+
+        logger.debug("vendor noise")
+        logger.info("ours")
+        logger.complete()
+
+        assert quiet.read_text() == "INFO ours\n"
+        assert full.read_text() == "DEBUG vendor noise\nINFO ours\n"
+
+    def test_promoted_level_passes_cached_minimum(self, tmp_path: Path) -> None:
+        """Test that a level promoted into range is still dispatched and shown remapped."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.remove()
+        log_file = tmp_path / "promoted.log"
+        logger.add(
+            log_file,
+            format="{level} {message}",
+            level="INFO",
+            level_map={"TRACE": LogLevel.Warning},
+        )
+
+        logger.trace("promoted")
+        logger.debug("dropped")
+        logger.complete()
+
+        assert log_file.read_text() == "WARNING promoted\n"
+
+    def test_configure_and_invalid_level(self, tmp_path: Path) -> None:
+        """Test that configure() accepts level_map and unknown names are rejected."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.remove()
+        log_file = tmp_path / "configured.log"
+        logger.configure(
+            handlers=[
+                {
+                    "sink": log_file,
+                    "format": "{level}",
+                    "level": "INFO",
+                    "level_map": {"ERROR": "INFO"},
+                }
+            ]
+        )
+        logger.error("x")
+        logger.complete()
+        assert log_file.read_text() == "INFO\n"
+
+        with pytest.raises(ValueError, match="Invalid log level"):
+            logger.add(tmp_path / "bad.log", level_map={"DEBUG": "LOUD"})
+
+
+class TestStaticFields:
+    """Test the per-handler static_fields option."""
+
+    def test_fields_lead_json_records_with_their_types(self, tmp_path: Path) -> None:
+        """Test that static fields are top-level, typed and only on their handler."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.remove()
+        versioned = tmp_path / "versioned.json"
+        plain = tmp_path / "plain.json"
+        logger.add(versioned, serialize=True, static_fields={"_v": 1, "service": "api"})
+        logger.add(plain, serialize=True)
+
+        logger.info("hello", user="alice")
+        logger.complete()
+
+        line = versioned.read_text()
+        assert line.startswith('{"_v":1,"service":"api","time":')
+        record = json.loads(line)
+        assert record["message"] == "hello"
+        assert record["extra"] == {"user": "alice"}
+        assert "_v" not in json.loads(plain.read_text())
+
+    def test_wrapped_text_and_callable_sinks(self, tmp_path: Path) -> None:
+        """Test wrapped JSON and serialized callable sinks; text output ignores the fields."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.remove()
+        wrapped = tmp_path / "wrapped.json"
+        text = tmp_path / "text.log"
+        messages: list[str] = []
+        logger.add(wrapped, serialize_wrapped=True, static_fields={"_v": 2})
+        logger.add(text, format="{message}", static_fields={"_v": 2})
+        logger.add(messages.append, serialize=True, static_fields={"_v": 2})
+
+        logger.info("hello")
+        logger.complete()
+
+        assert json.loads(wrapped.read_text())["_v"] == 2
+        assert text.read_text() == "hello\n"
+        assert json.loads(messages[0])["_v"] == 2
+
+    def test_reserved_keys_are_rejected(self, tmp_path: Path) -> None:
+        """Test that keys the JSON output already writes are rejected for every sink type."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.remove()
+
+        with pytest.raises(ValueError, match="already a JSON record field"):
+            logger.add(tmp_path / "bad.json", serialize=True, static_fields={"level": 1})
+        with pytest.raises(ValueError, match="already a JSON record field"):
+            logger.add(lambda _: None, serialize=True, static_fields={"message": "x"})
+        with pytest.raises(ValueError, match="already a JSON record field"):
+            logger.configure(handlers=[{"sink": sys.stderr, "static_fields": {"time": 0}}])
+
+
+class TestLogfmt:
+    """Test the per-handler logfmt option."""
+
+    def test_record_is_key_value_pairs(self, tmp_path: Path) -> None:
+        """Test field order, lowercase level and quoting of values."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.remove()
+        log_file = tmp_path / "app.logfmt"
+        logger.add(log_file, logfmt=True, static_fields={"_v": 1})
+
+        logger.bind(user="alice").warning('disk "almost" full', path="/var/log")
+        logger.complete()
+
+        line = log_file.read_text()
+        assert line.startswith('_v=1 ts="')
+        assert ' level=warning msg="disk \\"almost\\" full" ' in line
+        assert line.endswith(" path=/var/log user=alice\n")
+
+    def test_exception_is_quoted_on_one_line(self, tmp_path: Path) -> None:
+        """Test that a traceback stays on the record's line."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.remove()
+        log_file = tmp_path / "app.logfmt"
+        logger.configure(handlers=[{"sink": str(log_file), "logfmt": True}])
+
+        try:
+            raise ValueError("boom")
+        except ValueError:
+            logger.exception("failed")
+        logger.complete()
+
+        lines = log_file.read_text().splitlines()
+        assert len(lines) == 1
+        assert lines[0].endswith('ValueError: boom"')
+        assert ' exception="Traceback (most recent call last):\\n' in lines[0]
+
+
+class TestCsv:
+    """Test the per-handler csv and csv_header options."""
+
+    def test_rows_are_quoted_with_header_once(self, tmp_path: Path) -> None:
+        """Test the column set, RFC 4180 quoting and a single header per file."""
+        log_file = tmp_path / "ops.csv"
+        for message in ("Job done, 3 files", "second run"):
+            logger = Logger(PyLogger(LogLevel.Trace))
+            logger.remove()
+            logger.add(log_file, csv=True, csv_header=True)
+            logger.info(message, job="nightly")
+            logger.complete()
+            logger.remove()
+
+        rows = list(csv.reader(log_file.read_text().splitlines()))
+        assert rows[0] == ["time", "level", "name", "line", "message", "extra"]
+        assert len(rows) == 3
+        assert rows[1][1:2] + rows[1][4:] == ["INFO", "Job done, 3 files", '{"job":"nightly"}']
+        assert rows[2][4] == "second run"
+        assert rows[1][3].isdigit()
+
+    def test_header_requires_csv(self, tmp_path: Path) -> None:
+        """Test that csv_header alone is rejected."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.remove()
+
+        with pytest.raises(ValueError, match="csv_header requires csv=True"):
+            logger.add(tmp_path / "bad.csv", csv_header=True)
+
+
+class TestMode:
+    """Test the append ("a") and truncate ("w") file modes."""
+
+    def test_write_mode_empties_existing_file(self, tmp_path: Path) -> None:
+        """Test that mode="w" drops earlier content and "a" keeps it."""
+        log_file = tmp_path / "dev.log"
+        log_file.write_text("previous run\n")
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.remove()
+
+        logger.add(log_file, mode="w", format="{message}")
+        logger.info("fresh")
+        logger.complete()
+        logger.remove()
+        assert log_file.read_text() == "fresh\n"
+
+        logger.add(log_file, format="{message}")
+        logger.info("appended")
+        logger.complete()
+        logger.remove()
+        assert log_file.read_text() == "fresh\nappended\n"
+
+    def test_invalid_mode(self, tmp_path: Path) -> None:
+        """Test that modes other than "a" and "w" are rejected."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.remove()
+
+        with pytest.raises(ValueError, match="Invalid mode"):
+            logger.add(tmp_path / "app.log", mode="r+")
+
+
+class TestOnError:
+    """Test on_error for files that can't be opened."""
+
+    def test_raise_by_default(self, tmp_path: Path) -> None:
+        blocker = tmp_path / "not-a-dir"
+        blocker.write_text("")
+        logger = Logger(PyLogger(LogLevel.Trace))
+        with pytest.raises(OSError):
+            logger.add(str(blocker / "app.log"))
+
+    def test_skip_warns_and_discards_records(self, tmp_path: Path) -> None:
+        blocker = tmp_path / "not-a-dir"
+        blocker.write_text("")
+        code = f"""
+from logust import Logger, LogLevel, PyLogger
+
+logger = Logger(PyLogger(LogLevel.Trace))
+logger.remove()
+handle = logger.add({str(blocker / "app.log")!r}, on_error="skip")
+logger.info("dropped")
+handle.remove()
+print("started")
+"""
+        result = subprocess.run([sys.executable, "-c", code], capture_output=True, text=True)
+        assert result.returncode == 0, result.stderr
+        assert result.stdout == "started\n"
+        assert "Skipping file handler for" in result.stderr
+        assert blocker.read_text() == ""
+
+    def test_invalid_policy(self, tmp_path: Path) -> None:
+        logger = Logger(PyLogger(LogLevel.Trace))
+        with pytest.raises(ValueError, match="Invalid on_error"):
+            logger.add(str(tmp_path / "app.log"), on_error="ignore")
+
+
+class TestIfExists:
+    """Test the if_exists option for file handlers on the same path."""
+
+    def test_ignore_returns_existing_handler(
+        self, tmp_path: Path, monkeypatch: pytest.MonkeyPatch
+    ) -> None:
+        """Test that ./app.log matches app.log and records are written once."""
+        monkeypatch.chdir(tmp_path)
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.remove()
+
+        first = logger.add("app.log")
+        second = logger.add("./app.log", if_exists="ignore", format="{message}!")
+        logger.info("once")
+        logger.complete()
+
+        assert second == first
+        assert (tmp_path / "app.log").read_text().count("once") == 1
+        assert "once!" not in (tmp_path / "app.log").read_text()
+        logger.remove()
+
+    def test_replace_and_error(self, tmp_path: Path) -> None:
+        """Test that replace swaps the handler and error raises ValueError."""
+        log_file = tmp_path / "app.log"
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.remove()
+
+        first = logger.add(log_file, format="old {message}")
+        second = logger.add(str(log_file), if_exists="replace", format="new {message}")
+        logger.info("hello")
+        logger.complete()
+
+        assert second != first
+        assert not first.remove()
+        assert log_file.read_text() == "new hello\n"
+        with pytest.raises(ValueError, match="already exists"):
+            logger.add(log_file, if_exists="error")
+        with pytest.raises(ValueError, match="Invalid if_exists"):
+            logger.add(tmp_path / "other.log", if_exists="skip")
+        logger.add(tmp_path / "other.log", if_exists="error")
+        logger.remove()
+
+    def test_has_file_handler(self, tmp_path: Path, monkeypatch: pytest.MonkeyPatch) -> None:
+        """Test that file handlers are found by any spelling of their path."""
+        monkeypatch.chdir(tmp_path)
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.remove()
+        logger.add(sys.stderr)
+
+        assert not logger.has_file_handler("app.log")
+        assert logger.file_handler_id("app.log") is None
+        handler_id = logger.add("app.log")
+        assert logger.has_file_handler(tmp_path / "app.log")
+        assert logger.file_handler_id("./app.log") == handler_id
+        assert not logger.has_file_handler("other.log")
+
+        logger.remove(handler_id)
+        assert not logger.has_file_handler("app.log")
+        logger.remove()
+
+
+class TestSaveConfig:
+    """Test save_config()/restore_config()."""
+
+    def test_restore_brings_back_handlers_and_levels(self, tmp_path: Path) -> None:
+        """Test that handlers added later are dropped and the saved ones write again."""
+        main_file = tmp_path / "main.log"
+        temp_file = tmp_path / "temp.log"
+        seen: list[str] = []
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.remove()
+        main = logger.add(main_file, mode="w", format="{message}")
+        logger.add(seen.append, format="{message}")
+        logger.info("before")
+
+        saved = logger.save_config()
+        logger.remove()
+        logger.add(temp_file, format="{message}")
+        logger.level("SNAPSHOT_ONLY", no=33)
+        logger.log("SNAPSHOT_ONLY", "temporary")
+
+        logger.restore_config(saved)
+        logger.info("after")
+        logger.complete()
+        with pytest.raises(ValueError):
+            logger.log("SNAPSHOT_ONLY", "gone")
+
+        assert main_file.read_text() == "before\nafter\n"
+        assert temp_file.read_text() == "temporary\n"
+        assert seen == ["before", "after"]
+        assert logger.handler_stats(main) is not None
+
+        logger.remove()
+        logger.restore_config(saved)
+        logger.info("again")
+        logger.complete()
+        assert main_file.read_text() == "before\nafter\nagain\n"
+        logger.remove()
+
+
+
+class TestRateLimit:
+    """Test the per-handler rate_limit option."""
+
+    def test_records_over_limit_are_suppressed(self, tmp_path: Path) -> None:
+        """Test that only the burst is written and the rest is counted."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.remove()
+        log_file = tmp_path / "limited.log"
+        handler_id = logger.add(log_file, format="{message}", rate_limit="5/hour")
+
+        for i in range(20):
+            logger.info(f"Message {i}")
+        logger.complete()
+
+        assert log_file.read_text().splitlines() == [f"Message {i}" for i in range(5)]
+        assert logger.suppressed(handler_id) == 15
+
+    def test_limit_is_per_handler(self, tmp_path: Path) -> None:
+        """Test that a limited handler does not affect other handlers."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.remove()
+        limited = tmp_path / "limited.log"
+        unlimited = tmp_path / "unlimited.log"
+        limited_id = logger.add(limited, format="{message}", rate_limit="2/min")
+        unlimited_id = logger.add(unlimited, format="{message}")
+
+        for i in range(10):
+            logger.info(f"Message {i}")
+        logger.complete()
+
+        assert len(limited.read_text().splitlines()) == 2
+        assert len(unlimited.read_text().splitlines()) == 10
+        assert logger.suppressed(limited_id) == 8
+        assert logger.suppressed(unlimited_id) == 0
+        assert logger.suppressed() == 8
+
+    def test_custom_level_honors_limit(self, tmp_path: Path) -> None:
+        """Test that custom-level records go through the limiter."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.remove()
+        logger.level("NOTICE", no=22)
+        log_file = tmp_path / "custom.log"
+        handler_id = logger.add(log_file, format="{message}", rate_limit="3/hour")
+
+        for i in range(6):
+            logger.log("NOTICE", f"Notice {i}")
+        logger.complete()
+
+        assert len(log_file.read_text().splitlines()) == 3
+        assert logger.suppressed(handler_id) == 3
+
+    def test_skipped_records_do_not_use_tokens(self, tmp_path: Path) -> None:
+        """Test that records below the level or rejected by the filter keep their tokens."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.remove()
+        below_level = tmp_path / "level.log"
+        filtered = tmp_path / "filtered.log"
+        logger.add(below_level, level="INFO", format="{message}", rate_limit="2/hour")
+        logger.add(
+            filtered,
+            format="{message}",
+            filter=lambda record: "keep" in record["message"],
+            rate_limit="2/hour",
+        )
+
+        for i in range(10):
+            logger.debug(f"debug {i}")
+        logger.info("keep 1")
+        logger.info("keep 2")
+        logger.complete()
+
+        assert below_level.read_text().splitlines() == ["keep 1", "keep 2"]
+        assert filtered.read_text().splitlines() == ["keep 1", "keep 2"]
+        assert logger.suppressed() == 0
+
+    def test_console_and_configure_accept_rate_limit(
+        self, tmp_path: Path, capsys: pytest.CaptureFixture[str]
+    ) -> None:
+        """Test that console sinks and configure() accept rate_limit."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.remove()
+        log_file = tmp_path / "configured.log"
+        [handler_id] = logger.configure(
+            handlers=[{"sink": log_file, "format": "{message}", "rate_limit": "1/s"}]
+        )
+        console_id = logger.add(sys.stderr, format="{message}", rate_limit="1/s")
+
+        logger.info("first")
+        logger.info("second")
+        logger.complete()
+
+        assert log_file.read_text().splitlines() == ["first"]
+        assert logger.suppressed(handler_id) == 1
+        assert logger.suppressed(console_id) == 1
+
+    @pytest.mark.parametrize("spec", ["100", "0/s", "ten/s", "5/day"])
+    def test_invalid_rate_limit(self, tmp_path: Path, spec: str) -> None:
+        """Test that malformed limits are rejected."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.remove()
+
+        with pytest.raises(ValueError, match="Invalid rate_limit"):
+            logger.add(tmp_path / "bad.log", rate_limit=spec)
+
+
+class TestDedupe:
+    """Test collapsing repeated messages with dedupe=True."""
+
+    def test_repeats_are_summarized_on_change(self, tmp_path: Path) -> None:
+        """Test that a summary is written before the next different message."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.remove()
+        log_file = tmp_path / "dedupe.log"
+        logger.add(log_file, format="{level} | {message}", dedupe=True)
+
+        for _ in range(5):
+            logger.warning("retrying")
+        logger.info("connected")
+        logger.info("connected")
+        logger.info("done")
+        logger.complete()
+
+        assert log_file.read_text().splitlines() == [
+            "WARNING | retrying",
+            "WARNING | Last message repeated 4 times",
+            "INFO | connected",
+            "INFO | Last message repeated 1 time",
+            "INFO | done",
+        ]
+
+    def test_complete_flushes_pending_summary(self, tmp_path: Path) -> None:
+        """Test that complete() writes the summary for a run that is still going."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.remove()
+        log_file = tmp_path / "dedupe.log"
+        logger.add(log_file, format="{message}", dedupe=True, enqueue=True)
+
+        for _ in range(3):
+            logger.info("same")
+        logger.complete()
+        assert log_file.read_text().splitlines() == ["same", "Last message repeated 2 times"]
+
+        # The run continues after the flush; only new repeats are counted
+        logger.info("same")
+        logger.complete()
+        assert log_file.read_text().splitlines()[-1] == "Last message repeated 1 time"
+
+    def test_remove_flushes_pending_summary(self, tmp_path: Path) -> None:
+        """Test that removing the handler writes the pending summary."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.remove()
+        log_file = tmp_path / "dedupe.log"
+        handler_id = logger.add(log_file, format="{message}", dedupe=True)
+
+        logger.info("same")
+        logger.info("same")
+        logger.remove(handler_id)
+
+        assert log_file.read_text().splitlines() == ["same", "Last message repeated 1 time"]
+
+    def test_different_levels_are_not_collapsed(self, tmp_path: Path) -> None:
+        """Test that the same message at another level counts as new."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.remove()
+        logger.level("NOTICE", no=22)
+        log_file = tmp_path / "dedupe.log"
+        logger.add(log_file, format="{level} | {message}", dedupe=True)
+
+        logger.info("msg")
+        logger.log("NOTICE", "msg")
+        logger.log("NOTICE", "msg")
+        logger.warning("msg")
+        logger.complete()
+
+        assert log_file.read_text().splitlines() == [
+            "INFO | msg",
+            "NOTICE | msg",
+            "NOTICE | Last message repeated 1 time",
+            "WARNING | msg",
+        ]
+
+    def test_summary_is_formatted_as_json(self, tmp_path: Path) -> None:
+        """Test that the summary goes through the handler's formatting."""
+        import json
+
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.remove()
+        log_file = tmp_path / "dedupe.json"
+        logger.configure(handlers=[{"sink": log_file, "serialize": True, "dedupe": True}])
+
+        logger.bind(job="sync").error("failed")
+        logger.bind(job="sync").error("failed")
+        logger.complete()
+
+        summary = json.loads(log_file.read_text().splitlines()[-1])
+        assert summary["message"] == "Last message repeated 1 time"
+        assert summary["level"] == "ERROR"
+        assert summary["extra"] == {"job": "sync"}
+
+    def test_repeats_do_not_use_rate_limit_tokens(self, tmp_path: Path) -> None:
+        """Test that collapsed repeats are not counted against rate_limit."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.remove()
+        log_file = tmp_path / "dedupe.log"
+        handler_id = logger.add(log_file, format="{message}", dedupe=True, rate_limit="2/hour")
+
+        for _ in range(10):
+            logger.info("same")
+        logger.info("other")
+        logger.complete()
+
+        assert log_file.read_text().splitlines() == [
+            "same",
+            "Last message repeated 9 times",
+            "other",
+        ]
+        assert logger.suppressed(handler_id) == 0
+
 
 class TestComplete:
     """Test complete() flush behavior."""
@@ -198,6 +1520,62 @@ class TestComplete:
         assert "Message 0" in content
         assert "Message 99" in content
 
+    def test_complete_waits_for_enqueued_writes(self, tmp_path: Path) -> None:
+        """Test that complete() blocks until the async writer drains its queue."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.remove()
+        log_file = tmp_path / "async.log"
+        logger.add(log_file, format="{message}", enqueue=True)
+
+        for i in range(20_000):
+            logger.info(f"Message {i}")
+        logger.complete()
+
+        lines = log_file.read_text().splitlines()
+        assert len(lines) == 20_000
+        assert lines[-1] == "Message 19999"
+
+    def test_complete_while_other_thread_logs(self, tmp_path: Path) -> None:
+        """Test that complete() does not block other threads from logging."""
+        import threading
+
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.remove()
+        log_file = tmp_path / "async.log"
+        logger.add(log_file, format="{message}", enqueue=True)
+
+        def worker() -> None:
+            for i in range(2_000):
+                logger.info(f"worker {i}")
+
+        thread = threading.Thread(target=worker)
+        thread.start()
+        for _ in range(20):
+            logger.complete()
+        thread.join()
+        logger.complete()
+
+        lines = log_file.read_text().splitlines()
+        assert len(lines) == 2_000
+
+    def test_complete_single_handler(self, tmp_path: Path) -> None:
+        """Test that complete(handler_id) drains that handler and reports unknown ids."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.remove()
+        target = tmp_path / "target.log"
+        handle = logger.add(target, format="{message}", enqueue=True)
+        logger.add(tmp_path / "other.log", format="{message}", enqueue=True)
+        sink_handle = logger.add(lambda _: None)
+
+        for i in range(5_000):
+            logger.info(f"Message {i}")
+
+        assert logger.complete(handle) is True
+        assert target.read_text().splitlines()[-1] == "Message 4999"
+        assert logger.complete(sink_handle.id) is True
+        assert logger.complete(10**12) is False
+        assert logger.complete() is True
+
 
 class TestFilter:
     """Test handler filter functions."""
@@ -213,3 +1591,38 @@ class TestFilter:
         content = log_file.read_text()
         assert "Regular message" in content
         assert "Important message" in content
+
+
+@pytest.mark.skipif(not hasattr(socket, "AF_UNIX"), reason="journald needs Unix sockets")
+class TestJournald:
+    """Test add_journald() native journal entries."""
+
+    def test_entry_fields(self, tmp_path: Path) -> None:
+        """Test that a record becomes one datagram of journal fields."""
+        socket_path = tmp_path / "j.sock"
+        journal = socket.socket(socket.AF_UNIX, socket.SOCK_DGRAM)
+        journal.bind(str(socket_path))
+        journal.settimeout(5)
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.remove()
+        handle = logger.add_journald("myapp", level="INFO", socket_path=socket_path)
+
+        logger.debug("skipped")
+        logger.bind(request_id="abc").warning("Slow response")
+        entry = journal.recv(4096).decode()
+        journal.close()
+
+        fields = dict(line.split("=", 1) for line in entry.splitlines())
+        assert fields["MESSAGE"] == "Slow response"
+        assert fields["PRIORITY"] == "4"
+        assert fields["SYSLOG_IDENTIFIER"] == "myapp"
+        assert fields["REQUEST_ID"] == "abc"
+        assert fields["CODE_FUNC"] == "test_entry_fields"
+        assert fields["CODE_FILE"].endswith("test_handlers.py")
+        assert logger.handler_stats(handle)["records"] == 1
+
+    def test_missing_socket_raises(self, tmp_path: Path) -> None:
+        """Test that an unreachable journal raises OSError."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        with pytest.raises(OSError):
+            logger.add_journald("myapp", socket_path=tmp_path / "missing.sock")
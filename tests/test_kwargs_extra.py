@@ -2,6 +2,11 @@
 
 from __future__ import annotations
 
+import json
+import subprocess
+import sys
+from datetime import datetime, timezone
+from pathlib import Path
 from typing import Any
 
 import pytest
@@ -247,3 +252,134 @@ def test_all_levels_support_kwargs_extra_smoke() -> None:
         assert record["level"] == level
         assert record["message"] == f"{value} {value}"
         assert record["extra"] == {"marker": value}
+
+
+def test_extra_dict_applies_to_one_record_only() -> None:
+    logger, records = make_logger()
+
+    logger.info("done", extra={"duration_ms": 42})
+    logger.info("next")
+
+    assert [r["extra"] for r in records] == [{"duration_ms": "42"}, {}]
+
+
+def test_extra_dict_merges_over_bound_context() -> None:
+    logger, records = make_logger()
+
+    logger.bind(a=1, b=1).info("m", extra={"b": 2, "c": 3})
+
+    assert records[0]["extra"] == {"a": "1", "b": "2", "c": "3"}
+
+
+def test_format_kwargs_override_extra_dict() -> None:
+    logger, records = make_logger()
+
+    logger.info("{n} items", n=3, extra={"k": 1, "n": 0}, k=2)
+
+    assert records[0]["message"] == "3 items"
+    assert records[0]["extra"] == {"k": "2", "n": "0"}
+
+
+def test_extra_dict_does_not_format_message() -> None:
+    logger, records = make_logger()
+    extra = {"x": 1}
+
+    logger.warning("literal {x}", extra=extra)
+
+    assert records[0]["message"] == "literal {x}"
+    assert records[0]["extra"] == {"x": "1"}
+    assert extra == {"x": 1}
+
+
+def test_extra_dict_with_log_and_custom_level() -> None:
+    logger, records = make_logger()
+    logger.level("AUDIT", no=22)
+
+    logger.log("AUDIT", "custom", extra={"actor": "alice"})
+    logger.log("ERROR", "builtin", extra={"code": 7})
+
+    assert [(r["level"], r["extra"]) for r in records] == [
+        ("AUDIT", {"actor": "alice"}),
+        ("ERROR", {"code": "7"}),
+    ]
+
+
+def test_extra_dict_keeps_json_types_in_file_sink(tmp_path: Path) -> None:
+    logger = Logger(PyLogger(LogLevel.Trace))
+    logger.remove()
+    log_file = tmp_path / "extra.json"
+    logger.add(log_file, serialize=True)
+
+    logger.info("done", extra={"duration_ms": 42, "ok": True})
+    logger.complete()
+
+    record = json.loads(log_file.read_text())
+    assert record["extra"] == {"duration_ms": 42, "ok": True}
+
+
+def test_timestamp_overrides_record_time(tmp_path: Path) -> None:
+    logger, records = make_logger()
+    logger.level("AUDIT", no=22)
+    log_file = tmp_path / "replay.log"
+    logger.add(log_file, format="{time} {message}", utc=True)
+    replayed = datetime(2024, 5, 1, 12, 30, tzinfo=timezone.utc)
+
+    logger.info("millis", timestamp=int(replayed.timestamp() * 1000))
+    logger.warning("iso", timestamp="2024-05-01T12:30:00Z")
+    logger.log("AUDIT", "datetime", timestamp=replayed)
+    logger.info("now")
+    logger.complete()
+
+    times = [datetime.fromisoformat(r["timestamp"]) for r in records]
+    assert times[:3] == [replayed] * 3
+    assert times[3] > replayed
+    assert log_file.read_text().splitlines()[:3] == [
+        "2024-05-01 12:30:00.000 millis",
+        "2024-05-01 12:30:00.000 iso",
+        "2024-05-01 12:30:00.000 datetime",
+    ]
+
+
+def test_invalid_timestamp_is_rejected() -> None:
+    logger, _ = make_logger()
+    with pytest.raises(ValueError, match="Invalid timestamp"):
+        logger.info("bad", timestamp="yesterday")
+    with pytest.raises(TypeError, match="timestamp must be"):
+        logger.info("bad", timestamp=1.5)  # type: ignore[arg-type]
+
+
+def test_end_replaces_line_terminator(tmp_path: Path) -> None:
+    logger, _ = make_logger()
+    log_file = tmp_path / "progress.log"
+    handler_id = logger.add(log_file, format="{message}")
+
+    logger.info("working", end="")
+    for _ in range(3):
+        logger.info(".", end="")
+    logger.log("INFO", " done", end="!\n")
+    logger.info("next")
+    logger.complete()
+
+    assert log_file.read_text() == "working... done!\nnext\n"
+    stats = logger.handler_stats(handler_id)
+    assert stats is not None
+    assert stats["size"] == log_file.stat().st_size
+
+
+def test_end_on_console() -> None:
+    code = """
+import sys
+from logust import Logger, LogLevel
+from logust._logust import PyLogger
+logger = Logger(PyLogger(LogLevel.Info))
+logger.remove()
+logger.add(sys.stdout, format="{message}")
+logger.add(sys.stderr, format="{message}", enqueue=True)
+logger.info("working", end="")
+logger.info("...", end=" ")
+logger.info("done")
+"""
+    result = subprocess.run([sys.executable, "-c", code], capture_output=True, text=True)
+    assert result.returncode == 0, result.stderr
+    assert result.stdout == "working... done\n"
+    assert result.stderr == "working... done\n"
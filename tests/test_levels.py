@@ -2,8 +2,12 @@
 
 from __future__ import annotations
 
+import subprocess
+import sys
 from pathlib import Path
 
+import pytest
+
 from logust import Logger, LogLevel
 from logust._logust import PyLogger
 
@@ -40,7 +44,7 @@ class TestCustomLevels:
         """Test registering and using a custom level."""
         logger, log_file = logger_with_file
 
-        logger.level("NOTICE", no=25, color="cyan")
+        logger.level("NOTICE", no=22, color="cyan")
 
         logger.log("NOTICE", "Custom notice message")
         logger.complete()
@@ -59,6 +63,40 @@ class TestCustomLevels:
         content = log_file.read_text()
         assert "Alert message" in content
 
+    def test_duplicate_number_rejected(self) -> None:
+        """Test that a second custom level can't take a used number without overwrite."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.remove()
+        logger.level("DUP_FIRST", no=71)
+        with pytest.raises(ValueError, match="Level number 71 is already used by DUP_FIRST"):
+            logger.level("DUP_SECOND", no=71)
+        logger.level("dup_first", no=71, color="red")
+
+        logger.level("DUP_SECOND", no=71, overwrite=True)
+        records: list[dict] = []
+        logger.add_callback(records.append)
+        logger.log(71, "by number")
+        assert records[0]["level"] == "DUP_SECOND"
+
+    def test_builtin_number_or_name_rejected(self) -> None:
+        """Test that built-in level numbers and names can't be registered, even with overwrite."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.remove()
+        with pytest.raises(ValueError, match="Level number 20 is already used by INFO"):
+            logger.level("NOTICE20", no=20)
+        with pytest.raises(ValueError, match="WARNING is a built-in level"):
+            logger.level("warning", no=31)
+        with pytest.raises(ValueError, match="Level number 20 is already used by INFO"):
+            logger.level("NOTICE20", no=20, overwrite=True)
+        with pytest.raises(ValueError, match="INFO is a built-in level"):
+            logger.level("INFO", no=63, overwrite=True)
+
+        records: list[dict] = []
+        logger.add_callback(records.append)
+        logger.log(20, "by number")
+        logger.log_batch(20, ["batched"])
+        assert [record["level"] for record in records] == ["INFO", "INFO"]
+
     def test_custom_emit_no_above_builtin_range_callable_needs_caller(self) -> None:
         """Severity ``no`` above 50: Python must still pre-collect for ``{function}`` sinks."""
         inner = PyLogger(LogLevel.Trace)
@@ -82,6 +120,70 @@ class TestCustomLevels:
         assert "emit_audit" in out[0]
 
 
+class TestLevelColors:
+    """Test set_level_color()."""
+
+    def test_builtin_and_custom_colors(self) -> None:
+        """Test that recolored levels use the new ANSI color on colored consoles."""
+        code = (
+            "import sys\n"
+            "from logust import logger\n"
+            "logger.remove()\n"
+            "logger.add(sys.stdout, colorize=True, format='{level}')\n"
+            "logger.level('NOTICE', no=22, color='cyan')\n"
+            "logger.set_level_color('debug', 'bright_black')\n"
+            "logger.set_level_color('NOTICE', 'magenta')\n"
+            "logger.debug('a')\n"
+            "logger.log('DEBUG', 'b')\n"
+            "logger.log('NOTICE', 'c')\n"
+        )
+        result = subprocess.run([sys.executable, "-c", code], capture_output=True, text=True)
+        lines = result.stdout.splitlines()
+        assert len(lines) == 3, result.stderr
+        assert lines[:2] == ["\x1b[1;90mDEBUG\x1b[0m"] * 2
+        assert lines[2] == "\x1b[1;35mNOTICE\x1b[0m"
+
+    def test_unknown_level_or_color(self) -> None:
+        """Test that unknown names raise ValueError."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        with pytest.raises(ValueError, match="Unknown color"):
+            logger.set_level_color("DEBUG", "mauve")
+        with pytest.raises(ValueError, match="Invalid log level"):
+            logger.set_level_color("NOPE", "red")
+
+
+class TestLevelIcons:
+    """Test the {icon} token and set_level_icon()."""
+
+    def test_icon_token(self, tmp_path: Path) -> None:
+        """Test built-in, custom and overridden icons in files and callable sinks."""
+        log_file = tmp_path / "icons.log"
+        seen: list[str] = []
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.remove()
+        logger.add(log_file, format="{icon}|{message}")
+        logger.add(seen.append, format="{icon}|{message}")
+        logger.level("ICON_NOTICE", no=26, icon="*")
+        logger.level("ICON_PLAIN", no=27)
+
+        logger.success("ok")
+        logger.log("ERROR", "bad")
+        logger.log("ICON_NOTICE", "notice")
+        logger.log("ICON_PLAIN", "plain")
+        logger.set_level_icon("warning", "!")
+        try:
+            logger.warning("careful")
+        finally:
+            logger.set_level_icon("WARNING", "\u26a0")
+        logger.complete()
+
+        expected = ["\u2713|ok", "\u2717|bad", "*|notice", "|plain", "!|careful"]
+        assert log_file.read_text().splitlines() == expected
+        assert seen == expected
+        with pytest.raises(ValueError, match="Invalid log level"):
+            logger.set_level_icon("NOPE", "?")
+
+
 class TestSetGetLevel:
     """Test set_level and get_level methods."""
 
@@ -101,6 +203,18 @@ class TestSetGetLevel:
         assert logger.is_level_enabled(LogLevel.Trace) is True
         assert logger.is_level_enabled(LogLevel.Info) is True
 
+    def test_min_level_name(self) -> None:
+        """Test that min_level_name follows handlers and callbacks."""
+        inner = PyLogger(LogLevel.Error)
+        assert inner.min_level_name == "ERROR"
+
+        callback = inner.add_callback(lambda record: None, LogLevel.Warning)
+        assert inner.min_level_name == "WARNING"
+
+        inner.remove()
+        callback.remove()
+        assert inner.min_level_name is None
+
 
 class TestIsLevelEnabled:
     """Test is_level_enabled method."""
@@ -156,6 +270,91 @@ class TestIsLevelEnabled:
         assert logger.is_level_enabled(LogLevel.Debug) is False
         assert logger.is_level_enabled(LogLevel.Error) is False
 
+    def test_custom_level_below_minimum_skipped(self, tmp_path: Path) -> None:
+        """Test that PyLogger.log drops custom levels below every handler's level."""
+        inner = PyLogger(LogLevel.Trace)
+        inner.remove()
+        log_file = tmp_path / "min.log"
+        inner.add(str(log_file), level=LogLevel.Warning, format="{level} {message}")
+        inner.level("QUIET_CUSTOM", 21)
+        inner.level("LOUD_CUSTOM", 41)
+
+        inner.log("QUIET_CUSTOM", "dropped")
+        inner.log("LOUD_CUSTOM", "kept")
+        inner.complete()
+
+        assert log_file.read_text() == "LOUD_CUSTOM kept\n"
+
+
+class TestGlobalLevel:
+    """Test the global level floor."""
+
+    def test_floor_applies_to_every_handler_and_callback(self, tmp_path: Path) -> None:
+        """Test that records below the floor reach no handler, callback or bound logger."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.remove()
+        log_file = tmp_path / "floor.log"
+        logger.add(str(log_file), level="DEBUG", format="{level} {message}")
+        records: list[dict] = []
+        logger.add_callback(records.append, level="TRACE")
+        logger.level("FLOOR_CUSTOM", no=29)
+        bound = logger.bind(job="x")
+
+        assert logger.get_global_level() is None
+        logger.set_global_level("warning")
+        assert bound.get_global_level() == LogLevel.Warning
+        assert logger.would_log(LogLevel.Info) is False
+
+        logger.info("dropped")
+        bound.debug("dropped")
+        logger.log("FLOOR_CUSTOM", "dropped")
+        logger.log_batch("INFO", ["dropped"])
+        logger.error("kept")
+        logger.set_global_level(None)
+        logger.debug("back")
+        logger.complete()
+
+        assert log_file.read_text() == "ERROR kept\nDEBUG back\n"
+        assert [r["message"] for r in records] == ["kept", "back"]
+
+
+class TestWouldLog:
+    """Test would_log() cached-min-level guard."""
+
+    def test_would_log_builtin_levels(self) -> None:
+        """Test would_log with built-in levels against the handler threshold."""
+        inner = PyLogger(LogLevel.Warning)
+        logger = Logger(inner)
+
+        assert logger.would_log(LogLevel.Info) is False
+        assert logger.would_log(LogLevel.Warning) is True
+        assert logger.would_log("ERROR") is True
+        assert logger.would_log("DEBUG") is False
+
+    def test_would_log_numeric_no(self) -> None:
+        """Test would_log with a numeric level number (custom levels)."""
+        inner = PyLogger(LogLevel.Info)
+        logger = Logger(inner)
+        logger.level("VERBOSE_WOULD_LOG", no=15)
+
+        assert logger.would_log(15) is False
+        assert logger.would_log(25) is True
+        assert logger.would_log("VERBOSE_WOULD_LOG") is False
+
+    def test_would_log_unknown_name(self) -> None:
+        """Test that unknown level names never log."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        assert logger.would_log("NOT_A_LEVEL") is False
+
+    def test_would_log_false_while_paused(self) -> None:
+        """Test that would_log reports False while the logger is paused."""
+        inner = PyLogger(LogLevel.Trace)
+        logger = Logger(inner)
+        logger.pause()
+        assert inner.would_log(LogLevel.Critical) is False
+        logger.resume()
+        assert inner.would_log(LogLevel.Critical) is True
+
 
 class TestEnableDisable:
     """Test enable and disable methods for console output."""
@@ -196,3 +395,50 @@ class TestEnableDisable:
 
         logger.enable("ERROR")
         assert logger.is_enabled() is True
+
+
+def _run_split_console(body: str) -> subprocess.CompletedProcess[str]:
+    code = "from logust import logger\nlogger.split_console(format='{level} | {message}')\n" + body
+    return subprocess.run([sys.executable, "-c", code], capture_output=True, text=True)
+
+
+class TestSplitConsole:
+    """Test split_console() routing records to stdout/stderr by level."""
+
+    def test_levels_are_split_at_warning(self) -> None:
+        """Test that records below WARNING go to stdout and the rest to stderr."""
+        result = _run_split_console(
+            "logger.info('info')\nlogger.success('success')\n"
+            "logger.warning('warning')\nlogger.error('error')\n"
+        )
+        assert result.stdout.splitlines() == ["INFO | info", "SUCCESS | success"]
+        assert result.stderr.splitlines() == ["WARNING | warning", "ERROR | error"]
+
+    def test_split_replaces_default_console(self) -> None:
+        """Test that the default console handler is replaced, not duplicated."""
+        inner = PyLogger(LogLevel.Trace)
+        logger = Logger(inner)
+        stdout_id, stderr_id = logger.split_console()
+
+        assert stdout_id != stderr_id
+        assert inner.handler_count == 2
+        assert "stdout" in repr(inner) and "stderr" in repr(inner)
+
+    def test_disable_enable_restores_pair(self) -> None:
+        """Test that enable() after disable() reinstalls both streams."""
+        result = _run_split_console(
+            "logger.disable()\nlogger.info('hidden')\nlogger.error('hidden')\n"
+            "logger.enable()\nlogger.info('info')\nlogger.error('error')\n"
+        )
+        # enable() reinstalls the pair with the default format
+        assert [line.rsplit(" - ", 1)[1] for line in result.stdout.splitlines()] == ["info"]
+        assert [line.rsplit(" - ", 1)[1] for line in result.stderr.splitlines()] == ["error"]
+
+    def test_set_level_keeps_stderr_at_warning(self) -> None:
+        """Test that lowering the level does not send DEBUG records to stderr."""
+        result = _run_split_console(
+            "logger.set_level('ERROR')\nlogger.info('hidden')\nlogger.warning('hidden')\n"
+            "logger.set_level('DEBUG')\nlogger.debug('debug')\nlogger.error('error')\n"
+        )
+        assert result.stdout.splitlines() == ["DEBUG | debug"]
+        assert result.stderr.splitlines() == ["ERROR | error"]
@@ -3,8 +3,12 @@
 from __future__ import annotations
 
 from pathlib import Path
+from typing import Any
 
-from logust import Logger
+import pytest
+
+from logust import Logger, LogLevel
+from logust._logust import PyLogger
 
 
 class TestLogLevels:
@@ -122,6 +126,53 @@ class TestException:
         assert "ERROR" in content
         assert "No exception here" in content
 
+    def test_error_exc_formats_exception_object(
+        self, logger_with_file: tuple[Logger, Path]
+    ) -> None:
+        """Test error_exc() logs a saved exception with its traceback and chained cause."""
+        logger, log_file = logger_with_file
+
+        try:
+            try:
+                raise KeyError("missing")
+            except KeyError as cause:
+                raise ValueError("Saved error") from cause
+        except ValueError as e:
+            saved = e
+
+        logger.error_exc("Deferred failure", saved)
+        logger.complete()
+        content = log_file.read_text()
+
+        assert "ERROR" in content
+        assert "Deferred failure" in content
+        assert "Traceback (most recent call last):" in content
+        assert "KeyError: 'missing'" in content
+        assert "direct cause of the following exception" in content
+        assert "ValueError: Saved error" in content
+
+    def test_level_exc_variants(self, logger_with_file: tuple[Logger, Path]) -> None:
+        """Test every *_exc variant logs at its own level."""
+        logger, log_file = logger_with_file
+        exc = RuntimeError("boom")
+
+        for name in ("trace", "debug", "info", "success", "warning", "error", "fail", "critical"):
+            getattr(logger, f"{name}_exc")(f"{name} variant", exc)
+        logger.complete()
+
+        lines = [line for line in log_file.read_text().splitlines() if "variant" in line]
+        assert len(lines) == 8
+        assert "WARNING" in lines[4]
+        assert "CRITICAL" in lines[7]
+        assert log_file.read_text().count("RuntimeError: boom") == 8
+
+    def test_exc_rejects_non_exception(self, logger_with_file: tuple[Logger, Path]) -> None:
+        """Test *_exc methods require an exception instance."""
+        logger, _ = logger_with_file
+
+        with pytest.raises(TypeError, match="expected an exception instance"):
+            logger.error_exc("Not an exception", "oops")  # type: ignore[arg-type]
+
 
 class TestGenericLog:
     """Test generic log() method."""
@@ -156,3 +207,306 @@ class TestGenericLog:
         assert "ERROR" in content
         assert "Error with trace" in content
         assert "Traceback here" in content
+
+
+class TestPauseResume:
+    """Test pause()/resume() suspending all logging."""
+
+    def test_pause_drops_records(self, logger_with_file: tuple[Logger, Path]) -> None:
+        """Test that records logged while paused are dropped."""
+        logger, log_file = logger_with_file
+        logger.pause()
+        try:
+            assert logger.is_paused()
+            logger.info("Muted message")
+            logger.log("ERROR", "Muted generic message")
+        finally:
+            logger.resume()
+        assert not logger.is_paused()
+        logger.info("Restored message")
+        logger.complete()
+
+        content = log_file.read_text()
+        assert "Muted" not in content
+        assert "Restored message" in content
+
+    def test_pause_shared_with_bound_logger(self, logger_with_file: tuple[Logger, Path]) -> None:
+        """Test that pausing the parent also mutes bind()-derived loggers."""
+        logger, log_file = logger_with_file
+        bound = logger.bind(request_id="abc")
+        logger.pause()
+        try:
+            assert bound.is_paused()
+            bound.info("Bound muted message")
+        finally:
+            logger.resume()
+        bound.info("Bound restored message")
+        logger.complete()
+
+        content = log_file.read_text()
+        assert "Bound muted message" not in content
+        assert "Bound restored message" in content
+
+
+class TestLogBatch:
+    """Test log_batch() writing many records per call."""
+
+    def test_log_batch_writes_in_order(self, logger_with_file: tuple[Logger, Path]) -> None:
+        """Test that every batched message is written in order."""
+        logger, log_file = logger_with_file
+        logger.log_batch("INFO", (f"Batch message {i:03d}" for i in range(50)))
+        logger.complete()
+
+        lines = [line for line in log_file.read_text().splitlines() if "Batch message" in line]
+        assert len(lines) == 50
+        assert all(f"Batch message {i:03d}" in line for i, line in enumerate(lines))
+        assert all("INFO" in line for line in lines)
+
+    def test_log_batch_below_threshold(self, logger_with_file: tuple[Logger, Path]) -> None:
+        """Test that a batch below every handler level is dropped."""
+        logger, log_file = logger_with_file
+        logger.remove()
+        logger.add(log_file, level=LogLevel.Error, enqueue=False)
+        logger.log_batch(20, ["Dropped batch message"])
+        logger.complete()
+
+        assert "Dropped batch message" not in log_file.read_text()
+
+    def test_log_batch_custom_level(self, logger_with_file: tuple[Logger, Path]) -> None:
+        """Test batches at a custom level."""
+        logger, log_file = logger_with_file
+        logger.level("BATCHY", no=22)
+        logger.log_batch("BATCHY", ["Custom one", "Custom two"])
+        logger.complete()
+
+        content = log_file.read_text()
+        assert "BATCHY" in content
+        assert "Custom one" in content
+        assert "Custom two" in content
+
+    def test_log_batch_invalid_level(self) -> None:
+        """Test that an unknown level raises ValueError."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.disable()
+        with pytest.raises(ValueError, match="Invalid log level"):
+            logger.log_batch("NOT_A_BATCH_LEVEL", ["x"])
+
+    def test_log_batch_callbacks_once_per_message(self) -> None:
+        """Test that callbacks see each batched record exactly once."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.disable()
+        seen: list[dict[str, Any]] = []
+        logger.add_callback(seen.append)
+        logger.log_batch("WARNING", ["a", "b", "c"])
+
+        assert [r["message"] for r in seen] == ["a", "b", "c"]
+        assert all(r["level"] == "WARNING" for r in seen)
+
+    def test_log_batch_caller_info(self, tmp_path: Path) -> None:
+        """Test that shared caller info points at the log_batch call site."""
+        log_file = tmp_path / "caller.log"
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.disable()
+        logger.add(log_file, format="{function} - {message}", enqueue=False)
+        logger.log_batch("INFO", ["first", "second"])
+        logger.complete()
+
+        content = log_file.read_text()
+        assert "test_log_batch_caller_info - first" in content
+        assert "test_log_batch_caller_info - second" in content
+
+    def test_log_batch_rotation_size_accounting(self, tmp_path: Path) -> None:
+        """Test that size rotation triggers mid-batch without losing records."""
+        log_file = tmp_path / "batch-rotation.log"
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.disable()
+        logger.add(log_file, format="{message}", rotation="1 KB", enqueue=False)
+        payload = "x" * 90
+        logger.log_batch("INFO", [f"{i:03d}|{payload}" for i in range(40)])
+        logger.complete()
+
+        files = sorted(tmp_path.glob("batch-rotation*.log"))
+        assert len(files) > 1
+        lines = [line for f in files for line in f.read_text().splitlines()]
+        assert sorted(lines) == [f"{i:03d}|{payload}" for i in range(40)]
+        # Rotation happens before the write that would start past the limit
+        line_size = len(f"000|{payload}\n")
+        assert all(f.stat().st_size < 1024 + line_size for f in files)
+
+
+class Unrenderable:
+    """Argument whose str() must never be called."""
+
+    def __str__(self) -> str:
+        raise AssertionError("argument was rendered for a disabled level")
+
+
+class TestLogFmt:
+    """Test log_fmt() lazy positional interpolation."""
+
+    def test_positional_fields(self, tmp_path: Path) -> None:
+        """Test that {} and {N} fields are filled from the arguments."""
+        log_file = tmp_path / "fmt.log"
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.disable()
+        logger.add(log_file, format="{level} | {message}")
+
+        logger.log_fmt("INFO", "user {} logged in from {}", 42, "10.0.0.1")
+        logger.log_fmt(30, "{1} before {0} {{braces}}", "a", "b")
+        logger.complete()
+
+        assert log_file.read_text().splitlines() == [
+            "INFO | user 42 logged in from 10.0.0.1",
+            "WARNING | b before a {braces}",
+        ]
+
+    def test_format_spec_falls_back_to_str_format(self) -> None:
+        """Test that fields Rust does not handle still follow str.format."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.disable()
+        seen: list[dict[str, Any]] = []
+        logger.add_callback(seen.append)
+
+        logger.log_fmt("INFO", "{:.2f} {!r}", 3.14159, "x")
+
+        assert seen[0]["message"] == "3.14 'x'"
+
+    def test_disabled_level_skips_rendering(self) -> None:
+        """Test that arguments are never converted when no handler accepts the level."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.remove()
+        seen: list[dict[str, Any]] = []
+        logger.add_callback(seen.append, level="WARNING")
+        logger.level("CHATTY", no=12)
+
+        logger.log_fmt("DEBUG", "value {}", Unrenderable())
+        logger.log_fmt("CHATTY", "value {}", Unrenderable())
+        logger._inner.log_fmt(LogLevel.Info, "value {}", (Unrenderable(),))
+
+        assert seen == []
+
+    def test_custom_level_extra_and_exception(self) -> None:
+        """Test custom levels with per-call extra and exception text."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.disable()
+        seen: list[dict[str, Any]] = []
+        logger.add_callback(seen.append)
+        logger.level("AUDIT", no=22)
+
+        logger.log_fmt("AUDIT", "{} changed {}", "alice", "password", extra={"ip": "10.0.0.1"})
+        logger.log_fmt("ERROR", "failed: {}", "db", exception="Traceback: boom")
+
+        assert seen[0]["level"] == "AUDIT"
+        assert seen[0]["message"] == "alice changed password"
+        assert seen[0]["extra"] == {"ip": "10.0.0.1"}
+        assert seen[1]["message"] == "failed: db"
+        assert seen[1]["exception"] == "Traceback: boom"
+
+    def test_caller_info_points_at_call_site(self, tmp_path: Path) -> None:
+        """Test that {function} is the function calling log_fmt()."""
+        log_file = tmp_path / "caller.log"
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.disable()
+        logger.add(log_file, format="{function} - {message}")
+
+        logger.log_fmt("INFO", "hello {}", "world")
+        logger.complete()
+
+        assert log_file.read_text().strip() == "test_caller_info_points_at_call_site - hello world"
+
+    def test_patchers_see_rendered_message(self) -> None:
+        """Test that patched loggers receive the rendered message."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.disable()
+        seen: list[dict[str, Any]] = []
+        logger.add_callback(seen.append)
+
+        def shout(record: dict[str, Any]) -> None:
+            record["message"] = record["message"].upper()
+
+        logger.patch(shout).log_fmt("INFO", "hello {}", "world")
+
+        assert seen[0]["message"] == "HELLO WORLD"
+
+    def test_invalid_level(self) -> None:
+        """Test that an unknown level raises ValueError."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.disable()
+        try:
+            logger.log_fmt("NOT_A_FMT_LEVEL", "x {}", 1)
+        except ValueError:
+            pass
+        else:
+            raise AssertionError("expected ValueError")
+
+
+class TestRepr:
+    """Test repr() summarizing the configured handlers."""
+
+    def test_repr_lists_handlers(self, tmp_path: Path) -> None:
+        """Test that console, file and callable sinks appear with their levels."""
+        logger = Logger(PyLogger(LogLevel.Debug))
+        log_file = tmp_path / "app.log"
+        logger.add(log_file, level="INFO")
+        logger.add(lambda msg: None, level="ERROR")
+
+        assert repr(logger) == (
+            f"<Logger handlers=[Console(stdout, level=DEBUG), "
+            f"File('{log_file}', level=INFO), Callback(level=ERROR)]>"
+        )
+
+    def test_repr_empty_and_paused(self) -> None:
+        """Test repr with no handlers and while paused."""
+        logger = Logger(PyLogger(LogLevel.Debug))
+        logger.remove()
+        assert repr(logger) == "<Logger handlers=[]>"
+
+        logger.pause()
+        assert repr(logger) == "<Logger handlers=[] paused>"
+
+
+class TestReset:
+    """Test reset() returning the logger to its initial state."""
+
+    def test_reset_restores_default_console(self, tmp_path: Path) -> None:
+        """Test that handlers, callbacks and pause state are cleared."""
+        logger = Logger(PyLogger(LogLevel.Info))
+        logger.add(tmp_path / "app.log")
+        logger.add(lambda msg: None)
+        records: list[dict[str, Any]] = []
+        logger.add_callback(records.append)
+        logger.pause()
+
+        logger.reset()
+
+        assert repr(logger) == "<Logger handlers=[Console(stdout, level=DEBUG)]>"
+        assert not logger.is_paused()
+        assert logger.callback_calls() == 0
+
+    def test_reset_clears_context_and_patchers(self, tmp_path: Path) -> None:
+        """Test that configured extra and patchers no longer apply after reset."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.configure(extra={"app": "demo"}, patcher=lambda r: r.update(message="patched"))
+
+        logger.reset()
+        logger.remove()
+        log_file = tmp_path / "after.log"
+        logger.add(log_file, format="{message}|{extra[app]}")
+        logger.info("plain")
+        logger.complete()
+
+        assert log_file.read_text() == "plain|\n"
+
+    def test_reset_allows_fresh_handlers(self, tmp_path: Path) -> None:
+        """Test that removed file handlers stop receiving records."""
+        logger = Logger(PyLogger(LogLevel.Trace))
+        logger.remove()
+        before = tmp_path / "before.log"
+        logger.add(before, format="{message}")
+        logger.info("one")
+
+        logger.reset()
+        logger.remove()
+        logger.info("two")
+
+        assert before.read_text() == "one\n"
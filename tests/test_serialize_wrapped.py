@@ -0,0 +1,159 @@
+"""Tests for loguru-style wrapped JSON output (serialize_wrapped)."""
+
+from __future__ import annotations
+
+import json
+import os
+import subprocess
+import sys
+import threading
+from pathlib import Path
+from typing import Any
+
+from logust._logger import Logger
+from logust._logust import LogLevel, PyLogger
+
+
+def _new_logger() -> Logger:
+    logger = Logger(PyLogger(LogLevel.Trace))
+    logger.disable()
+    return logger
+
+
+def _read_lines(log_file: Path) -> list[dict[str, Any]]:
+    return [json.loads(line) for line in log_file.read_text(encoding="utf-8").splitlines()]
+
+
+class TestSerializeWrapped:
+    """Tests for the {"text": ..., "record": {...}} JSON shape."""
+
+    def test_file_sink_emits_text_and_record(self, tmp_path: Path) -> None:
+        """Formatted text goes under "text", structured fields under "record"."""
+        logger = _new_logger()
+        log_file = tmp_path / "wrapped.json"
+        logger.add(log_file, format="{level} - {message}", serialize_wrapped=True)
+
+        logger.bind(user="alice").warning("disk low", free=3)
+        logger.complete()
+
+        [line] = _read_lines(log_file)
+        assert set(line) == {"text", "record"}
+        assert line["text"] == "WARNING - disk low"
+
+        record = line["record"]
+        assert sorted(record) == [
+            "elapsed",
+            "exception",
+            "extra",
+            "file",
+            "function",
+            "level",
+            "line",
+            "message",
+            "module",
+            "name",
+            "process",
+            "thread",
+            "time",
+        ]
+        assert record["message"] == "disk low"
+        assert record["level"] == {"icon": "\u26a0", "name": "WARNING", "no": 30}
+        assert record["extra"] == {"free": 3, "user": "alice"}
+        assert record["exception"] is None
+        assert record["function"] == "test_file_sink_emits_text_and_record"
+        assert record["file"]["name"] == "test_serialize_wrapped.py"
+        assert record["module"] == "test_serialize_wrapped"
+        assert record["line"] > 0
+        assert isinstance(record["time"]["timestamp"], float)
+        assert isinstance(record["elapsed"]["seconds"], float)
+
+    def test_record_includes_thread_and_process(self, tmp_path: Path) -> None:
+        """Thread and process fields are filled even when the format omits them."""
+        logger = _new_logger()
+        log_file = tmp_path / "wrapped.json"
+        logger.add(log_file, format="{message}", serialize_wrapped=True)
+
+        logger.info("hello")
+        logger.complete()
+
+        [line] = _read_lines(log_file)
+        assert line["record"]["process"]["id"] == os.getpid()
+        assert line["record"]["thread"]["name"] == threading.current_thread().name
+
+    def test_custom_level_icon_and_number(self, tmp_path: Path) -> None:
+        """Custom levels report their own number and icon."""
+        logger = _new_logger()
+        logger.level("NOTICE", no=22, icon="!")
+        log_file = tmp_path / "wrapped.json"
+        logger.add(log_file, format="{message}", serialize_wrapped=True)
+
+        logger.log("NOTICE", "custom")
+        logger.complete()
+
+        [line] = _read_lines(log_file)
+        assert line["record"]["level"] == {"icon": "!", "name": "NOTICE", "no": 22}
+
+    def test_exception_is_captured(self, tmp_path: Path) -> None:
+        """Exceptions appear under record.exception."""
+        logger = _new_logger()
+        log_file = tmp_path / "wrapped.json"
+        logger.add(log_file, format="{message}", serialize_wrapped=True)
+
+        try:
+            raise ValueError("boom")
+        except ValueError:
+            logger.exception("failed")
+        logger.complete()
+
+        [line] = _read_lines(log_file)
+        assert "ValueError: boom" in line["record"]["exception"]
+
+    def test_plain_serialize_stays_flat(self, tmp_path: Path) -> None:
+        """serialize=True keeps the flat JSON shape."""
+        logger = _new_logger()
+        log_file = tmp_path / "flat.json"
+        logger.add(log_file, serialize=True)
+
+        logger.info("flat")
+        logger.complete()
+
+        [line] = _read_lines(log_file)
+        assert "record" not in line
+        assert line["message"] == "flat"
+
+    def test_configure_accepts_serialize_wrapped(self, tmp_path: Path) -> None:
+        """configure() handler dicts accept serialize_wrapped."""
+        logger = _new_logger()
+        log_file = tmp_path / "wrapped.json"
+        logger.configure(
+            handlers=[{"sink": log_file, "format": "{message}", "serialize_wrapped": True}]
+        )
+
+        logger.info("configured")
+        logger.complete()
+
+        [line] = _read_lines(log_file)
+        assert line["text"] == "configured"
+        assert line["record"]["message"] == "configured"
+
+    def test_console_sink_has_no_ansi(self) -> None:
+        """Console wrapped JSON is plain even when colors are forced."""
+        code = """
+import sys
+from logust import logger
+logger.remove()
+logger.add(sys.stdout, format="{level} | {message}", serialize_wrapped=True)
+logger.info("console")
+"""
+        env = {k: v for k, v in os.environ.items() if k != "NO_COLOR"}
+        env["FORCE_COLOR"] = "1"
+        result = subprocess.run(
+            [sys.executable, "-c", code],
+            capture_output=True,
+            text=True,
+            env=env,
+        )
+        assert "\x1b[" not in result.stdout
+        line = json.loads(result.stdout.strip())
+        assert line["text"] == "INFO | console"
+        assert line["record"]["message"] == "console"
@@ -2,6 +2,8 @@
 
 from __future__ import annotations
 
+import os
+import threading
 from pathlib import Path
 from typing import Any
 from unittest.mock import patch
@@ -454,21 +456,27 @@ class TestConditionalInfoGathering:
             logger.info("Test message")
             mock_caller.assert_called_once()
 
-    def test_thread_info_skipped_when_not_needed(self, tmp_path: Path) -> None:
-        """_get_thread_info should not be called when not needed."""
+    def test_thread_info_uses_python_thread_name(self, tmp_path: Path) -> None:
+        """{thread} should render the Python thread name, not just the id."""
         inner = PyLogger(LogLevel.Trace)
         logger = Logger(inner)
         logger.remove()
 
-        log_file = tmp_path / "simple.log"
-        logger.add(str(log_file), format="{time} | {message}")
+        log_file = tmp_path / "thread.log"
+        logger.add(str(log_file), format="{thread} | {message}")
 
-        with patch("logust._logger._get_thread_info") as mock_thread:
-            logger.info("Test message")
-            mock_thread.assert_not_called()
+        logger.info("main")
+        worker = threading.Thread(target=lambda: logger.info("worker"), name="worker")
+        worker.start()
+        worker.join()
 
-    def test_thread_info_called_when_needed(self, tmp_path: Path) -> None:
-        """_get_thread_info should be called when needed."""
+        logger.complete()
+        lines = log_file.read_text().splitlines()
+        assert lines[0] == f"MainThread:{threading.get_ident()} | main"
+        assert lines[1] == f"worker:{worker.ident} | worker"
+
+    def test_thread_name_looked_up_once_per_thread(self, tmp_path: Path) -> None:
+        """The Python thread name is read on a thread's first record, then cached."""
         inner = PyLogger(LogLevel.Trace)
         logger = Logger(inner)
         logger.remove()
@@ -476,37 +484,60 @@ class TestConditionalInfoGathering:
         log_file = tmp_path / "thread.log"
         logger.add(str(log_file), format="{thread} | {message}")
 
-        with patch("logust._logger._get_thread_info") as mock_thread:
-            mock_thread.return_value = ("MainThread", 12345)
-            logger.info("Test message")
-            mock_thread.assert_called_once()
+        lookups: list[str] = []
+        current_thread = threading.current_thread
+
+        def counting_current_thread() -> threading.Thread:
+            thread = current_thread()
+            lookups.append(thread.name)
+            return thread
 
-    def test_process_info_skipped_when_not_needed(self, tmp_path: Path) -> None:
-        """_get_process_info should not be called when not needed."""
+        def log_three() -> None:
+            for i in range(3):
+                logger.info(str(i))
+
+        with patch("threading.current_thread", counting_current_thread):
+            worker = threading.Thread(target=log_three, name="cached")
+            worker.start()
+            worker.join()
+
+        logger.complete()
+        assert lookups.count("cached") == 1
+        assert log_file.read_text().splitlines() == [
+            f"cached:{worker.ident} | {i}" for i in range(3)
+        ]
+
+    def test_process_info_uses_python_process_name(self, tmp_path: Path) -> None:
+        """{process} should render the multiprocessing process name and pid."""
         inner = PyLogger(LogLevel.Trace)
         logger = Logger(inner)
         logger.remove()
 
-        log_file = tmp_path / "simple.log"
-        logger.add(str(log_file), format="{time} | {message}")
+        log_file = tmp_path / "process.log"
+        logger.add(str(log_file), format="{process} | {message}")
 
-        with patch("logust._logger._get_process_info") as mock_process:
-            logger.info("Test message")
-            mock_process.assert_not_called()
+        logger.info("Test message")
+
+        logger.complete()
+        assert log_file.read_text() == f"MainProcess:{os.getpid()} | Test message\n"
 
-    def test_process_info_called_when_needed(self, tmp_path: Path) -> None:
-        """_get_process_info should be called when needed."""
+    def test_thread_info_for_full_record_filter(self, tmp_path: Path) -> None:
+        """A filter receiving the whole record should see the Python thread name."""
         inner = PyLogger(LogLevel.Trace)
         logger = Logger(inner)
         logger.remove()
 
-        log_file = tmp_path / "process.log"
-        logger.add(str(log_file), format="{process} | {message}")
+        seen: list[str] = []
+        log_file = tmp_path / "filter.log"
+        logger.add(
+            str(log_file),
+            format="{message}",
+            filter=lambda record: seen.append(record["thread_name"]) is None,
+        )
 
-        with patch("logust._logger._get_process_info") as mock_process:
-            mock_process.return_value = ("MainProcess", 99999)
-            logger.info("Test message")
-            mock_process.assert_called_once()
+        logger.info("Test message")
+
+        assert seen == ["MainThread"]
 
 
 # ============================================================================
@@ -540,9 +571,9 @@ class TestCollectOptionsWithAdd:
         # Flush to ensure log is written
         logger.complete()
 
-        # Log should have empty function
+        # The empty function is dropped along with its separator
         content = log_file.read_text()
-        assert " | Test message" in content
+        assert content == "Test message\n"
 
     def test_collect_true_forces_caller(self, tmp_path: Path) -> None:
         """collect=CollectOptions(caller=True) should force caller collection."""
@@ -608,9 +639,7 @@ class TestCollectOptionsWithAdd:
             collect=CollectOptions(thread=ThreadInfo(name="WorkerThread", id=42)),
         )
 
-        with patch("logust._logger._get_thread_info") as mock_thread:
-            logger.info("Test message")
-            mock_thread.assert_not_called()
+        logger.info("Test message")
 
         logger.complete()
         content = log_file.read_text()
@@ -631,9 +660,7 @@ class TestCollectOptionsWithAdd:
             collect=CollectOptions(process=ProcessInfo(name="Worker", id=1234)),
         )
 
-        with patch("logust._logger._get_process_info") as mock_process:
-            logger.info("Test message")
-            mock_process.assert_not_called()
+        logger.info("Test message")
 
         logger.complete()
         content = log_file.read_text()
@@ -192,6 +192,52 @@ def test_string_passthrough_in_json(tmp_path: Path) -> None:
     assert record["extra"] == {"user": "alice", "path": "/api/v1"}
 
 
+def test_special_characters_round_trip_in_json(tmp_path: Path) -> None:
+    """Quotes, backslashes, newlines and control chars keep each record on one valid line."""
+    inner = PyLogger(LogLevel.Trace)
+    logger = Logger(inner)
+    logger.disable()
+    log_file = tmp_path / "escape.json"
+    logger.add(log_file, serialize=True)
+
+    messages = [
+        'say "hi"',
+        "C:\\path\\to\\file",
+        "line one\nline two\r\n",
+        "bell\x07 nul\x00 esc\x1b[31m",
+        '{"looks": "like json"}',
+    ]
+    for message in messages:
+        logger.bind(payload=message).info(message)
+    logger.complete()
+
+    lines = log_file.read_text(encoding="utf-8").splitlines()
+    assert len(lines) == len(messages)
+    for line, message in zip(lines, messages):
+        record = json.loads(line)
+        assert record["message"] == message
+        # JSON-like strings stay strings: encoded once, never re-parsed
+        assert record["extra"]["payload"] == message
+
+
+def test_json_string_extra_is_quoted_not_embedded(tmp_path: Path) -> None:
+    """A string holding JSON is written as a JSON string; a dict is written as an object."""
+    inner = PyLogger(LogLevel.Trace)
+    logger = Logger(inner)
+    logger.disable()
+    log_file = tmp_path / "json_string.json"
+    logger.add(log_file, serialize=True)
+
+    logger.info("hi", as_string='{"a":1}', as_dict={"a": 1})
+    logger.complete()
+
+    line = log_file.read_text(encoding="utf-8")
+    assert '"as_string":"{\\"a\\":1}"' in line
+    assert '"as_dict":{"a":1}' in line
+    record = json.loads(line)
+    assert record["extra"] == {"as_dict": {"a": 1}, "as_string": '{"a":1}'}
+
+
 def test_bool_distinct_from_int_in_json(tmp_path: Path) -> None:
     """``True`` must serialize as ``true``, not ``1`` (bool is subclass of int)."""
     inner = PyLogger(LogLevel.Trace)
@@ -668,3 +714,25 @@ def test_extra_format_token_renders_as_string(tmp_path: Path) -> None:
 
     text = log_file.read_text(encoding="utf-8").strip()
     assert text == "42|True|done"
+
+
+def test_nested_extra_fields(tmp_path: Path) -> None:
+    """A bound dict stays nested in JSON and ``{extra[user][id]}`` reads inside it."""
+    inner = PyLogger(LogLevel.Trace)
+    logger = Logger(inner)
+    logger.disable()
+    text_file = tmp_path / "nested.log"
+    json_file = tmp_path / "nested.json"
+    template = "{extra[user][name]}|{extra[user][roles][0]}|{extra[user][admin]}|{extra[user][x]}"
+    logger.add(text_file, format=template)
+    logger.add(json_file, serialize=True)
+    lines: list[str] = []
+    logger.add(lines.append, format=template)
+
+    user = {"id": 1, "name": "ada", "roles": ["owner"], "admin": True}
+    logger.bind(user=user).info("signed in")
+    logger.complete()
+
+    assert text_file.read_text(encoding="utf-8").strip() == "ada|owner|True|"
+    assert lines == ["ada|owner|True|"]
+    assert json.loads(json_file.read_text(encoding="utf-8"))["extra"]["user"] == user